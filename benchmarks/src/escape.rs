@@ -0,0 +1,63 @@
+use leptos::leptos_dom::ssr::{escape_attr, escape_text};
+use test::Bencher;
+
+// text and attribute values like those on a typical server-rendered page:
+// mostly plain, with the occasional character that needs to be escaped
+fn page_text() -> String {
+    [
+        "Welcome to our benchmark page.",
+        "Here's some introductory text, with a link to https://leptos.dev/.",
+        "Prices start at $5 & go up to $50 for <premium> plans.",
+        "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do \
+         eiusmod tempor incididunt ut labore et dolore magna aliqua.",
+        "“Quotes” and ünïcödé text don't slow things down.",
+    ]
+    .repeat(20)
+    .join(" ")
+}
+
+fn attr_values() -> Vec<&'static str> {
+    [
+        "btn btn-primary",
+        "/todos?filter=active&page=2",
+        "width: 100%; font-family: \"Inter\", sans-serif",
+        "todo-item completed",
+    ]
+    .repeat(25)
+}
+
+#[bench]
+fn escape_text_memchr(b: &mut Bencher) {
+    let text = page_text();
+    b.iter(|| escape_text(&text).len());
+}
+
+#[bench]
+fn escape_text_html_escape(b: &mut Bencher) {
+    let text = page_text();
+    b.iter(|| html_escape::encode_safe(&text).len());
+}
+
+#[bench]
+fn escape_attrs_memchr(b: &mut Bencher) {
+    let values = attr_values();
+    b.iter(|| {
+        values
+            .iter()
+            .map(|value| escape_attr(value).len())
+            .sum::<usize>()
+    });
+}
+
+#[bench]
+fn escape_attrs_html_escape(b: &mut Bencher) {
+    let values = attr_values();
+    b.iter(|| {
+        values
+            .iter()
+            .map(|value| {
+                html_escape::encode_double_quoted_attribute(value).len()
+            })
+            .sum::<usize>()
+    });
+}
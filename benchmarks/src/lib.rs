@@ -2,6 +2,7 @@
 
 extern crate test;
 
+mod escape;
 mod reactive;
 mod ssr;
 mod todomvc;
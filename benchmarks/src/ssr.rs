@@ -234,3 +234,48 @@ fn yew_ssr_bench(b: &mut Bencher) {
 		});
 	});
 }
+
+fn leptos_streaming_ssr(b: &mut Bencher, pooled: bool) {
+	use futures::StreamExt;
+	use leptos::*;
+
+	leptos::ssr::set_buffer_pool_enabled(pooled);
+	b.iter(|| {
+		tokio_test::block_on(async {
+			let chunks = leptos::ssr::render_to_stream(|| {
+				view! {
+					<main>
+						{(0..20)
+							.map(|i| {
+								view! {
+									<Suspense fallback=|| "Loading...">
+										<ul>
+											{(0..50)
+												.map(|j| view! { <li>{i} "." {j}</li> })
+												.collect_view()}
+										</ul>
+									</Suspense>
+								}
+							})
+							.collect_view()}
+					</main>
+				}
+				.into_view()
+			})
+			.collect::<Vec<_>>()
+			.await;
+			assert!(!chunks.is_empty());
+		});
+	});
+	leptos::ssr::set_buffer_pool_enabled(true);
+}
+
+#[bench]
+fn leptos_streaming_ssr_pooled_bench(b: &mut Bencher) {
+	leptos_streaming_ssr(b, true);
+}
+
+#[bench]
+fn leptos_streaming_ssr_unpooled_bench(b: &mut Bencher) {
+	leptos_streaming_ssr(b, false);
+}
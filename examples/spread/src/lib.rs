@@ -1,6 +1,6 @@
 use leptos::*;
 
-/// Demonstrates how attributes and event handlers can be spread onto elements.
+/// Demonstrates how attributes, classes and event handlers can be spread onto elements.
 #[component]
 pub fn SpreadingExample() -> impl IntoView {
     fn alert(msg: impl AsRef<str>) {
@@ -17,6 +17,7 @@ pub fn SpreadingExample() -> impl IntoView {
 
     let combined: Vec<Binding> = vec![
         ("data-foo", "123".into_attribute()).into(),
+        Binding::class("combined", true),
         EventHandlerFn::Click(Box::new(|_e: ev::MouseEvent| {
             alert("combined clicked");
         }))
@@ -20,12 +20,18 @@ use leptos::{
     ssr::render_to_stream_with_prefix_undisposed_with_context_and_block_replacement,
     *,
 };
-use leptos_integration_utils::{build_async_response, html_parts_separated};
+use leptos_integration_utils::{
+    build_async_response, html_parts_separated, HydrationScript,
+    HYDRATION_SCRIPTS_ENDPOINT,
+};
 use leptos_meta::*;
 use leptos_router::*;
 use parking_lot::RwLock;
 use regex::Regex;
-use server_fn::{redirect::REDIRECT_HEADER, request::actix::ActixRequest};
+use server_fn::{
+    client::REQUEST_ID_HEADER, redirect::REDIRECT_HEADER,
+    request::actix::ActixRequest,
+};
 use std::{
     fmt::{Debug, Display},
     future::Future,
@@ -34,6 +40,7 @@ use std::{
 };
 #[cfg(debug_assertions)]
 use tracing::instrument;
+use tracing::Instrument;
 /// This struct lets you define headers and override the status of the Response from an Element or a Server Function
 /// Typically contained inside of a ResponseOptions. Setting this is useful for cookies and custom responses.
 #[derive(Debug, Clone, Default)]
@@ -188,6 +195,7 @@ pub fn redirect(path: &str) {
 /// This function always provides context values including the following types:
 /// - [ResponseOptions]
 /// - [HttpRequest](actix_web::HttpRequest)
+/// - [RequestId](leptos::RequestId)
 #[tracing::instrument(level = "trace", fields(error), skip_all)]
 pub fn handle_server_fns() -> Route {
     handle_server_fns_with_context(|| {})
@@ -212,12 +220,15 @@ pub fn handle_server_fns() -> Route {
 /// This function always provides context values including the following types:
 /// - [ResponseOptions]
 /// - [HttpRequest](actix_web::HttpRequest)
+/// - [RequestId](leptos::RequestId)
 #[tracing::instrument(level = "trace", fields(error), skip_all)]
 pub fn handle_server_fns_with_context(
     additional_context: impl Fn() + 'static + Clone + Send,
 ) -> Route {
     web::to(move |req: HttpRequest, payload: Payload| {
         let additional_context = additional_context.clone();
+        let request_id = request_id_from_headers(&req);
+        let current_span = request_id.span();
         async move {
             let additional_context = additional_context.clone();
 
@@ -231,7 +242,9 @@ pub fn handle_server_fns_with_context(
                 additional_context();
                 provide_context(req.clone());
                 let res_parts = ResponseOptions::default();
+                provide_cookies(&req, &res_parts);
                 provide_context(res_parts.clone());
+                provide_request_id(request_id);
 
                 let mut res = service
                     .0
@@ -279,6 +292,7 @@ pub fn handle_server_fns_with_context(
                 ))
             }
         }
+        .instrument(current_span)
     })
 }
 
@@ -338,6 +352,7 @@ pub fn handle_server_fns_with_context(
 /// - [HttpRequest](actix_web::HttpRequest)
 /// - [MetaContext](leptos_meta::MetaContext)
 /// - [RouterIntegrationContext](leptos_router::RouterIntegrationContext)
+/// - [RequestId](leptos::RequestId)
 #[tracing::instrument(level = "trace", fields(error), skip_all)]
 pub fn render_app_to_stream<IV>(
     options: LeptosOptions,
@@ -407,6 +422,7 @@ where
 /// - [HttpRequest](actix_web::HttpRequest)
 /// - [MetaContext](leptos_meta::MetaContext)
 /// - [RouterIntegrationContext](leptos_router::RouterIntegrationContext)
+/// - [RequestId](leptos::RequestId)
 #[tracing::instrument(level = "trace", fields(error), skip_all)]
 pub fn render_app_to_stream_in_order<IV>(
     options: LeptosOptions,
@@ -474,6 +490,7 @@ where
 /// - [HttpRequest](actix_web::HttpRequest)
 /// - [MetaContext](leptos_meta::MetaContext)
 /// - [RouterIntegrationContext](leptos_router::RouterIntegrationContext)
+/// - [RequestId](leptos::RequestId)
 #[tracing::instrument(level = "trace", fields(error), skip_all)]
 pub fn render_app_async<IV>(
     options: LeptosOptions,
@@ -486,6 +503,66 @@ where
     render_app_async_with_context(options, || {}, app_fn, method)
 }
 
+/// Chooses the [`SsrMode`] used to render each request, overriding the mode
+/// of the route it matches.
+///
+/// Add it to the app with [`App::app_data`](actix_web::App::app_data), and it
+/// will be used by [`.leptos_routes()`](LeptosRoutes::leptos_routes). This is
+/// useful because some crawlers run no JavaScript, and so only see the
+/// fallbacks of any `<Suspense/>` that is streamed out of order.
+///
+/// ```rust,ignore
+/// App::new()
+///     .app_data(SsrModeSelector::async_for_bots())
+///     .leptos_routes(leptos_options.to_owned(), routes.to_owned(), App)
+/// ```
+#[derive(Clone)]
+#[allow(clippy::type_complexity)]
+pub struct SsrModeSelector(
+    Arc<dyn Fn(&HttpRequest, SsrMode) -> SsrMode + Send + Sync>,
+);
+
+impl SsrModeSelector {
+    /// Creates a selector from a function that receives the request and the
+    /// mode of the matched route, and returns the mode to render it with.
+    pub fn new(
+        select: impl Fn(&HttpRequest, SsrMode) -> SsrMode + Send + Sync + 'static,
+    ) -> Self {
+        Self(Arc::new(select))
+    }
+
+    /// Renders requests from bots, as recognized by their `User-Agent`, with
+    /// [`SsrMode::Async`], so they receive the complete HTML of the page.
+    /// Other requests use the mode of their route.
+    pub fn async_for_bots() -> Self {
+        Self::new(|req, mode| {
+            let is_bot = req
+                .headers()
+                .get(header::USER_AGENT)
+                .and_then(|ua| ua.to_str().ok())
+                .is_some_and(leptos_integration_utils::is_bot_user_agent);
+            if is_bot {
+                SsrMode::Async
+            } else {
+                mode
+            }
+        })
+    }
+
+    fn select(req: &HttpRequest, mode: SsrMode) -> SsrMode {
+        match req.app_data::<Self>() {
+            Some(selector) => (selector.0)(req, mode),
+            None => mode,
+        }
+    }
+}
+
+impl Debug for SsrModeSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SsrModeSelector").finish_non_exhaustive()
+    }
+}
+
 /// Returns an Actix [struct@Route] that listens for a `GET` request and tries
 /// to route it using [leptos_router], serving an HTML stream of your application.
 ///
@@ -498,6 +575,7 @@ where
 /// - [HttpRequest](actix_web::HttpRequest)
 /// - [MetaContext](leptos_meta::MetaContext)
 /// - [RouterIntegrationContext](leptos_router::RouterIntegrationContext)
+/// - [RequestId](leptos::RequestId)
 #[tracing::instrument(level = "trace", fields(error), skip_all)]
 pub fn render_app_to_stream_with_context<IV>(
     options: LeptosOptions,
@@ -534,6 +612,7 @@ where
 /// - [HttpRequest](actix_web::HttpRequest)
 /// - [MetaContext](leptos_meta::MetaContext)
 /// - [RouterIntegrationContext](leptos_router::RouterIntegrationContext)
+/// - [RequestId](leptos::RequestId)
 #[tracing::instrument(level = "trace", fields(error), skip_all)]
 pub fn render_app_to_stream_with_context_and_replace_blocks<IV>(
     options: LeptosOptions,
@@ -550,13 +629,15 @@ where
         let app_fn = app_fn.clone();
         let additional_context = additional_context.clone();
         let res_options = ResponseOptions::default();
+        let request_id = request_id_from_headers(&req);
+        let current_span = request_id.span();
 
         async move {
             let app = {
                 let app_fn = app_fn.clone();
                 let res_options = res_options.clone();
                 move || {
-                    provide_contexts(&req, res_options);
+                    provide_contexts(&req, res_options, request_id);
                     (app_fn)().into_view()
                 }
             };
@@ -570,6 +651,7 @@ where
             )
             .await
         }
+        .instrument(current_span)
     };
     match method {
         Method::Get => web::get().to(handler),
@@ -592,6 +674,7 @@ where
 /// - [HttpRequest](actix_web::HttpRequest)
 /// - [MetaContext](leptos_meta::MetaContext)
 /// - [RouterIntegrationContext](leptos_router::RouterIntegrationContext)
+/// - [RequestId](leptos::RequestId)
 #[tracing::instrument(level = "trace", fields(error), skip_all)]
 pub fn render_app_to_stream_in_order_with_context<IV>(
     options: LeptosOptions,
@@ -607,13 +690,15 @@ where
         let app_fn = app_fn.clone();
         let additional_context = additional_context.clone();
         let res_options = ResponseOptions::default();
+        let request_id = request_id_from_headers(&req);
+        let current_span = request_id.span();
 
         async move {
             let app = {
                 let app_fn = app_fn.clone();
                 let res_options = res_options.clone();
                 move || {
-                    provide_contexts(&req, res_options);
+                    provide_contexts(&req, res_options, request_id);
                     (app_fn)().into_view()
                 }
             };
@@ -621,6 +706,7 @@ where
             stream_app_in_order(&options, app, res_options, additional_context)
                 .await
         }
+        .instrument(current_span)
     };
     match method {
         Method::Get => web::get().to(handler),
@@ -644,6 +730,7 @@ where
 /// - [HttpRequest](actix_web::HttpRequest)
 /// - [MetaContext](leptos_meta::MetaContext)
 /// - [RouterIntegrationContext](leptos_router::RouterIntegrationContext)
+/// - [RequestId](leptos::RequestId)
 #[tracing::instrument(level = "trace", fields(error), skip_all)]
 pub fn render_app_async_with_context<IV>(
     options: LeptosOptions,
@@ -659,13 +746,15 @@ where
         let app_fn = app_fn.clone();
         let additional_context = additional_context.clone();
         let res_options = ResponseOptions::default();
+        let request_id = request_id_from_headers(&req);
+        let current_span = request_id.span();
 
         async move {
             let app = {
                 let app_fn = app_fn.clone();
                 let res_options = res_options.clone();
                 move || {
-                    provide_contexts(&req, res_options);
+                    provide_contexts(&req, res_options, request_id);
                     (app_fn)().into_view()
                 }
             };
@@ -678,6 +767,7 @@ where
             )
             .await
         }
+        .instrument(current_span)
     };
     match method {
         Method::Get => web::get().to(handler),
@@ -688,20 +778,289 @@ where
     }
 }
 
+// renders with the route's `mode`, unless an `SsrModeSelector` chooses another
+// one for the request
+fn render_app_with_mode<IV>(
+    options: LeptosOptions,
+    additional_context: impl Fn() + 'static + Clone + Send,
+    app_fn: impl Fn() -> IV + Clone + 'static,
+    method: Method,
+    mode: SsrMode,
+) -> Route
+where
+    IV: IntoView,
+{
+    let handler = move |req: HttpRequest| {
+        let options = options.clone();
+        let app_fn = app_fn.clone();
+        let additional_context = additional_context.clone();
+        let res_options = ResponseOptions::default();
+        let request_id = request_id_from_headers(&req);
+        let current_span = request_id.span();
+        let mode = SsrModeSelector::select(&req, mode);
+
+        async move {
+            let app = {
+                let app_fn = app_fn.clone();
+                let res_options = res_options.clone();
+                move || {
+                    provide_contexts(&req, res_options, request_id);
+                    (app_fn)().into_view()
+                }
+            };
+
+            match mode {
+                SsrMode::OutOfOrder | SsrMode::PartiallyBlocked => {
+                    stream_app(
+                        &options,
+                        app,
+                        res_options,
+                        additional_context,
+                        mode == SsrMode::PartiallyBlocked,
+                    )
+                    .await
+                }
+                SsrMode::InOrder => {
+                    stream_app_in_order(
+                        &options,
+                        app,
+                        res_options,
+                        additional_context,
+                    )
+                    .await
+                }
+                SsrMode::Async => {
+                    render_app_async_helper(
+                        &options,
+                        app,
+                        res_options,
+                        additional_context,
+                    )
+                    .await
+                }
+            }
+        }
+        .instrument(current_span)
+    };
+    match method {
+        Method::Get => web::get().to(handler),
+        Method::Post => web::post().to(handler),
+        Method::Put => web::put().to(handler),
+        Method::Delete => web::delete().to(handler),
+        Method::Patch => web::patch().to(handler),
+    }
+}
+
+/// Returns an Actix [struct@Route](actix_web::Route) that serves the
+/// [`HydrationScript`]s of the app, for pages rendered with
+/// [`inline_hydration_scripts`](LeptosOptions::inline_hydration_scripts) set
+/// to `false`. Each script is served at its
+/// [`file_name`](HydrationScript::file_name) under
+/// [`HYDRATION_SCRIPTS_ENDPOINT`]; any other path gets `404 Not Found`.
+///
+/// [`.leptos_routes_with_context`](LeptosRoutes::leptos_routes_with_context)
+/// registers this route at [`HYDRATION_SCRIPTS_ENDPOINT`].
 #[tracing::instrument(level = "trace", fields(error), skip_all)]
-fn provide_contexts(req: &HttpRequest, res_options: ResponseOptions) {
+pub fn serve_hydration_scripts(options: LeptosOptions) -> Route {
+    web::get().to(move |req: HttpRequest| {
+        let script = req
+            .match_info()
+            .get("file")
+            .and_then(HydrationScript::from_file_name);
+        let res = match script {
+            Some(script) => HttpResponse::Ok()
+                .content_type("text/javascript")
+                .body(script.source(&options).into_owned()),
+            None => HttpResponse::NotFound().finish(),
+        };
+        async move { res }
+    })
+}
+
+/// Returns a response that streams feature flag updates to the browser as
+/// server-sent events, for [`FeatureFlags::listen`](leptos::FeatureFlags::listen).
+/// Each item of `updates` is sent as one event, with the flags that changed.
+///
+/// ```no_run
+/// use actix_web::{web, App};
+/// use futures::stream;
+/// use leptos::FlagValues;
+/// use leptos_actix::stream_feature_flags;
+///
+/// let app = App::new().route(
+///     "/api/flags/stream",
+///     web::get().to(|| async {
+///         let mut flags = FlagValues::new();
+///         flags.insert("new-checkout".into(), true.into());
+///         stream_feature_flags(stream::iter([flags]))
+///     }),
+/// );
+/// ```
+pub fn stream_feature_flags(
+    updates: impl Stream<Item = FlagValues> + 'static,
+) -> HttpResponse {
+    let events = updates.map(|flags| {
+        Ok::<_, Error>(web::Bytes::from(flag_update_event(&flags)))
+    });
+    HttpResponse::Ok()
+        .content_type("text/event-stream")
+        .insert_header((header::CACHE_CONTROL, "no-cache"))
+        .streaming(events)
+}
+
+/// Returns an Actix [struct@Route](actix_web::Route) that re-renders a single
+/// [`Region`](leptos::Region) of the app, for use with
+/// [`refresh_region`](leptos::refresh_region).
+///
+/// It expects the `id` and `path` query parameters described in
+/// [`REGION_ENDPOINT`](leptos::REGION_ENDPOINT): the app is rendered at `path`,
+/// after all `async` [Resource](leptos::Resource)s have loaded, and only the
+/// HTML of the region with that ID is returned. If there is no such region,
+/// it responds with `404 Not Found`.
+///
+/// [`.leptos_routes_with_context`](LeptosRoutes::leptos_routes_with_context)
+/// registers this route at [`REGION_ENDPOINT`](leptos::REGION_ENDPOINT).
+///
+/// ## Provided Context Types
+/// This function always provides context values including the following types:
+/// - [ResponseOptions]
+/// - [HttpRequest](actix_web::HttpRequest)
+/// - [MetaContext](leptos_meta::MetaContext)
+/// - [RouterIntegrationContext](leptos_router::RouterIntegrationContext)
+/// - [RequestId](leptos::RequestId)
+#[tracing::instrument(level = "trace", fields(error), skip_all)]
+pub fn render_region_with_context<IV>(
+    additional_context: impl Fn() + 'static + Clone + Send,
+    app_fn: impl Fn() -> IV + Clone + 'static,
+) -> Route
+where
+    IV: IntoView,
+{
+    let handler = move |req: HttpRequest| {
+        let app_fn = app_fn.clone();
+        let additional_context = additional_context.clone();
+        let res_options = ResponseOptions::default();
+        let request_id = request_id_from_headers(&req);
+        let current_span = request_id.span();
+
+        async move {
+            let params = Url::try_from(leptos_corrected_path(&req).as_str())
+                .map(|url| url.search_params)
+                .unwrap_or_default();
+            let (Some(id), Some(path)) =
+                (params.get("id").cloned(), params.get("path").cloned())
+            else {
+                return HttpResponse::BadRequest()
+                    .body("expected `id` and `path` query parameters");
+            };
+
+            let app = {
+                let res_options = res_options.clone();
+                move || {
+                    provide_contexts(&req, res_options, request_id);
+                    // render the page the region is on, not the endpoint
+                    provide_context(RouterIntegrationContext::new(
+                        ServerIntegration {
+                            path: format!("http://leptos{path}"),
+                        },
+                    ));
+                    (app_fn)().into_view()
+                }
+            };
+
+            let (stream, runtime) =
+                leptos::ssr::render_to_stream_in_order_with_prefix_undisposed_with_context(
+                    app,
+                    move || "".into(),
+                    additional_context,
+                );
+            let html = stream.collect::<String>().await;
+            runtime.dispose();
+
+            let res_options = res_options.0.read();
+
+            let (status, headers) =
+                (res_options.status, res_options.headers.clone());
+
+            let mut res = match extract_region(&html, &id) {
+                Some(region) => {
+                    let mut res = HttpResponse::Ok()
+                        .content_type("text/html; charset=utf-8")
+                        .body(region.to_string());
+                    if let Some(status) = status {
+                        *res.status_mut() = status;
+                    }
+                    res
+                }
+                None => HttpResponse::NotFound().finish(),
+            };
+
+            // Add headers manipulated in the response
+            for (key, value) in headers.into_iter() {
+                res.headers_mut().append(key, value);
+            }
+
+            res
+        }
+        .instrument(current_span)
+    };
+    web::get().to(handler)
+}
+
+#[tracing::instrument(level = "trace", fields(error), skip_all)]
+fn provide_contexts(
+    req: &HttpRequest,
+    res_options: ResponseOptions,
+    request_id: RequestId,
+) {
     let path = leptos_corrected_path(req);
 
     let integration = ServerIntegration { path };
     provide_context(RouterIntegrationContext::new(integration));
     provide_context(MetaContext::new());
+    provide_cookies(req, &res_options);
+    provide_client_hints(ClientHints::from_headers(|name| {
+        req.headers()
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+    }));
     provide_context(res_options);
     provide_context(req.clone());
+    provide_request_id(request_id);
+    provide_request_scope(RequestScope::new());
     provide_server_redirect(redirect);
     #[cfg(feature = "nonce")]
     leptos::nonce::provide_nonce();
 }
 
+/// Lets [`use_cookie`] read the cookies of the request, and set cookies with
+/// `Set-Cookie` headers in the [`ResponseOptions`].
+fn provide_cookies(req: &HttpRequest, res_options: &ResponseOptions) {
+    let cookies = req
+        .headers()
+        .get_all(header::COOKIE)
+        .filter_map(|value| value.to_str().ok())
+        .collect::<Vec<_>>()
+        .join("; ");
+    let res_options = res_options.clone();
+    provide_server_cookies(cookies, move |cookie| match HeaderValue::from_str(
+        cookie,
+    ) {
+        Ok(value) => res_options.append_header(header::SET_COOKIE, value),
+        Err(e) => tracing::error!("invalid cookie {cookie}: {e}"),
+    });
+}
+
+/// Returns the [`RequestId`] sent in the [`REQUEST_ID_HEADER`] header, or a
+/// new one.
+fn request_id_from_headers(req: &HttpRequest) -> RequestId {
+    RequestId::from_header(
+        req.headers()
+            .get(REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok()),
+    )
+}
+
 fn leptos_corrected_path(req: &HttpRequest) -> String {
     let path = req.path();
     let query = req.query_string();
@@ -1229,6 +1588,21 @@ where
             router = router.route(path, handler);
         }
 
+        // register the endpoint that re-renders a single region
+        router = router.route(
+            REGION_ENDPOINT,
+            render_region_with_context(
+                additional_context.clone(),
+                app_fn.clone(),
+            ),
+        );
+
+        // register the endpoint that serves the hydration scripts
+        router = router.route(
+            &format!("{HYDRATION_SCRIPTS_ENDPOINT}/{{file}}"),
+            serve_hydration_scripts(options.clone()),
+        );
+
         // register routes defined in Leptos's Router
         for listing in paths.iter() {
             let path = listing.path();
@@ -1253,41 +1627,15 @@ where
                     )
                 } else {
                     router.route(
-                    path,
-                    match mode {
-                        SsrMode::OutOfOrder => {
-                            render_app_to_stream_with_context(
-                                options.clone(),
-                                additional_context_and_method.clone(),
-                                app_fn.clone(),
-                                method,
-                            )
-                        }
-                        SsrMode::PartiallyBlocked => {
-                            render_app_to_stream_with_context_and_replace_blocks(
-                                options.clone(),
-                                additional_context_and_method.clone(),
-                                app_fn.clone(),
-                                method,
-                                true,
-                            )
-                        }
-                        SsrMode::InOrder => {
-                            render_app_to_stream_in_order_with_context(
-                                options.clone(),
-                                additional_context_and_method.clone(),
-                                app_fn.clone(),
-                                method,
-                            )
-                        }
-                        SsrMode::Async => render_app_async_with_context(
+                        path,
+                        render_app_with_mode(
                             options.clone(),
                             additional_context_and_method.clone(),
                             app_fn.clone(),
                             method,
+                            mode,
                         ),
-                    },
-                )
+                    )
                 };
             }
         }
@@ -1332,6 +1680,21 @@ impl LeptosRoutes for &mut ServiceConfig {
             router = router.route(path, handler);
         }
 
+        // register the endpoint that re-renders a single region
+        router = router.route(
+            REGION_ENDPOINT,
+            render_region_with_context(
+                additional_context.clone(),
+                app_fn.clone(),
+            ),
+        );
+
+        // register the endpoint that serves the hydration scripts
+        router = router.route(
+            &format!("{HYDRATION_SCRIPTS_ENDPOINT}/{{file}}"),
+            serve_hydration_scripts(options.clone()),
+        );
+
         // register routes defined in Leptos's Router
         for listing in paths.iter() {
             let path = listing.path();
@@ -1340,39 +1703,13 @@ impl LeptosRoutes for &mut ServiceConfig {
             for method in listing.methods() {
                 router = router.route(
                     path,
-                    match mode {
-                        SsrMode::OutOfOrder => {
-                            render_app_to_stream_with_context(
-                                options.clone(),
-                                additional_context.clone(),
-                                app_fn.clone(),
-                                method,
-                            )
-                        }
-                        SsrMode::PartiallyBlocked => {
-                            render_app_to_stream_with_context_and_replace_blocks(
-                                options.clone(),
-                                additional_context.clone(),
-                                app_fn.clone(),
-                                method,
-                                true,
-                            )
-                        }
-                        SsrMode::InOrder => {
-                            render_app_to_stream_in_order_with_context(
-                                options.clone(),
-                                additional_context.clone(),
-                                app_fn.clone(),
-                                method,
-                            )
-                        }
-                        SsrMode::Async => render_app_async_with_context(
-                            options.clone(),
-                            additional_context.clone(),
-                            app_fn.clone(),
-                            method,
-                        ),
-                    },
+                    render_app_with_mode(
+                        options.clone(),
+                        additional_context.clone(),
+                        app_fn.clone(),
+                        method,
+                        mode,
+                    ),
                 );
             }
         }
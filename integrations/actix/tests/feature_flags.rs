@@ -0,0 +1,23 @@
+use actix_web::body::to_bytes;
+use futures::stream;
+use leptos::FlagValues;
+use leptos_actix::stream_feature_flags;
+
+#[actix_web::test]
+async fn streams_each_update_as_an_event() {
+    let mut first = FlagValues::new();
+    first.insert("new-checkout".into(), true.into());
+    let mut second = FlagValues::new();
+    second.insert("theme".into(), "dark".into());
+
+    let res = stream_feature_flags(stream::iter([first, second]));
+    assert_eq!(
+        res.headers().get("content-type").unwrap(),
+        "text/event-stream"
+    );
+    let body = to_bytes(res.into_body()).await.ok().unwrap();
+    assert_eq!(
+        body,
+        "data: {\"new-checkout\":true}\n\ndata: {\"theme\":\"dark\"}\n\n"
+    );
+}
@@ -0,0 +1,50 @@
+use actix_web::{test, App};
+use leptos::*;
+use leptos_actix::{generate_route_list, LeptosRoutes, SsrModeSelector};
+use leptos_router::{Route, Router, Routes};
+
+#[component]
+fn SuspenseApp() -> impl IntoView {
+    let view = || {
+        let data = create_resource(|| (), |_| async { "Loaded".to_string() });
+        view! {
+            <Suspense fallback=|| "Loading">
+                {move || data.get()}
+            </Suspense>
+        }
+    };
+    view! {
+        <Router>
+            <Routes>
+                <Route path="/" view/>
+            </Routes>
+        </Router>
+    }
+}
+
+async fn render(user_agent: &str) -> String {
+    let options = LeptosOptions::builder().output_name("app").build();
+    let routes = generate_route_list(SuspenseApp);
+    let app = test::init_service(
+        App::new()
+            .app_data(SsrModeSelector::async_for_bots())
+            .leptos_routes(options, routes, SuspenseApp),
+    )
+    .await;
+    let req = test::TestRequest::get()
+        .uri("/")
+        .insert_header(("user-agent", user_agent))
+        .to_request();
+    let body = test::call_and_read_body(&app, req).await;
+    String::from_utf8(body.to_vec()).unwrap()
+}
+
+#[actix_web::test]
+async fn bots_receive_the_complete_page() {
+    let browser = render("Mozilla/5.0 (X11; Linux x86_64) Firefox/120.0").await;
+    assert!(browser.contains("Loading"));
+
+    let bot = render("Mozilla/5.0 (compatible; Googlebot/2.1)").await;
+    assert!(bot.contains("Loaded"));
+    assert!(!bot.contains("Loading"));
+}
@@ -49,12 +49,17 @@ use futures::{
     Future, SinkExt, Stream, StreamExt,
 };
 use leptos::{ssr::*, *};
-use leptos_integration_utils::{build_async_response, html_parts_separated};
+use leptos_integration_utils::{
+    build_async_response, html_parts_separated, HydrationScript,
+    HYDRATION_SCRIPTS_ENDPOINT,
+};
 use leptos_meta::{generate_head_metadata_separated, MetaContext};
 use leptos_router::*;
 use once_cell::sync::OnceCell;
 use parking_lot::RwLock;
-use server_fn::{error::NoCustomError, redirect::REDIRECT_HEADER};
+use server_fn::{
+    client::REQUEST_ID_HEADER, error::NoCustomError, redirect::REDIRECT_HEADER,
+};
 use std::{fmt::Debug, io, pin::Pin, sync::Arc, thread::available_parallelism};
 use tokio_util::task::LocalPoolHandle;
 use tracing::Instrument;
@@ -209,6 +214,7 @@ pub fn generate_request_and_parts(
 /// This function always provides context values including the following types:
 /// - [`Parts`]
 /// - [`ResponseOptions`]
+/// - [`RequestId`](leptos::RequestId)
 #[tracing::instrument(level = "trace", fields(error), skip_all)]
 pub async fn handle_server_fns(req: Request<Body>) -> impl IntoResponse {
     handle_server_fns_inner(|| {}, req).await
@@ -255,6 +261,7 @@ macro_rules! spawn_task {
 /// This function always provides context values including the following types:
 /// - [`Parts`]
 /// - [`ResponseOptions`]
+/// - [`RequestId`](leptos::RequestId)
 #[tracing::instrument(level = "trace", fields(error), skip_all)]
 pub async fn handle_server_fns_with_context(
     additional_context: impl Fn() + 'static + Clone + Send,
@@ -271,8 +278,10 @@ async fn handle_server_fns_inner(
 
     let (tx, rx) = futures::channel::oneshot::channel();
 
-    // capture current span to enable trace context propagation
-    let current_span = tracing::Span::current();
+    // create a span for the request within the current span, to enable trace
+    // context propagation
+    let request_id = request_id_from_headers(req.headers());
+    let current_span = request_id.span();
 
     spawn_task!(async move {
         // enter captured span for trace context propagation in spawned task
@@ -287,8 +296,11 @@ async fn handle_server_fns_inner(
             let runtime = create_runtime();
 
             additional_context();
+            let res_options = ResponseOptions::default();
+            provide_cookies(&parts, &res_options);
             provide_context(parts);
-            provide_context(ResponseOptions::default());
+            provide_context(res_options);
+            provide_request_id(request_id);
 
             // store Accepts and Referer in case we need them for redirect (below)
             let accepts_html = req
@@ -416,6 +428,7 @@ pub type PinnedHtmlStream =
 /// This function always provides context values including the following types:
 /// - [`Parts`]
 /// - [`ResponseOptions`]
+/// - [`RequestId`](leptos::RequestId)
 /// - [`MetaContext`](leptos_meta::MetaContext)
 /// - [`RouterIntegrationContext`](leptos_router::RouterIntegrationContext)
 #[tracing::instrument(level = "trace", fields(error), skip_all)]
@@ -434,6 +447,68 @@ where
     render_app_to_stream_with_context(options, || {}, app_fn)
 }
 
+/// Chooses the [`SsrMode`] used to render each request, overriding the mode
+/// of the route it matches.
+///
+/// Add it to the router as an [`Extension`](axum::Extension) after the
+/// Leptos routes, and it will be used by
+/// [`.leptos_routes()`](LeptosRoutes::leptos_routes) and [`render_route`].
+/// This is useful because some crawlers run no JavaScript, and so only see
+/// the fallbacks of any `<Suspense/>` that is streamed out of order.
+///
+/// ```rust,ignore
+/// let app = Router::new()
+///     .leptos_routes(&leptos_options, routes, App)
+///     .layer(Extension(SsrModeSelector::async_for_bots()))
+///     .with_state(leptos_options);
+/// ```
+#[derive(Clone)]
+#[allow(clippy::type_complexity)]
+pub struct SsrModeSelector(
+    Arc<dyn Fn(&Request<Body>, SsrMode) -> SsrMode + Send + Sync>,
+);
+
+impl SsrModeSelector {
+    /// Creates a selector from a function that receives the request and the
+    /// mode of the matched route, and returns the mode to render it with.
+    pub fn new(
+        select: impl Fn(&Request<Body>, SsrMode) -> SsrMode + Send + Sync + 'static,
+    ) -> Self {
+        Self(Arc::new(select))
+    }
+
+    /// Renders requests from bots, as recognized by their `User-Agent`, with
+    /// [`SsrMode::Async`], so they receive the complete HTML of the page.
+    /// Other requests use the mode of their route.
+    pub fn async_for_bots() -> Self {
+        Self::new(|req, mode| {
+            let is_bot = req
+                .headers()
+                .get(header::USER_AGENT)
+                .and_then(|ua| ua.to_str().ok())
+                .is_some_and(leptos_integration_utils::is_bot_user_agent);
+            if is_bot {
+                SsrMode::Async
+            } else {
+                mode
+            }
+        })
+    }
+
+    fn select(req: &Request<Body>, mode: SsrMode) -> SsrMode {
+        match req.extensions().get::<Self>() {
+            Some(selector) => (selector.0)(req, mode),
+            None => mode,
+        }
+    }
+}
+
+impl Debug for SsrModeSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SsrModeSelector").finish_non_exhaustive()
+    }
+}
+
 /// Returns an Axum [Handler](axum::handler::Handler) that listens for a `GET` request and tries
 /// to route it using [leptos_router], serving an HTML stream of your application.
 /// The difference between calling this and `render_app_to_stream_with_context()` is that this
@@ -507,6 +582,7 @@ where
 /// This function always provides context values including the following types:
 /// - [`Parts`]
 /// - [`ResponseOptions`]
+/// - [`RequestId`](leptos::RequestId)
 /// - [`MetaContext`](leptos_meta::MetaContext)
 /// - [`RouterIntegrationContext`](leptos_router::RouterIntegrationContext)
 #[tracing::instrument(level = "trace", fields(error), skip_all)]
@@ -548,6 +624,7 @@ where
 /// This function always provides context values including the following types:
 /// - [`Parts`]
 /// - [`ResponseOptions`]
+/// - [`RequestId`](leptos::RequestId)
 /// - [`MetaContext`](leptos_meta::MetaContext)
 /// - [`RouterIntegrationContext`](leptos_router::RouterIntegrationContext)
 #[tracing::instrument(level = "trace", fields(error), skip_all)]
@@ -632,7 +709,7 @@ where
                 )
             });
         // 3. Match listing mode against known, and choose function
-        match listing.mode() {
+        match SsrModeSelector::select(&req, listing.mode()) {
             SsrMode::OutOfOrder => ooo(req),
             SsrMode::PartiallyBlocked => pb(req),
             SsrMode::InOrder => io(req),
@@ -641,6 +718,52 @@ where
     }
 }
 
+// renders with the route's `mode`, unless an `SsrModeSelector` chooses another
+// one for the request
+fn render_app_with_mode<IV>(
+    options: LeptosOptions,
+    additional_context: impl Fn() + 'static + Clone + Send,
+    app_fn: impl Fn() -> IV + Clone + Send + 'static,
+    mode: SsrMode,
+) -> impl Fn(
+    Request<Body>,
+) -> Pin<Box<dyn Future<Output = Response<Body>> + Send + 'static>>
+       + Clone
+       + Send
+       + 'static
+where
+    IV: IntoView,
+{
+    let ooo = render_app_to_stream_with_context(
+        options.clone(),
+        additional_context.clone(),
+        app_fn.clone(),
+    );
+    let pb = render_app_to_stream_with_context_and_replace_blocks(
+        options.clone(),
+        additional_context.clone(),
+        app_fn.clone(),
+        true,
+    );
+    let io = render_app_to_stream_in_order_with_context(
+        options.clone(),
+        additional_context.clone(),
+        app_fn.clone(),
+    );
+    let asyn =
+        render_app_async_with_context(options, additional_context, app_fn);
+
+    move |req| match SsrModeSelector::select(&req, mode) {
+        SsrMode::OutOfOrder => ooo(req),
+        SsrMode::PartiallyBlocked => pb(req),
+        SsrMode::InOrder => io(req),
+        SsrMode::Async => {
+            let res = asyn(req);
+            Box::pin(async move { res.await.map(Body::from) })
+        }
+    }
+}
+
 /// Returns an Axum [Handler](axum::handler::Handler) that listens for a `GET` request and tries
 /// to route it using [leptos_router], serving an HTML stream of your application.
 ///
@@ -659,6 +782,7 @@ where
 /// This function always provides context values including the following types:
 /// - [`Parts`]
 /// - [`ResponseOptions`]
+/// - [`RequestId`](leptos::RequestId)
 /// - [`MetaContext`](leptos_meta::MetaContext)
 /// - [`RouterIntegrationContext`](leptos_router::RouterIntegrationContext)
 #[tracing::instrument(level = "trace", fields(error), skip_all)]
@@ -686,7 +810,8 @@ where
             let res_options3 = default_res_options.clone();
             let (tx, rx) = futures::channel::mpsc::channel(8);
 
-            let current_span = tracing::Span::current();
+            let request_id = request_id_from_headers(req.headers());
+            let current_span = request_id.span();
             spawn_task!(async move {
                 let app = {
                     // Need to get the path and query string of the Request
@@ -697,7 +822,12 @@ where
                     let full_path = format!("http://leptos.dev{path}");
                     let (_, req_parts) = generate_request_and_parts(req);
                     move || {
-                        provide_contexts(full_path, req_parts, default_res_options);
+                        provide_contexts(
+                            full_path,
+                            req_parts,
+                            default_res_options,
+                            request_id,
+                        );
                         app_fn().into_view()
                     }
                 };
@@ -820,6 +950,7 @@ async fn forward_stream(
 /// This function always provides context values including the following types:
 /// - [`Parts`]
 /// - [`ResponseOptions`]
+/// - [`RequestId`](leptos::RequestId)
 /// - [`MetaContext`](leptos_meta::MetaContext)
 /// - [`RouterIntegrationContext`](leptos_router::RouterIntegrationContext)
 #[tracing::instrument(level = "trace", fields(error), skip_all)]
@@ -854,13 +985,19 @@ where
                 let full_path = format!("http://leptos.dev{path}");
 
                 let (tx, rx) = futures::channel::mpsc::channel(8);
-                let current_span = tracing::Span::current();
+                let request_id = request_id_from_headers(req.headers());
+                let current_span = request_id.span();
                 spawn_task!(async move {
                     let app = {
                         let full_path = full_path.clone();
                         let (parts, _) = req.into_parts();
                         move || {
-                            provide_contexts(full_path, parts, default_res_options);
+                            provide_contexts(
+                                full_path,
+                                parts,
+                                default_res_options,
+                                request_id,
+                            );
                             app_fn().into_view()
                         }
                     };
@@ -888,17 +1025,56 @@ fn provide_contexts(
     path: String,
     parts: Parts,
     default_res_options: ResponseOptions,
+    request_id: RequestId,
 ) {
     let integration = ServerIntegration { path };
     provide_context(RouterIntegrationContext::new(integration));
     provide_context(MetaContext::new());
+    provide_cookies(&parts, &default_res_options);
+    provide_client_hints(ClientHints::from_headers(|name| {
+        parts
+            .headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+    }));
     provide_context(parts);
     provide_context(default_res_options);
+    provide_request_id(request_id);
+    provide_request_scope(RequestScope::new());
     provide_server_redirect(redirect);
     #[cfg(feature = "nonce")]
     leptos::nonce::provide_nonce();
 }
 
+/// Lets [`use_cookie`] read the cookies of the request, and set cookies with
+/// `Set-Cookie` headers in the [`ResponseOptions`].
+fn provide_cookies(parts: &Parts, res_options: &ResponseOptions) {
+    let cookies = parts
+        .headers
+        .get_all(header::COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .collect::<Vec<_>>()
+        .join("; ");
+    let res_options = res_options.clone();
+    provide_server_cookies(cookies, move |cookie| match HeaderValue::from_str(
+        cookie,
+    ) {
+        Ok(value) => res_options.append_header(header::SET_COOKIE, value),
+        Err(e) => tracing::error!("invalid cookie {cookie}: {e}"),
+    });
+}
+
+/// Returns the [`RequestId`] sent in the [`REQUEST_ID_HEADER`] header, or a
+/// new one.
+fn request_id_from_headers(headers: &HeaderMap) -> RequestId {
+    RequestId::from_header(
+        headers
+            .get(REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok()),
+    )
+}
+
 /// Returns an Axum [Handler](axum::handler::Handler) that listens for a `GET` request and tries
 /// to route it using [leptos_router], asynchronously rendering an HTML page after all
 /// `async` [Resource](leptos::Resource)s have loaded.
@@ -950,6 +1126,7 @@ fn provide_contexts(
 /// This function always provides context values including the following types:
 /// - [`Parts`]
 /// - [`ResponseOptions`]
+/// - [`RequestId`](leptos::RequestId)
 /// - [`MetaContext`](leptos_meta::MetaContext)
 /// - [`RouterIntegrationContext`](leptos_router::RouterIntegrationContext)
 #[tracing::instrument(level = "trace", fields(error), skip_all)]
@@ -992,6 +1169,7 @@ where
 /// This function always provides context values including the following types:
 /// - [`Parts`]
 /// - [`ResponseOptions`]
+/// - [`RequestId`](leptos::RequestId)
 /// - [`MetaContext`](leptos_meta::MetaContext)
 /// - [`RouterIntegrationContext`](leptos_router::RouterIntegrationContext)
 #[tracing::instrument(level = "trace", fields(error), skip_all)]
@@ -1026,6 +1204,8 @@ where
                 let full_path = format!("http://leptos.dev{path}");
 
                 let (tx, rx) = futures::channel::oneshot::channel();
+                let request_id = request_id_from_headers(req.headers());
+                let current_span = request_id.span();
                 spawn_task!(async move {
                     let app = {
                         let full_path = full_path.clone();
@@ -1035,6 +1215,7 @@ where
                                 full_path,
                                 req_parts,
                                 default_res_options,
+                                request_id,
                             );
                             app_fn().into_view()
                         }
@@ -1059,7 +1240,7 @@ where
                     *writable = new_res_parts;
 
                     _ = tx.send(html);
-                });
+                }.instrument(current_span));
 
                 let html = rx.await.expect("to complete HTML rendering");
 
@@ -1121,6 +1302,7 @@ where
 /// This function always provides context values including the following types:
 /// - [`Parts`]
 /// - [`ResponseOptions`]
+/// - [`RequestId`](leptos::RequestId)
 /// - [`MetaContext`](leptos_meta::MetaContext)
 /// - [`RouterIntegrationContext`](leptos_router::RouterIntegrationContext)
 #[tracing::instrument(level = "trace", fields(error), skip_all)]
@@ -1156,6 +1338,8 @@ where
 
                 let (tx, rx) = futures::channel::oneshot::channel();
 
+                let request_id = request_id_from_headers(req.headers());
+                let current_span = request_id.span();
                 spawn_task!(async move {
                     let app = {
                         let full_path = full_path.clone();
@@ -1165,6 +1349,7 @@ where
                                 full_path,
                                 req_parts,
                                 default_res_options,
+                                request_id,
                             );
                             app_fn().into_view()
                         }
@@ -1189,7 +1374,7 @@ where
                     *writable = new_res_parts;
 
                     _ = tx.send(html);
-                });
+                }.instrument(current_span));
 
                 let html = rx.await.expect("to complete HTML rendering");
 
@@ -1209,6 +1394,198 @@ where
     }
 }
 
+/// Returns an Axum [Handler](axum::handler::Handler) that serves the
+/// [`HydrationScript`]s of the app, for pages rendered with
+/// [`inline_hydration_scripts`](LeptosOptions::inline_hydration_scripts) set
+/// to `false`. Each script is served at its
+/// [`file_name`](HydrationScript::file_name) under
+/// [`HYDRATION_SCRIPTS_ENDPOINT`]; any other path gets `404 Not Found`.
+///
+/// [`.leptos_routes_with_context`](LeptosRoutes::leptos_routes_with_context)
+/// registers this handler at [`HYDRATION_SCRIPTS_ENDPOINT`].
+#[tracing::instrument(level = "trace", fields(error), skip_all)]
+pub fn serve_hydration_scripts(
+    options: LeptosOptions,
+) -> impl Fn(
+    Request<Body>,
+) -> Pin<Box<dyn Future<Output = Response<String>> + Send + 'static>>
+       + Clone
+       + Send
+       + 'static {
+    move |req: Request<Body>| {
+        let script = req
+            .uri()
+            .path()
+            .rsplit('/')
+            .next()
+            .and_then(HydrationScript::from_file_name);
+        let res = match script {
+            Some(script) => Response::builder()
+                .header(header::CONTENT_TYPE, "text/javascript")
+                .body(script.source(&options).into_owned()),
+            None => Response::builder()
+                .status(StatusCode::NOT_FOUND)
+                .body(String::new()),
+        };
+        Box::pin(async move { res.expect("valid response") })
+    }
+}
+
+/// Returns a response that streams feature flag updates to the browser as
+/// server-sent events, for [`FeatureFlags::listen`](leptos::FeatureFlags::listen).
+/// Each item of `updates` is sent as one event, with the flags that changed.
+///
+/// ```no_run
+/// use axum::{routing::get, Router};
+/// use futures::stream;
+/// use leptos::FlagValues;
+/// use leptos_axum::stream_feature_flags;
+///
+/// let app: Router = Router::new().route(
+///     "/api/flags/stream",
+///     get(|| async {
+///         let mut flags = FlagValues::new();
+///         flags.insert("new-checkout".into(), true.into());
+///         stream_feature_flags(stream::iter([flags]))
+///     }),
+/// );
+/// ```
+pub fn stream_feature_flags(
+    updates: impl Stream<Item = FlagValues> + Send + 'static,
+) -> Response<Body> {
+    let events =
+        updates.map(|flags| Ok::<_, io::Error>(flag_update_event(&flags)));
+    Response::builder()
+        .header(header::CONTENT_TYPE, "text/event-stream")
+        .header(header::CACHE_CONTROL, "no-cache")
+        .body(Body::from_stream(events))
+        .expect("valid response")
+}
+
+/// Returns an Axum [Handler](axum::handler::Handler) that re-renders a single
+/// [`Region`](leptos::Region) of the app, for use with
+/// [`refresh_region`](leptos::refresh_region).
+///
+/// It expects the `id` and `path` query parameters described in
+/// [`REGION_ENDPOINT`](leptos::REGION_ENDPOINT): the app is rendered at `path`,
+/// after all `async` [Resource](leptos::Resource)s have loaded, and only the
+/// HTML of the region with that ID is returned. If there is no such region,
+/// it responds with `404 Not Found`.
+///
+/// [`.leptos_routes_with_context`](LeptosRoutes::leptos_routes_with_context)
+/// registers this handler at [`REGION_ENDPOINT`](leptos::REGION_ENDPOINT).
+///
+/// ## Provided Context Types
+/// This function always provides context values including the following types:
+/// - [`Parts`]
+/// - [`ResponseOptions`]
+/// - [`RequestId`](leptos::RequestId)
+/// - [`MetaContext`](leptos_meta::MetaContext)
+/// - [`RouterIntegrationContext`](leptos_router::RouterIntegrationContext)
+#[tracing::instrument(level = "trace", fields(error), skip_all)]
+pub fn render_region_with_context<IV>(
+    additional_context: impl Fn() + 'static + Clone + Send,
+    app_fn: impl Fn() -> IV + Clone + Send + 'static,
+) -> impl Fn(
+    Request<Body>,
+) -> Pin<Box<dyn Future<Output = Response<String>> + Send + 'static>>
+       + Clone
+       + Send
+       + 'static
+where
+    IV: IntoView,
+{
+    move |req: Request<Body>| {
+        Box::pin({
+            let app_fn = app_fn.clone();
+            let add_context = additional_context.clone();
+            let default_res_options = ResponseOptions::default();
+            let res_options2 = default_res_options.clone();
+            let res_options3 = default_res_options.clone();
+
+            async move {
+                let query = req.uri().path_and_query().unwrap().as_str();
+                let params =
+                    Url::try_from(format!("http://leptos.dev{query}").as_str())
+                        .map(|url| url.search_params)
+                        .unwrap_or_default();
+                let (Some(id), Some(path)) =
+                    (params.get("id").cloned(), params.get("path").cloned())
+                else {
+                    let mut res = Response::new(
+                        "expected `id` and `path` query parameters".to_string(),
+                    );
+                    *res.status_mut() = StatusCode::BAD_REQUEST;
+                    return res;
+                };
+
+                let full_path = format!("http://leptos.dev{path}");
+
+                let (tx, rx) = futures::channel::oneshot::channel();
+
+                let request_id = request_id_from_headers(req.headers());
+                let current_span = request_id.span();
+                spawn_task!(async move {
+                    let app = {
+                        let (_, req_parts) = generate_request_and_parts(req);
+                        move || {
+                            provide_contexts(
+                                full_path,
+                                req_parts,
+                                default_res_options,
+                                request_id,
+                            );
+                            app_fn().into_view()
+                        }
+                    };
+
+                    let (stream, runtime) =
+                            render_to_stream_in_order_with_prefix_undisposed_with_context(
+                                app,
+                                || "".into(),
+                                add_context,
+                            );
+
+                    // Extract the value of ResponseOptions from here
+                    let res_options = use_context::<ResponseOptions>().unwrap();
+
+                    let html = stream.collect::<String>().await;
+                    runtime.dispose();
+
+                    let new_res_parts = res_options.0.read().clone();
+
+                    let mut writable = res_options2.0.write();
+                    *writable = new_res_parts;
+
+                    _ = tx.send(extract_region(&html, &id).map(String::from));
+                }.instrument(current_span));
+
+                let region = rx.await.expect("to complete HTML rendering");
+                let found = region.is_some();
+
+                let mut res = Response::new(region.unwrap_or_default());
+
+                let res_options = res_options3.0.read();
+
+                if let Some(status) = res_options.status {
+                    *res.status_mut() = status
+                }
+                if !found {
+                    *res.status_mut() = StatusCode::NOT_FOUND;
+                }
+                let mut res_headers = res_options.headers.clone();
+                res.headers_mut().extend(res_headers.drain());
+                res.headers_mut().insert(
+                    header::CONTENT_TYPE,
+                    HeaderValue::from_static("text/html; charset=utf-8"),
+                );
+
+                res
+            }
+        })
+    }
+}
+
 /// Generates a list of all routes defined in Leptos's Router in your app. We can then use this to automatically
 /// create routes in Axum's Router without having to use wildcard matching or fallbacks. Takes in your root app Element
 /// as an argument so it can walk you app tree. This version is tailored to generate Axum compatible paths.
@@ -1653,6 +2030,21 @@ where
             );
         }
 
+        // register the endpoint that re-renders a single region
+        router = router.route(
+            REGION_ENDPOINT,
+            get(render_region_with_context(
+                cx_with_state.clone(),
+                app_fn.clone(),
+            )),
+        );
+
+        // register the endpoint that serves the hydration scripts
+        router = router.route(
+            &format!("{HYDRATION_SCRIPTS_ENDPOINT}/:file"),
+            get(serve_hydration_scripts(LeptosOptions::from_ref(options))),
+        );
+
         // register router paths
         for listing in paths.iter() {
             let path = listing.path();
@@ -1685,68 +2077,22 @@ where
                         )
                     }
                 } else {
+                    let s = render_app_with_mode(
+                        LeptosOptions::from_ref(options),
+                        cx_with_state_and_method.clone(),
+                        app_fn.clone(),
+                        listing.mode(),
+                    );
                     router.route(
-                    path,
-                    match listing.mode() {
-                        SsrMode::OutOfOrder => {
-                            let s = render_app_to_stream_with_context(
-                                LeptosOptions::from_ref(options),
-                                cx_with_state_and_method.clone(),
-                                app_fn.clone(),
-                            );
-                            match method {
-                                leptos_router::Method::Get => get(s),
-                                leptos_router::Method::Post => post(s),
-                                leptos_router::Method::Put => put(s),
-                                leptos_router::Method::Delete => delete(s),
-                                leptos_router::Method::Patch => patch(s),
-                            }
-                        }
-                        SsrMode::PartiallyBlocked => {
-                            let s = render_app_to_stream_with_context_and_replace_blocks(
-                                LeptosOptions::from_ref(options),
-                                cx_with_state_and_method.clone(),
-                                app_fn.clone(),
-                                true
-                            );
-                            match method {
-                                leptos_router::Method::Get => get(s),
-                                leptos_router::Method::Post => post(s),
-                                leptos_router::Method::Put => put(s),
-                                leptos_router::Method::Delete => delete(s),
-                                leptos_router::Method::Patch => patch(s),
-                            }
-                        }
-                        SsrMode::InOrder => {
-                            let s = render_app_to_stream_in_order_with_context(
-                                LeptosOptions::from_ref(options),
-                                cx_with_state_and_method.clone(),
-                                app_fn.clone(),
-                            );
-                            match method {
-                                leptos_router::Method::Get => get(s),
-                                leptos_router::Method::Post => post(s),
-                                leptos_router::Method::Put => put(s),
-                                leptos_router::Method::Delete => delete(s),
-                                leptos_router::Method::Patch => patch(s),
-                            }
-                        }
-                        SsrMode::Async => {
-                            let s = render_app_async_with_context(
-                                LeptosOptions::from_ref(options),
-                                cx_with_state_and_method.clone(),
-                                app_fn.clone(),
-                            );
-                            match method {
-                                leptos_router::Method::Get => get(s),
-                                leptos_router::Method::Post => post(s),
-                                leptos_router::Method::Put => put(s),
-                                leptos_router::Method::Delete => delete(s),
-                                leptos_router::Method::Patch => patch(s),
-                            }
-                        }
-                    },
-                )
+                        path,
+                        match method {
+                            leptos_router::Method::Get => get(s),
+                            leptos_router::Method::Post => post(s),
+                            leptos_router::Method::Put => put(s),
+                            leptos_router::Method::Delete => delete(s),
+                            leptos_router::Method::Patch => patch(s),
+                        },
+                    )
                 };
             }
         }
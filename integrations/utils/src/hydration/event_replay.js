@@ -0,0 +1,39 @@
+// Records the events that happen before the page is hydrated, and replays
+// them once it is, so that early clicks and keystrokes reach their handlers.
+(() => {
+    const types = ["click", "dblclick", "input", "change", "keydown", "keyup"];
+    const queue = [];
+    const record = (ev) => queue.push(ev);
+    for (const type of types) {
+        document.addEventListener(type, record, true);
+    }
+    // a click on a link or a submit button that wasn't prevented has already
+    // navigated or submitted its form, which replaying would do again
+    const activated = (ev) => {
+        if (!ev.type.endsWith("click") || ev.defaultPrevented) {
+            return false;
+        }
+        const link = ev.target.closest?.("a[href], area[href]");
+        const submit = ev.target.closest?.(
+            "button, input[type=submit], input[type=image]"
+        );
+        return Boolean(link || (submit?.type !== "button" && submit?.form));
+    };
+    window.__leptos_replay = () => {
+        window.__leptos_replay = () => {};
+        for (const type of types) {
+            document.removeEventListener(type, record, true);
+        }
+        for (const ev of queue) {
+            const target = ev.target;
+            // a click on a form control has already changed it, which fired
+            // an `input` or `change` event that is replayed instead
+            const changed =
+                ev.type.endsWith("click") &&
+                target.closest?.("input, label, select, summary, textarea");
+            if (target.isConnected && !changed && !activated(ev)) {
+                target.dispatchEvent(new ev.constructor(ev.type, ev));
+            }
+        }
+    };
+})();
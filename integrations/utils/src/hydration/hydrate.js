@@ -0,0 +1,21 @@
+// Loads the WASM when the browser is idle, and hydrates the page.
+function idle(c) {
+    if ("requestIdleCallback" in window) {
+        window.requestIdleCallback(c);
+    } else {
+        c();
+    }
+}
+idle(() => {
+    import("__LEPTOS_JS__")
+        .then((mod) => mod.default("__LEPTOS_WASM__").then(() => mod))
+        .then((mod) => {
+            if (window.__leptos_hydrate_islands) {
+                window.__leptos_hydrate_islands(mod);
+            }
+            mod.hydrate();
+            if (window.__leptos_replay) {
+                window.__leptos_replay();
+            }
+        });
+});
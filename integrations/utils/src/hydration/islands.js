@@ -0,0 +1,8 @@
+// Hydrates the islands on the page once the WASM has loaded.
+window.__leptos_hydrate_islands = (mod) => {
+    window.__leptos_hydrate_island = (e) =>
+        mod["_island_" + e.dataset.component](e);
+    for (const e of document.querySelectorAll("leptos-island")) {
+        mod["_island_" + e.dataset.component](e);
+    }
+};
@@ -1,5 +1,7 @@
 use futures::{Stream, StreamExt};
-use leptos::{nonce::use_nonce, use_context, RuntimeId};
+use leptos::{
+    nonce::use_nonce, use_context, use_request_id, RequestScope, RuntimeId,
+};
 use leptos_config::LeptosOptions;
 use leptos_meta::MetaContext;
 use std::{borrow::Cow, collections::HashMap, env, fs};
@@ -51,11 +53,97 @@ fn autoreload(nonce_str: &str, options: &LeptosOptions) -> String {
     }
 }
 
-#[tracing::instrument(level = "trace", fields(error), skip_all)]
-pub fn html_parts_separated(
-    options: &LeptosOptions,
-    meta: Option<&MetaContext>,
-) -> (String, &'static str) {
+/// The path at which the server integrations serve the [`HydrationScript`]s,
+/// when [`LeptosOptions::inline_hydration_scripts`] is `false`. Each script is
+/// served at its [`file_name`](HydrationScript::file_name) under this path.
+pub const HYDRATION_SCRIPTS_ENDPOINT: &str = "/__leptos_hydration";
+
+/// One of the scripts that the page uses to load the app and hydrate it,
+/// which run in the order of the variants.
+///
+/// They are inlined into the `<head>` of each page, or referenced from
+/// [`HYDRATION_SCRIPTS_ENDPOINT`], depending on
+/// [`LeptosOptions::inline_hydration_scripts`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HydrationScript {
+    /// A tiny script that records the clicks, inputs, and key presses that
+    /// happen before the page is hydrated, and replays them once it is. It
+    /// runs first, before the rest of the page is parsed. Clicks that already
+    /// followed a link or submitted a form are not replayed.
+    EventReplay,
+    /// Hydrates the islands, with the `experimental-islands` feature.
+    Islands,
+    /// Loads the JS and WASM built from the app once the browser is idle, and
+    /// hydrates the page.
+    Hydrate,
+}
+
+impl HydrationScript {
+    /// The scripts that the page uses, in the order they run.
+    pub fn all() -> impl Iterator<Item = Self> {
+        [Self::EventReplay, Self::Islands, Self::Hydrate]
+            .into_iter()
+            .filter(|script| {
+                *script != Self::Islands
+                    || cfg!(feature = "experimental-islands")
+            })
+    }
+
+    /// The name of the script under [`HYDRATION_SCRIPTS_ENDPOINT`].
+    pub fn file_name(self) -> &'static str {
+        match self {
+            Self::EventReplay => "event-replay.js",
+            Self::Islands => "islands.js",
+            Self::Hydrate => "hydrate.js",
+        }
+    }
+
+    /// The script with the given [`file_name`](Self::file_name).
+    pub fn from_file_name(file_name: &str) -> Option<Self> {
+        [Self::EventReplay, Self::Islands, Self::Hydrate]
+            .into_iter()
+            .find(|script| script.file_name() == file_name)
+    }
+
+    /// The JavaScript source of the script, for the app with the given
+    /// options.
+    pub fn source(self, options: &LeptosOptions) -> Cow<'static, str> {
+        match self {
+            Self::EventReplay => {
+                include_str!("hydration/event_replay.js").into()
+            }
+            Self::Islands => include_str!("hydration/islands.js").into(),
+            Self::Hydrate => {
+                let (js, wasm) = asset_urls(options);
+                include_str!("hydration/hydrate.js")
+                    .replace("__LEPTOS_JS__", &js)
+                    .replace("__LEPTOS_WASM__", &wasm)
+                    .into()
+            }
+        }
+    }
+
+    // the `<script>` tag that inlines or references the script
+    fn tag(self, options: &LeptosOptions, nonce: &str) -> String {
+        // only the event replay runs before the page has been parsed
+        let ty = match self {
+            Self::EventReplay => "",
+            _ => r#" type="module""#,
+        };
+        if options.inline_hydration_scripts {
+            let source = self.source(options);
+            format!("<script{ty}{nonce}>{source}</script>")
+        } else {
+            let file_name = self.file_name();
+            format!(
+                r#"<script{ty} src="{HYDRATION_SCRIPTS_ENDPOINT}/{file_name}"{nonce}></script>"#
+            )
+        }
+    }
+}
+
+// the URLs of the JS and WASM files built from the app
+fn asset_urls(options: &LeptosOptions) -> (String, String) {
     // First check runtime env, then build time, then default:
     let pkg_path = match std::env::var("CDN_PKG_PATH").ok().map(Cow::from) {
         Some(path) => path,
@@ -65,11 +153,6 @@ pub fn html_parts_separated(
         },
     };
     let output_name = &options.output_name;
-    let nonce = use_nonce();
-    let nonce = nonce
-        .as_ref()
-        .map(|nonce| format!(" nonce=\"{nonce}\""))
-        .unwrap_or_default();
 
     // Because wasm-pack adds _bg to the end of the WASM filename, and we want to maintain compatibility with it's default options
     // we add _bg to the wasm files if cargo-leptos doesn't set the env var LEPTOS_OUTPUT_NAME at compile time
@@ -79,7 +162,34 @@ pub fn html_parts_separated(
         wasm_output_name.push_str("_bg");
     }
 
+    let (js_hash, wasm_hash, _) = get_hashes(options);
+    (
+        format!("{pkg_path}/{output_name}{js_hash}.js"),
+        format!("{pkg_path}/{wasm_output_name}{wasm_hash}.wasm"),
+    )
+}
+
+#[tracing::instrument(level = "trace", fields(error), skip_all)]
+pub fn html_parts_separated(
+    options: &LeptosOptions,
+    meta: Option<&MetaContext>,
+) -> (String, &'static str) {
+    let output_name = &options.output_name;
+    let nonce = use_nonce();
+    let nonce = nonce
+        .as_ref()
+        .map(|nonce| format!(" nonce=\"{nonce}\""))
+        .unwrap_or_default();
+
     let leptos_autoreload = autoreload(&nonce, options);
+    // lets the client use the same request ID until the first navigation
+    let request_id = use_request_id()
+        .map(|id| format!(r#"<meta name="leptos-request-id" content="{id}"/>"#))
+        .unwrap_or_default();
+    // lets `use_request_rng` and `use_request_time` hydrate the same values
+    let request_scope = use_context::<RequestScope>()
+        .map(|scope| scope.meta_tag())
+        .unwrap_or_default();
 
     let html_metadata =
         meta.and_then(|mc| mc.html.as_string()).unwrap_or_default();
@@ -87,29 +197,17 @@ pub fn html_parts_separated(
         .as_ref()
         .map(|meta| meta.dehydrate())
         .unwrap_or_default();
-    let import_callback = if cfg!(feature = "experimental-islands") {
-        /* r#"() => {
-          for (let e of document.querySelectorAll("leptos-island")) {
-            let l = e.dataset.component;
-            console.log("hydrating island");
-            mod["_island_" + l];
-          }
-          mod.hydrate();
-        }"# */
-        r#"() => {       
-            for (let e of document.querySelectorAll("leptos-island")) {
-                let l = e.dataset.component;
-                mod["_island_" + l](e);
-            }
-            mod.hydrate();
-        }
-        "#
-        //r#"()=>{for(let e of document.querySelectorAll("leptos-island")){let l=e.dataset.component;mod["_island_"+l](e)};mod.hydrate();}"#
-    } else {
-        "() => mod.hydrate()"
-    };
 
-    let (js_hash, wasm_hash, css_hash) = get_hashes(options);
+    let (js_url, wasm_url) = asset_urls(options);
+    let (_, _, css_hash) = get_hashes(options);
+    let mut scripts = HydrationScript::all();
+    let event_replay = scripts
+        .next()
+        .map(|script| script.tag(options, &nonce))
+        .unwrap_or_default();
+    let scripts = scripts
+        .map(|script| script.tag(options, &nonce))
+        .collect::<String>();
 
     let head = head.replace(
         &format!("{output_name}.css"),
@@ -122,24 +220,13 @@ pub fn html_parts_separated(
                 <head>
                     <meta charset="utf-8"/>
                     <meta name="viewport" content="width=device-width, initial-scale=1"/>
+                    {event_replay}
+                    {request_id}
+                    {request_scope}
                     {head}
-                    <link rel="modulepreload" href="{pkg_path}/{output_name}{js_hash}.js"{nonce}>
-                    <link rel="preload" href="{pkg_path}/{wasm_output_name}{wasm_hash}.wasm" as="fetch" type="application/wasm" crossorigin=""{nonce}>
-                    <script type="module"{nonce}>
-                        function idle(c) {{
-                            if ("requestIdleCallback" in window) {{
-                                window.requestIdleCallback(c);
-                            }} else {{
-                                c();
-                            }}
-                        }}
-                        idle(() => {{
-                            import('{pkg_path}/{output_name}{js_hash}.js')
-                                .then(mod => {{
-                                    mod.default('{pkg_path}/{wasm_output_name}{wasm_hash}.wasm').then({import_callback});
-                                }})
-                        }});
-                    </script>
+                    <link rel="modulepreload" href="{js_url}"{nonce}>
+                    <link rel="preload" href="{wasm_url}" as="fetch" type="application/wasm" crossorigin=""{nonce}>
+                    {scripts}
                     {leptos_autoreload}
                 </head>"#
     );
@@ -213,3 +300,23 @@ pub async fn build_async_response(
 
     format!("{head}<body{body_meta}>{buf}{tail}")
 }
+
+/// Returns `true` if the `User-Agent` header belongs to a search engine
+/// crawler, a link preview generator, or another bot that may not run the
+/// JavaScript that out-of-order streaming relies on.
+pub fn is_bot_user_agent(user_agent: &str) -> bool {
+    const BOTS: &[&str] = &[
+        "bot",
+        "crawl",
+        "spider",
+        "slurp",
+        "facebookexternalhit",
+        "embedly",
+        "quora link preview",
+        "vkshare",
+        "whatsapp",
+        "lighthouse",
+    ];
+    let user_agent = user_agent.to_ascii_lowercase();
+    BOTS.iter().any(|bot| user_agent.contains(bot))
+}
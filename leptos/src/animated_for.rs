@@ -0,0 +1,177 @@
+use core::time::Duration;
+use leptos_dom::{EachAnimation, IntoView};
+use leptos_macro::component;
+use std::hash::Hash;
+
+/// Like [`For`](crate::For), but animates items as they are added, removed,
+/// and moved, using CSS classes.
+///
+/// - When an item is added, `enter_class` is added to it and removed right
+///   away, so that a CSS transition on the item animates it from the styles
+///   of that class.
+/// - When an item is removed, `leave_class` is added to it, and it is removed
+///   from the DOM after `leave_duration`.
+/// - When an item changes position, it is moved back to where it was with a
+///   `transform`, and then slides to its new position while it has
+///   `move_class`, which should set a `transition` for `transform`.
+///
+/// To let the other items slide into the space of a removed item right away,
+/// `leave_class` can take it out of the layout with `position: absolute`.
+/// The animations only run in the browser, and only after the list was first
+/// rendered.
+///
+/// ```
+/// # use core::time::Duration;
+/// # use leptos::*;
+/// #[component]
+/// fn Todos(todos: ReadSignal<Vec<(usize, String)>>) -> impl IntoView {
+///     view! {
+///         <ul>
+///             <AnimatedFor
+///                 each=move || todos.get()
+///                 key=|(id, _)| *id
+///                 enter_class="fade-in"
+///                 leave_class="fade-out"
+///                 leave_duration=Duration::from_millis(300)
+///                 move_class="slide"
+///                 move_duration=Duration::from_millis(300)
+///                 let:todo
+///             >
+///                 <li>{todo.1}</li>
+///             </AnimatedFor>
+///         </ul>
+///     }
+/// }
+/// ```
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    tracing::instrument(level = "trace", skip_all)
+)]
+#[component(transparent)]
+pub fn AnimatedFor<IF, I, T, EF, N, KF, K>(
+    /// Items over which the component should iterate.
+    each: IF,
+    /// A key function that will be applied to each item.
+    key: KF,
+    /// A function that takes the item, and returns the view that will be
+    /// displayed for each item.
+    children: EF,
+    /// Optional CSS class that an item is animated from when it is added.
+    #[prop(optional)]
+    enter_class: &'static str,
+    /// Optional CSS class to apply to an item when it is removed.
+    #[prop(optional)]
+    leave_class: &'static str,
+    /// How long a removed item stays in the DOM, with `leave_class`.
+    #[prop(optional)]
+    leave_duration: Duration,
+    /// Optional CSS class to apply to an item while it slides to its new
+    /// position.
+    #[prop(optional)]
+    move_class: &'static str,
+    /// How long an item that was moved keeps `move_class`.
+    #[prop(optional)]
+    move_duration: Duration,
+) -> impl IntoView
+where
+    IF: Fn() -> I + 'static,
+    I: IntoIterator<Item = T>,
+    EF: Fn(T) -> N + 'static,
+    N: IntoView + 'static,
+    KF: Fn(&T) -> K + 'static,
+    K: Eq + Hash + 'static,
+    T: 'static,
+{
+    #[cfg(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    ))]
+    let animation = css_animation(
+        enter_class,
+        leave_class,
+        leave_duration,
+        move_class,
+        move_duration,
+    );
+    #[cfg(not(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    )))]
+    let animation = {
+        let _ = (
+            enter_class,
+            leave_class,
+            leave_duration,
+            move_class,
+            move_duration,
+        );
+        EachAnimation::new()
+    };
+
+    leptos_dom::Each::new(each, key, children)
+        .animation(animation)
+        .into_view()
+}
+
+#[cfg(all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate")))]
+fn css_animation(
+    enter_class: &'static str,
+    leave_class: &'static str,
+    leave_duration: Duration,
+    move_class: &'static str,
+    move_duration: Duration,
+) -> EachAnimation {
+    use leptos_dom::helpers::set_timeout;
+    use wasm_bindgen::JsCast;
+
+    // reading the layout makes the browser apply the styles set so far, so
+    // that the next change is transitioned from them
+    fn force_layout(el: &web_sys::Element) {
+        _ = el.client_width();
+    }
+
+    let mut animation = EachAnimation::new();
+    if !enter_class.is_empty() {
+        animation = animation.on_enter(move |el| {
+            let classes = el.class_list();
+            _ = classes.add_1(enter_class);
+            force_layout(el);
+            _ = classes.remove_1(enter_class);
+        });
+    }
+    if !leave_class.is_empty() {
+        animation = animation.on_leave(move |el, done| {
+            _ = el.class_list().add_1(leave_class);
+            set_timeout(done, leave_duration);
+        });
+    }
+    if !move_class.is_empty() {
+        animation = animation.on_move(move |el, dx, dy| {
+            let Some(el) = el.dyn_ref::<web_sys::HtmlElement>() else {
+                return;
+            };
+            let style = el.style();
+            let classes = el.class_list();
+            // first, jump back to the old position without a transition...
+            _ = classes.remove_1(move_class);
+            _ = style.set_property("transition", "none");
+            _ = style.set_property(
+                "transform",
+                &format!("translate({dx}px, {dy}px)"),
+            );
+            force_layout(el);
+            // ...then transition to the new one
+            _ = style.remove_property("transition");
+            _ = classes.add_1(move_class);
+            _ = style.remove_property("transform");
+            let el = el.clone();
+            set_timeout(
+                move || {
+                    _ = el.class_list().remove_1(move_class);
+                },
+                move_duration,
+            );
+        });
+    }
+    animation
+}
@@ -0,0 +1,326 @@
+use leptos_dom::{IntoView, View};
+use leptos_reactive::{provide_context, use_context};
+use std::{
+    any::{type_name, Any},
+    cell::RefCell,
+    collections::HashMap,
+    fmt,
+    rc::Rc,
+};
+
+/// Type-erased props for an [`AnyComponent`].
+///
+/// Props can either be a Rust value of the component’s props type, or (with the
+/// `serde` feature) a serialized JSON description of the props, which allows
+/// views to be described by code that does not know the concrete props type.
+pub struct AnyProps(AnyPropsInner);
+
+enum AnyPropsInner {
+    Value(Box<dyn Any>),
+    Json(String),
+}
+
+impl fmt::Debug for AnyProps {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            AnyPropsInner::Value(_) => f.debug_tuple("AnyProps").finish(),
+            AnyPropsInner::Json(json) => {
+                f.debug_tuple("AnyProps").field(json).finish()
+            }
+        }
+    }
+}
+
+impl AnyProps {
+    /// Wraps a typed props value.
+    pub fn new<P: 'static>(props: P) -> Self {
+        Self(AnyPropsInner::Value(Box::new(props)))
+    }
+
+    /// Wraps props that have been serialized as JSON.
+    pub fn json(json: impl Into<String>) -> Self {
+        Self(AnyPropsInner::Json(json.into()))
+    }
+
+    /// Props for a component that takes no props.
+    pub fn none() -> Self {
+        Self::new(())
+    }
+
+    fn downcast<P: 'static>(
+        self,
+        component: &str,
+    ) -> Result<P, AnyComponentError> {
+        let wrong_type = || AnyComponentError::WrongPropsType {
+            component: component.to_string(),
+            expected: type_name::<P>(),
+        };
+        match self.0 {
+            AnyPropsInner::Value(value) => value
+                .downcast::<P>()
+                .map(|props| *props)
+                .map_err(|_| wrong_type()),
+            AnyPropsInner::Json(_) => Err(wrong_type()),
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    fn deserialize<P>(self, component: &str) -> Result<P, AnyComponentError>
+    where
+        P: serde::de::DeserializeOwned + 'static,
+    {
+        match self.0 {
+            AnyPropsInner::Json(json) => {
+                serde_json::from_str(&json).map_err(|e| {
+                    AnyComponentError::Deserialize {
+                        component: component.to_string(),
+                        message: e.to_string(),
+                    }
+                })
+            }
+            AnyPropsInner::Value(_) => self.downcast(component),
+        }
+    }
+}
+
+/// An error that occurs when rendering a type-erased component.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AnyComponentError {
+    /// No component with this name has been registered.
+    NotFound(String),
+    /// The props are not of the type the component expects.
+    WrongPropsType {
+        /// The name of the component.
+        component: String,
+        /// The name of the props type the component expects.
+        expected: &'static str,
+    },
+    /// The serialized props could not be deserialized.
+    Deserialize {
+        /// The name of the component.
+        component: String,
+        /// The deserialization error.
+        message: String,
+    },
+}
+
+impl fmt::Display for AnyComponentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFound(name) => {
+                write!(f, "no component named `{name}` has been registered")
+            }
+            Self::WrongPropsType {
+                component,
+                expected,
+            } => write!(
+                f,
+                "component `{component}` expected props of type `{expected}`"
+            ),
+            Self::Deserialize { component, message } => write!(
+                f,
+                "could not deserialize props for component `{component}`: \
+                 {message}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for AnyComponentError {}
+
+/// An object-safe component, which can be stored as `Box<dyn AnyComponent>`
+/// and rendered with [`AnyProps`].
+///
+/// This allows components to be collected and rendered at runtime without
+/// knowing their concrete props types, for example when plugin crates
+/// contribute views to a [`ComponentRegistry`].
+pub trait AnyComponent {
+    /// The name under which this component is registered.
+    fn name(&self) -> &str;
+
+    /// Renders the component with the given props.
+    fn render(&self, props: AnyProps) -> Result<View, AnyComponentError>;
+}
+
+/// A type-erased component created from a function.
+#[derive(Clone)]
+pub struct DynComponent {
+    name: String,
+    #[allow(clippy::type_complexity)]
+    render: Rc<dyn Fn(&str, AnyProps) -> Result<View, AnyComponentError>>,
+}
+
+impl fmt::Debug for DynComponent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DynComponent")
+            .field("name", &self.name)
+            .finish()
+    }
+}
+
+impl DynComponent {
+    /// Creates a component that takes props of type `P`, which must be passed
+    /// with [`AnyProps::new`].
+    ///
+    /// ```rust
+    /// # use leptos::*;
+    /// # let runtime = create_runtime();
+    /// let greeting = DynComponent::new("Greeting", |name: String| {
+    ///     view! { <p>"Hello, " {name} "!"</p> }
+    /// });
+    /// assert!(greeting.render(AnyProps::new("Alice".to_string())).is_ok());
+    /// assert!(greeting.render(AnyProps::new(42)).is_err());
+    /// # runtime.dispose();
+    /// ```
+    pub fn new<P, V>(
+        name: impl Into<String>,
+        component: impl Fn(P) -> V + 'static,
+    ) -> Self
+    where
+        P: 'static,
+        V: IntoView,
+    {
+        Self {
+            name: name.into(),
+            render: Rc::new(move |name, props| {
+                props
+                    .downcast::<P>(name)
+                    .map(|props| component(props).into_view())
+            }),
+        }
+    }
+
+    /// Creates a component that takes props of type `P`, which can either be
+    /// passed with [`AnyProps::new`] or serialized with [`AnyProps::json`].
+    #[cfg(feature = "serde")]
+    pub fn deserializing<P, V>(
+        name: impl Into<String>,
+        component: impl Fn(P) -> V + 'static,
+    ) -> Self
+    where
+        P: serde::de::DeserializeOwned + 'static,
+        V: IntoView,
+    {
+        Self {
+            name: name.into(),
+            render: Rc::new(move |name, props| {
+                props
+                    .deserialize::<P>(name)
+                    .map(|props| component(props).into_view())
+            }),
+        }
+    }
+}
+
+impl AnyComponent for DynComponent {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn render(&self, props: AnyProps) -> Result<View, AnyComponentError> {
+        (self.render)(&self.name, props)
+    }
+}
+
+/// A registry of type-erased components, which can be looked up and rendered
+/// by name at runtime.
+///
+/// Provide it near the root of the app with [`provide_component_registry`],
+/// and let plugins register components into it:
+///
+/// ```rust
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// // in a plugin crate
+/// pub fn register(registry: &ComponentRegistry) {
+///     registry.register(DynComponent::new("Hello", |(): ()| {
+///         view! { <p>"Hello from a plugin!"</p> }
+///     }));
+/// }
+///
+/// // in the host app
+/// let registry = provide_component_registry();
+/// register(&registry);
+/// let view = registry.render("Hello", AnyProps::none());
+/// assert!(view.is_ok());
+/// # runtime.dispose();
+/// ```
+#[derive(Clone, Default)]
+pub struct ComponentRegistry(
+    Rc<RefCell<HashMap<String, Rc<dyn AnyComponent>>>>,
+);
+
+impl fmt::Debug for ComponentRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.0.borrow().keys()).finish()
+    }
+}
+
+impl ComponentRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a component under its [`name`](AnyComponent::name),
+    /// replacing any component previously registered with the same name.
+    pub fn register(&self, component: impl AnyComponent + 'static) {
+        self.register_boxed(Box::new(component));
+    }
+
+    /// Registers a boxed component under its [`name`](AnyComponent::name).
+    pub fn register_boxed(&self, component: Box<dyn AnyComponent>) {
+        let component: Rc<dyn AnyComponent> = component.into();
+        self.0
+            .borrow_mut()
+            .insert(component.name().to_string(), component);
+    }
+
+    /// Removes the component with the given name.
+    pub fn unregister(&self, name: &str) -> bool {
+        self.0.borrow_mut().remove(name).is_some()
+    }
+
+    /// Returns the component registered with the given name.
+    pub fn get(&self, name: &str) -> Option<Rc<dyn AnyComponent>> {
+        self.0.borrow().get(name).cloned()
+    }
+
+    /// Whether a component with the given name has been registered.
+    pub fn contains(&self, name: &str) -> bool {
+        self.0.borrow().contains_key(name)
+    }
+
+    /// The names of all registered components.
+    pub fn names(&self) -> Vec<String> {
+        self.0.borrow().keys().cloned().collect()
+    }
+
+    /// Renders the component with the given name.
+    pub fn render(
+        &self,
+        name: &str,
+        props: AnyProps,
+    ) -> Result<View, AnyComponentError> {
+        // clone the component out, so it can register other components while rendering
+        let component = self
+            .get(name)
+            .ok_or_else(|| AnyComponentError::NotFound(name.to_string()))?;
+        component.render(props)
+    }
+}
+
+/// Provides a [`ComponentRegistry`] via context, if one has not already been
+/// provided, and returns it.
+pub fn provide_component_registry() -> ComponentRegistry {
+    use_context::<ComponentRegistry>().unwrap_or_else(|| {
+        let registry = ComponentRegistry::new();
+        provide_context(registry.clone());
+        registry
+    })
+}
+
+/// Returns the [`ComponentRegistry`] provided via context, if any.
+pub fn use_component_registry() -> Option<ComponentRegistry> {
+    use_context::<ComponentRegistry>()
+}
@@ -0,0 +1,51 @@
+use leptos::{component, ChildrenFn, ViewFn};
+use leptos_dom::IntoView;
+use leptos_reactive::{create_effect, signal_prelude::*};
+
+/// Renders a value that can only be known in the browser, like the viewport
+/// width or a date formatted in the user’s locale, without causing a
+/// hydration mismatch.
+///
+/// The server renders the `fallback`. In the browser, the `fallback` is
+/// rendered first as well, so hydration finds exactly the HTML and hydration
+/// keys that the server produced. Once hydration has finished, the fallback is
+/// replaced with the children, which are rendered in the browser.
+///
+/// The fallback prop is optional and defaults to rendering nothing.
+///
+/// ```rust
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// # if false {
+/// view! {
+///   <BrowserOnlyValue fallback=|| "—">
+///     {move || window().inner_width().ok().and_then(|w| w.as_f64())}
+///   </BrowserOnlyValue>
+/// }
+/// # ;
+/// # }
+/// # runtime.dispose();
+/// ```
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    tracing::instrument(level = "trace", skip_all)
+)]
+#[component]
+pub fn BrowserOnlyValue(
+    /// The browser-only value, rendered after hydration.
+    children: ChildrenFn,
+    /// A closure that returns what gets rendered on the server and during
+    /// hydration. By default this is the empty view.
+    #[prop(optional, into)]
+    fallback: ViewFn,
+) -> impl IntoView {
+    let (mounted, set_mounted) = create_signal(false);
+    // effects never run on the server, and only run in the browser once the
+    // current hydration pass has finished
+    create_effect(move |_| set_mounted.set(true));
+
+    move || match mounted.get() {
+        true => children().into_view(),
+        false => fallback.run(),
+    }
+}
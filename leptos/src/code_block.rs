@@ -0,0 +1,160 @@
+use crate::AttributeValue;
+use cfg_if::cfg_if;
+use leptos::component;
+use leptos_dom::{html, HydrationCtx, IntoView, NodeRef};
+use leptos_macro::view;
+use leptos_reactive::{signal_prelude::*, MaybeSignal};
+
+/// Highlights `source` as the given language, returning HTML in which each
+/// token is wrapped in a `<span>` with classes describing its scope (like
+/// `source rust` or `keyword control`).
+///
+/// The language can be given by name (`"Rust"`) or by file extension
+/// (`"rs"`). Unknown languages are highlighted as plain text.
+///
+/// Highlighting uses [`syntect`](https://docs.rs/syntect), and requires the
+/// `syntax-highlighting` feature. Without it, the source is only escaped.
+/// Use [`highlight_theme_css`] to generate a stylesheet for the classes.
+pub fn highlight_code(source: &str, language: &str) -> String {
+    #[cfg(feature = "syntax-highlighting")]
+    {
+        if let Some(html) = syntax::highlight(source, language) {
+            return html;
+        }
+    }
+    let _ = language;
+    escape(source)
+}
+
+/// Generates a stylesheet for the classes produced by [`highlight_code`],
+/// from one of `syntect`’s bundled themes (like `"InspiredGitHub"` or
+/// `"base16-ocean.dark"`).
+///
+/// Returns `None` if there is no theme with that name.
+#[cfg(feature = "syntax-highlighting")]
+pub fn highlight_theme_css(theme: &str) -> Option<String> {
+    syntax::theme_css(theme)
+}
+
+#[cfg(feature = "syntax-highlighting")]
+mod syntax {
+    use std::sync::OnceLock;
+    use syntect::{
+        highlighting::ThemeSet,
+        html::{
+            css_for_theme_with_class_style, ClassStyle, ClassedHTMLGenerator,
+        },
+        parsing::SyntaxSet,
+        util::LinesWithEndings,
+    };
+
+    fn syntaxes() -> &'static SyntaxSet {
+        static SYNTAXES: OnceLock<SyntaxSet> = OnceLock::new();
+        SYNTAXES.get_or_init(SyntaxSet::load_defaults_newlines)
+    }
+
+    pub fn highlight(source: &str, language: &str) -> Option<String> {
+        let syntaxes = syntaxes();
+        let syntax = syntaxes
+            .find_syntax_by_token(language)
+            .unwrap_or_else(|| syntaxes.find_syntax_plain_text());
+        let mut generator = ClassedHTMLGenerator::new_with_class_style(
+            syntax,
+            syntaxes,
+            ClassStyle::Spaced,
+        );
+        for line in LinesWithEndings::from(source) {
+            generator
+                .parse_html_for_line_which_includes_newline(line)
+                .ok()?;
+        }
+        Some(generator.finalize())
+    }
+
+    pub fn theme_css(theme: &str) -> Option<String> {
+        let themes = ThemeSet::load_defaults();
+        let theme = themes.themes.get(theme)?;
+        css_for_theme_with_class_style(theme, ClassStyle::Spaced).ok()
+    }
+}
+
+fn escape(source: &str) -> String {
+    let mut escaped = String::with_capacity(source.len());
+    for c in source.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Displays a block of syntax-highlighted code.
+///
+/// The code is highlighted while rendering on the server, so the initial HTML
+/// already contains the highlighted spans. When hydrating, the server’s HTML is
+/// kept as it is; the code is only highlighted again in the browser if the
+/// `code` signal changes.
+///
+/// ```
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// let source = r#"fn main() { println!("Hello!"); }"#;
+/// view! {
+///     <CodeBlock code=source language="rust"/>
+/// }
+/// # ;
+/// # runtime.dispose();
+/// ```
+///
+/// See [`highlight_code`] for how highlighting works.
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    tracing::instrument(level = "trace", skip_all)
+)]
+#[component]
+pub fn CodeBlock(
+    /// The source code to display.
+    #[prop(into)]
+    code: MaybeSignal<String>,
+    /// The language, given by name (`"Rust"`) or extension (`"rs"`).
+    #[prop(into)]
+    language: String,
+    /// Optional CSS class for the `<pre>`.
+    #[prop(optional, into)]
+    class: Option<AttributeValue>,
+) -> impl IntoView {
+    let code_ref = NodeRef::<html::Code>::new();
+    let code_el = html::code()
+        .node_ref(code_ref)
+        .attr("class", format!("language-{language}"));
+    // while hydrating, the highlighted HTML is already in the DOM
+    let code_el = if HydrationCtx::is_hydrating() {
+        code_el
+    } else {
+        code_el.inner_html(
+            code.with_untracked(|code| highlight_code(code, &language)),
+        )
+    };
+
+    cfg_if! { if #[cfg(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    ))] {
+        leptos_reactive::create_effect(move |prev: Option<()>| {
+            code.with(|code| {
+                if prev.is_some() {
+                    if let Some(el) = code_ref.get_untracked() {
+                        el.set_inner_html(&highlight_code(code, &language));
+                    }
+                }
+            })
+        });
+    } else {
+        let _ = code_ref;
+    }}
+
+    view! { <pre class=class>{code_el}</pre> }
+}
@@ -0,0 +1,116 @@
+use crate::{use_cookie_with_options, CookieOptions};
+use core::time::Duration;
+use leptos_reactive::{
+    create_memo, create_rw_signal, signal_prelude::*, Signal, SignalSetter,
+};
+use std::fmt;
+
+/// The name of the cookie in which [`use_color_scheme`] keeps the color
+/// scheme chosen by the user.
+pub const COLOR_SCHEME_COOKIE: &str = "color-scheme";
+
+/// A light or dark color scheme, as returned by [`use_color_scheme`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ColorScheme {
+    /// Dark text on a light background.
+    #[default]
+    Light,
+    /// Light text on a dark background.
+    Dark,
+}
+
+impl ColorScheme {
+    /// The name of the color scheme, `"light"` or `"dark"`, as it is used by
+    /// CSS and stored in the [`COLOR_SCHEME_COOKIE`].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Light => "light",
+            Self::Dark => "dark",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "light" => Some(Self::Light),
+            "dark" => Some(Self::Dark),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ColorScheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Returns a signal with the color scheme to use, and a setter with which the
+/// user can choose a color scheme, or, with `None`, go back to the one of
+/// their system.
+///
+/// The color scheme chosen by the user is kept in the
+/// [`COLOR_SCHEME_COOKIE`] for a year, so that the server can render the page
+/// with the right scheme, and it doesn't flash in the other one while
+/// loading. When the user hasn't chosen one, the `prefers-color-scheme` media
+/// query is followed, which is only known in the browser: the server renders
+/// [`ColorScheme::Light`], and a hydrated page switches to the preferred
+/// scheme once hydration has finished.
+///
+/// ```rust
+/// # use leptos::*;
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let (color_scheme, set_color_scheme) = use_color_scheme();
+///     let toggle = move |_| {
+///         set_color_scheme.set(Some(match color_scheme.get() {
+///             ColorScheme::Light => ColorScheme::Dark,
+///             ColorScheme::Dark => ColorScheme::Light,
+///         }))
+///     };
+///
+///     view! {
+///         <div class=move || color_scheme.get().to_string()>
+///             <button on:click=toggle>"Toggle dark mode"</button>
+///             <button on:click=move |_| set_color_scheme.set(None)>
+///                 "Use system setting"
+///             </button>
+///         </div>
+///     }
+/// }
+/// ```
+#[track_caller]
+pub fn use_color_scheme(
+) -> (Signal<ColorScheme>, SignalSetter<Option<ColorScheme>>) {
+    let (cookie, set_cookie) = use_cookie_with_options(
+        COLOR_SCHEME_COOKIE,
+        CookieOptions {
+            max_age: Some(Duration::from_secs(60 * 60 * 24 * 365)),
+            ..Default::default()
+        },
+    );
+    let prefers_dark = create_rw_signal(false);
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    ))]
+    crate::device::watch_media_query(
+        "(prefers-color-scheme: dark)",
+        move |matches| prefers_dark.set(matches),
+    );
+
+    let color_scheme = create_memo(move |_| {
+        cookie
+            .with(|cookie| cookie.as_deref().and_then(ColorScheme::from_name))
+            .unwrap_or(if prefers_dark.get() {
+                ColorScheme::Dark
+            } else {
+                ColorScheme::Light
+            })
+    });
+    let set_color_scheme =
+        SignalSetter::map(move |scheme: Option<ColorScheme>| {
+            set_cookie.set(scheme.map(|scheme| scheme.as_str().to_string()))
+        });
+    (color_scheme.into(), set_color_scheme)
+}
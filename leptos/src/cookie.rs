@@ -0,0 +1,190 @@
+use core::time::Duration;
+use leptos_reactive::{
+    create_rw_signal, provide_context, signal_prelude::*, Signal, SignalSetter,
+};
+use std::{fmt, rc::Rc};
+
+/// Gives [`use_cookie`] access to the cookies of the current request, when
+/// rendering on the server. The server integrations provide this for each
+/// request with [`provide_server_cookies`].
+#[derive(Clone)]
+pub struct ServerCookies {
+    header: Rc<str>,
+    set_cookie: Rc<dyn Fn(&str)>,
+}
+
+impl fmt::Debug for ServerCookies {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ServerCookies")
+            .field("header", &self.header)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Provides the cookies of the current request on the server, from the
+/// request's `Cookie` header, and a function that adds a `Set-Cookie` header
+/// with the given value to the response.
+pub fn provide_server_cookies(
+    cookie_header: impl Into<String>,
+    set_cookie: impl Fn(&str) + 'static,
+) {
+    provide_context(ServerCookies {
+        header: cookie_header.into().into(),
+        set_cookie: Rc::new(set_cookie),
+    });
+}
+
+/// The attributes of a cookie that is set with [`use_cookie_with_options`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CookieOptions {
+    /// The path of the pages that the cookie is sent for. Defaults to `/`.
+    pub path: String,
+    /// How long the cookie is kept. Defaults to `None`, which keeps it until
+    /// the browser is closed.
+    pub max_age: Option<Duration>,
+    /// Whether the cookie is only sent over HTTPS. Defaults to `false`.
+    pub secure: bool,
+}
+
+impl Default for CookieOptions {
+    fn default() -> Self {
+        Self {
+            path: "/".into(),
+            max_age: None,
+            secure: false,
+        }
+    }
+}
+
+/// Returns a signal with the value of the cookie with the given name, and a
+/// setter that sets or, with `None`, removes the cookie.
+///
+/// The cookie is read from the request when rendering on the server, and from
+/// `document.cookie` in the browser, so the value is available during
+/// server-side rendering and matches while hydrating. Setting it on the server
+/// adds a `Set-Cookie` header to the response, which only works before the
+/// headers have been sent, i.e., not after a stream has started.
+///
+/// The value is not encoded, so it should only contain characters that are
+/// allowed in a cookie value. `HttpOnly` cookies can't be read or set in the
+/// browser.
+///
+/// ```rust
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// provide_server_cookies("visits=2; theme=dark", |set_cookie| {
+///     assert_eq!(set_cookie, "visits=3; Path=/; SameSite=Lax")
+/// });
+/// let (visits, set_visits) = use_cookie("visits");
+/// assert_eq!(visits.get().as_deref(), Some("2"));
+///
+/// let count = visits.get().and_then(|n| n.parse::<u32>().ok()).unwrap_or(0);
+/// set_visits.set(Some((count + 1).to_string()));
+/// assert_eq!(visits.get().as_deref(), Some("3"));
+/// # runtime.dispose();
+/// ```
+#[track_caller]
+pub fn use_cookie(
+    name: impl Into<String>,
+) -> (Signal<Option<String>>, SignalSetter<Option<String>>) {
+    use_cookie_with_options(name, CookieOptions::default())
+}
+
+/// Like [`use_cookie`], but sets the cookie with the given attributes.
+#[track_caller]
+pub fn use_cookie_with_options(
+    name: impl Into<String>,
+    options: CookieOptions,
+) -> (Signal<Option<String>>, SignalSetter<Option<String>>) {
+    let name = name.into();
+    let cookie = create_rw_signal(
+        read_cookies().and_then(|cookies| find_cookie(&cookies, &name)),
+    );
+    let set_cookie = SignalSetter::map(move |value: Option<String>| {
+        write_cookie(&set_cookie_value(&name, value.as_deref(), &options));
+        cookie.set(value);
+    });
+    (cookie.into(), set_cookie)
+}
+
+fn read_cookies() -> Option<String> {
+    #[cfg(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    ))]
+    {
+        use wasm_bindgen::JsCast;
+
+        leptos_dom::document()
+            .unchecked_into::<web_sys::HtmlDocument>()
+            .cookie()
+            .ok()
+    }
+    #[cfg(not(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    )))]
+    {
+        leptos_reactive::use_context::<ServerCookies>()
+            .map(|cookies| cookies.header.to_string())
+    }
+}
+
+fn write_cookie(value: &str) {
+    #[cfg(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    ))]
+    {
+        use wasm_bindgen::JsCast;
+
+        _ = leptos_dom::document()
+            .unchecked_into::<web_sys::HtmlDocument>()
+            .set_cookie(value);
+    }
+    #[cfg(not(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    )))]
+    match leptos_reactive::use_context::<ServerCookies>() {
+        Some(cookies) => (cookies.set_cookie)(value),
+        None => {
+            leptos_dom::debug_warn!(
+                "Tried to set a cookie on the server, but the server \
+                 integration did not provide `ServerCookies`."
+            );
+        }
+    }
+}
+
+fn find_cookie(cookies: &str, name: &str) -> Option<String> {
+    cookies.split(';').find_map(|cookie| {
+        let (key, value) = cookie.trim().split_once('=')?;
+        (key == name).then(|| value.trim_matches('"').to_string())
+    })
+}
+
+fn set_cookie_value(
+    name: &str,
+    value: Option<&str>,
+    options: &CookieOptions,
+) -> String {
+    let mut cookie = format!(
+        "{name}={}; Path={}",
+        value.unwrap_or_default(),
+        options.path
+    );
+    match (value, options.max_age) {
+        // an expired cookie is removed
+        (None, _) => cookie.push_str("; Max-Age=0"),
+        (Some(_), Some(max_age)) => {
+            cookie.push_str(&format!("; Max-Age={}", max_age.as_secs()))
+        }
+        (Some(_), None) => {}
+    }
+    cookie.push_str("; SameSite=Lax");
+    if options.secure {
+        cookie.push_str("; Secure");
+    }
+    cookie
+}
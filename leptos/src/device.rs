@@ -0,0 +1,298 @@
+#[cfg(all(
+    target_arch = "wasm32",
+    any(feature = "csr", feature = "hydrate")
+))]
+use leptos_reactive::SignalSet;
+use leptos_reactive::{create_rw_signal, provide_context, use_context, Signal};
+
+/// The [Client Hints](https://developer.mozilla.org/en-US/docs/Web/HTTP/Client_hints)
+/// sent with the current request, which [`use_prefers_reduced_motion`],
+/// [`use_pointer_type`], and [`use_network_information`] use as their values
+/// when rendering on the server. The server integrations provide these for
+/// each request with [`provide_client_hints`].
+///
+/// Browsers only send most of these headers after the server has asked for
+/// them in an `Accept-CH` response header, like [`ClientHints::ACCEPT_CH`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ClientHints {
+    /// The `Sec-CH-Prefers-Reduced-Motion` header.
+    pub prefers_reduced_motion: Option<bool>,
+    /// The `Sec-CH-UA-Mobile` header.
+    pub mobile: Option<bool>,
+    /// The `ECT`, `Downlink`, `RTT`, and `Save-Data` headers.
+    pub network: NetworkInformation,
+}
+
+impl ClientHints {
+    /// The value of an `Accept-CH` header that asks for every hint that is
+    /// used.
+    pub const ACCEPT_CH: &'static str =
+        "Sec-CH-Prefers-Reduced-Motion, Sec-CH-UA-Mobile, ECT, Downlink, RTT, \
+         Save-Data";
+
+    /// Reads the hints from the request headers, given a function that
+    /// returns the value of the header with the given name.
+    ///
+    /// ```rust
+    /// # use leptos::*;
+    /// let hints = ClientHints::from_headers(|name| match name {
+    ///     "sec-ch-prefers-reduced-motion" => Some("reduce"),
+    ///     "ect" => Some("3g"),
+    ///     "save-data" => Some("on"),
+    ///     _ => None,
+    /// });
+    /// assert_eq!(hints.prefers_reduced_motion, Some(true));
+    /// assert_eq!(hints.mobile, None);
+    /// assert_eq!(hints.network.effective_type.as_deref(), Some("3g"));
+    /// assert!(hints.network.save_data);
+    /// ```
+    pub fn from_headers<'a>(header: impl Fn(&str) -> Option<&'a str>) -> Self {
+        Self {
+            prefers_reduced_motion: header("sec-ch-prefers-reduced-motion")
+                .map(|value| value.trim() == "reduce"),
+            mobile: header("sec-ch-ua-mobile")
+                .map(|value| value.trim() == "?1"),
+            network: NetworkInformation {
+                effective_type: header("ect")
+                    .map(|value| value.trim().to_string()),
+                downlink: header("downlink")
+                    .and_then(|value| value.trim().parse().ok()),
+                rtt: header("rtt").and_then(|value| value.trim().parse().ok()),
+                save_data: header("save-data")
+                    .is_some_and(|value| value.trim() == "on"),
+            },
+        }
+    }
+}
+
+/// Provides the [`ClientHints`] of the current request on the server.
+pub fn provide_client_hints(hints: ClientHints) {
+    provide_context(hints);
+}
+
+fn use_client_hints() -> ClientHints {
+    use_context().unwrap_or_default()
+}
+
+/// Returns a signal that is `true` while the user prefers to see less
+/// motion, using the `prefers-reduced-motion` media query, so that
+/// animations can be shortened or turned off.
+///
+/// On the server, it is taken from the `Sec-CH-Prefers-Reduced-Motion`
+/// [client hint](ClientHints), and is `false` if there is none. In the
+/// browser, the media query is read once hydration has finished.
+///
+/// ```rust
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// let reduced_motion = use_prefers_reduced_motion();
+/// let class = move || (!reduced_motion.get()).then_some("animated");
+/// view! { <div class=class>"…"</div> }
+/// # ;
+/// # runtime.dispose();
+/// ```
+pub fn use_prefers_reduced_motion() -> Signal<bool> {
+    let reduced_motion = create_rw_signal(
+        use_client_hints().prefers_reduced_motion.unwrap_or(false),
+    );
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    ))]
+    watch_media_query("(prefers-reduced-motion: reduce)", move |matches| {
+        reduced_motion.set(matches)
+    });
+
+    reduced_motion.into()
+}
+
+/// The accuracy of the user's primary pointing device, as returned by
+/// [`use_pointer_type`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PointerType {
+    /// An accurate pointing device, like a mouse.
+    #[default]
+    Fine,
+    /// A pointing device of limited accuracy, like a finger on a touch
+    /// screen, for which controls should be larger.
+    Coarse,
+    /// There is no pointing device.
+    None,
+}
+
+/// Returns a signal with the type of the user's primary pointing device,
+/// using the `pointer` media query.
+///
+/// On the server, it is [`PointerType::Coarse`] if the `Sec-CH-UA-Mobile`
+/// [client hint](ClientHints) says that the browser is on a mobile device,
+/// and [`PointerType::Fine`] otherwise. In the browser, the media query is
+/// read once hydration has finished.
+pub fn use_pointer_type() -> Signal<PointerType> {
+    let pointer_type = create_rw_signal(match use_client_hints().mobile {
+        Some(true) => PointerType::Coarse,
+        _ => PointerType::Fine,
+    });
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    ))]
+    for (query, pointer) in [
+        ("(pointer: fine)", PointerType::Fine),
+        ("(pointer: coarse)", PointerType::Coarse),
+        ("(pointer: none)", PointerType::None),
+    ] {
+        watch_media_query(query, move |matches| {
+            if matches {
+                pointer_type.set(pointer);
+            }
+        });
+    }
+
+    pointer_type.into()
+}
+
+/// The quality of the user's network connection, as returned by
+/// [`use_network_information`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NetworkInformation {
+    /// The effective type of the connection, based on its recently observed
+    /// speed: `"slow-2g"`, `"2g"`, `"3g"`, or `"4g"`.
+    pub effective_type: Option<String>,
+    /// The estimated bandwidth, in megabits per second.
+    pub downlink: Option<f64>,
+    /// The estimated round-trip time, in milliseconds.
+    pub rtt: Option<u32>,
+    /// Whether the user has asked to use less data.
+    pub save_data: bool,
+}
+
+/// Returns a signal with information about the user's network connection,
+/// from the [Network Information API](https://developer.mozilla.org/en-US/docs/Web/API/Network_Information_API),
+/// so that large media can be replaced with smaller versions on slow
+/// connections.
+///
+/// On the server, it is taken from the `ECT`, `Downlink`, `RTT`, and
+/// `Save-Data` [client hints](ClientHints). In the browser, it is read once
+/// hydration has finished, and updated when the connection changes. Browsers
+/// that don't support the API leave it at its default, which is empty.
+///
+/// ```rust
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// let network = use_network_information();
+/// let src = move || {
+///     network.with(|network| {
+///         let slow = matches!(
+///             network.effective_type.as_deref(),
+///             Some("slow-2g" | "2g")
+///         );
+///         if network.save_data || slow {
+///             "/hero-small.jpg"
+///         } else {
+///             "/hero.jpg"
+///         }
+///     })
+/// };
+/// view! { <img src=src/> }
+/// # ;
+/// # runtime.dispose();
+/// ```
+pub fn use_network_information() -> Signal<NetworkInformation> {
+    let network = create_rw_signal(use_client_hints().network);
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    ))]
+    watch_network_information(network);
+
+    network.into()
+}
+
+/// Calls `f` with whether the media query matches once hydration has
+/// finished, and each time that changes, until the current reactive owner is
+/// cleaned up.
+#[cfg(all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate")))]
+pub(crate) fn watch_media_query(query: &str, f: impl Fn(bool) + 'static) {
+    use leptos_reactive::{create_effect, on_cleanup};
+    use std::rc::Rc;
+    use wasm_bindgen::{closure::Closure, JsCast};
+
+    let Ok(Some(query)) = leptos_dom::window().match_media(query) else {
+        return;
+    };
+    let f = Rc::new(f);
+    // read the initial value once hydration has finished, so the view matches
+    // the one rendered on the server
+    create_effect({
+        let query = query.clone();
+        let f = Rc::clone(&f);
+        move |_| f(query.matches())
+    });
+    let callback = Closure::<dyn Fn()>::new({
+        let query = query.clone();
+        move || f(query.matches())
+    });
+    _ = query.add_event_listener_with_callback(
+        "change",
+        callback.as_ref().unchecked_ref(),
+    );
+    on_cleanup(move || {
+        _ = query.remove_event_listener_with_callback(
+            "change",
+            callback.as_ref().unchecked_ref(),
+        );
+    });
+}
+
+#[cfg(all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate")))]
+fn watch_network_information(
+    network: leptos_reactive::RwSignal<NetworkInformation>,
+) {
+    use js_sys::Reflect;
+    use leptos_reactive::{create_effect, on_cleanup};
+    use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+
+    // `navigator.connection` is not in every browser, nor in `web-sys`
+    let Ok(connection) = Reflect::get(
+        &leptos_dom::window().navigator(),
+        &JsValue::from_str("connection"),
+    ) else {
+        return;
+    };
+    let Ok(connection) = connection.dyn_into::<web_sys::EventTarget>() else {
+        return;
+    };
+    let read = {
+        let connection = connection.clone();
+        move || {
+            let get = |name: &str| {
+                Reflect::get(&connection, &JsValue::from_str(name))
+                    .unwrap_or(JsValue::UNDEFINED)
+            };
+            network.set(NetworkInformation {
+                effective_type: get("effectiveType").as_string(),
+                downlink: get("downlink").as_f64(),
+                rtt: get("rtt").as_f64().map(|rtt| rtt as u32),
+                save_data: get("saveData").is_truthy(),
+            });
+        }
+    };
+    create_effect({
+        let read = read.clone();
+        move |_| read()
+    });
+    let callback = Closure::<dyn Fn()>::new(read);
+    _ = connection.add_event_listener_with_callback(
+        "change",
+        callback.as_ref().unchecked_ref(),
+    );
+    on_cleanup(move || {
+        _ = connection.remove_event_listener_with_callback(
+            "change",
+            callback.as_ref().unchecked_ref(),
+        );
+    });
+}
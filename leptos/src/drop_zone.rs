@@ -0,0 +1,367 @@
+use leptos_dom::{html::ElementDescriptor, NodeRef};
+#[cfg(any(
+    test,
+    all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate"))
+))]
+use leptos_reactive::SignalSet;
+use leptos_reactive::{create_rw_signal, RwSignal, Signal};
+use std::cell::Cell;
+
+/// Which files a drop zone accepts, and where it takes them from, as used by
+/// [`use_drop_zone_with_options`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropZoneOptions {
+    /// The types of files that are accepted, in the format of the `accept`
+    /// attribute of a file input: extensions like `.pdf`, MIME types like
+    /// `application/json`, or wildcards like `image/*`. Defaults to none,
+    /// which accepts every file.
+    pub accept: Vec<String>,
+    /// Whether more than one file is accepted at a time. If not, only the
+    /// first accepted file is kept. Defaults to `true`.
+    pub multiple: bool,
+    /// Whether files that are pasted anywhere on the page while the drop zone
+    /// is mounted are handled like dropped files. Defaults to `false`.
+    pub paste: bool,
+}
+
+impl Default for DropZoneOptions {
+    fn default() -> Self {
+        Self {
+            accept: Vec::new(),
+            multiple: true,
+            paste: false,
+        }
+    }
+}
+
+impl DropZoneOptions {
+    /// Whether a file with the given name and MIME type is accepted.
+    ///
+    /// ```rust
+    /// # use leptos::*;
+    /// let options = DropZoneOptions {
+    ///     accept: vec!["image/*".into(), ".pdf".into()],
+    ///     ..Default::default()
+    /// };
+    /// assert!(options.accepts("cat.png", "image/png"));
+    /// assert!(options.accepts("Report.PDF", ""));
+    /// assert!(!options.accepts("notes.txt", "text/plain"));
+    /// ```
+    pub fn accepts(&self, name: &str, mime: &str) -> bool {
+        self.accept.is_empty()
+            || self.accept.iter().any(|accept| {
+                let accept = accept.trim();
+                if accept.starts_with('.') {
+                    name.len() > accept.len()
+                        && name
+                            .get(name.len() - accept.len()..)
+                            .is_some_and(|ext| ext.eq_ignore_ascii_case(accept))
+                } else if let Some(group) = accept.strip_suffix("/*") {
+                    mime.split_once('/').is_some_and(|(mime_group, _)| {
+                        mime_group.eq_ignore_ascii_case(group)
+                    })
+                } else {
+                    mime.eq_ignore_ascii_case(accept)
+                }
+            })
+    }
+
+    /// Splits files into the accepted and the rejected ones, keeping only
+    /// the first accepted file unless `multiple` is set.
+    #[cfg(any(
+        test,
+        all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate"))
+    ))]
+    fn sort_files<F>(
+        &self,
+        files: impl IntoIterator<Item = F>,
+        name_and_type: impl Fn(&F) -> (String, String),
+    ) -> (Vec<F>, Vec<F>) {
+        let (mut accepted, rejected): (Vec<_>, Vec<_>) =
+            files.into_iter().partition(|file| {
+                let (name, mime) = name_and_type(file);
+                self.accepts(&name, &mime)
+            });
+        if !self.multiple {
+            accepted.truncate(1);
+        }
+        (accepted, rejected)
+    }
+}
+
+/// The signals returned by [`use_drop_zone`].
+#[derive(Clone, Copy, Debug)]
+pub struct DropZone {
+    /// Whether files are being dragged over the element.
+    pub is_over: Signal<bool>,
+    /// The accepted files that were last dropped or pasted.
+    pub files: Signal<Vec<web_sys::File>>,
+    /// The files that were last dropped or pasted, but were not accepted.
+    pub rejected: Signal<Vec<web_sys::File>>,
+}
+
+/// Turns an element into a zone that files can be dropped on, returning
+/// whether files are being dragged over it, and the files that were dropped.
+///
+/// `is_over` stays `true` while the files are dragged over the children of
+/// the element, which fire their own `dragenter` and `dragleave` events.
+/// Drags that don't carry files, like selected text, are ignored. Each drop
+/// replaces the files with the new ones.
+///
+/// On the server, and until the element is mounted, there are no files.
+///
+/// ```rust
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// let zone = create_node_ref::<html::Div>();
+/// let drop_zone = use_drop_zone(zone);
+/// view! {
+///     <div node_ref=zone class:over=move || drop_zone.is_over.get()>
+///         "Drop files here"
+///         <ul>
+///             {move || {
+///                 drop_zone
+///                     .files
+///                     .get()
+///                     .into_iter()
+///                     .map(|file| view! { <li>{file.name()}</li> })
+///                     .collect_view()
+///             }}
+///         </ul>
+///     </div>
+/// }
+/// # ;
+/// # runtime.dispose();
+/// ```
+pub fn use_drop_zone<El>(node_ref: NodeRef<El>) -> DropZone
+where
+    El: ElementDescriptor + Clone + 'static,
+{
+    use_drop_zone_with_options(node_ref, DropZoneOptions::default())
+}
+
+/// Like [`use_drop_zone`], but only accepts the files allowed by the given
+/// options, and can also take pasted files.
+pub fn use_drop_zone_with_options<El>(
+    node_ref: NodeRef<El>,
+    options: DropZoneOptions,
+) -> DropZone
+where
+    El: ElementDescriptor + Clone + 'static,
+{
+    let tracker = DropTracker::new();
+    let is_over = tracker.is_over;
+    let files = create_rw_signal(Vec::new());
+    let rejected = create_rw_signal(Vec::new());
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    ))]
+    node_ref.on_load(move |el| {
+        use crate::gestures::listen;
+        use leptos_reactive::batch;
+        use std::rc::Rc;
+        use web_sys::{ClipboardEvent, DataTransfer, DragEvent};
+
+        let paste = options.paste;
+        let carries_files = |data: &Option<DataTransfer>| {
+            data.as_ref().is_some_and(|data| {
+                data.types()
+                    .iter()
+                    .any(|ty| ty.as_string().as_deref() == Some("Files"))
+            })
+        };
+        let take_files = Rc::new(move |data: DataTransfer| {
+            let Some(list) = data.files() else {
+                return;
+            };
+            let (accepted, refused) = options.sort_files(
+                (0..list.length()).filter_map(|i| list.get(i)),
+                |file| (file.name(), file.type_()),
+            );
+            batch(|| {
+                files.set(accepted);
+                rejected.set(refused);
+            });
+        });
+
+        let tracker = Rc::new(tracker);
+        let target = (*el.into_any()).clone();
+        listen(&target, "dragenter", {
+            let tracker = Rc::clone(&tracker);
+            move |ev: DragEvent| {
+                if carries_files(&ev.data_transfer()) {
+                    ev.prevent_default();
+                    tracker.enter();
+                }
+            }
+        });
+        listen(&target, "dragover", move |ev: DragEvent| {
+            let data = ev.data_transfer();
+            if carries_files(&data) {
+                // allows the drop
+                ev.prevent_default();
+                if let Some(data) = data {
+                    data.set_drop_effect("copy");
+                }
+            }
+        });
+        listen(&target, "dragleave", {
+            let tracker = Rc::clone(&tracker);
+            move |ev: DragEvent| {
+                if carries_files(&ev.data_transfer()) {
+                    tracker.leave();
+                }
+            }
+        });
+        listen(&target, "drop", {
+            let take_files = Rc::clone(&take_files);
+            move |ev: DragEvent| {
+                let data = ev.data_transfer();
+                if !carries_files(&data) {
+                    return;
+                }
+                // keeps the browser from opening the files
+                ev.prevent_default();
+                tracker.drop();
+                if let Some(data) = data {
+                    take_files(data);
+                }
+            }
+        });
+        if paste {
+            listen(
+                &leptos_dom::document(),
+                "paste",
+                move |ev: ClipboardEvent| {
+                    let data = ev.clipboard_data();
+                    if carries_files(&data) {
+                        ev.prevent_default();
+                        if let Some(data) = data {
+                            take_files(data);
+                        }
+                    }
+                },
+            );
+        }
+    });
+    #[cfg(not(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    )))]
+    let _ = (node_ref, options, tracker);
+
+    DropZone {
+        is_over: is_over.into(),
+        files: files.into(),
+        rejected: rejected.into(),
+    }
+}
+
+/// Follows a drag of files over a drop zone and its children.
+struct DropTracker {
+    // `dragenter` and `dragleave` also fire when the drag moves between the
+    // element's children, so this counts how many of them it is over; it is
+    // only read in the browser
+    #[allow(unused)]
+    entered: Cell<u32>,
+    is_over: RwSignal<bool>,
+}
+
+impl DropTracker {
+    fn new() -> Self {
+        Self {
+            entered: Cell::new(0),
+            is_over: create_rw_signal(false),
+        }
+    }
+}
+
+#[cfg(any(
+    test,
+    all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate"))
+))]
+impl DropTracker {
+    fn enter(&self) {
+        self.entered.set(self.entered.get() + 1);
+        self.is_over.set(true);
+    }
+
+    fn leave(&self) {
+        self.entered.set(self.entered.get().saturating_sub(1));
+        if self.entered.get() == 0 {
+            self.is_over.set(false);
+        }
+    }
+
+    fn drop(&self) {
+        self.entered.set(0);
+        self.is_over.set(false);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos_reactive::{create_runtime, SignalGet};
+
+    #[test]
+    fn drop_zone_stays_over_while_the_drag_moves_between_children() {
+        let runtime = create_runtime();
+        let tracker = DropTracker::new();
+        let is_over = tracker.is_over;
+
+        tracker.enter();
+        assert!(is_over.get());
+        // onto a child, then back to the zone
+        tracker.enter();
+        tracker.leave();
+        assert!(is_over.get());
+        tracker.leave();
+        assert!(!is_over.get());
+        tracker.leave();
+        tracker.enter();
+        assert!(is_over.get());
+
+        tracker.enter();
+        tracker.drop();
+        assert!(!is_over.get());
+        tracker.enter();
+        tracker.leave();
+        assert!(!is_over.get());
+
+        runtime.dispose();
+    }
+
+    #[test]
+    fn dropped_files_are_sorted_by_the_accepted_types() {
+        let files = [
+            ("a.png", "image/png"),
+            ("notes.txt", "text/plain"),
+            ("b.jpg", "image/jpeg"),
+        ];
+        let name_and_type =
+            |file: &(&str, &str)| (file.0.to_string(), file.1.to_string());
+        let mut options = DropZoneOptions {
+            accept: vec!["image/*".into()],
+            ..Default::default()
+        };
+        assert_eq!(
+            options.sort_files(files, name_and_type),
+            (
+                vec![("a.png", "image/png"), ("b.jpg", "image/jpeg")],
+                vec![("notes.txt", "text/plain")]
+            )
+        );
+
+        options.multiple = false;
+        assert_eq!(
+            options.sort_files(files, name_and_type),
+            (
+                vec![("a.png", "image/png")],
+                vec![("notes.txt", "text/plain")]
+            )
+        );
+    }
+}
@@ -0,0 +1,275 @@
+//! Views built at runtime from serializable descriptions.
+//!
+//! A [`ViewDescription`] is a plain data structure of elements, text,
+//! attribute bindings to named signals, and references to components in a
+//! [`ComponentRegistry`](crate::ComponentRegistry). It can be sent from the
+//! server or produced by a visual page builder, and rendered with
+//! [`render_dynamic`].
+
+use crate::{use_component_registry, AnyComponentError, AnyProps};
+use leptos_dom::{html, Fragment, IntoView, View};
+use leptos_reactive::{
+    provide_context, use_context, Signal, SignalGet, SignalWith,
+};
+use serde::{Deserialize, Serialize};
+use std::{cell::RefCell, collections::HashMap, fmt, rc::Rc};
+
+/// A serializable description of a view, which can be rendered with
+/// [`render_dynamic`].
+///
+/// This makes it possible to build views from data, for example when the
+/// server decides what UI to show, or when pages are created in a visual page
+/// builder.
+///
+/// ```rust
+/// # use leptos::*;
+/// use leptos::dynamic_view::*;
+///
+/// let description: ViewDescription = serde_json::from_str(
+///     r#"{
+///         "type": "element",
+///         "tag": "p",
+///         "attrs": { "class": "greeting", "title": { "signal": "name" } },
+///         "children": [
+///             { "type": "text", "text": "Hello, " },
+///             { "type": "signal", "name": "name" }
+///         ]
+///     }"#,
+/// )
+/// .unwrap();
+/// # let runtime = create_runtime();
+/// let name = create_rw_signal("Alice".to_string());
+/// provide_dynamic_signals(DynamicSignals::new().with("name", name));
+/// let view = render_dynamic(&description).unwrap();
+/// # runtime.dispose();
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ViewDescription {
+    /// An HTML element.
+    Element {
+        /// The tag name, like `div`.
+        tag: String,
+        /// The element’s attributes.
+        #[serde(default)]
+        attrs: HashMap<String, AttributeDescription>,
+        /// The element’s children.
+        #[serde(default)]
+        children: Vec<ViewDescription>,
+    },
+    /// A static text node.
+    Text {
+        /// The text.
+        text: String,
+    },
+    /// A text node bound to a named signal in [`DynamicSignals`].
+    Signal {
+        /// The name of the signal.
+        name: String,
+    },
+    /// A component from the [`ComponentRegistry`](crate::ComponentRegistry),
+    /// whose props are passed as JSON.
+    Component {
+        /// The name under which the component was registered.
+        name: String,
+        /// The component’s props.
+        #[serde(default)]
+        props: serde_json::Value,
+    },
+    /// A list of views with no wrapping element.
+    Fragment {
+        /// The views.
+        #[serde(default)]
+        children: Vec<ViewDescription>,
+    },
+}
+
+/// The value of an attribute in a [`ViewDescription`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum AttributeDescription {
+    /// A static string value.
+    Static(String),
+    /// A boolean attribute, which is present if `true`.
+    Bool(bool),
+    /// A value bound to a named signal in [`DynamicSignals`].
+    Signal {
+        /// The name of the signal.
+        signal: String,
+    },
+}
+
+/// The named signals that a [`ViewDescription`] can bind to.
+#[derive(Clone, Default)]
+pub struct DynamicSignals(Rc<RefCell<HashMap<String, Signal<String>>>>);
+
+impl fmt::Debug for DynamicSignals {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.0.borrow().keys()).finish()
+    }
+}
+
+impl DynamicSignals {
+    /// Creates an empty set of signals.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a named signal.
+    pub fn insert(
+        &self,
+        name: impl Into<String>,
+        signal: impl Into<Signal<String>>,
+    ) {
+        self.0.borrow_mut().insert(name.into(), signal.into());
+    }
+
+    /// Adds a named signal, returning `self` for chaining.
+    pub fn with(
+        self,
+        name: impl Into<String>,
+        signal: impl Into<Signal<String>>,
+    ) -> Self {
+        self.insert(name, signal);
+        self
+    }
+
+    /// Returns the signal with the given name.
+    pub fn get(&self, name: &str) -> Option<Signal<String>> {
+        self.0.borrow().get(name).copied()
+    }
+}
+
+/// Provides the named signals that [`render_dynamic`] binds to.
+pub fn provide_dynamic_signals(signals: DynamicSignals) {
+    provide_context(signals);
+}
+
+/// An error that occurs when rendering a [`ViewDescription`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DynamicViewError {
+    /// The description refers to a signal that has not been provided.
+    UnknownSignal(String),
+    /// The description contains an element that cannot be rendered
+    /// dynamically.
+    DisallowedElement(String),
+    /// The description contains an attribute that cannot be rendered
+    /// dynamically.
+    DisallowedAttribute(String),
+    /// A component could not be rendered.
+    Component(AnyComponentError),
+}
+
+impl fmt::Display for DynamicViewError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownSignal(name) => {
+                write!(f, "no signal named `{name}` has been provided")
+            }
+            Self::DisallowedElement(tag) => {
+                write!(f, "<{tag}> cannot be rendered dynamically")
+            }
+            Self::DisallowedAttribute(name) => {
+                write!(
+                    f,
+                    "the `{name}` attribute cannot be rendered dynamically"
+                )
+            }
+            Self::Component(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for DynamicViewError {}
+
+impl From<AnyComponentError> for DynamicViewError {
+    fn from(value: AnyComponentError) -> Self {
+        Self::Component(value)
+    }
+}
+
+/// Builds a view from a [`ViewDescription`].
+///
+/// Signals are looked up in the [`DynamicSignals`] provided with
+/// [`provide_dynamic_signals`], and components in the
+/// [`ComponentRegistry`](crate::ComponentRegistry) provided with
+/// [`provide_component_registry`](crate::provide_component_registry).
+///
+/// The resulting view is made of ordinary elements, so it can be
+/// server-rendered and hydrated like any other view, as long as the same
+/// description is rendered on the server and the client.
+///
+/// Because descriptions often come from outside the app, `<script>` elements
+/// and inline event handler attributes (`on*`) are rejected.
+pub fn render_dynamic(
+    description: &ViewDescription,
+) -> Result<View, DynamicViewError> {
+    let signals = use_context::<DynamicSignals>().unwrap_or_default();
+    render_description(description, &signals)
+}
+
+fn render_description(
+    description: &ViewDescription,
+    signals: &DynamicSignals,
+) -> Result<View, DynamicViewError> {
+    let signal = |name: &str| {
+        signals
+            .get(name)
+            .ok_or_else(|| DynamicViewError::UnknownSignal(name.to_string()))
+    };
+
+    Ok(match description {
+        ViewDescription::Element {
+            tag,
+            attrs,
+            children,
+        } => {
+            let tag = tag.to_ascii_lowercase();
+            if tag == "script" || tag.is_empty() {
+                return Err(DynamicViewError::DisallowedElement(tag));
+            }
+            let mut el = html::custom(html::Custom::new(tag));
+            // sort so server and client render attributes in the same order
+            let mut attrs = attrs.iter().collect::<Vec<_>>();
+            attrs.sort_by_key(|(name, _)| *name);
+            for (name, value) in attrs {
+                if name.to_ascii_lowercase().starts_with("on") {
+                    return Err(DynamicViewError::DisallowedAttribute(
+                        name.clone(),
+                    ));
+                }
+                let name = name.clone();
+                el = match value {
+                    AttributeDescription::Static(value) => {
+                        el.attr(name, value.clone())
+                    }
+                    AttributeDescription::Bool(value) => el.attr(name, *value),
+                    AttributeDescription::Signal { signal: name_of } => {
+                        let signal = signal(name_of)?;
+                        el.attr(name, move || signal.get())
+                    }
+                };
+            }
+            for child in children {
+                el = el.child(render_description(child, signals)?);
+            }
+            el.into_view()
+        }
+        ViewDescription::Text { text } => text.clone().into_view(),
+        ViewDescription::Signal { name } => {
+            let signal = signal(name)?;
+            (move || signal.with(Clone::clone)).into_view()
+        }
+        ViewDescription::Component { name, props } => {
+            let registry = use_component_registry().unwrap_or_default();
+            registry.render(name, AnyProps::json(props.to_string()))?
+        }
+        ViewDescription::Fragment { children } => Fragment::new(
+            children
+                .iter()
+                .map(|child| render_description(child, signals))
+                .collect::<Result<Vec<_>, _>>()?,
+        )
+        .into_view(),
+    })
+}
@@ -1,7 +1,9 @@
 use crate::Children;
 use leptos_dom::{Errors, HydrationCtx, IntoView};
 use leptos_macro::{component, view};
-use leptos_reactive::{provide_context, run_as_child, signal_prelude::*};
+use leptos_reactive::{
+    provide_context, run_as_child, signal_prelude::*, ResourceRetry,
+};
 
 /// When you render a `Result<_, _>` in your view, in the `Err` case it will
 /// render nothing, and search up through the view tree for an `<ErrorBoundary/>`.
@@ -49,6 +51,47 @@ use leptos_reactive::{provide_context, run_as_child, signal_prelude::*};
 ///   /* etc. */
 /// ```
 ///
+/// ## Errors From the Server
+/// When a resource inside the `<ErrorBoundary/>` fails on the server, its
+/// `Err` is sent to the client like any other value, so the client shows the
+/// same error while hydrating instead of fetching it again. This requires the
+/// error type to be serializable; if it is not, the resource can return a
+/// [`SerializedError`](crate::error::SerializedError) made from it instead.
+///
+/// The resources are not refetched until the fallback asks for it with the
+/// [`ResourceRetry`] that the `<ErrorBoundary/>` provides:
+///
+/// ```
+/// # use leptos::{error::SerializedError, *};
+/// # let runtime = create_runtime();
+/// # if false {
+/// let user = create_resource(
+///     || (),
+///     |_| async {
+///         "abc"
+///             .parse::<u32>()
+///             .map_err(|error| SerializedError::new("/errors/user-id", &error))
+///     },
+/// );
+///
+/// view! {
+///   <Suspense fallback=|| "Loading...">
+///     <ErrorBoundary fallback=|errors| {
+///       let retry = expect_context::<ResourceRetry>();
+///       view! {
+///         <p>{move || errors.with(|errors| errors.iter().map(|(_, e)| e.to_string()).collect::<Vec<_>>())}</p>
+///         <button on:click=move |_| retry.retry()>"Retry"</button>
+///       }
+///     }>
+///       {move || user.get()}
+///     </ErrorBoundary>
+///   </Suspense>
+/// }
+/// # ;
+/// # }
+/// # runtime.dispose();
+/// ```
+///
 /// ## Beginner's Tip: ErrorBoundary Requires Your Error To Implement std::error::Error.
 /// `ErrorBoundary` requires your `Result<T,E>` to implement [IntoView](https://docs.rs/leptos/latest/leptos/trait.IntoView.html).
 /// `Result<T,E>` only implements `IntoView` if `E` implements [std::error::Error](https://doc.rust-lang.org/std/error/trait.Error.html).
@@ -87,6 +130,7 @@ where
         let errors: RwSignal<Errors> = create_rw_signal(Errors::default());
 
         provide_context(errors);
+        provide_context(ResourceRetry::new());
 
         // Run children so that they render and execute resources
         _ = HydrationCtx::next_error();
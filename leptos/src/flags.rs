@@ -0,0 +1,232 @@
+use leptos::{component, ChildrenFn, Suspense, ViewFn};
+use leptos_dom::IntoView;
+use leptos_macro::view;
+use leptos_reactive::{
+    create_resource, create_rw_signal, expect_context, provide_context,
+    signal_prelude::*, Resource, RwSignal, Signal,
+};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::{collections::BTreeMap, future::Future};
+
+/// The values of feature flags, by name.
+pub type FlagValues = BTreeMap<String, Value>;
+
+/// The feature flags of the app, provided with [`provide_feature_flags`].
+///
+/// A flag is enabled when its value is `true`. Flags can also hold any other
+/// JSON value, like the name of an experiment variant, which can be read with
+/// [`use_flag_value`].
+#[derive(Clone, Copy, Debug)]
+pub struct FeatureFlags {
+    initial: Resource<(), FlagValues>,
+    updates: RwSignal<FlagValues>,
+}
+
+impl FeatureFlags {
+    /// The value of the flag with the given name, or `None` if it isn't set
+    /// or the flags are still loading. This is reactive.
+    pub fn get(&self, name: &str) -> Option<Value> {
+        self.updates
+            .with(|updates| updates.get(name).cloned())
+            .or_else(|| {
+                self.initial
+                    .with(|initial| initial.as_ref()?.get(name).cloned())
+            })
+    }
+
+    /// Sets the value of a flag in this app, overriding the value that was
+    /// evaluated on the server.
+    pub fn set(&self, name: impl Into<String>, value: impl Into<Value>) {
+        self.updates.update(|updates| {
+            updates.insert(name.into(), value.into());
+        });
+    }
+
+    /// Listens for flag updates from the server, sent as
+    /// [server-sent events](https://developer.mozilla.org/en-US/docs/Web/API/Server-sent_events)
+    /// by the endpoint at `url`, until the current reactive owner is cleaned
+    /// up.
+    ///
+    /// The data of each message should be a JSON object of flag names and
+    /// values, like `{"new-checkout": true}`. The flags in a message override
+    /// their current values, and the other flags are left as they are.
+    ///
+    /// The integrations serve such an endpoint with `stream_feature_flags`,
+    /// given a stream of updates.
+    ///
+    /// This does nothing on the server.
+    pub fn listen(&self, url: &str) {
+        #[cfg(all(
+            target_arch = "wasm32",
+            any(feature = "csr", feature = "hydrate")
+        ))]
+        listen(self.updates, url);
+        #[cfg(not(all(
+            target_arch = "wasm32",
+            any(feature = "csr", feature = "hydrate")
+        )))]
+        let _ = url;
+    }
+}
+
+/// Formats flag values as a server-sent event, which
+/// [`FeatureFlags::listen`] applies as an update in the browser.
+pub fn flag_update_event(flags: &FlagValues) -> String {
+    let data = serde_json::to_string(flags)
+        .expect("flag values should serialize to JSON");
+    format!("data: {data}\n\n")
+}
+
+/// Provides the feature flags of the app as context, so that they can be read
+/// with [`use_flag`], [`use_flag_value`], and [`<Flag/>`](Flag).
+///
+/// `evaluate` is called on the server for each request, so it can decide
+/// which flags are enabled for the current user, for example by reading the
+/// request from context. The flags are loaded by a [`Resource`], so they are
+/// serialized to the browser along with the HTML, and `evaluate` is not
+/// called again while hydrating. In client-side rendered apps, `evaluate` is
+/// called in the browser, usually to call a server function.
+///
+/// ```rust
+/// # use leptos::*;
+/// #[server]
+/// async fn evaluate_flags() -> Result<FlagValues, ServerFnError> {
+///     let mut flags = FlagValues::new();
+///     flags.insert("new-checkout".into(), true.into());
+///     Ok(flags)
+/// }
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let flags = provide_feature_flags(|| async {
+///         evaluate_flags().await.unwrap_or_default()
+///     });
+///     flags.listen("/api/flags/stream");
+///
+///     view! {
+///         <Flag name="new-checkout" fallback=|| view! { <OldCheckout/> }>
+///             <NewCheckout/>
+///         </Flag>
+///     }
+/// }
+/// # #[component] fn OldCheckout() -> impl IntoView {}
+/// # #[component] fn NewCheckout() -> impl IntoView {}
+/// ```
+pub fn provide_feature_flags<Fu>(
+    evaluate: impl Fn() -> Fu + 'static,
+) -> FeatureFlags
+where
+    Fu: Future<Output = FlagValues> + 'static,
+{
+    let flags = FeatureFlags {
+        initial: create_resource(|| (), move |_| evaluate()),
+        updates: create_rw_signal(FlagValues::new()),
+    };
+    provide_context(flags);
+    flags
+}
+
+/// Returns the feature flags provided with [`provide_feature_flags`].
+///
+/// # Panics
+/// Panics if the flags have not been provided.
+#[track_caller]
+pub fn use_feature_flags() -> FeatureFlags {
+    expect_context()
+}
+
+/// Returns a signal that is `true` while the flag with the given name is
+/// enabled.
+///
+/// Reading the signal under `<Suspense/>` waits for the flags to load; use
+/// [`<Flag/>`](Flag) to show different views depending on a flag.
+///
+/// # Panics
+/// Panics if the flags have not been provided with [`provide_feature_flags`].
+#[track_caller]
+pub fn use_flag(name: impl Into<String>) -> Signal<bool> {
+    let flags = use_feature_flags();
+    let name = name.into();
+    Signal::derive(move || flags.get(&name) == Some(Value::Bool(true)))
+}
+
+/// Returns a signal with the value of the flag with the given name, or `None`
+/// if it isn't set or can't be deserialized as a `T`.
+///
+/// # Panics
+/// Panics if the flags have not been provided with [`provide_feature_flags`].
+#[track_caller]
+pub fn use_flag_value<T>(name: impl Into<String>) -> Signal<Option<T>>
+where
+    T: DeserializeOwned + 'static,
+{
+    let flags = use_feature_flags();
+    let name = name.into();
+    Signal::derive(move || {
+        flags
+            .get(&name)
+            .and_then(|value| serde_json::from_value(value).ok())
+    })
+}
+
+/// Shows its children while the flag with the given name is enabled, and the
+/// `fallback` otherwise, or while the flags are loading.
+///
+/// The flags must have been provided with [`provide_feature_flags`].
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    tracing::instrument(level = "trace", skip_all)
+)]
+#[component]
+pub fn Flag(
+    /// The name of the flag.
+    #[prop(into)]
+    name: String,
+    /// The view to show while the flag is enabled.
+    children: ChildrenFn,
+    /// A closure that returns what gets rendered while the flag is disabled.
+    /// By default this is the empty view.
+    #[prop(optional, into)]
+    fallback: ViewFn,
+) -> impl IntoView {
+    let enabled = use_flag(name);
+    let loading = fallback.clone();
+    let content = move || match enabled.get() {
+        true => children().into_view(),
+        false => fallback.run(),
+    };
+
+    view! {
+        <Suspense fallback=loading>{content.clone()}</Suspense>
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate")))]
+fn listen(updates: RwSignal<FlagValues>, url: &str) {
+    use leptos_reactive::on_cleanup;
+    use wasm_bindgen::{closure::Closure, JsCast};
+
+    let Ok(source) = web_sys::EventSource::new(url) else {
+        leptos_dom::error!("could not listen for feature flags at {url}");
+        return;
+    };
+    let on_message = Closure::<dyn Fn(web_sys::MessageEvent)>::new(
+        move |ev: web_sys::MessageEvent| {
+            let Some(data) = ev.data().as_string() else {
+                return;
+            };
+            match serde_json::from_str::<FlagValues>(&data) {
+                Ok(flags) => updates.update(|updates| updates.extend(flags)),
+                Err(e) => {
+                    leptos_dom::warn!("invalid feature flag update: {e}")
+                }
+            }
+        },
+    );
+    source.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+    on_cleanup(move || {
+        source.close();
+        drop(on_message);
+    });
+}
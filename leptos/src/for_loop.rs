@@ -1,5 +1,6 @@
-use leptos_dom::IntoView;
+use leptos_dom::{ForItemContext, IntoView};
 use leptos_macro::component;
+use leptos_reactive::{expect_context, Signal};
 use std::hash::Hash;
 
 /// Iterates over children and displays them, keyed by the `key` function given.
@@ -50,6 +51,88 @@ use std::hash::Hash;
 ///   }
 /// }
 /// ```
+///
+/// To iterate over an [`RwSignal<Vec<T>>`](crate::RwSignal) without cloning
+/// the vector, iterate over per-row handles with
+/// [`RwSignal::rows`](crate::RwSignal::rows). Updating a row through its
+/// handle only re-renders that row.
+///
+/// ```
+/// # use leptos::*;
+/// // `Todo` does not need to implement `Clone`
+/// struct Todo {
+///   id: usize,
+///   title: String,
+/// }
+///
+/// #[component]
+/// fn Todos(todos: RwSignal<Vec<Todo>>) -> impl IntoView {
+///   let rows = todos.rows();
+///
+///   view! {
+///     <For
+///       each=move || rows.iter_keyed(|todo| todo.id)
+///       // the handle of a row stays the same as long as its key does
+///       key=|row| *row
+///       children=move |row| {
+///         view! {
+///           <p on:click=move |_| row.update(|todo| todo.title.push('!'))>
+///             {move || row.with(|todo| todo.title.clone())}
+///           </p>
+///         }
+///       }
+///     />
+///   }
+/// }
+/// ```
+///
+/// To render a row differently depending on its position, set `item_context`
+/// and read the row's [`ForItemContext`](crate::ForItemContext) from context.
+/// Its signals are kept up to date as rows are added, removed, and moved.
+///
+/// ```
+/// # use leptos::*;
+/// #[component]
+/// fn Names(names: ReadSignal<Vec<String>>) -> impl IntoView {
+///   view! {
+///     <For each=move || names.get() key=|name| name.clone() item_context=true let:name>
+///       <Name name/>
+///     </For>
+///   }
+/// }
+///
+/// #[component]
+/// fn Name(name: String) -> impl IntoView {
+///   let position = expect_context::<ForItemContext>();
+///   view! {
+///     {name}
+///     <Show when=move || !position.is_last.get()>", "</Show>
+///   }
+/// }
+/// ```
+///
+/// If rendering an item can fail, `children` can return a `Result`. An item
+/// that fails renders nothing, and its error is reported to the nearest
+/// [`ErrorBoundary`](crate::ErrorBoundary) until the item is removed; the
+/// other items are rendered and diffed as usual.
+///
+/// ```
+/// # use leptos::*;
+/// #[component]
+/// fn Amounts(amounts: ReadSignal<Vec<String>>) -> impl IntoView {
+///   view! {
+///     <ErrorBoundary fallback=|_| "Some amounts are invalid.">
+///       <For
+///         each=move || amounts.get()
+///         key=|amount| amount.clone()
+///         children=|amount| {
+///           amount.parse::<f64>().map(|n| view! { <li>{n}</li> })
+///         }
+///       />
+///     </ErrorBoundary>
+///   }
+/// }
+/// ```
 #[cfg_attr(
     any(debug_assertions, feature = "ssr"),
     tracing::instrument(level = "trace", skip_all)
@@ -99,6 +182,10 @@ pub fn For<IF, I, T, EF, N, KF, K>(
     /// # }
     /// ```
     children: EF,
+    /// Whether to provide a [`ForItemContext`](crate::ForItemContext) via
+    /// context to each item.
+    #[prop(optional)]
+    item_context: bool,
 ) -> impl IntoView
 where
     IF: Fn() -> I + 'static,
@@ -109,5 +196,62 @@ where
     K: Eq + Hash + 'static,
     T: 'static,
 {
-    leptos_dom::Each::new(each, key, children).into_view()
+    leptos_dom::Each::new(each, key, children)
+        .item_context(item_context)
+        .into_view()
+}
+
+/// Like [`For`], but the view of each item is also given a signal with the
+/// item’s current index, which is updated as items are added, removed, and
+/// moved.
+///
+/// ```
+/// # use leptos::*;
+/// #[component]
+/// fn Ranking(players: ReadSignal<Vec<String>>) -> impl IntoView {
+///   view! {
+///     <ol>
+///       <ForEnumerate
+///         each=move || players.get()
+///         key=|player| player.clone()
+///         children=|index, player| {
+///           view! { <li>{move || index.get() + 1} ". " {player}</li> }
+///         }
+///       />
+///     </ol>
+///   }
+/// }
+/// ```
+///
+/// The item’s [`ForItemContext`](crate::ForItemContext) is provided via
+/// context as well.
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    tracing::instrument(level = "trace", skip_all)
+)]
+#[component(transparent)]
+pub fn ForEnumerate<IF, I, T, EF, N, KF, K>(
+    /// Items over which the component should iterate.
+    each: IF,
+    /// A key function that will be applied to each item.
+    key: KF,
+    /// A function that takes the index of the item and the item, and returns
+    /// the view that will be displayed for each item.
+    children: EF,
+) -> impl IntoView
+where
+    IF: Fn() -> I + 'static,
+    I: IntoIterator<Item = T>,
+    EF: Fn(Signal<usize>, T) -> N + 'static,
+    N: IntoView + 'static,
+    KF: Fn(&T) -> K + 'static,
+    K: Eq + Hash + 'static,
+    T: 'static,
+{
+    leptos_dom::Each::new(each, key, move |item| {
+        let index = expect_context::<ForItemContext>().index;
+        children(index, item)
+    })
+    .item_context(true)
+    .into_view()
 }
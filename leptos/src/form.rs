@@ -0,0 +1,540 @@
+//! Forms whose inputs are bound to the fields of a struct.
+//!
+//! [`use_form`] creates a [`Form`] from any type that implements [`Serialize`]
+//! and [`Deserialize`](serde::Deserialize). Each field of the struct is kept
+//! as the text of an input, named the way
+//! [`ActionForm`](https://docs.rs/leptos_router/latest/leptos_router/fn.ActionForm.html)
+//! and server functions expect it (`name`, `address[city]`, `tags[0]`...), so
+//! the same names work for the `name` attributes of the inputs. The form
+//! tracks which fields have been changed or touched, runs validators as the
+//! user types, and parses the inputs back into the struct.
+//!
+//! ```rust
+//! # use leptos::*;
+//! # use leptos::form::use_form;
+//! # use serde::{Deserialize, Serialize};
+//! #[derive(Default, Serialize, Deserialize)]
+//! struct Signup {
+//!     email: String,
+//!     age: u8,
+//! }
+//!
+//! #[component]
+//! fn SignupForm() -> impl IntoView {
+//!     let form = use_form::<Signup>().validate("email", |email| {
+//!         if email.contains('@') {
+//!             Ok(())
+//!         } else {
+//!             Err("Enter an email address.".into())
+//!         }
+//!     });
+//!     let email = form.field("email");
+//!     let age = form.field("age");
+//!
+//!     view! {
+//!         <form on:submit=form.on_submit()>
+//!             <input name="email" bind:value=email.value() on:blur=move |_| email.touch()/>
+//!             <Show when=move || email.is_touched()>
+//!                 <p class="error">{move || email.error()}</p>
+//!             </Show>
+//!             <input name="age" type="number" bind:value=age.value()/>
+//!             <button disabled=move || !form.is_valid()>"Sign up"</button>
+//!         </form>
+//!     }
+//! }
+//! ```
+
+use leptos_reactive::{
+    batch, create_effect, create_memo, create_rw_signal, create_trigger,
+    signal_prelude::*, spawn_local, store_value, untrack, with_owner, Memo,
+    Owner, RwSignal, StoredValue, Trigger,
+};
+use leptos_server::Action;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use std::{
+    collections::BTreeMap, fmt, future::Future, marker::PhantomData, rc::Rc,
+};
+
+type Validator = Rc<dyn Fn(&str) -> Result<(), String>>;
+
+/// Creates a [`Form`] for a struct, starting from its default value.
+pub fn use_form<T>() -> Form<T>
+where
+    T: Default + Serialize + DeserializeOwned + 'static,
+{
+    create_form(&T::default())
+}
+
+/// Creates a [`Form`] for a struct, starting from the given value.
+///
+/// ```rust
+/// # use leptos::*;
+/// # use leptos::form::create_form;
+/// # use serde::{Deserialize, Serialize};
+/// # let runtime = create_runtime();
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Address {
+///     city: String,
+///     zip: u32,
+/// }
+///
+/// let form = create_form(&Address {
+///     city: "Berlin".into(),
+///     zip: 10115,
+/// });
+/// assert_eq!(form.field("zip").value().get(), "10115");
+///
+/// form.field("city").value().set("Hamburg".into());
+/// assert!(form.is_dirty());
+/// assert_eq!(
+///     form.value().unwrap(),
+///     Address {
+///         city: "Hamburg".into(),
+///         zip: 10115
+///     }
+/// );
+///
+/// form.field("zip").value().set("north".into());
+/// assert!(form.value().is_err());
+/// assert!(!form.is_valid());
+/// # runtime.dispose();
+/// ```
+pub fn create_form<T>(initial: &T) -> Form<T>
+where
+    T: Serialize + DeserializeOwned + 'static,
+{
+    let form = Form {
+        owner: Owner::current(),
+        fields: store_value(BTreeMap::new()),
+        fields_changed: create_trigger(),
+        ty: PhantomData,
+    };
+    for (name, value) in flatten(initial) {
+        form.field_state(&name, Some(value));
+    }
+    form
+}
+
+/// A form whose inputs are bound to the fields of a struct of type `T`,
+/// created with [`use_form`] or [`create_form`].
+///
+/// Fields are named the way `serde_qs` names them: a field `city` of a
+/// field `address` is `address[city]`, and the first item of a field `tags`
+/// is `tags[0]`.
+pub struct Form<T: 'static> {
+    owner: Option<Owner>,
+    fields: StoredValue<BTreeMap<String, FieldState>>,
+    // notified when a field is added, so that the state of the whole form
+    // includes it
+    fields_changed: Trigger,
+    ty: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for Form<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Form<T> {}
+
+impl<T> fmt::Debug for Form<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Form")
+            .field(
+                "fields",
+                &self
+                    .fields
+                    .try_with_value(|fields| {
+                        fields.keys().cloned().collect::<Vec<_>>()
+                    })
+                    .unwrap_or_default(),
+            )
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> Form<T>
+where
+    T: Serialize + DeserializeOwned + 'static,
+{
+    /// Returns the field with the given name, adding it to the form if the
+    /// initial value didn't have it, e.g., because it was `None`.
+    pub fn field(&self, name: &str) -> Field {
+        Field(self.field_state(name, None))
+    }
+
+    fn field_state(&self, name: &str, initial: Option<String>) -> FieldState {
+        if let Some(field) =
+            self.fields.with_value(|fields| fields.get(name).copied())
+        {
+            return field;
+        }
+        let field = self.with_owner(|| FieldState::new(initial));
+        self.fields.update_value(|fields| {
+            fields.insert(name.to_string(), field);
+        });
+        self.fields_changed.notify();
+        field
+    }
+
+    /// Adds a validator to the field with the given name, which runs whenever
+    /// its value changes. The first error of its validators is the field's
+    /// [`error`](Field::error).
+    pub fn validate(
+        self,
+        name: &str,
+        validator: impl Fn(&str) -> Result<(), String> + 'static,
+    ) -> Self {
+        let field = self.field_state(name, None);
+        field.validators.update_value(|validators| {
+            validators.push(Rc::new(validator));
+        });
+        field.validators_changed.notify();
+        self
+    }
+
+    /// Adds an asynchronous validator to the field with the given name, such
+    /// as one that calls a server function. It runs in the browser whenever
+    /// the value of the field changes and it has no other errors; the result
+    /// of an earlier run is ignored if the value has changed since.
+    ///
+    /// While it runs, the field [is validating](Field::is_validating) and the
+    /// form is not [valid](Form::is_valid).
+    pub fn validate_async<Fut>(
+        self,
+        name: &str,
+        validator: impl Fn(String) -> Fut + 'static,
+    ) -> Self
+    where
+        Fut: Future<Output = Result<(), String>> + 'static,
+    {
+        let field = self.field_state(name, None);
+        let (check, version) =
+            self.with_owner(|| (AsyncCheck::new(), store_value(0usize)));
+        field.async_checks.update_value(|checks| checks.push(check));
+        field.validators_changed.notify();
+
+        let run = move || {
+            let value = field.value.get();
+            version.update_value(|version| *version += 1);
+            if field.sync_error.with(Option::is_some) {
+                check.error.set(None);
+                check.pending.set(false);
+                return;
+            }
+            let current = version.get_value();
+            check.pending.set(true);
+            let result = validator(value);
+            spawn_local(async move {
+                let result = result.await;
+                if version.try_get_value() == Some(current) {
+                    batch(|| {
+                        check.error.set(result.err());
+                        check.pending.set(false);
+                    });
+                }
+            });
+        };
+        self.with_owner(|| create_effect(move |_| run()));
+        self
+    }
+
+    /// Parses the values of all fields into a `T`.
+    ///
+    /// A field that was not part of the initial value and is still empty is
+    /// left out, so that an `Option` stays `None`. As with an `<ActionForm/>`,
+    /// a collection that can be empty needs `#[serde(default)]`.
+    pub fn value(&self) -> Result<T, serde_qs::Error> {
+        self.fields_changed.track();
+        let pairs = self.fields.with_value(|fields| {
+            fields
+                .iter()
+                .filter_map(|(name, field)| {
+                    let value = field.value.get();
+                    (field.initial.with_value(Option::is_some)
+                        || !value.is_empty())
+                    .then(|| (name.clone(), value))
+                })
+                .collect::<BTreeMap<_, _>>()
+        });
+        let query = serde_qs::to_string(&pairs)?;
+        serde_qs::Config::new(5, false).deserialize_str(&query)
+    }
+
+    /// Whether the fields can be parsed into a `T`, none of them has an
+    /// error, and no asynchronous validator is running.
+    pub fn is_valid(&self) -> bool {
+        let fields_valid = self.with_fields(|fields| {
+            fields.iter().all(|field| {
+                let field = Field(*field);
+                field.error().is_none() && !field.is_validating()
+            })
+        });
+        fields_valid && self.value().is_ok()
+    }
+
+    /// Whether the value of any field is different from its initial value.
+    pub fn is_dirty(&self) -> bool {
+        self.with_fields(|fields| {
+            fields.iter().any(|field| Field(*field).is_dirty())
+        })
+    }
+
+    /// Whether any field has been [touched](Field::touch).
+    pub fn is_touched(&self) -> bool {
+        self.with_fields(|fields| {
+            fields.iter().any(|field| Field(*field).is_touched())
+        })
+    }
+
+    /// Marks every field as touched, e.g., to show all errors when the form
+    /// is submitted.
+    pub fn touch_all(&self) {
+        let fields = self.with_fields(|fields| fields.to_vec());
+        batch(|| {
+            for field in fields {
+                Field(field).touch();
+            }
+        });
+    }
+
+    /// Sets the values of the fields to those of `value`, without changing
+    /// their initial values.
+    pub fn set(&self, value: &T) {
+        batch(|| {
+            for (name, value) in flatten(value) {
+                self.field(&name).value().set(value);
+            }
+        });
+    }
+
+    /// Resets every field to its initial value and marks it as not touched.
+    pub fn reset(&self) {
+        let fields = self.with_fields(|fields| fields.to_vec());
+        batch(|| {
+            for field in fields {
+                field
+                    .value
+                    .set(field.initial.get_value().unwrap_or_default());
+                field.touched.set(false);
+            }
+        });
+    }
+
+    /// Returns a `submit` event handler that [touches](Form::touch_all) all
+    /// fields and prevents the submission if the form is not
+    /// [valid](Form::is_valid).
+    ///
+    /// An `<ActionForm/>` does not dispatch its action when the submission
+    /// has been prevented, so the form is only sent to the server function
+    /// once it is valid:
+    /// ```rust,ignore
+    /// view! {
+    ///     <ActionForm action=signup on:submit=form.on_submit()>
+    ///         // ...
+    ///     </ActionForm>
+    /// }
+    /// ```
+    pub fn on_submit(&self) -> impl Fn(web_sys::SubmitEvent) + Copy + 'static {
+        let form = *self;
+        move |ev: web_sys::SubmitEvent| {
+            form.touch_all();
+            if !untrack(|| form.is_valid()) {
+                ev.prevent_default();
+            }
+        }
+    }
+
+    /// [Touches](Form::touch_all) all fields and, if the form is
+    /// [valid](Form::is_valid), dispatches the action with its value.
+    /// Returns whether the action was dispatched.
+    ///
+    /// This is useful when `T` is the type of the arguments of a server
+    /// function and the form is submitted without an `<ActionForm/>`.
+    pub fn dispatch<O: 'static>(&self, action: Action<T, O>) -> bool {
+        self.touch_all();
+        match untrack(|| self.is_valid().then(|| self.value())) {
+            Some(Ok(value)) => {
+                action.dispatch(value);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    // the state of the form lives as long as the form, even if a field is
+    // first used inside an effect that runs again
+    fn with_owner<U>(&self, f: impl FnOnce() -> U) -> U {
+        match self.owner {
+            Some(owner) => with_owner(owner, f),
+            None => f(),
+        }
+    }
+
+    fn with_fields<U>(&self, f: impl FnOnce(&[FieldState]) -> U) -> U {
+        self.fields_changed.track();
+        let fields = self
+            .fields
+            .with_value(|fields| fields.values().copied().collect::<Vec<_>>());
+        f(&fields)
+    }
+}
+
+#[derive(Clone, Copy)]
+struct FieldState {
+    value: RwSignal<String>,
+    initial: StoredValue<Option<String>>,
+    touched: RwSignal<bool>,
+    validators: StoredValue<Vec<Validator>>,
+    async_checks: StoredValue<Vec<AsyncCheck>>,
+    // notified when a validator is added
+    validators_changed: Trigger,
+    sync_error: Memo<Option<String>>,
+}
+
+impl FieldState {
+    fn new(initial: Option<String>) -> Self {
+        let value = create_rw_signal(initial.clone().unwrap_or_default());
+        let validators = store_value(Vec::<Validator>::new());
+        let validators_changed = create_trigger();
+        let sync_error = create_memo(move |_| {
+            validators_changed.track();
+            let validators = validators.get_value();
+            value.with(|value| {
+                validators
+                    .iter()
+                    .find_map(|validator| validator(value).err())
+            })
+        });
+        Self {
+            value,
+            initial: store_value(initial),
+            touched: create_rw_signal(false),
+            validators,
+            async_checks: store_value(Vec::new()),
+            validators_changed,
+            sync_error,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct AsyncCheck {
+    error: RwSignal<Option<String>>,
+    pending: RwSignal<bool>,
+}
+
+impl AsyncCheck {
+    fn new() -> Self {
+        Self {
+            error: create_rw_signal(None),
+            pending: create_rw_signal(false),
+        }
+    }
+}
+
+/// A field of a [`Form`].
+#[derive(Clone, Copy)]
+pub struct Field(FieldState);
+
+impl fmt::Debug for Field {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Field")
+            .field("value", &self.0.value)
+            .field("initial", &self.0.initial.try_get_value())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Field {
+    /// The text of the field, which an input can be bound to with
+    /// `bind:value`.
+    pub fn value(&self) -> RwSignal<String> {
+        self.0.value
+    }
+
+    /// Whether the value is different from the initial value.
+    pub fn is_dirty(&self) -> bool {
+        self.0.initial.with_value(|initial| {
+            let initial = initial.as_deref().unwrap_or_default();
+            self.0.value.with(|value| value != initial)
+        })
+    }
+
+    /// Whether the field has been [touched](Field::touch).
+    pub fn is_touched(&self) -> bool {
+        self.0.touched.get()
+    }
+
+    /// Marks the field as touched. This is usually done when its input loses
+    /// focus, so that errors are only shown once the user has left it.
+    pub fn touch(&self) {
+        if !self.0.touched.get_untracked() {
+            self.0.touched.set(true);
+        }
+    }
+
+    /// The first error of the field's validators, if any, or else the error
+    /// of its asynchronous validators.
+    pub fn error(&self) -> Option<String> {
+        self.0.sync_error.get().or_else(|| {
+            self.0.validators_changed.track();
+            self.0.async_checks.with_value(|checks| {
+                checks.iter().find_map(|check| check.error.get())
+            })
+        })
+    }
+
+    /// Whether an asynchronous validator of the field is running.
+    pub fn is_validating(&self) -> bool {
+        self.0.validators_changed.track();
+        self.0
+            .async_checks
+            .with_value(|checks| checks.iter().any(|check| check.pending.get()))
+    }
+}
+
+/// Serializes a value into the names and text of its fields.
+fn flatten(value: &impl Serialize) -> Vec<(String, String)> {
+    fn push(name: String, value: Value, fields: &mut Vec<(String, String)>) {
+        match value {
+            Value::Null => {}
+            Value::Bool(value) => fields.push((name, value.to_string())),
+            Value::Number(value) => fields.push((name, value.to_string())),
+            Value::String(value) => fields.push((name, value)),
+            Value::Array(items) => {
+                for (idx, item) in items.into_iter().enumerate() {
+                    push(format!("{name}[{idx}]"), item, fields);
+                }
+            }
+            Value::Object(entries) => {
+                for (key, item) in entries {
+                    push(format!("{name}[{key}]"), item, fields);
+                }
+            }
+        }
+    }
+
+    let mut fields = Vec::new();
+    match serde_json::to_value(value) {
+        Ok(Value::Object(entries)) => {
+            for (key, item) in entries {
+                push(key, item, &mut fields);
+            }
+        }
+        Ok(_) => {
+            leptos_dom::debug_warn!(
+                "[use_form] A form can only be created for a struct or a map."
+            );
+        }
+        Err(e) => {
+            leptos_dom::debug_warn!(
+                "[use_form] Could not serialize the value of the form: {}",
+                e
+            );
+        }
+    }
+    fields
+}
@@ -0,0 +1,814 @@
+use leptos_dom::{html::ElementDescriptor, NodeRef};
+use leptos_reactive::{create_rw_signal, RwSignal, Signal};
+#[cfg(any(
+    test,
+    all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate"))
+))]
+use leptos_reactive::{SignalGetUntracked, SignalSet};
+use std::cell::{Cell, RefCell};
+
+/// A position or distance on the page, in CSS pixels.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Point {
+    /// The horizontal coordinate, which grows to the right.
+    pub x: f64,
+    /// The vertical coordinate, which grows downwards.
+    pub y: f64,
+}
+
+impl Point {
+    /// Creates a point with the given coordinates.
+    pub const fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+
+    /// The distance between this point and another.
+    pub fn distance(self, other: Point) -> f64 {
+        (self.x - other.x).hypot(self.y - other.y)
+    }
+}
+
+impl std::ops::Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl std::ops::Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+/// Whether an element is being dragged, as returned by [`use_drag`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DragState {
+    /// No pointer is dragging the element.
+    #[default]
+    Idle,
+    /// A pointer was pressed on the element and has not been released.
+    Dragging,
+}
+
+/// The signals returned by [`use_drag`].
+#[derive(Clone, Copy, Debug)]
+pub struct Drag {
+    /// The total distance the element has been dragged, which can be used to
+    /// translate it. Starts at the origin.
+    pub position: Signal<Point>,
+    /// The distance the pointer has moved since the current or last drag
+    /// started.
+    pub delta: Signal<Point>,
+    /// Whether the element is being dragged.
+    pub state: Signal<DragState>,
+}
+
+/// Makes an element draggable with a mouse, pen, or finger, returning how
+/// far it has been dragged.
+///
+/// The element captures the pointer while it is dragged, so that the drag
+/// continues when the pointer leaves it. Only the first pointer that is
+/// pressed on the element drags it, and only with the primary button. Touch
+/// screens scroll instead of dragging unless the element has
+/// `touch-action: none`.
+///
+/// On the server, and until the element is mounted, the signals keep their
+/// initial values.
+///
+/// ```rust
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// let handle = create_node_ref::<html::Div>();
+/// let drag = use_drag(handle);
+/// let transform = move || {
+///     let Point { x, y } = drag.position.get();
+///     format!("translate({x}px, {y}px)")
+/// };
+/// view! {
+///     <div
+///         node_ref=handle
+///         style="touch-action: none"
+///         style:transform=transform
+///         class:dragging=move || drag.state.get() == DragState::Dragging
+///     />
+/// }
+/// # ;
+/// # runtime.dispose();
+/// ```
+pub fn use_drag<El>(node_ref: NodeRef<El>) -> Drag
+where
+    El: ElementDescriptor + Clone + 'static,
+{
+    let tracker = DragTracker::new();
+    let drag = tracker.signals();
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    ))]
+    node_ref.on_load(move |el| {
+        use std::rc::Rc;
+
+        let tracker = Rc::new(tracker);
+        let target = (*el.into_any()).clone();
+        listen(&target, "pointerdown", {
+            let tracker = Rc::clone(&tracker);
+            let target = target.clone();
+            move |ev: web_sys::PointerEvent| {
+                if tracker.press(
+                    ev.pointer_id(),
+                    ev.button(),
+                    client_point(&ev),
+                ) {
+                    _ = target.set_pointer_capture(ev.pointer_id());
+                }
+            }
+        });
+        listen(&target, "pointermove", {
+            let tracker = Rc::clone(&tracker);
+            move |ev: web_sys::PointerEvent| {
+                tracker.move_to(ev.pointer_id(), client_point(&ev))
+            }
+        });
+        for event in ["pointerup", "pointercancel"] {
+            listen(&target, event, {
+                let tracker = Rc::clone(&tracker);
+                move |ev: web_sys::PointerEvent| {
+                    tracker.release(ev.pointer_id())
+                }
+            });
+        }
+    });
+    #[cfg(not(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    )))]
+    let _ = (node_ref, tracker);
+
+    drag
+}
+
+/// Follows the pointer that drags an element, and updates the signals of a
+/// [`Drag`] as it moves.
+struct DragTracker {
+    // the pointer that is dragging, where it was pressed, and the position
+    // when it was pressed, which are only read in the browser
+    #[allow(unused)]
+    drag: Cell<Option<(i32, Point, Point)>>,
+    position: RwSignal<Point>,
+    delta: RwSignal<Point>,
+    state: RwSignal<DragState>,
+}
+
+impl DragTracker {
+    fn new() -> Self {
+        Self {
+            drag: Cell::new(None),
+            position: create_rw_signal(Point::default()),
+            delta: create_rw_signal(Point::default()),
+            state: create_rw_signal(DragState::Idle),
+        }
+    }
+
+    fn signals(&self) -> Drag {
+        Drag {
+            position: self.position.into(),
+            delta: self.delta.into(),
+            state: self.state.into(),
+        }
+    }
+}
+
+#[cfg(any(
+    test,
+    all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate"))
+))]
+impl DragTracker {
+    /// Starts a drag if no other pointer is dragging and the primary button
+    /// was pressed, returning whether the element should capture the
+    /// pointer.
+    fn press(&self, pointer: i32, button: i16, at: Point) -> bool {
+        if self.drag.get().is_some() || button != 0 {
+            return false;
+        }
+        self.drag
+            .set(Some((pointer, at, self.position.get_untracked())));
+        self.delta.set(Point::default());
+        self.state.set(DragState::Dragging);
+        true
+    }
+
+    fn move_to(&self, pointer: i32, at: Point) {
+        if let Some((id, start, base)) = self.drag.get() {
+            if id == pointer {
+                let moved = at - start;
+                self.delta.set(moved);
+                self.position.set(base + moved);
+            }
+        }
+    }
+
+    fn release(&self, pointer: i32) {
+        let id = self.drag.get().map(|(id, ..)| id);
+        if id == Some(pointer) {
+            self.drag.set(None);
+            self.state.set(DragState::Idle);
+        }
+    }
+}
+
+/// The direction of a swipe, as returned by [`use_swipe`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SwipeDirection {
+    /// Towards the left edge of the page.
+    Left,
+    /// Towards the right edge of the page.
+    Right,
+    /// Towards the top of the page.
+    Up,
+    /// Towards the bottom of the page.
+    Down,
+}
+
+impl SwipeDirection {
+    /// The direction of a swipe that moved the pointer by `delta`, along
+    /// whichever axis it moved furthest, or `None` if it moved less than
+    /// `threshold` pixels along that axis.
+    ///
+    /// ```rust
+    /// # use leptos::*;
+    /// let direction =
+    ///     |x, y| SwipeDirection::from_delta(Point::new(x, y), 50.0);
+    /// assert_eq!(direction(-80.0, 20.0), Some(SwipeDirection::Left));
+    /// assert_eq!(direction(10.0, 60.0), Some(SwipeDirection::Down));
+    /// assert_eq!(direction(40.0, -30.0), None);
+    /// ```
+    pub fn from_delta(delta: Point, threshold: f64) -> Option<Self> {
+        let (distance, direction) = if delta.x.abs() >= delta.y.abs() {
+            let direction = if delta.x < 0.0 {
+                SwipeDirection::Left
+            } else {
+                SwipeDirection::Right
+            };
+            (delta.x.abs(), direction)
+        } else {
+            let direction = if delta.y < 0.0 {
+                SwipeDirection::Up
+            } else {
+                SwipeDirection::Down
+            };
+            (delta.y.abs(), direction)
+        };
+        (distance >= threshold && distance > 0.0).then_some(direction)
+    }
+}
+
+/// How a swipe is recognized by [`use_swipe_with_options`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SwipeOptions {
+    /// How far, in pixels, the pointer must move along one axis for the
+    /// gesture to count as a swipe. Defaults to `50.0`.
+    pub threshold: f64,
+}
+
+impl Default for SwipeOptions {
+    fn default() -> Self {
+        Self { threshold: 50.0 }
+    }
+}
+
+/// The signals returned by [`use_swipe`].
+#[derive(Clone, Copy, Debug)]
+pub struct Swipe {
+    /// The direction of the last swipe, which is set when the pointer is
+    /// released, and `None` until the first swipe.
+    pub direction: Signal<Option<SwipeDirection>>,
+    /// The distance the pointer has moved since the current or last swipe
+    /// started.
+    pub delta: Signal<Point>,
+    /// Whether a pointer is pressed on the element.
+    pub is_swiping: Signal<bool>,
+}
+
+/// Recognizes swipes on an element, with a threshold of 50 pixels.
+///
+/// Like [`use_drag`], the element captures the pointer during the swipe.
+/// Touch screens may scroll instead of swiping, which can be prevented along
+/// one axis with `touch-action: pan-y` or `pan-x`, or along both with
+/// `touch-action: none`. On the server, the signals keep their initial
+/// values.
+///
+/// ```rust
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// let carousel = create_node_ref::<html::Div>();
+/// let swipe = use_swipe(carousel);
+/// let page = create_rw_signal(0);
+/// create_effect(move |_| match swipe.direction.get() {
+///     Some(SwipeDirection::Left) => page.update(|page| *page += 1),
+///     Some(SwipeDirection::Right) => {
+///         page.update(|page| *page = (*page - 1).max(0))
+///     }
+///     _ => {}
+/// });
+/// view! { <div node_ref=carousel style="touch-action: pan-y"/> }
+/// # ;
+/// # runtime.dispose();
+/// ```
+pub fn use_swipe<El>(node_ref: NodeRef<El>) -> Swipe
+where
+    El: ElementDescriptor + Clone + 'static,
+{
+    use_swipe_with_options(node_ref, SwipeOptions::default())
+}
+
+/// Like [`use_swipe`], but recognizes swipes with the given options.
+pub fn use_swipe_with_options<El>(
+    node_ref: NodeRef<El>,
+    options: SwipeOptions,
+) -> Swipe
+where
+    El: ElementDescriptor + Clone + 'static,
+{
+    let tracker = SwipeTracker::new(options);
+    let swipe = tracker.signals();
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    ))]
+    node_ref.on_load(move |el| {
+        use std::rc::Rc;
+
+        let tracker = Rc::new(tracker);
+        let target = (*el.into_any()).clone();
+        listen(&target, "pointerdown", {
+            let tracker = Rc::clone(&tracker);
+            let target = target.clone();
+            move |ev: web_sys::PointerEvent| {
+                if tracker.press(
+                    ev.pointer_id(),
+                    ev.button(),
+                    client_point(&ev),
+                ) {
+                    _ = target.set_pointer_capture(ev.pointer_id());
+                }
+            }
+        });
+        listen(&target, "pointermove", {
+            let tracker = Rc::clone(&tracker);
+            move |ev: web_sys::PointerEvent| {
+                tracker.move_to(ev.pointer_id(), client_point(&ev))
+            }
+        });
+        listen(&target, "pointerup", {
+            let tracker = Rc::clone(&tracker);
+            move |ev: web_sys::PointerEvent| {
+                tracker.release(ev.pointer_id(), client_point(&ev))
+            }
+        });
+        listen(
+            &target,
+            "pointercancel",
+            move |ev: web_sys::PointerEvent| tracker.cancel(ev.pointer_id()),
+        );
+    });
+    #[cfg(not(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    )))]
+    let _ = (node_ref, tracker);
+
+    swipe
+}
+
+/// Follows the pointer that swipes over an element, and updates the signals
+/// of a [`Swipe`] as it moves.
+struct SwipeTracker {
+    // the pointer that is swiping, and where it was pressed, which are only
+    // read in the browser
+    #[allow(unused)]
+    swipe: Cell<Option<(i32, Point)>>,
+    #[allow(unused)]
+    options: SwipeOptions,
+    direction: RwSignal<Option<SwipeDirection>>,
+    delta: RwSignal<Point>,
+    is_swiping: RwSignal<bool>,
+}
+
+impl SwipeTracker {
+    fn new(options: SwipeOptions) -> Self {
+        Self {
+            swipe: Cell::new(None),
+            options,
+            direction: create_rw_signal(None),
+            delta: create_rw_signal(Point::default()),
+            is_swiping: create_rw_signal(false),
+        }
+    }
+
+    fn signals(&self) -> Swipe {
+        Swipe {
+            direction: self.direction.into(),
+            delta: self.delta.into(),
+            is_swiping: self.is_swiping.into(),
+        }
+    }
+}
+
+#[cfg(any(
+    test,
+    all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate"))
+))]
+impl SwipeTracker {
+    /// Starts a swipe if no other pointer is swiping and the primary button
+    /// was pressed, returning whether the element should capture the
+    /// pointer.
+    fn press(&self, pointer: i32, button: i16, at: Point) -> bool {
+        if self.swipe.get().is_some() || button != 0 {
+            return false;
+        }
+        self.swipe.set(Some((pointer, at)));
+        self.delta.set(Point::default());
+        self.is_swiping.set(true);
+        true
+    }
+
+    fn move_to(&self, pointer: i32, at: Point) {
+        if let Some((id, start)) = self.swipe.get() {
+            if id == pointer {
+                self.delta.set(at - start);
+            }
+        }
+    }
+
+    /// Ends the swipe when the pointer is released, and sets its direction
+    /// if it moved far enough.
+    fn release(&self, pointer: i32, at: Point) {
+        if let Some(start) = self.end(pointer) {
+            let moved = at - start;
+            self.delta.set(moved);
+            if let Some(swiped) =
+                SwipeDirection::from_delta(moved, self.options.threshold)
+            {
+                self.direction.set(Some(swiped));
+            }
+        }
+    }
+
+    /// Ends the swipe without a direction: a cancelled pointer was taken over
+    /// by the browser, e.g. to scroll, so it is not a swipe.
+    fn cancel(&self, pointer: i32) {
+        self.end(pointer);
+    }
+
+    fn end(&self, pointer: i32) -> Option<Point> {
+        let (id, start) = self.swipe.get()?;
+        if id != pointer {
+            return None;
+        }
+        self.swipe.set(None);
+        self.is_swiping.set(false);
+        Some(start)
+    }
+}
+
+/// The limits of the scale set by [`use_pinch_with_options`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PinchOptions {
+    /// The smallest scale. Defaults to `0.25`.
+    pub min_scale: f64,
+    /// The largest scale. Defaults to `4.0`.
+    pub max_scale: f64,
+}
+
+impl Default for PinchOptions {
+    fn default() -> Self {
+        Self {
+            min_scale: 0.25,
+            max_scale: 4.0,
+        }
+    }
+}
+
+/// The signals returned by [`use_pinch`].
+#[derive(Clone, Copy, Debug)]
+pub struct Pinch {
+    /// The scale the element has been zoomed to, which starts at `1.0`, and
+    /// is kept from one pinch to the next.
+    pub scale: Signal<f64>,
+    /// Whether two pointers are pressed on the element.
+    pub is_pinching: Signal<bool>,
+}
+
+/// Recognizes pinches with two fingers on an element, returning the scale
+/// it has been zoomed to, between `0.25` and `4.0`.
+///
+/// The scale changes in proportion to the distance between the two pointers.
+/// The element should have `touch-action: none`, so that the browser does
+/// not zoom the page instead. On the server, the scale stays at `1.0`.
+///
+/// ```rust
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// let map = create_node_ref::<html::Div>();
+/// let pinch = use_pinch(map);
+/// view! {
+///     <div node_ref=map style="touch-action: none">
+///         <img
+///             src="/map.png"
+///             style:transform=move || format!("scale({})", pinch.scale.get())
+///         />
+///     </div>
+/// }
+/// # ;
+/// # runtime.dispose();
+/// ```
+pub fn use_pinch<El>(node_ref: NodeRef<El>) -> Pinch
+where
+    El: ElementDescriptor + Clone + 'static,
+{
+    use_pinch_with_options(node_ref, PinchOptions::default())
+}
+
+/// Like [`use_pinch`], but limits the scale with the given options.
+pub fn use_pinch_with_options<El>(
+    node_ref: NodeRef<El>,
+    options: PinchOptions,
+) -> Pinch
+where
+    El: ElementDescriptor + Clone + 'static,
+{
+    let tracker = PinchTracker::new(options);
+    let pinch = tracker.signals();
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    ))]
+    node_ref.on_load(move |el| {
+        use std::rc::Rc;
+
+        let tracker = Rc::new(tracker);
+        let target = (*el.into_any()).clone();
+        listen(&target, "pointerdown", {
+            let tracker = Rc::clone(&tracker);
+            let target = target.clone();
+            move |ev: web_sys::PointerEvent| {
+                if tracker.press(ev.pointer_id(), client_point(&ev)) {
+                    _ = target.set_pointer_capture(ev.pointer_id());
+                }
+            }
+        });
+        listen(&target, "pointermove", {
+            let tracker = Rc::clone(&tracker);
+            move |ev: web_sys::PointerEvent| {
+                tracker.move_to(ev.pointer_id(), client_point(&ev))
+            }
+        });
+        for event in ["pointerup", "pointercancel"] {
+            listen(&target, event, {
+                let tracker = Rc::clone(&tracker);
+                move |ev: web_sys::PointerEvent| {
+                    tracker.release(ev.pointer_id())
+                }
+            });
+        }
+    });
+    #[cfg(not(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    )))]
+    let _ = (node_ref, tracker);
+
+    pinch
+}
+
+/// Follows the two pointers that pinch an element, and updates the signals
+/// of a [`Pinch`] as they move.
+struct PinchTracker {
+    // the pressed pointers and where they are, and the distance between the
+    // first two and the scale when the second one was pressed, which are
+    // only read in the browser
+    #[allow(unused)]
+    pointers: RefCell<Vec<(i32, Point)>>,
+    #[allow(unused)]
+    start: Cell<Option<(f64, f64)>>,
+    #[allow(unused)]
+    options: PinchOptions,
+    scale: RwSignal<f64>,
+    is_pinching: RwSignal<bool>,
+}
+
+impl PinchTracker {
+    fn new(options: PinchOptions) -> Self {
+        Self {
+            pointers: RefCell::new(Vec::new()),
+            start: Cell::new(None),
+            options,
+            scale: create_rw_signal(1.0),
+            is_pinching: create_rw_signal(false),
+        }
+    }
+
+    fn signals(&self) -> Pinch {
+        Pinch {
+            scale: self.scale.into(),
+            is_pinching: self.is_pinching.into(),
+        }
+    }
+}
+
+#[cfg(any(
+    test,
+    all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate"))
+))]
+impl PinchTracker {
+    /// Adds a pressed pointer, starting a pinch if it is the second one, and
+    /// returns whether the element should capture it.
+    fn press(&self, pointer: i32, at: Point) -> bool {
+        let distance = {
+            let mut pointers = self.pointers.borrow_mut();
+            if pointers.len() == 2 {
+                return false;
+            }
+            pointers.push((pointer, at));
+            if pointers.len() < 2 {
+                return true;
+            }
+            pointers[0].1.distance(pointers[1].1)
+        };
+        self.start.set(Some((distance, self.scale.get_untracked())));
+        self.is_pinching.set(true);
+        true
+    }
+
+    fn move_to(&self, pointer: i32, at: Point) {
+        let distance = {
+            let mut pointers = self.pointers.borrow_mut();
+            let Some(pressed) =
+                pointers.iter_mut().find(|(id, _)| *id == pointer)
+            else {
+                return;
+            };
+            pressed.1 = at;
+            if pointers.len() < 2 {
+                return;
+            }
+            pointers[0].1.distance(pointers[1].1)
+        };
+        if let Some((start_distance, start_scale)) = self.start.get() {
+            if start_distance > 0.0 {
+                self.scale.set(
+                    (start_scale * distance / start_distance)
+                        .clamp(self.options.min_scale, self.options.max_scale),
+                );
+            }
+        }
+    }
+
+    fn release(&self, pointer: i32) {
+        let mut pointers = self.pointers.borrow_mut();
+        let len = pointers.len();
+        pointers.retain(|(id, _)| *id != pointer);
+        if len == 2 && pointers.len() < 2 {
+            self.start.set(None);
+            self.is_pinching.set(false);
+        }
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate")))]
+fn client_point(ev: &web_sys::PointerEvent) -> Point {
+    Point::new(ev.client_x() as f64, ev.client_y() as f64)
+}
+
+/// Calls `f` with each event of the given type on the target, until the
+/// current reactive owner is cleaned up.
+#[cfg(all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate")))]
+pub(crate) fn listen<E>(
+    target: &web_sys::EventTarget,
+    event: &'static str,
+    f: impl Fn(E) + 'static,
+) where
+    E: wasm_bindgen::convert::FromWasmAbi + 'static,
+{
+    use leptos_reactive::on_cleanup;
+    use wasm_bindgen::{closure::Closure, JsCast};
+
+    let callback = Closure::<dyn Fn(E)>::new(f);
+    _ = target.add_event_listener_with_callback(
+        event,
+        callback.as_ref().unchecked_ref(),
+    );
+    let target = target.clone();
+    on_cleanup(move || {
+        _ = target.remove_event_listener_with_callback(
+            event,
+            callback.as_ref().unchecked_ref(),
+        );
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos_reactive::{create_runtime, SignalGet};
+
+    #[test]
+    fn drags_continue_from_the_last_position() {
+        let runtime = create_runtime();
+        let tracker = DragTracker::new();
+        let drag = tracker.signals();
+
+        assert!(!tracker.press(1, 2, Point::new(10.0, 10.0)));
+        assert_eq!(drag.state.get(), DragState::Idle);
+        assert!(tracker.press(1, 0, Point::new(10.0, 10.0)));
+        assert_eq!(drag.state.get(), DragState::Dragging);
+        // only the first pointer drags
+        assert!(!tracker.press(2, 0, Point::new(50.0, 50.0)));
+        tracker.move_to(2, Point::new(100.0, 100.0));
+        tracker.move_to(1, Point::new(15.0, 30.0));
+        assert_eq!(drag.delta.get(), Point::new(5.0, 20.0));
+        assert_eq!(drag.position.get(), Point::new(5.0, 20.0));
+        tracker.release(2);
+        assert_eq!(drag.state.get(), DragState::Dragging);
+        tracker.release(1);
+        assert_eq!(drag.state.get(), DragState::Idle);
+        tracker.move_to(1, Point::new(0.0, 0.0));
+        assert_eq!(drag.position.get(), Point::new(5.0, 20.0));
+
+        assert!(tracker.press(3, 0, Point::new(0.0, 0.0)));
+        assert_eq!(drag.delta.get(), Point::default());
+        tracker.move_to(3, Point::new(-5.0, 0.0));
+        assert_eq!(drag.delta.get(), Point::new(-5.0, 0.0));
+        assert_eq!(drag.position.get(), Point::new(0.0, 20.0));
+
+        runtime.dispose();
+    }
+
+    #[test]
+    fn swipes_set_a_direction_when_released_past_the_threshold() {
+        let runtime = create_runtime();
+        let tracker = SwipeTracker::new(SwipeOptions { threshold: 20.0 });
+        let swipe = tracker.signals();
+
+        assert!(tracker.press(1, 0, Point::new(0.0, 0.0)));
+        assert!(swipe.is_swiping.get());
+        tracker.move_to(1, Point::new(5.0, -3.0));
+        assert_eq!(swipe.delta.get(), Point::new(5.0, -3.0));
+        assert_eq!(swipe.direction.get(), None);
+        tracker.release(1, Point::new(30.0, -10.0));
+        assert!(!swipe.is_swiping.get());
+        assert_eq!(swipe.delta.get(), Point::new(30.0, -10.0));
+        assert_eq!(swipe.direction.get(), Some(SwipeDirection::Right));
+
+        // short and cancelled swipes keep the last direction
+        tracker.press(1, 0, Point::new(0.0, 0.0));
+        tracker.release(1, Point::new(0.0, 10.0));
+        assert_eq!(swipe.direction.get(), Some(SwipeDirection::Right));
+        tracker.press(2, 0, Point::new(0.0, 0.0));
+        tracker.move_to(2, Point::new(0.0, 50.0));
+        tracker.cancel(2);
+        assert!(!swipe.is_swiping.get());
+        assert_eq!(swipe.direction.get(), Some(SwipeDirection::Right));
+
+        tracker.press(3, 0, Point::new(0.0, 0.0));
+        tracker.release(3, Point::new(5.0, -25.0));
+        assert_eq!(swipe.direction.get(), Some(SwipeDirection::Up));
+
+        runtime.dispose();
+    }
+
+    #[test]
+    fn pinches_scale_with_the_distance_between_two_pointers() {
+        let runtime = create_runtime();
+        let tracker = PinchTracker::new(PinchOptions::default());
+        let pinch = tracker.signals();
+
+        assert!(tracker.press(1, Point::new(0.0, 0.0)));
+        assert!(!pinch.is_pinching.get());
+        assert!(tracker.press(2, Point::new(100.0, 0.0)));
+        assert!(pinch.is_pinching.get());
+        assert!(!tracker.press(3, Point::new(50.0, 50.0)));
+        tracker.move_to(2, Point::new(200.0, 0.0));
+        assert_eq!(pinch.scale.get(), 2.0);
+        tracker.move_to(2, Point::new(1000.0, 0.0));
+        assert_eq!(pinch.scale.get(), 4.0);
+        tracker.release(1);
+        assert!(!pinch.is_pinching.get());
+
+        // the next pinch starts from the last scale
+        tracker.press(4, Point::new(0.0, 0.0));
+        tracker.move_to(4, Point::new(900.0, 0.0));
+        assert_eq!(pinch.scale.get(), 0.4);
+        tracker.move_to(4, Point::new(999.0, 0.0));
+        assert_eq!(pinch.scale.get(), 0.25);
+
+        runtime.dispose();
+    }
+}
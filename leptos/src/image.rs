@@ -0,0 +1,354 @@
+use crate::AttributeValue;
+use cfg_if::cfg_if;
+use leptos::component;
+use leptos_dom::{html, IntoView, NodeRef};
+use leptos_macro::view;
+use leptos_reactive::{
+    create_rw_signal, provide_context, signal_prelude::*, use_context,
+};
+use std::{fmt, rc::Rc};
+
+/// The widths (in pixels) that [`Image`] will request from an [`ImageLoader`]
+/// if no other widths are configured.
+pub const DEFAULT_IMAGE_WIDTHS: &[u32] =
+    &[640, 750, 828, 1080, 1200, 1920, 2048, 3840];
+
+/// The quality that [`Image`] will request from an [`ImageLoader`] if no
+/// other quality is configured.
+pub const DEFAULT_IMAGE_QUALITY: u8 = 75;
+
+/// Describes a single resized variant of an image, which an [`ImageLoader`]
+/// turns into a URL.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ImageRequest<'a> {
+    /// The path or URL of the original image.
+    pub src: &'a str,
+    /// The width, in pixels, of the requested variant.
+    pub width: u32,
+    /// The requested quality, between `1` and `100`.
+    pub quality: u8,
+}
+
+/// Generates the URL for a resized variant of an image, usually by pointing to
+/// an image-resizing endpoint or CDN.
+#[derive(Clone)]
+pub struct ImageLoader(Rc<dyn Fn(&ImageRequest<'_>) -> String>);
+
+impl fmt::Debug for ImageLoader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ImageLoader").finish()
+    }
+}
+
+impl ImageLoader {
+    /// Creates a loader from a function that builds the URL for each variant.
+    ///
+    /// ```rust
+    /// # use leptos::*;
+    /// let loader = ImageLoader::new(|req| {
+    ///     format!("https://cdn.example.com/{}?width={}", req.src, req.width)
+    /// });
+    /// ```
+    pub fn new(f: impl Fn(&ImageRequest<'_>) -> String + 'static) -> Self {
+        Self(Rc::new(f))
+    }
+
+    /// Creates a loader for an endpoint that takes the original image as the
+    /// `url` query parameter, and the requested size as `w` and `q`.
+    ///
+    /// For example, `ImageLoader::endpoint("/_image")` will request
+    /// `/_image?url=%2Fcat.png&w=640&q=75`.
+    pub fn endpoint(endpoint: impl Into<String>) -> Self {
+        let endpoint = endpoint.into();
+        Self::new(move |req| {
+            format!(
+                "{endpoint}?url={}&w={}&q={}",
+                encode_query_value(req.src),
+                req.width,
+                req.quality
+            )
+        })
+    }
+
+    /// Returns the URL for the given variant.
+    pub fn url(&self, req: &ImageRequest<'_>) -> String {
+        (self.0)(req)
+    }
+}
+
+/// Configuration shared by all [`Image`] components below the point at which
+/// it is provided with [`provide_image_config`].
+#[derive(Clone, Debug)]
+pub struct ImageConfig {
+    /// Generates the URLs used in `srcset`. If this is `None`, images are
+    /// rendered using only their original `src`.
+    pub loader: Option<ImageLoader>,
+    /// The widths that may be requested from the loader.
+    pub widths: Vec<u32>,
+    /// The default quality requested from the loader.
+    pub quality: u8,
+}
+
+impl Default for ImageConfig {
+    fn default() -> Self {
+        Self {
+            loader: None,
+            widths: DEFAULT_IMAGE_WIDTHS.to_vec(),
+            quality: DEFAULT_IMAGE_QUALITY,
+        }
+    }
+}
+
+impl ImageConfig {
+    /// Creates a config that uses the given loader, with the default widths
+    /// and quality.
+    pub fn with_loader(loader: ImageLoader) -> Self {
+        Self {
+            loader: Some(loader),
+            ..Default::default()
+        }
+    }
+
+    /// Builds the `srcset` and fallback `src` for an image.
+    ///
+    /// If `sizes` is `true`, every configured width is listed with a `w`
+    /// descriptor. Otherwise, only the variants needed for `1x` and `2x`
+    /// pixel densities at the given display `width` are listed.
+    pub fn srcset(
+        &self,
+        src: &str,
+        width: u32,
+        quality: Option<u8>,
+        sizes: bool,
+    ) -> Option<(String, String)> {
+        let loader = self.loader.as_ref()?;
+        let quality = quality.unwrap_or(self.quality);
+        let mut widths = self.widths.clone();
+        widths.sort_unstable();
+        widths.dedup();
+        let largest = *widths.last()?;
+
+        let candidates: Vec<(u32, String)> = if sizes {
+            widths
+                .into_iter()
+                .map(|width| (width, format!("{width}w")))
+                .collect()
+        } else {
+            let mut candidates: Vec<(u32, String)> = Vec::with_capacity(2);
+            for density in [1, 2] {
+                let target = width.saturating_mul(density);
+                let width = widths
+                    .iter()
+                    .copied()
+                    .find(|w| *w >= target)
+                    .unwrap_or(largest);
+                if !candidates.iter().any(|(w, _)| *w == width) {
+                    candidates.push((width, format!("{density}x")));
+                }
+            }
+            candidates
+        };
+
+        let url = |width| {
+            loader.url(&ImageRequest {
+                src,
+                width,
+                quality,
+            })
+        };
+        let srcset = candidates
+            .iter()
+            .map(|(width, descriptor)| format!("{} {descriptor}", url(*width)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let fallback = url(candidates.last()?.0);
+        Some((srcset, fallback))
+    }
+}
+
+/// Provides an [`ImageConfig`] for all [`Image`] components rendered below
+/// this point in the tree.
+pub fn provide_image_config(config: ImageConfig) {
+    provide_context(config);
+}
+
+/// Renders a responsive `<img>`.
+///
+/// - `width` and `height` are always set on the element, so the browser can
+///   reserve space for the image before it loads and avoid layout shift.
+/// - If an [`ImageLoader`] has been provided with [`provide_image_config`],
+///   `srcset` (and `sizes`, if given) are generated to request resized variants.
+/// - Images are lazy-loaded unless `priority` is set. In client-side rendered
+///   apps, the image is only requested once it is near the viewport, using an
+///   [`IntersectionObserver`](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserver).
+/// - A `placeholder` (typically a tiny, blurred data URL) is rendered as the
+///   element’s background during server rendering, so it is visible before the
+///   full image has loaded.
+///
+/// The server-rendered output is a plain `<img>` with `loading="lazy"`, so it
+/// works even if JavaScript never loads.
+///
+/// ```rust
+/// # use leptos::*;
+/// # #[component]
+/// # pub fn App() -> impl IntoView {
+/// provide_image_config(ImageConfig::with_loader(ImageLoader::endpoint(
+///     "/_image",
+/// )));
+///
+/// view! {
+///     <Image
+///         src="/hero.jpg"
+///         alt="A mountain at sunrise"
+///         width=1200
+///         height=800
+///         sizes="(max-width: 768px) 100vw, 1200px"
+///         priority=true
+///     />
+/// }
+/// # }
+/// ```
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    tracing::instrument(level = "trace", skip_all)
+)]
+#[component]
+pub fn Image(
+    /// The path or URL of the original image.
+    #[prop(into)]
+    src: String,
+    /// Alternative text describing the image.
+    #[prop(into)]
+    alt: String,
+    /// The intrinsic width of the image, in pixels.
+    width: u32,
+    /// The intrinsic height of the image, in pixels.
+    height: u32,
+    /// The `sizes` attribute, describing how wide the image will be displayed
+    /// at different viewport sizes.
+    #[prop(optional, into)]
+    sizes: Option<String>,
+    /// The quality to request from the loader. Defaults to the configured quality.
+    #[prop(optional)]
+    quality: Option<u8>,
+    /// Loads the image eagerly with high priority. Use this for images that are
+    /// visible above the fold.
+    #[prop(optional)]
+    priority: bool,
+    /// A small image (usually a base64-encoded data URL) shown while the full
+    /// image loads.
+    #[prop(optional, into)]
+    placeholder: Option<String>,
+    /// Optional CSS class for the `<img>`.
+    #[prop(optional, into)]
+    class: Option<AttributeValue>,
+) -> impl IntoView {
+    let config = use_context::<ImageConfig>().unwrap_or_default();
+    let (srcset, fallback) = config
+        .srcset(&src, width, quality, sizes.is_some())
+        .map(|(srcset, fallback)| (Some(srcset), fallback))
+        .unwrap_or((None, src));
+
+    // in a CSR app, we defer loading until the image is in view; otherwise, the
+    // server-rendered `loading="lazy"` already tells the browser what to do
+    let in_view = create_rw_signal(priority || !cfg!(feature = "csr"));
+    let loaded = create_rw_signal(false);
+    let img_ref = NodeRef::<html::Img>::new();
+    cfg_if! { if #[cfg(all(target_arch = "wasm32", feature = "csr"))] {
+        if !priority {
+            observe_intersection(img_ref, in_view);
+        }
+    } else {
+        let _ = img_ref;
+    }}
+
+    let style = move || {
+        if loaded.get() {
+            return None;
+        }
+        placeholder.as_ref().map(|placeholder| {
+            format!(
+                "background-image:url(\"{placeholder}\");\
+                 background-size:cover;background-position:center;\
+                 background-repeat:no-repeat"
+            )
+        })
+    };
+
+    view! {
+        <img
+            node_ref=img_ref
+            alt=alt
+            width=width
+            height=height
+            class=class
+            style=style
+            sizes=sizes
+            srcset=move || in_view.get().then(|| srcset.clone()).flatten()
+            src=move || in_view.get().then(|| fallback.clone())
+            loading=if priority { "eager" } else { "lazy" }
+            fetchpriority=priority.then_some("high")
+            decoding="async"
+            on:load=move |_| loaded.set(true)
+        />
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "csr"))]
+fn observe_intersection(img_ref: NodeRef<html::Img>, in_view: RwSignal<bool>) {
+    use leptos_reactive::on_cleanup;
+    use wasm_bindgen::{closure::Closure, JsCast};
+
+    img_ref.on_load(move |img| {
+        let callback = Closure::<
+            dyn Fn(js_sys::Array, web_sys::IntersectionObserver),
+        >::new(
+            move |entries: js_sys::Array,
+                  observer: web_sys::IntersectionObserver| {
+                let intersecting = entries.iter().any(|entry| {
+                    entry
+                        .unchecked_into::<web_sys::IntersectionObserverEntry>()
+                        .is_intersecting()
+                });
+                if intersecting {
+                    in_view.set(true);
+                    observer.disconnect();
+                }
+            },
+        );
+        let mut options = web_sys::IntersectionObserverInit::new();
+        options.root_margin("200px");
+
+        match web_sys::IntersectionObserver::new_with_options(
+            callback.as_ref().unchecked_ref(),
+            &options,
+        ) {
+            Ok(observer) => {
+                observer.observe(&img);
+                on_cleanup(move || {
+                    observer.disconnect();
+                    drop(callback);
+                });
+            }
+            // if the observer can't be created, just load the image
+            Err(_) => in_view.set(true),
+        }
+    });
+}
+
+fn encode_query_value(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z'
+            | b'a'..=b'z'
+            | b'0'..=b'9'
+            | b'-'
+            | b'_'
+            | b'.'
+            | b'~' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
@@ -0,0 +1,301 @@
+use leptos_dom::{html::ElementDescriptor, NodeRef};
+use leptos_reactive::{
+    create_resource, create_rw_signal, signal_prelude::*, spawn_local,
+    store_value, Resource, RwSignal, Serializable, Signal, StoredValue,
+};
+use std::{fmt, future::Future, pin::Pin, rc::Rc};
+
+/// The state of one page of an [`InfiniteResource`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PageState<E> {
+    /// The page is being fetched.
+    Loading,
+    /// The page was fetched, and had `len` items.
+    Loaded {
+        /// The number of items on the page.
+        len: usize,
+    },
+    /// Fetching the page failed.
+    Failed(E),
+}
+
+enum Page<T, E> {
+    Loading,
+    Loaded(Vec<T>),
+    Failed(E),
+}
+
+type FetchPage<T, E> =
+    Rc<dyn Fn(usize) -> Pin<Box<dyn Future<Output = Result<Vec<T>, E>>>>>;
+
+/// A list that is loaded one page at a time, created with
+/// [`create_infinite_resource`].
+pub struct InfiniteResource<T: 'static, E: 'static> {
+    /// The items of every page that has been loaded so far, in order.
+    pub items: Signal<Vec<T>>,
+    /// The state of each page that has been requested, starting with the
+    /// first one.
+    pub pages: Signal<Vec<PageState<E>>>,
+    /// Whether there may be more pages to load. This is `false` once a page
+    /// is loaded without any items.
+    pub has_more: Signal<bool>,
+    /// Whether a page is being fetched.
+    pub loading: Signal<bool>,
+    /// The error of the last page, if fetching it failed.
+    pub error: Signal<Option<E>>,
+    first: Resource<(), Result<Vec<T>, E>>,
+    rest: RwSignal<Vec<Page<T, E>>>,
+    fetch_page: StoredValue<FetchPage<T, E>>,
+}
+
+impl<T, E> Clone for InfiniteResource<T, E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, E> Copy for InfiniteResource<T, E> {}
+
+impl<T, E> fmt::Debug for InfiniteResource<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InfiniteResource").finish_non_exhaustive()
+    }
+}
+
+impl<T, E> InfiniteResource<T, E>
+where
+    T: Clone + 'static,
+    E: Clone + 'static,
+{
+    /// Fetches the next page, unless a page is already loading or there are
+    /// no more pages. If the last page failed to load, it is fetched again.
+    pub fn load_more(&self) {
+        if self.loading.get_untracked() || !self.has_more.get_untracked() {
+            return;
+        }
+        let first_failed = self.pages.with_untracked(|pages| {
+            matches!(pages.first(), Some(PageState::Failed(_)))
+        });
+        if first_failed {
+            self.first.refetch();
+            return;
+        }
+
+        let rest = self.rest;
+        let index = rest.with_untracked(|rest| match rest.last() {
+            Some(Page::Failed(_)) => rest.len() - 1,
+            _ => rest.len(),
+        });
+        rest.update(|rest| {
+            rest.truncate(index);
+            rest.push(Page::Loading);
+        });
+        // the first page is loaded by the resource, so `rest[0]` is page 2
+        let fut = self
+            .fetch_page
+            .with_value(|fetch_page| fetch_page(index + 2));
+        spawn_local(async move {
+            let page = match fut.await {
+                Ok(items) => Page::Loaded(items),
+                Err(e) => Page::Failed(e),
+            };
+            rest.try_update(|rest| {
+                if let Some(slot) = rest.get_mut(index) {
+                    *slot = page;
+                }
+            });
+        });
+    }
+
+    /// Drops every page except the first, and fetches the first page again.
+    pub fn reset(&self) {
+        self.rest.set(Vec::new());
+        self.first.refetch();
+    }
+
+    /// Calls [`load_more`](Self::load_more) whenever the element of
+    /// `sentinel` scrolls into view, which is usually placed after the last
+    /// item. This does nothing on the server.
+    pub fn load_more_when_visible<El>(&self, sentinel: NodeRef<El>)
+    where
+        El: ElementDescriptor + Clone + 'static,
+    {
+        #[cfg(all(
+            target_arch = "wasm32",
+            any(feature = "csr", feature = "hydrate")
+        ))]
+        observe_sentinel(*self, sentinel);
+        #[cfg(not(all(
+            target_arch = "wasm32",
+            any(feature = "csr", feature = "hydrate")
+        )))]
+        let _ = sentinel;
+    }
+}
+
+/// Creates a list that is loaded one page at a time, like an infinite
+/// scrolling feed. `fetch_page` is called with the number of the page to
+/// load, starting from `1`.
+///
+/// The first page is loaded by a [`Resource`], so it is rendered on the
+/// server and sent to the browser along with the HTML, like the value of any
+/// other resource read under `<Suspense/>`. Further pages are fetched in the
+/// browser when you call [`load_more`](InfiniteResource::load_more), starting
+/// with page `2`. A page without any items marks the end of the list.
+///
+/// ```rust
+/// # use leptos::*;
+/// # async fn fetch_posts(page: usize) -> Result<Vec<String>, ServerFnError> {
+/// #     Ok(vec![])
+/// # }
+/// #[component]
+/// fn Feed() -> impl IntoView {
+///     let posts = create_infinite_resource(fetch_posts);
+///     let sentinel = create_node_ref::<html::Div>();
+///     posts.load_more_when_visible(sentinel);
+///
+///     view! {
+///         <Suspense fallback=|| "Loading...">
+///             <For each=move || posts.items.get() key=|post| post.clone() let:post>
+///                 <p>{post}</p>
+///             </For>
+///         </Suspense>
+///         <Show when=move || posts.error.get().is_some()>
+///             <button on:click=move |_| posts.load_more()>"Try again"</button>
+///         </Show>
+///         <div node_ref=sentinel/>
+///     }
+/// }
+/// ```
+pub fn create_infinite_resource<T, E, Fu>(
+    fetch_page: impl Fn(usize) -> Fu + 'static,
+) -> InfiniteResource<T, E>
+where
+    T: Clone + 'static,
+    E: Clone + 'static,
+    Fu: Future<Output = Result<Vec<T>, E>> + 'static,
+    Result<Vec<T>, E>: Serializable,
+{
+    let fetch_page: FetchPage<T, E> =
+        Rc::new(move |page| Box::pin(fetch_page(page)));
+    let first = create_resource(|| (), {
+        let fetch_page = Rc::clone(&fetch_page);
+        move |_| fetch_page(1)
+    });
+    let first_loading = first.loading();
+    let rest = create_rw_signal(Vec::new());
+
+    let pages = Signal::derive(move || {
+        let first = if first_loading.get() {
+            PageState::Loading
+        } else {
+            first.with(|first| match first {
+                None => PageState::Loading,
+                Some(Ok(items)) => PageState::Loaded { len: items.len() },
+                Some(Err(e)) => PageState::Failed(e.clone()),
+            })
+        };
+        rest.with(|rest: &Vec<Page<T, E>>| {
+            std::iter::once(first)
+                .chain(rest.iter().map(|page| match page {
+                    Page::Loading => PageState::Loading,
+                    Page::Loaded(items) => {
+                        PageState::Loaded { len: items.len() }
+                    }
+                    Page::Failed(e) => PageState::Failed(e.clone()),
+                }))
+                .collect()
+        })
+    });
+    let items = Signal::derive(move || {
+        let mut items = first.with(|first| match first {
+            Some(Ok(items)) => items.clone(),
+            _ => Vec::new(),
+        });
+        rest.with(|rest| {
+            for page in rest {
+                if let Page::Loaded(page) = page {
+                    items.extend(page.iter().cloned());
+                }
+            }
+        });
+        items
+    });
+    let last =
+        move || pages.with(|pages: &Vec<PageState<E>>| pages.last().cloned());
+    let has_more = Signal::derive(move || {
+        !matches!(last(), Some(PageState::Loaded { len: 0 }))
+    });
+    let loading =
+        Signal::derive(move || matches!(last(), Some(PageState::Loading)));
+    let error = Signal::derive(move || match last() {
+        Some(PageState::Failed(e)) => Some(e),
+        _ => None,
+    });
+
+    InfiniteResource {
+        items,
+        pages,
+        has_more,
+        loading,
+        error,
+        first,
+        rest,
+        fetch_page: store_value(fetch_page),
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate")))]
+fn observe_sentinel<T, E, El>(
+    resource: InfiniteResource<T, E>,
+    sentinel: NodeRef<El>,
+) where
+    T: Clone + 'static,
+    E: Clone + 'static,
+    El: ElementDescriptor + Clone + 'static,
+{
+    use leptos_reactive::{create_effect, on_cleanup};
+    use wasm_bindgen::{closure::Closure, JsCast};
+
+    // keep loading while the sentinel is visible, because the observer is
+    // only notified when it enters or leaves the viewport
+    let visible = create_rw_signal(false);
+    create_effect(move |_| {
+        if visible.get()
+            && resource.has_more.get()
+            && !resource.loading.get()
+            && resource.error.with(Option::is_none)
+        {
+            resource.load_more();
+        }
+    });
+
+    sentinel.on_load(move |el| {
+        let callback = Closure::<
+            dyn Fn(js_sys::Array, web_sys::IntersectionObserver),
+        >::new(
+            move |entries: js_sys::Array, _: web_sys::IntersectionObserver| {
+                if let Some(entry) = entries.iter().last() {
+                    visible.set(
+                        entry
+                            .unchecked_into::<web_sys::IntersectionObserverEntry>()
+                            .is_intersecting(),
+                    );
+                }
+            },
+        );
+        let mut options = web_sys::IntersectionObserverInit::new();
+        options.root_margin("200px");
+
+        if let Ok(observer) = web_sys::IntersectionObserver::new_with_options(
+            callback.as_ref().unchecked_ref(),
+            &options,
+        ) {
+            observer.observe(&el.into_any());
+            on_cleanup(move || {
+                observer.disconnect();
+                drop(callback);
+            });
+        }
+    });
+}
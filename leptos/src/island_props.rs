@@ -0,0 +1,169 @@
+use leptos_reactive::{use_context, RwSignal, Signal, SignalWith};
+use serde::de::DeserializeOwned;
+
+/// The serialized props of the island that is currently being rendered,
+/// which can be updated after the island has hydrated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct IslandProps(RwSignal<String>);
+
+/// Returns the current props of the surrounding island, deserialized as `T`.
+///
+/// Islands serialize their props once, when they are rendered on the server.
+/// If the page around an island is later re-rendered on the server (for
+/// example, by refreshing part of the page), the island can be given its new
+/// props with [`update_island_props`] instead of being reloaded. This signal
+/// updates whenever that happens.
+///
+/// `T` can be any type that deserializes from the island’s props, such as a
+/// struct with some or all of the same fields.
+///
+/// Returns `None` when the props are not available, i.e., when called outside
+/// an island, or when rendering on the server, where the island’s own props
+/// should be used.
+///
+/// ```rust
+/// # use leptos::*;
+/// # use serde::Deserialize;
+/// #[derive(Deserialize)]
+/// struct InitialProps {
+///     initial: i32,
+/// }
+///
+/// #[island]
+/// fn Counter(initial: i32) -> impl IntoView {
+///     let props = use_island_props::<InitialProps>();
+///     let initial = move || {
+///         props
+///             .with(|props| props.as_ref().map(|props| props.initial))
+///             .unwrap_or(initial)
+///     };
+///     view! { <p>"Starting from " {initial}</p> }
+/// }
+/// ```
+pub fn use_island_props<T>() -> Signal<Option<T>>
+where
+    T: DeserializeOwned + 'static,
+{
+    let props = use_context::<IslandProps>();
+    Signal::derive(move || {
+        props.and_then(|props| {
+            props.0.with(|json| serde_json::from_str(json).ok())
+        })
+    })
+}
+
+#[cfg(feature = "hydrate")]
+mod hydrate {
+    use super::IslandProps;
+    use leptos_dom::document;
+    use leptos_reactive::{
+        create_rw_signal, on_cleanup, provide_context, SignalSet,
+        SignalWithUntracked,
+    };
+    use std::{cell::RefCell, collections::HashMap};
+    use wasm_bindgen::JsCast;
+
+    thread_local! {
+        static ISLAND_PROPS: RefCell<HashMap<(String, String), IslandProps>> =
+            Default::default();
+    }
+
+    pub(crate) fn island_key(
+        el: &web_sys::Element,
+    ) -> Option<(String, String)> {
+        Some((
+            el.get_attribute("data-component")?,
+            el.get_attribute("data-hkc")?,
+        ))
+    }
+
+    #[doc(hidden)]
+    /// Makes the props of an island that is being hydrated available to
+    /// [`use_island_props`](super::use_island_props), and registers them so
+    /// they can be updated later.
+    pub fn provide_island_props(el: &web_sys::HtmlElement) {
+        let props = IslandProps(create_rw_signal(
+            el.get_attribute("data-props").unwrap_or_default(),
+        ));
+        provide_context(props);
+        if let Some(key) = island_key(el) {
+            ISLAND_PROPS.with(|islands| {
+                islands.borrow_mut().insert(key.clone(), props)
+            });
+            on_cleanup(move || {
+                ISLAND_PROPS.with(|islands| {
+                    let mut islands = islands.borrow_mut();
+                    if islands.get(&key) == Some(&props) {
+                        islands.remove(&key);
+                    }
+                })
+            });
+        }
+    }
+
+    /// Pushes new serialized props into an island that has already hydrated.
+    ///
+    /// This updates its `data-props` attribute and notifies
+    /// [`use_island_props`](super::use_island_props), if the props differ from
+    /// the current ones. Returns `true` if the props changed.
+    pub fn set_island_props(island: &web_sys::Element, props: &str) -> bool {
+        if island.get_attribute("data-props").as_deref() == Some(props) {
+            return false;
+        }
+        _ = island.set_attribute("data-props", props);
+        if let Some(key) = island_key(island) {
+            let signal = ISLAND_PROPS
+                .with(|islands| islands.borrow().get(&key).copied());
+            if let Some(IslandProps(signal)) = signal {
+                if signal.with_untracked(|current| current != props) {
+                    signal.set(props.to_string());
+                }
+            }
+        }
+        true
+    }
+
+    /// Compares the islands in freshly server-rendered HTML with the islands
+    /// that are already on the page, and pushes any changed props into the
+    /// existing islands with [`set_island_props`].
+    ///
+    /// Islands are matched by their component name and hydration key, so
+    /// `fresh` should be the result of rendering the same part of the app
+    /// again, e.g., a region of the page re-rendered by the server.
+    ///
+    /// Returns the number of islands whose props changed.
+    pub fn update_island_props(fresh: &web_sys::Element) -> usize {
+        let Ok(fresh_islands) = fresh.query_selector_all("leptos-island")
+        else {
+            return 0;
+        };
+        let mut updated = 0;
+        for idx in 0..fresh_islands.length() {
+            let Some(fresh_island) = fresh_islands
+                .item(idx)
+                .and_then(|node| node.dyn_into::<web_sys::Element>().ok())
+            else {
+                continue;
+            };
+            let (Some((component, hkc)), Some(props)) = (
+                island_key(&fresh_island),
+                fresh_island.get_attribute("data-props"),
+            ) else {
+                continue;
+            };
+            let selector = format!(
+                "leptos-island[data-component=\"{component}\"][data-hkc=\"\
+                 {hkc}\"]"
+            );
+            if let Ok(Some(island)) = document().query_selector(&selector) {
+                if set_island_props(&island, &props) {
+                    updated += 1;
+                }
+            }
+        }
+        updated
+    }
+}
+
+#[cfg(feature = "hydrate")]
+pub use hydrate::*;
@@ -0,0 +1,69 @@
+use leptos_dom::HydrationKey;
+use serde::Serialize;
+use std::sync::{Arc, RwLock};
+
+/// An island that was rendered on the server, as passed to the hook set with
+/// [`set_island_report_hook`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize)]
+pub struct RenderedIsland {
+    /// The name of the island’s component.
+    pub component: &'static str,
+    /// The hydration key of the island, which is unique within its page.
+    pub hydration_key: String,
+    /// The length of the island’s props, serialized as JSON into its
+    /// `data-props` attribute, in bytes. Islands whose only prop is
+    /// `children` have no serialized props.
+    pub props_bytes: usize,
+}
+
+type IslandReportHook = Arc<dyn Fn(&RenderedIsland) + Send + Sync>;
+
+static HOOK: RwLock<Option<IslandReportHook>> = RwLock::new(None);
+
+/// Calls `hook` with every island that is rendered on the server from now on.
+///
+/// This can be used to find islands whose props make pages large, which are
+/// often better loaded as a resource from inside the island, or to write a
+/// report for a bundle analyzer. [`RenderedIsland`] serializes to JSON:
+///
+/// ```rust
+/// # use leptos::*;
+/// set_island_report_hook(|island| {
+///     if island.props_bytes > 100_000 {
+///         eprintln!("{}", serde_json::to_string(island).unwrap());
+///     }
+/// });
+/// # clear_island_report_hook();
+/// ```
+///
+/// Islands are only reported when they are rendered with the `ssr` feature.
+/// The hook replaces any hook that was set before, and is shared by every
+/// thread.
+pub fn set_island_report_hook(
+    hook: impl Fn(&RenderedIsland) + Send + Sync + 'static,
+) {
+    *HOOK.write().unwrap_or_else(|e| e.into_inner()) = Some(Arc::new(hook));
+}
+
+/// Stops reporting the islands rendered on the server to the hook set with
+/// [`set_island_report_hook`].
+pub fn clear_island_report_hook() {
+    *HOOK.write().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+#[doc(hidden)]
+pub fn report_island(
+    component: &'static str,
+    hydration_key: HydrationKey,
+    props_bytes: usize,
+) {
+    // the lock is released before the hook runs, so it can replace itself
+    let hook = HOOK.read().unwrap_or_else(|e| e.into_inner()).clone();
+    if let Some(hook) = hook {
+        hook(&RenderedIsland {
+            component,
+            hydration_key: hydration_key.to_string(),
+            props_bytes,
+        });
+    }
+}
@@ -89,6 +89,8 @@
 //! - `tracing` Adds additional support for [`tracing`](https://docs.rs/tracing/latest/tracing/) to components.
 //! - `default-tls` Use default native TLS support. (Only applies when using server functions with a non-WASM client like a desktop app.)
 //! - `rustls` Use `rustls`. (Only applies when using server functions with a non-WASM client like a desktop app.)
+//! - `reqwest` Call server functions with [`reqwest`](https://docs.rs/reqwest/latest/reqwest/) outside the browser, from a desktop app,
+//!   a CLI tool, or a test. Set the server’s URL with [`set_server_fn_base_url_and_client`](server_fn::client::set_server_fn_base_url_and_client).
 //! - `template_macro` Enables the [`template!`](leptos_macro::template) macro, which offers faster DOM node creation for some use cases in `csr`.
 //!
 //! **Important Note:** You must enable one of `csr`, `hydrate`, or `ssr` to tell Leptos
@@ -141,8 +143,14 @@
 
 mod additional_attributes;
 pub use additional_attributes::*;
+mod any_component;
+pub use any_component::*;
 mod await_;
 pub use await_::*;
+#[cfg(feature = "serde")]
+pub mod dynamic_view;
+#[cfg(feature = "serde")]
+pub mod form;
 pub use leptos_config::{self, get_configuration, LeptosOptions};
 #[cfg(not(all(
     target_arch = "wasm32",
@@ -153,19 +161,25 @@ pub mod ssr {
     pub use leptos_dom::{ssr::*, ssr_in_order::*};
 }
 pub use leptos_dom::{
-    self, create_node_ref, document, ev,
+    self, buffer_dom_writes, classes, create_node_ref, document, ev,
+    flush_dom_writes,
     helpers::{
-        event_target, event_target_checked, event_target_value,
-        request_animation_frame, request_animation_frame_with_handle,
-        request_idle_callback, request_idle_callback_with_handle, set_interval,
+        event_target, event_target_checked, event_target_files,
+        event_target_selected_values, event_target_value,
+        event_target_value_as, request_animation_frame,
+        request_animation_frame_with_handle, request_idle_callback,
+        request_idle_callback_with_handle, set_interval,
         set_interval_with_handle, set_timeout, set_timeout_with_handle,
-        window_event_listener, window_event_listener_untyped,
+        window_event_listener, window_event_listener_untyped, CallbackTiming,
     },
     html,
     html::Binding,
-    math, mount_to, mount_to_body, nonce, svg, window, Attribute, Class,
-    CollectView, Errors, EventHandlerFn, Fragment, HtmlElement, IntoAttribute,
-    IntoClass, IntoProperty, IntoStyle, IntoView, NodeRef, Property, View,
+    math, mount_root, mount_root_to_body, mount_to, mount_to_body,
+    mount_to_multiple, nonce, style, svg, use_id, window, Attribute, Class,
+    ClassList, CollectView, Color, EachAnimation, Errors, EventHandlerFn,
+    ForItemContext, Fragment, HtmlElement, Interpolate, IntoAttribute,
+    IntoClass, IntoProperty, IntoStyle, IntoView, Length, NodeRef, Property,
+    StyleMap, Unkeyed, View,
 };
 /// Utilities for simple isomorphic logging to the console or terminal.
 pub mod logging {
@@ -174,13 +188,15 @@ pub mod logging {
 
 /// Types to make it easier to handle errors in your application.
 pub mod error {
-    pub use server_fn::error::{Error, Result};
+    pub use server_fn::error::{Error, Result, SerializedError};
 }
 #[cfg(all(target_arch = "wasm32", feature = "template_macro"))]
 pub use leptos_macro::template;
 #[cfg(not(all(target_arch = "wasm32", feature = "template_macro")))]
 pub use leptos_macro::view as template;
-pub use leptos_macro::{component, island, slice, slot, view, Params};
+pub use leptos_macro::{
+    component, island, slice, slot, view, IntoView, Params, Store, Theme,
+};
 cfg_if::cfg_if!(
     if #[cfg(feature="spin")] {
         pub use leptos_spin_macro::server;
@@ -191,25 +207,75 @@ cfg_if::cfg_if!(
 pub use leptos_reactive::*;
 pub use leptos_server::{
     self, create_action, create_multi_action, create_server_action,
-    create_server_multi_action, Action, MultiAction, ServerFnError,
-    ServerFnErrorErr,
+    create_server_multi_action, provide_request_id, use_request_id, Action,
+    MultiAction, RequestId, ServerFnError, ServerFnErrorErr,
 };
 pub use server_fn::{self, ServerFn as _};
 mod error_boundary;
 pub use error_boundary::*;
+mod animated_for;
 mod animated_show;
+mod browser_only;
+mod code_block;
+mod color_scheme;
+mod cookie;
+mod device;
+mod drop_zone;
+#[cfg(feature = "serde")]
+mod flags;
 mod for_loop;
+mod gestures;
+mod image;
+mod infinite_resource;
+#[cfg(feature = "experimental-islands")]
+mod island_props;
+#[cfg(feature = "experimental-islands")]
+mod island_report;
+mod media;
+mod print;
 mod provider;
+mod region;
+mod request_scope;
+mod screen;
 mod show;
+#[cfg(feature = "serde")]
+mod state;
+mod table;
+mod virtual_for;
+pub use animated_for::*;
 pub use animated_show::*;
+pub use browser_only::*;
+pub use code_block::*;
+pub use color_scheme::*;
+pub use cookie::*;
+pub use device::*;
+pub use drop_zone::*;
+#[cfg(feature = "serde")]
+pub use flags::*;
 pub use for_loop::*;
+pub use gestures::*;
+pub use image::*;
+pub use infinite_resource::*;
+#[cfg(feature = "experimental-islands")]
+pub use island_props::*;
+#[cfg(feature = "experimental-islands")]
+pub use island_report::*;
+pub use media::*;
+pub use print::*;
 pub use provider::*;
+pub use region::*;
+pub use request_scope::*;
+pub use screen::*;
 #[cfg(feature = "experimental-islands")]
 pub use serde;
 #[cfg(feature = "experimental-islands")]
 pub use serde_json;
 pub use show::*;
+#[cfg(feature = "serde")]
+pub use state::*;
 pub use suspense_component::*;
+pub use table::*;
+pub use virtual_for::*;
 mod suspense_component;
 mod transition;
 
@@ -0,0 +1,462 @@
+use leptos_dom::{html::ElementDescriptor, NodeRef};
+#[cfg(any(
+    test,
+    all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate"))
+))]
+use leptos_reactive::SignalSet;
+use leptos_reactive::{
+    create_rw_signal, store_value, RwSignal, Signal, SignalGetUntracked,
+    StoredValue,
+};
+use std::time::Duration;
+
+/// How [`use_media_with_options`] updates its signals.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MediaOptions {
+    /// The shortest time between two updates of the current time while the
+    /// media plays. Seeking, pausing, and the end of the media always update
+    /// it. Defaults to 250 milliseconds.
+    pub time_update_interval: Duration,
+}
+
+impl Default for MediaOptions {
+    fn default() -> Self {
+        Self {
+            time_update_interval: Duration::from_millis(250),
+        }
+    }
+}
+
+/// The state of an `<audio>` or `<video>` element and the methods to control
+/// it, as returned by [`use_media`].
+#[derive(Clone, Copy, Debug)]
+pub struct Media {
+    /// Whether the media is paused, which it is until it is played.
+    pub paused: Signal<bool>,
+    /// Whether the media is playing, i.e., not paused, waiting for data, or
+    /// at its end.
+    pub playing: Signal<bool>,
+    /// Whether the media has played to its end.
+    pub ended: Signal<bool>,
+    /// The playback position, in seconds.
+    pub current_time: Signal<f64>,
+    /// The length of the media, in seconds, which is `None` until it is
+    /// known, and infinite for streams.
+    pub duration: Signal<Option<f64>>,
+    /// The ranges of the media that have been loaded, as the start and end of
+    /// each, in seconds.
+    pub buffered: Signal<Vec<(f64, f64)>>,
+    /// The volume, from `0.0` to `1.0`. Setting it changes the volume of the
+    /// element.
+    pub volume: RwSignal<f64>,
+    /// Whether the media is muted. Setting it mutes or unmutes the element.
+    pub muted: RwSignal<bool>,
+    /// The speed at which the media plays, where `1.0` is normal speed.
+    /// Setting it changes the speed of the element.
+    pub playback_rate: RwSignal<f64>,
+    element: StoredValue<Option<web_sys::HtmlMediaElement>>,
+}
+
+impl Media {
+    /// Starts or resumes playing the media. Browsers may refuse to play media
+    /// with sound before the user has interacted with the page.
+    pub fn play(&self) {
+        self.element.with_value(|element| {
+            if let Some(element) = element {
+                _ = element.play();
+            }
+        });
+    }
+
+    /// Pauses the media.
+    pub fn pause(&self) {
+        self.element.with_value(|element| {
+            if let Some(element) = element {
+                _ = element.pause();
+            }
+        });
+    }
+
+    /// Plays the media if it is paused, and pauses it otherwise.
+    pub fn toggle(&self) {
+        if self.paused.get_untracked() {
+            self.play();
+        } else {
+            self.pause();
+        }
+    }
+
+    /// Moves the playback position to the given time, in seconds.
+    pub fn seek(&self, time: f64) {
+        self.element.with_value(|element| {
+            if let Some(element) = element {
+                element.set_current_time(time);
+            }
+        });
+    }
+}
+
+/// Binds signals to an `<audio>` or `<video>` element, so that media controls
+/// can be built without listening to each of its events.
+///
+/// The signals are updated by the element's events, and setting
+/// [`volume`](Media::volume), [`muted`](Media::muted), or
+/// [`playback_rate`](Media::playback_rate) updates the element. The current
+/// time is updated at most every 250 milliseconds while the media plays.
+/// The listeners are removed when the current reactive owner is cleaned up.
+///
+/// On the server, and until the element is mounted, the signals keep their
+/// initial values, and the methods do nothing.
+///
+/// ```rust
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// let video = create_node_ref::<html::Video>();
+/// let media = use_media(video);
+/// let progress = move || {
+///     let duration = media.duration.get().unwrap_or_default();
+///     format!("{:.0} / {:.0}", media.current_time.get(), duration)
+/// };
+/// view! {
+///     <video node_ref=video src="/intro.mp4"/>
+///     <button on:click=move |_| media.toggle()>
+///         {move || if media.paused.get() { "Play" } else { "Pause" }}
+///     </button>
+///     <button on:click=move |_| media.seek(0.0)>"Restart"</button>
+///     <input
+///         type="range"
+///         min="0"
+///         max="1"
+///         step="0.1"
+///         prop:value=move || media.volume.get()
+///         on:input=move |ev| {
+///             if let Ok(volume) = event_target_value_as(&ev) {
+///                 media.volume.set(volume);
+///             }
+///         }
+///     />
+///     <span>{progress}</span>
+/// }
+/// # ;
+/// # runtime.dispose();
+/// ```
+pub fn use_media<El>(node_ref: NodeRef<El>) -> Media
+where
+    El: ElementDescriptor + Clone + 'static,
+{
+    use_media_with_options(node_ref, MediaOptions::default())
+}
+
+/// Like [`use_media`], but updates the signals with the given options.
+pub fn use_media_with_options<El>(
+    node_ref: NodeRef<El>,
+    options: MediaOptions,
+) -> Media
+where
+    El: ElementDescriptor + Clone + 'static,
+{
+    let state = MediaState::new();
+    let media = Media {
+        paused: state.paused.into(),
+        playing: state.playing.into(),
+        ended: state.ended.into(),
+        current_time: state.current_time.into(),
+        duration: state.duration.into(),
+        buffered: state.buffered.into(),
+        volume: create_rw_signal(1.0),
+        muted: create_rw_signal(false),
+        playback_rate: create_rw_signal(1.0),
+        element: store_value(None),
+    };
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    ))]
+    node_ref.on_load(move |el| {
+        use wasm_bindgen::JsCast;
+
+        let Ok(element) = (*el.into_any())
+            .clone()
+            .dyn_into::<web_sys::HtmlMediaElement>()
+        else {
+            leptos_dom::debug_warn!(
+                "use_media() was given a NodeRef to an element that is not \
+                 <audio> or <video>"
+            );
+            return;
+        };
+        media.element.set_value(Some(element.clone()));
+        bind_media(media, state, element, options);
+    });
+    #[cfg(not(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    )))]
+    let _ = (node_ref, options, state);
+
+    media
+}
+
+/// The signals of a [`Media`] that only the element's events set.
+#[derive(Clone, Copy)]
+struct MediaState {
+    paused: RwSignal<bool>,
+    playing: RwSignal<bool>,
+    ended: RwSignal<bool>,
+    current_time: RwSignal<f64>,
+    duration: RwSignal<Option<f64>>,
+    buffered: RwSignal<Vec<(f64, f64)>>,
+}
+
+impl MediaState {
+    fn new() -> Self {
+        Self {
+            paused: create_rw_signal(true),
+            playing: create_rw_signal(false),
+            ended: create_rw_signal(false),
+            current_time: create_rw_signal(0.0),
+            duration: create_rw_signal(None),
+            buffered: create_rw_signal(Vec::new()),
+        }
+    }
+}
+
+#[cfg(any(
+    test,
+    all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate"))
+))]
+impl MediaState {
+    /// The media was asked to play.
+    fn play(self) {
+        self.paused.set(false);
+        self.ended.set(false);
+    }
+
+    /// The media started playing, or resumed after waiting for data.
+    fn start_playing(self) {
+        self.playing.set(true);
+    }
+
+    fn pause(self) {
+        self.paused.set(true);
+        self.playing.set(false);
+    }
+
+    /// The media stopped playing to wait for data.
+    fn wait(self) {
+        self.playing.set(false);
+    }
+
+    fn end(self) {
+        self.ended.set(true);
+        self.playing.set(false);
+    }
+}
+
+/// Limits how often the current time is read while the media plays.
+#[cfg(any(
+    test,
+    all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate"))
+))]
+struct TimeUpdates {
+    // in milliseconds
+    interval: f64,
+    last: std::cell::Cell<f64>,
+}
+
+#[cfg(any(
+    test,
+    all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate"))
+))]
+impl TimeUpdates {
+    fn new(interval: Duration) -> Self {
+        Self {
+            interval: interval.as_secs_f64() * 1000.0,
+            last: std::cell::Cell::new(f64::NEG_INFINITY),
+        }
+    }
+
+    /// Whether the time should be read at `now`, in milliseconds, which it
+    /// always is if `forced`. If so, `now` becomes the time of the last read.
+    fn take(&self, now: f64, forced: bool) -> bool {
+        let due = forced || now - self.last.get() >= self.interval;
+        if due {
+            self.last.set(now);
+        }
+        due
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate")))]
+fn bind_media(
+    media: Media,
+    state: MediaState,
+    element: web_sys::HtmlMediaElement,
+    options: MediaOptions,
+) {
+    use crate::gestures::listen;
+    use leptos_reactive::{create_effect, SignalGet};
+    use std::rc::Rc;
+
+    let MediaState {
+        paused,
+        ended,
+        current_time,
+        duration,
+        buffered,
+        ..
+    } = state;
+    let time_updates = Rc::new(TimeUpdates::new(options.time_update_interval));
+    // a pause, a seek, or the end moves the time on its own, so reading it
+    // is forced then
+    let read_time = {
+        let element = element.clone();
+        move |forced: bool| {
+            if time_updates.take(js_sys::Date::now(), forced) {
+                current_time.set(element.current_time());
+            }
+        }
+    };
+    let read_duration = {
+        let element = element.clone();
+        move || {
+            let value = element.duration();
+            duration.set((!value.is_nan()).then_some(value));
+        }
+    };
+    let read_buffered = {
+        let element = element.clone();
+        move || {
+            let ranges = element.buffered();
+            buffered.set(
+                (0..ranges.length())
+                    .filter_map(|i| {
+                        Some((ranges.start(i).ok()?, ranges.end(i).ok()?))
+                    })
+                    .collect(),
+            );
+        }
+    };
+    let target: &web_sys::EventTarget = &element;
+
+    // the element may have loaded before it was mounted, and its attributes
+    // set the volume, whether it is muted, and the playback rate
+    media.volume.set(element.volume());
+    media.muted.set(element.muted());
+    media.playback_rate.set(element.playback_rate());
+    paused.set(element.paused());
+    ended.set(element.ended());
+    read_time(true);
+    read_duration();
+    read_buffered();
+
+    listen(target, "play", move |_: web_sys::Event| state.play());
+    listen(target, "playing", move |_: web_sys::Event| {
+        state.start_playing()
+    });
+    listen(target, "pause", move |_: web_sys::Event| state.pause());
+    listen(target, "waiting", move |_: web_sys::Event| state.wait());
+    listen(target, "ended", {
+        let read_time = read_time.clone();
+        move |_: web_sys::Event| {
+            state.end();
+            read_time(true);
+        }
+    });
+    listen(target, "timeupdate", {
+        let element = element.clone();
+        let read_time = read_time.clone();
+        move |_: web_sys::Event| {
+            read_time(element.paused() || element.seeking())
+        }
+    });
+    listen(target, "seeked", {
+        let read_time = read_time.clone();
+        move |_: web_sys::Event| read_time(true)
+    });
+    for event in ["durationchange", "loadedmetadata", "emptied"] {
+        listen(target, event, {
+            let read_duration = read_duration.clone();
+            move |_: web_sys::Event| read_duration()
+        });
+    }
+    listen(target, "progress", move |_: web_sys::Event| read_buffered());
+    listen(target, "volumechange", {
+        let element = element.clone();
+        move |_: web_sys::Event| {
+            if media.volume.get_untracked() != element.volume() {
+                media.volume.set(element.volume());
+            }
+            if media.muted.get_untracked() != element.muted() {
+                media.muted.set(element.muted());
+            }
+        }
+    });
+    listen(target, "ratechange", {
+        let element = element.clone();
+        move |_: web_sys::Event| {
+            if media.playback_rate.get_untracked() != element.playback_rate() {
+                media.playback_rate.set(element.playback_rate());
+            }
+        }
+    });
+
+    create_effect(move |_| {
+        let volume = media.volume.get();
+        if element.volume() != volume {
+            element.set_volume(volume.clamp(0.0, 1.0));
+        }
+        let muted = media.muted.get();
+        if element.muted() != muted {
+            element.set_muted(muted);
+        }
+        let playback_rate = media.playback_rate.get();
+        if element.playback_rate() != playback_rate {
+            element.set_playback_rate(playback_rate);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos_reactive::{create_runtime, SignalGet};
+
+    #[test]
+    fn media_events_update_the_playback_state() {
+        let runtime = create_runtime();
+        let state = MediaState::new();
+        let playback =
+            || (state.paused.get(), state.playing.get(), state.ended.get());
+
+        state.play();
+        assert_eq!(playback(), (false, false, false));
+        state.start_playing();
+        assert_eq!(playback(), (false, true, false));
+        state.wait();
+        assert_eq!(playback(), (false, false, false));
+        state.start_playing();
+        state.end();
+        assert_eq!(playback(), (false, false, true));
+        // playing again starts over
+        state.play();
+        assert_eq!(playback(), (false, false, false));
+        state.start_playing();
+        state.pause();
+        assert_eq!(playback(), (true, false, false));
+
+        runtime.dispose();
+    }
+
+    #[test]
+    fn time_updates_are_throttled_unless_forced() {
+        let updates = TimeUpdates::new(Duration::from_millis(250));
+        assert!(updates.take(0.0, false));
+        assert!(!updates.take(100.0, false));
+        assert!(updates.take(200.0, true));
+        assert!(!updates.take(400.0, false));
+        assert!(updates.take(450.0, false));
+        assert!(!updates.take(699.0, false));
+    }
+}
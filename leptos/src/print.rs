@@ -0,0 +1,140 @@
+use leptos::{component, ChildrenFn, ViewFn};
+use leptos_dom::{helpers::window_event_listener_untyped, IntoView};
+use leptos_reactive::{on_cleanup, signal_prelude::*};
+
+/// Runs `f` each time the browser is about to print the page, or to show a
+/// print preview, until the current reactive owner is cleaned up.
+///
+/// Any changes made to the page from `f` (including by setting signals) are
+/// included in the printed page.
+///
+/// This does nothing on the server.
+///
+/// ```rust
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// let expanded = create_rw_signal(false);
+/// // print every section of a report, not only the ones that are expanded
+/// use_before_print(move || expanded.set(true));
+/// use_after_print(move || expanded.set(false));
+/// # runtime.dispose();
+/// ```
+pub fn use_before_print(f: impl Fn() + 'static) {
+    let handle = window_event_listener_untyped("beforeprint", move |_| f());
+    on_cleanup(move || handle.remove());
+}
+
+/// Runs `f` each time the browser has printed the page, or closed the print
+/// preview, until the current reactive owner is cleaned up.
+///
+/// This does nothing on the server.
+pub fn use_after_print(f: impl Fn() + 'static) {
+    let handle = window_event_listener_untyped("afterprint", move |_| f());
+    on_cleanup(move || handle.remove());
+}
+
+/// Returns a signal that is `true` while the page is being printed, using the
+/// `print` media query.
+///
+/// The signal is set before the browser lays out the page for printing, so
+/// views that depend on it are printed with their print layout. On the server,
+/// and during hydration, it is `false`.
+///
+/// ```rust
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// let printing = use_print_mode();
+/// view! {
+///   <p>{move || if printing.get() { "Printed on paper" } else { "On screen" }}</p>
+/// }
+/// # ;
+/// # runtime.dispose();
+/// ```
+pub fn use_print_mode() -> Signal<bool> {
+    let printing = create_rw_signal(false);
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    ))]
+    crate::device::watch_media_query("print", move |matches| {
+        printing.set(matches)
+    });
+
+    // not every browser notifies the media query before printing, but they
+    // all fire these events
+    use_before_print(move || printing.set(true));
+    use_after_print(move || printing.set(false));
+
+    printing.into()
+}
+
+/// Renders its children only while the page is being printed, and the
+/// `fallback` otherwise.
+///
+/// Together with [`ScreenOnly`], this makes it possible to use a different
+/// layout for printing, for example a table instead of a chart. The children
+/// are only created when the browser starts printing, so an expensive print
+/// layout does not slow down the page.
+///
+/// ```rust
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// view! {
+///   <ScreenOnly>
+///     <canvas id="sales-chart"></canvas>
+///   </ScreenOnly>
+///   <PrintOnly>
+///     <table>"…"</table>
+///   </PrintOnly>
+/// }
+/// # ;
+/// # runtime.dispose();
+/// ```
+///
+/// See [`use_print_mode`] for how printing is detected.
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    tracing::instrument(level = "trace", skip_all)
+)]
+#[component]
+pub fn PrintOnly(
+    /// The view that is printed.
+    children: ChildrenFn,
+    /// A closure that returns what gets rendered when the page is not being
+    /// printed. By default this is the empty view.
+    #[prop(optional, into)]
+    fallback: ViewFn,
+) -> impl IntoView {
+    let printing = use_print_mode();
+
+    move || match printing.get() {
+        true => children().into_view(),
+        false => fallback.run(),
+    }
+}
+
+/// Renders its children unless the page is being printed, and the `fallback`
+/// while it is.
+///
+/// See [`PrintOnly`] for an example.
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    tracing::instrument(level = "trace", skip_all)
+)]
+#[component]
+pub fn ScreenOnly(
+    /// The view that is shown on screen.
+    children: ChildrenFn,
+    /// A closure that returns what gets rendered when the page is being
+    /// printed. By default this is the empty view.
+    #[prop(optional, into)]
+    fallback: ViewFn,
+) -> impl IntoView {
+    let printing = use_print_mode();
+
+    move || match printing.get() {
+        true => fallback.run(),
+        false => children().into_view(),
+    }
+}
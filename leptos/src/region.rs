@@ -0,0 +1,250 @@
+use crate::Children;
+use leptos_dom::{html, IntoView};
+use leptos_macro::component;
+use std::fmt;
+
+/// The path of the endpoint that the server integrations register to re-render
+/// a single [`Region`].
+///
+/// It takes two query parameters: `id`, the ID of the region, and `path`, the
+/// path (and query) of the page the region is on.
+pub const REGION_ENDPOINT: &str = "/__leptos_region";
+
+/// Marks a server-rendered part of the page that can be re-rendered on its own
+/// with [`refresh_region`], without reloading the whole page.
+///
+/// This is most useful with islands: the region’s contents are rendered on the
+/// server, and any islands inside it are hydrated again after a refresh.
+///
+/// ```rust
+/// # use leptos::*;
+/// #[component]
+/// fn Cart() -> impl IntoView {
+///     view! {
+///         <Region id="cart">
+///             <p>"Items in your cart: 3"</p>
+///         </Region>
+///     }
+/// }
+/// ```
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    tracing::instrument(level = "trace", skip_all)
+)]
+#[component]
+pub fn Region(
+    /// The ID of the region, which must be unique on the page.
+    #[prop(into)]
+    id: String,
+    /// The contents of the region.
+    children: Children,
+) -> impl IntoView {
+    html::custom(html::Custom::new("leptos-region"))
+        .attr("id", id)
+        .child(children())
+}
+
+/// Finds the [`Region`] with the given ID in a server-rendered HTML page, and
+/// returns its HTML, including the `<leptos-region>` element itself.
+pub fn extract_region<'a>(html: &'a str, id: &str) -> Option<&'a str> {
+    const OPEN: &str = "<leptos-region";
+    const CLOSE: &str = "</leptos-region>";
+
+    let mut search_from = 0;
+    let start = loop {
+        let start = search_from + html[search_from..].find(OPEN)?;
+        let tag_end = start + html[start..].find('>')?;
+        let tag = &html[start..tag_end];
+        if tag.contains(&format!(" id=\"{id}\"")) {
+            break start;
+        }
+        search_from = tag_end;
+    };
+
+    // find the matching closing tag, skipping over any nested regions
+    let mut depth = 0;
+    let mut pos = start;
+    loop {
+        let next_open = html[pos + 1..].find(OPEN).map(|idx| idx + pos + 1);
+        let next_close =
+            html[pos + 1..].find(CLOSE).map(|idx| idx + pos + 1)?;
+        match next_open {
+            Some(open) if open < next_close => {
+                depth += 1;
+                pos = open;
+            }
+            _ if depth == 0 => {
+                return Some(&html[start..next_close + CLOSE.len()]);
+            }
+            _ => {
+                depth -= 1;
+                pos = next_close;
+            }
+        }
+    }
+}
+
+/// An error that occurs when refreshing a [`Region`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RegionError {
+    /// There is no region with this ID, either on the page or in the server’s
+    /// response.
+    NotFound(String),
+    /// The server responded with an error status.
+    Status(u16),
+    /// The request could not be sent, or the response could not be read.
+    Fetch(String),
+}
+
+impl fmt::Display for RegionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFound(id) => write!(f, "no region with ID `{id}`"),
+            Self::Status(status) => {
+                write!(f, "the server responded with status {status}")
+            }
+            Self::Fetch(message) => {
+                write!(f, "could not refresh the region: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RegionError {}
+
+/// Re-renders the [`Region`] with the given ID on the server, and swaps the new
+/// HTML into the page.
+///
+/// Islands in the region that were already on the page are kept (along with
+/// their state) and receive their new props, as with
+/// [`update_island_props`](crate::update_island_props); new islands are
+/// hydrated.
+#[cfg(feature = "hydrate")]
+pub async fn refresh_region(id: &str) -> Result<(), RegionError> {
+    use leptos_dom::{document, window};
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_futures::JsFuture;
+
+    let fetch_error =
+        |e: wasm_bindgen::JsValue| RegionError::Fetch(format!("{e:?}"));
+
+    let location = window().location();
+    let path = format!(
+        "{}{}",
+        location.pathname().unwrap_or_default(),
+        location.search().unwrap_or_default()
+    );
+    let url = format!(
+        "{REGION_ENDPOINT}?id={}&path={}",
+        js_sys::encode_uri_component(id),
+        js_sys::encode_uri_component(&path)
+    );
+    let response: web_sys::Response =
+        JsFuture::from(window().fetch_with_str(&url))
+            .await
+            .map_err(fetch_error)?
+            .unchecked_into();
+    if !response.ok() {
+        return Err(RegionError::Status(response.status()));
+    }
+    let html = JsFuture::from(response.text().map_err(fetch_error)?)
+        .await
+        .map_err(fetch_error)?
+        .as_string()
+        .unwrap_or_default();
+
+    let not_found = || RegionError::NotFound(id.to_string());
+    let current = document().get_element_by_id(id).ok_or_else(not_found)?;
+    let template: web_sys::HtmlTemplateElement = document()
+        .create_element("template")
+        .map_err(fetch_error)?
+        .unchecked_into();
+    template.set_inner_html(&html);
+    let fresh = template
+        .content()
+        .query_selector("leptos-region")
+        .ok()
+        .flatten()
+        .ok_or_else(not_found)?;
+
+    #[cfg(feature = "experimental-islands")]
+    let new_islands = islands::rebind(&current, &fresh);
+    current
+        .replace_with_with_node_1(&fresh)
+        .map_err(fetch_error)?;
+    #[cfg(feature = "experimental-islands")]
+    islands::hydrate(new_islands);
+
+    Ok(())
+}
+
+#[cfg(all(feature = "hydrate", feature = "experimental-islands"))]
+mod islands {
+    use crate::{island_props::island_key, set_island_props};
+    use wasm_bindgen::{JsCast, JsValue};
+
+    /// Moves the islands that are already on the page into the fresh HTML,
+    /// updating their props, and returns the islands that are new.
+    pub fn rebind(
+        current: &web_sys::Element,
+        fresh: &web_sys::Element,
+    ) -> Vec<web_sys::Element> {
+        let mut new_islands = Vec::new();
+        let Ok(fresh_islands) = fresh.query_selector_all("leptos-island")
+        else {
+            return new_islands;
+        };
+        for idx in 0..fresh_islands.length() {
+            let Some(fresh_island) = fresh_islands
+                .item(idx)
+                .and_then(|node| node.dyn_into::<web_sys::Element>().ok())
+            else {
+                continue;
+            };
+            // skip islands inside an island that has already been replaced
+            if !fresh.contains(Some(&fresh_island)) {
+                continue;
+            }
+            let existing = island_key(&fresh_island).and_then(|(name, hkc)| {
+                let selector = format!(
+                    "leptos-island[data-component=\"{name}\"][data-hkc=\"\
+                     {hkc}\"]"
+                );
+                current.query_selector(&selector).ok().flatten()
+            });
+            match existing {
+                Some(existing) => {
+                    if let Some(props) =
+                        fresh_island.get_attribute("data-props")
+                    {
+                        set_island_props(&existing, &props);
+                    }
+                    _ = fresh_island.replace_with_with_node_1(&existing);
+                }
+                None => new_islands.push(fresh_island),
+            }
+        }
+        new_islands
+    }
+
+    /// Hydrates new islands, using the hook installed by the server
+    /// integrations’ hydration script.
+    pub fn hydrate(islands: Vec<web_sys::Element>) {
+        let hydrate_island = js_sys::Reflect::get(
+            &leptos_dom::window(),
+            &JsValue::from_str("__leptos_hydrate_island"),
+        )
+        .ok()
+        .and_then(|f| f.dyn_into::<js_sys::Function>().ok());
+        let Some(hydrate_island) = hydrate_island else {
+            leptos_dom::warn!(
+                "could not hydrate islands in a refreshed region: the \
+                 hydration script did not install `__leptos_hydrate_island`"
+            );
+            return;
+        };
+        for island in islands {
+            _ = hydrate_island.call1(&JsValue::NULL, &island);
+        }
+    }
+}
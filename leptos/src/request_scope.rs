@@ -0,0 +1,239 @@
+use leptos_dom::use_id;
+#[cfg(not(all(
+    target_arch = "wasm32",
+    any(feature = "csr", feature = "hydrate")
+)))]
+use leptos_reactive::{provide_context, use_context};
+use std::time::Duration;
+
+/// A random seed and a timestamp that are fixed for one server-rendered
+/// request, which [`use_request_rng`] and [`use_request_time`] use so that
+/// the values they return in the browser are the same as the ones the page
+/// was rendered with on the server.
+///
+/// The server integrations provide a new `RequestScope` for each request
+/// with [`provide_request_scope`], and include it in the page, where the
+/// browser reads it when it first needs it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RequestScope {
+    seed: u64,
+    time: Duration,
+}
+
+impl RequestScope {
+    /// The `name` of the `<meta>` tag that the scope is sent to the browser
+    /// in.
+    const META_NAME: &'static str = "leptos-request-scope";
+
+    /// Creates a scope with a random seed and the current time.
+    pub fn new() -> Self {
+        let id = leptos_server::RequestId::new();
+        Self {
+            seed: hash(id.as_str()),
+            time: now(),
+        }
+    }
+
+    /// Creates a scope with the given seed and time, which is the duration
+    /// since the UNIX epoch. This is useful to render pages that are the same
+    /// every time, like in tests.
+    pub fn with_seed(seed: u64, time: Duration) -> Self {
+        Self { seed, time }
+    }
+
+    /// Returns the `<meta>` tag that sends the scope to the browser, which
+    /// the server integrations include in the `<head>` of the page.
+    pub fn meta_tag(&self) -> String {
+        format!(
+            r#"<meta name="{}" content="{}:{}"/>"#,
+            Self::META_NAME,
+            self.seed,
+            self.time.as_millis()
+        )
+    }
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    ))]
+    fn from_page() -> Option<Self> {
+        let content = leptos_dom::document()
+            .query_selector(&format!("meta[name={}]", Self::META_NAME))
+            .ok()
+            .flatten()?
+            .get_attribute("content")?;
+        let (seed, time) = content.split_once(':')?;
+        Some(Self {
+            seed: seed.parse().ok()?,
+            time: Duration::from_millis(time.parse().ok()?),
+        })
+    }
+}
+
+impl Default for RequestScope {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate")))]
+thread_local! {
+    static PAGE_SCOPE: std::cell::Cell<Option<RequestScope>> =
+        const { std::cell::Cell::new(None) };
+}
+
+/// Sets the [`RequestScope`] of the current request on the server, which
+/// the server integrations call for each request. In the browser, this
+/// replaces the scope that was read from the page.
+pub fn provide_request_scope(scope: RequestScope) {
+    #[cfg(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    ))]
+    PAGE_SCOPE.with(|page| page.set(Some(scope)));
+    #[cfg(not(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    )))]
+    provide_context(scope);
+}
+
+/// Returns the [`RequestScope`] of the current request on the server, or the
+/// one the page was rendered with in the browser. If there is none, a new
+/// one is created.
+pub fn use_request_scope() -> RequestScope {
+    #[cfg(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    ))]
+    {
+        PAGE_SCOPE.with(|page| {
+            let scope = page
+                .get()
+                .or_else(RequestScope::from_page)
+                .unwrap_or_default();
+            page.set(Some(scope));
+            scope
+        })
+    }
+    #[cfg(not(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    )))]
+    {
+        use_context::<RequestScope>().unwrap_or_else(|| {
+            let scope = RequestScope::new();
+            provide_context(scope);
+            scope
+        })
+    }
+}
+
+/// Returns the time at which the current page was requested, as the duration
+/// since the UNIX epoch.
+///
+/// On the server, this is the time of the request; in the browser, it is the
+/// same time, sent with the page, rather than the time at which the page
+/// hydrates. Text derived from it, like “5 minutes ago,” therefore does not
+/// cause hydration mismatches. Use a signal that is updated after hydration
+/// to show the current time instead.
+///
+/// ```
+/// # use leptos::*;
+/// # use std::time::Duration;
+/// # let runtime = create_runtime();
+/// #[component]
+/// fn Posted(at: Duration) -> impl IntoView {
+///     let minutes = use_request_time().saturating_sub(at).as_secs() / 60;
+///     view! { <span>{minutes} " minutes ago"</span> }
+/// }
+/// # runtime.dispose();
+/// ```
+pub fn use_request_time() -> Duration {
+    use_request_scope().time
+}
+
+/// Returns a random number generator that produces the same numbers when a
+/// component is rendered on the server and when it hydrates in the browser,
+/// so random IDs, colors, or orderings do not cause hydration mismatches.
+///
+/// The generator is seeded with the seed of the [`RequestScope`] and the
+/// position of the call in the rendering process, like [`use_id`], so it
+/// should be called while creating the view, in the body of a component.
+/// Every call returns a differently seeded generator. The numbers are not
+/// suitable for cryptography.
+///
+/// ```
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// #[component]
+/// fn Avatar(name: String) -> impl IntoView {
+///     let hue = use_request_rng().below(360);
+///     view! {
+///         <span style:background=format!("hsl({hue}, 60%, 70%)")>{name}</span>
+///     }
+/// }
+/// # runtime.dispose();
+/// ```
+pub fn use_request_rng() -> RequestRng {
+    let seed = use_request_scope().seed ^ hash(&use_id());
+    RequestRng(seed)
+}
+
+/// A small, fast random number generator, returned by [`use_request_rng`].
+///
+/// It produces the same numbers on every platform for the same seed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RequestRng(u64);
+
+impl RequestRng {
+    /// Returns a random `u64`.
+    pub fn next_u64(&mut self) -> u64 {
+        // SplitMix64
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a random number in `0.0..1.0`.
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Returns a random number in `0..n`, or `0` if `n` is `0`.
+    pub fn below(&mut self, n: u64) -> u64 {
+        if n == 0 {
+            0
+        } else {
+            ((self.next_u64() as u128 * n as u128) >> 64) as u64
+        }
+    }
+}
+
+// FNV-1a, which is the same on every platform, unlike the std hashers
+fn hash(value: &str) -> u64 {
+    value.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+fn now() -> Duration {
+    #[cfg(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    ))]
+    {
+        Duration::from_millis(js_sys::Date::now() as u64)
+    }
+    #[cfg(not(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    )))]
+    {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+    }
+}
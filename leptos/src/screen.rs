@@ -0,0 +1,735 @@
+use leptos_dom::{html::ElementDescriptor, NodeRef};
+use leptos_reactive::{
+    create_rw_signal, store_value, RwSignal, Signal, StoredValue,
+};
+
+/// Whether an element is shown fullscreen, and the methods to show it, as
+/// returned by [`use_fullscreen`].
+#[derive(Clone, Copy, Debug)]
+pub struct Fullscreen {
+    /// Whether the page is allowed to show elements fullscreen. It is `false`
+    /// in browsers without the Fullscreen API, like Safari on iPhone, in
+    /// frames that are not allowed to, and on the server.
+    pub is_supported: Signal<bool>,
+    /// Whether the element is shown fullscreen.
+    pub is_fullscreen: Signal<bool>,
+    /// Why the last request to show the element fullscreen failed, e.g.
+    /// because it was not made in response to user input.
+    pub error: Signal<Option<String>>,
+    // only read in the browser
+    #[allow(unused)]
+    last_error: RwSignal<Option<String>>,
+    #[allow(unused)]
+    element: StoredValue<Option<web_sys::Element>>,
+}
+
+impl Fullscreen {
+    /// Shows the element fullscreen. Browsers only allow this in response to
+    /// user input, like a click.
+    pub fn enter(&self) {
+        #[cfg(all(
+            target_arch = "wasm32",
+            any(feature = "csr", feature = "hydrate")
+        ))]
+        self.element.with_value(|element| {
+            if let Some(element) = element {
+                web::settle(
+                    web::call(element, "requestFullscreen", &[]),
+                    self.last_error,
+                    |_| {},
+                );
+            }
+        });
+    }
+
+    /// Leaves fullscreen, if the element is shown fullscreen.
+    pub fn exit(&self) {
+        #[cfg(all(
+            target_arch = "wasm32",
+            any(feature = "csr", feature = "hydrate")
+        ))]
+        self.element.with_value(|element| {
+            if let Some(element) = element {
+                web::exit_fullscreen(element);
+            }
+        });
+    }
+
+    /// Leaves fullscreen if the element is shown fullscreen, and enters it
+    /// otherwise.
+    pub fn toggle(&self) {
+        use leptos_reactive::SignalGetUntracked;
+
+        if self.is_fullscreen.get_untracked() {
+            self.exit();
+        } else {
+            self.enter();
+        }
+    }
+}
+
+/// Shows an element fullscreen with the
+/// [Fullscreen API](https://developer.mozilla.org/en-US/docs/Web/API/Fullscreen_API).
+///
+/// `is_fullscreen` follows the element in and out of fullscreen, including
+/// when the user leaves it with the Escape key. If the element is still
+/// fullscreen when the current reactive owner is cleaned up, fullscreen is
+/// left.
+///
+/// On the server, and until the element is mounted, fullscreen is not
+/// supported, and the methods do nothing.
+///
+/// ```rust
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// let player = create_node_ref::<html::Div>();
+/// let fullscreen = use_fullscreen(player);
+/// view! {
+///     <div node_ref=player>
+///         <video src="/intro.mp4"/>
+///         <Show when=move || fullscreen.is_supported.get()>
+///             <button on:click=move |_| fullscreen.toggle()>
+///                 {move || {
+///                     if fullscreen.is_fullscreen.get() {
+///                         "Exit fullscreen"
+///                     } else {
+///                         "Fullscreen"
+///                     }
+///                 }}
+///             </button>
+///         </Show>
+///     </div>
+/// }
+/// # ;
+/// # runtime.dispose();
+/// ```
+pub fn use_fullscreen<El>(node_ref: NodeRef<El>) -> Fullscreen
+where
+    El: ElementDescriptor + Clone + 'static,
+{
+    let is_supported = create_rw_signal(false);
+    let is_fullscreen = create_rw_signal(false);
+    let last_error = create_rw_signal(None);
+    let fullscreen = Fullscreen {
+        is_supported: is_supported.into(),
+        is_fullscreen: is_fullscreen.into(),
+        error: last_error.into(),
+        last_error,
+        element: store_value(None),
+    };
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    ))]
+    node_ref.on_load(move |el| {
+        use crate::gestures::listen;
+        use leptos_reactive::{on_cleanup, SignalSet};
+
+        let element: web_sys::Element = (*el.into_any()).clone().into();
+        let document = leptos_dom::document();
+        is_supported.set(document.fullscreen_enabled());
+        fullscreen.element.set_value(Some(element.clone()));
+
+        listen(&document, "fullscreenchange", {
+            let document = document.clone();
+            let element = element.clone();
+            move |_: web_sys::Event| {
+                is_fullscreen.set(
+                    document.fullscreen_element().as_ref() == Some(&element),
+                )
+            }
+        });
+        on_cleanup(move || web::exit_fullscreen(&element));
+    });
+    #[cfg(not(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    )))]
+    let _ = (node_ref, is_supported, is_fullscreen);
+
+    fullscreen
+}
+
+/// Whether the screen is kept on, and the methods to keep it on, as
+/// returned by [`use_wake_lock`].
+#[derive(Clone, Copy, Debug)]
+pub struct WakeLock {
+    /// Whether the browser supports the Screen Wake Lock API. It is `false`
+    /// on the server.
+    pub is_supported: Signal<bool>,
+    /// Whether the screen is being kept on.
+    pub is_active: Signal<bool>,
+    /// Why the last request to keep the screen on failed, e.g. because it
+    /// was refused by a permissions policy, or because the battery is low.
+    pub error: Signal<Option<String>>,
+    active: RwSignal<bool>,
+    #[allow(unused)]
+    last_error: RwSignal<Option<String>>,
+    // whether the lock should be held while the page is visible
+    wanted: StoredValue<bool>,
+    sentinel: StoredValue<Option<web_sys::wasm_bindgen::JsValue>>,
+}
+
+impl WakeLock {
+    /// Keeps the screen from dimming or locking until [`release`] is called,
+    /// or the current reactive owner is cleaned up.
+    ///
+    /// [`release`]: WakeLock::release
+    pub fn request(&self) {
+        self.wanted.set_value(true);
+        #[cfg(all(
+            target_arch = "wasm32",
+            any(feature = "csr", feature = "hydrate")
+        ))]
+        self.acquire();
+    }
+
+    /// Lets the screen dim and lock again.
+    pub fn release(&self) {
+        use leptos_reactive::SignalSet;
+
+        self.wanted.set_value(false);
+        let sentinel = self.sentinel.try_update_value(Option::take).flatten();
+        #[cfg(all(
+            target_arch = "wasm32",
+            any(feature = "csr", feature = "hydrate")
+        ))]
+        if let Some(sentinel) = sentinel {
+            _ = web::call(&sentinel, "release", &[]);
+        }
+        #[cfg(not(all(
+            target_arch = "wasm32",
+            any(feature = "csr", feature = "hydrate")
+        )))]
+        let _ = sentinel;
+        self.active.try_set(false);
+    }
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    ))]
+    fn acquire(self) {
+        use js_sys::Reflect;
+        use wasm_bindgen::{closure::Closure, JsValue};
+
+        if self.sentinel.with_value(Option::is_some) {
+            return;
+        }
+        let wake_lock = Reflect::get(
+            &leptos_dom::window().navigator(),
+            &JsValue::from_str("wakeLock"),
+        )
+        .unwrap_or(JsValue::UNDEFINED);
+        let request = web::call(&wake_lock, "request", &["screen".into()]);
+        web::settle(request, self.last_error, move |sentinel| {
+            if !self.is_wanted() {
+                _ = web::call(&sentinel, "release", &[]);
+                return;
+            }
+            // the browser releases the lock when the page is hidden
+            let on_release = Closure::once_into_js(move || self.revoke());
+            _ = Reflect::set(
+                &sentinel,
+                &JsValue::from_str("onrelease"),
+                &on_release,
+            );
+            self.hold(sentinel);
+        });
+    }
+}
+
+#[cfg(any(
+    test,
+    all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate"))
+))]
+impl WakeLock {
+    /// Whether a lock that was just granted should be kept, which it isn't
+    /// if it was released, or the owner cleaned up, while it was being
+    /// requested, or if another lock is already held.
+    fn is_wanted(self) -> bool {
+        let wanted = self.wanted.try_get_value().unwrap_or(false);
+        let held = self.sentinel.try_with_value(Option::is_some);
+        wanted && held == Some(false)
+    }
+
+    /// Keeps a granted lock until it is released.
+    fn hold(self, sentinel: web_sys::wasm_bindgen::JsValue) {
+        use leptos_reactive::SignalSet;
+
+        self.sentinel.set_value(Some(sentinel));
+        self.active.set(true);
+    }
+
+    /// Forgets a lock that the browser released, e.g. because the page was
+    /// hidden.
+    fn revoke(self) {
+        use leptos_reactive::SignalSet;
+
+        self.sentinel.try_set_value(None);
+        self.active.try_set(false);
+    }
+}
+
+/// Keeps the screen from dimming or locking while it is needed, like during
+/// a presentation or a recipe, with the
+/// [Screen Wake Lock API](https://developer.mozilla.org/en-US/docs/Web/API/Screen_Wake_Lock_API).
+///
+/// The browser releases the lock whenever the page is hidden; it is
+/// requested again when the page becomes visible, until
+/// [`release`](WakeLock::release) is called. The lock is released when the
+/// current reactive owner is cleaned up.
+///
+/// On the server, the wake lock is not supported, and the methods do
+/// nothing.
+///
+/// ```rust
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// let wake_lock = use_wake_lock();
+/// view! {
+///     <label>
+///         <input
+///             type="checkbox"
+///             disabled=move || !wake_lock.is_supported.get()
+///             prop:checked=move || wake_lock.is_active.get()
+///             on:change=move |ev| {
+///                 if event_target_checked(&ev) {
+///                     wake_lock.request();
+///                 } else {
+///                     wake_lock.release();
+///                 }
+///             }
+///         />
+///         "Keep the screen on"
+///     </label>
+///     {move || wake_lock.error.get()}
+/// }
+/// # ;
+/// # runtime.dispose();
+/// ```
+pub fn use_wake_lock() -> WakeLock {
+    let is_supported = create_rw_signal(false);
+    let active = create_rw_signal(false);
+    let last_error = create_rw_signal(None);
+    let wake_lock = WakeLock {
+        is_supported: is_supported.into(),
+        is_active: active.into(),
+        error: last_error.into(),
+        active,
+        last_error,
+        wanted: store_value(false),
+        sentinel: store_value(None),
+    };
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    ))]
+    {
+        use crate::gestures::listen;
+        use leptos_reactive::{create_effect, on_cleanup, SignalSet};
+
+        // read once hydration has finished, so the view matches the one
+        // rendered on the server
+        create_effect(move |_| {
+            is_supported.set(
+                js_sys::Reflect::has(
+                    &leptos_dom::window().navigator(),
+                    &"wakeLock".into(),
+                )
+                .unwrap_or(false),
+            )
+        });
+        let document = leptos_dom::document();
+        listen(&document, "visibilitychange", {
+            let document = document.clone();
+            move |_: web_sys::Event| {
+                if !document.hidden() && wake_lock.wanted.get_value() {
+                    wake_lock.acquire();
+                }
+            }
+        });
+        on_cleanup(move || wake_lock.release());
+    }
+    #[cfg(not(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    )))]
+    let _ = is_supported;
+
+    wake_lock
+}
+
+/// The orientation of the screen, as returned by [`use_screen_orientation`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    /// Portrait, in the device's usual orientation for portrait.
+    PortraitPrimary,
+    /// Portrait, upside down from the device's usual orientation for
+    /// portrait.
+    PortraitSecondary,
+    /// Landscape, in the device's usual orientation for landscape.
+    LandscapePrimary,
+    /// Landscape, upside down from the device's usual orientation for
+    /// landscape.
+    LandscapeSecondary,
+}
+
+impl Orientation {
+    /// Whether the screen is taller than it is wide.
+    pub fn is_portrait(self) -> bool {
+        matches!(self, Self::PortraitPrimary | Self::PortraitSecondary)
+    }
+
+    /// Whether the screen is wider than it is tall.
+    pub fn is_landscape(self) -> bool {
+        !self.is_portrait()
+    }
+
+    #[cfg(any(
+        test,
+        all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate"))
+    ))]
+    fn from_type(ty: &str) -> Option<Self> {
+        match ty {
+            "portrait-primary" => Some(Self::PortraitPrimary),
+            "portrait-secondary" => Some(Self::PortraitSecondary),
+            "landscape-primary" => Some(Self::LandscapePrimary),
+            "landscape-secondary" => Some(Self::LandscapeSecondary),
+            _ => None,
+        }
+    }
+}
+
+/// The orientations that the screen can be locked to with
+/// [`ScreenOrientation::lock`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OrientationLock {
+    /// Any orientation, which allows the screen to rotate even if the user
+    /// has locked the rotation of the device.
+    Any,
+    /// The device's natural orientation.
+    Natural,
+    /// Either of the landscape orientations.
+    Landscape,
+    /// Either of the portrait orientations.
+    Portrait,
+    /// [`Orientation::PortraitPrimary`].
+    PortraitPrimary,
+    /// [`Orientation::PortraitSecondary`].
+    PortraitSecondary,
+    /// [`Orientation::LandscapePrimary`].
+    LandscapePrimary,
+    /// [`Orientation::LandscapeSecondary`].
+    LandscapeSecondary,
+}
+
+impl OrientationLock {
+    /// The name of the orientation in the Screen Orientation API.
+    ///
+    /// ```rust
+    /// # use leptos::*;
+    /// assert_eq!(OrientationLock::Landscape.as_str(), "landscape");
+    /// assert_eq!(
+    ///     OrientationLock::PortraitPrimary.as_str(),
+    ///     "portrait-primary"
+    /// );
+    /// ```
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Any => "any",
+            Self::Natural => "natural",
+            Self::Landscape => "landscape",
+            Self::Portrait => "portrait",
+            Self::PortraitPrimary => "portrait-primary",
+            Self::PortraitSecondary => "portrait-secondary",
+            Self::LandscapePrimary => "landscape-primary",
+            Self::LandscapeSecondary => "landscape-secondary",
+        }
+    }
+}
+
+/// The orientation of the screen, and the methods to lock it, as returned by
+/// [`use_screen_orientation`].
+#[derive(Clone, Copy, Debug)]
+pub struct ScreenOrientation {
+    /// Whether the browser supports the Screen Orientation API. It is `false`
+    /// on the server.
+    pub is_supported: Signal<bool>,
+    /// The orientation of the screen, which is `None` on the server.
+    pub orientation: Signal<Option<Orientation>>,
+    /// How far the screen is rotated from the device's natural orientation,
+    /// in degrees: `0`, `90`, `180`, or `270`.
+    pub angle: Signal<u16>,
+    /// Whether the orientation has been locked.
+    pub is_locked: Signal<bool>,
+    /// Why the last request to lock the orientation failed. Most browsers
+    /// only allow it while the page is shown fullscreen, and desktop
+    /// browsers not at all.
+    pub error: Signal<Option<String>>,
+    locked: RwSignal<bool>,
+    #[allow(unused)]
+    last_error: RwSignal<Option<String>>,
+}
+
+impl ScreenOrientation {
+    /// Locks the screen to the given orientation, until [`unlock`] is
+    /// called, or the current reactive owner is cleaned up.
+    ///
+    /// [`unlock`]: ScreenOrientation::unlock
+    pub fn lock(&self, orientation: OrientationLock) {
+        #[cfg(all(
+            target_arch = "wasm32",
+            any(feature = "csr", feature = "hydrate")
+        ))]
+        {
+            use leptos_reactive::SignalSet;
+
+            let locked = self.locked;
+            web::settle(
+                web::call(
+                    &web::screen_orientation(),
+                    "lock",
+                    &[orientation.as_str().into()],
+                ),
+                self.last_error,
+                move |_| {
+                    locked.try_set(true);
+                },
+            );
+        }
+        #[cfg(not(all(
+            target_arch = "wasm32",
+            any(feature = "csr", feature = "hydrate")
+        )))]
+        let _ = orientation;
+    }
+
+    /// Lets the screen rotate with the device again.
+    pub fn unlock(&self) {
+        use leptos_reactive::{SignalGetUntracked, SignalSet};
+
+        if self.locked.try_get_untracked() == Some(true) {
+            #[cfg(all(
+                target_arch = "wasm32",
+                any(feature = "csr", feature = "hydrate")
+            ))]
+            let _ = web::call(&web::screen_orientation(), "unlock", &[]);
+            self.locked.set(false);
+        }
+    }
+}
+
+/// Returns the orientation of the screen, which is updated as the device
+/// rotates, with the
+/// [Screen Orientation API](https://developer.mozilla.org/en-US/docs/Web/API/Screen_Orientation_API),
+/// and the methods to lock it to an orientation, e.g. while a game is
+/// played fullscreen.
+///
+/// The orientation is read once hydration has finished, and unlocked when
+/// the current reactive owner is cleaned up. On the server, it is not known,
+/// and the methods do nothing.
+///
+/// ```rust
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// let screen = use_screen_orientation();
+/// let rotate_hint = move || {
+///     screen
+///         .orientation
+///         .get()
+///         .is_some_and(Orientation::is_portrait)
+///         .then(|| view! { <p>"Rotate your device for a wider view"</p> })
+/// };
+/// view! {
+///     {rotate_hint}
+///     <button on:click=move |_| screen.lock(OrientationLock::Landscape)>
+///         "Lock to landscape"
+///     </button>
+/// }
+/// # ;
+/// # runtime.dispose();
+/// ```
+pub fn use_screen_orientation() -> ScreenOrientation {
+    let is_supported = create_rw_signal(false);
+    let orientation = create_rw_signal(None);
+    let angle = create_rw_signal(0);
+    let locked = create_rw_signal(false);
+    let last_error = create_rw_signal(None);
+    let screen = ScreenOrientation {
+        is_supported: is_supported.into(),
+        orientation: orientation.into(),
+        angle: angle.into(),
+        is_locked: locked.into(),
+        error: last_error.into(),
+        locked,
+        last_error,
+    };
+
+    #[cfg(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    ))]
+    {
+        use crate::gestures::listen;
+        use js_sys::Reflect;
+        use leptos_reactive::{batch, create_effect, on_cleanup, SignalSet};
+        use wasm_bindgen::{JsCast, JsValue};
+
+        let target = web::screen_orientation();
+        let read = {
+            let target = target.clone();
+            move || {
+                let get = |name: &str| {
+                    Reflect::get(&target, &JsValue::from_str(name))
+                        .unwrap_or(JsValue::UNDEFINED)
+                };
+                batch(|| {
+                    is_supported.set(!target.is_undefined());
+                    orientation.set(
+                        get("type")
+                            .as_string()
+                            .and_then(|ty| Orientation::from_type(&ty)),
+                    );
+                    angle.set(get("angle").as_f64().unwrap_or_default() as u16);
+                });
+            }
+        };
+        // read once hydration has finished, so the view matches the one
+        // rendered on the server
+        create_effect({
+            let read = read.clone();
+            move |_| read()
+        });
+        if let Ok(target) = target.dyn_into::<web_sys::EventTarget>() {
+            listen(&target, "change", move |_: web_sys::Event| read());
+        }
+        on_cleanup(move || screen.unlock());
+    }
+
+    screen
+}
+
+#[cfg(all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate")))]
+mod web {
+    use js_sys::{Array, Function, Promise, Reflect};
+    use leptos_reactive::{spawn_local, RwSignal, SignalSet};
+    use wasm_bindgen::{JsCast, JsValue};
+    use wasm_bindgen_futures::JsFuture;
+
+    /// Calls a method that may be missing from the browser, and from
+    /// `web-sys`.
+    pub(super) fn call(
+        target: &JsValue,
+        method: &str,
+        args: &[JsValue],
+    ) -> Result<JsValue, JsValue> {
+        let function = Reflect::get(target, &JsValue::from_str(method))?
+            .dyn_into::<Function>()
+            .map_err(|_| {
+                JsValue::from_str(&format!("{method}() is not supported"))
+            })?;
+        function.apply(target, &args.iter().collect::<Array>())
+    }
+
+    /// Waits for the promise returned by [`call`], then calls `f` with its
+    /// value, or stores its error.
+    pub(super) fn settle(
+        result: Result<JsValue, JsValue>,
+        error: RwSignal<Option<String>>,
+        f: impl FnOnce(JsValue) + 'static,
+    ) {
+        let result = result.map(|value| match value.dyn_into::<Promise>() {
+            Ok(promise) => promise,
+            Err(value) => Promise::resolve(&value),
+        });
+        spawn_local(async move {
+            let result = match result {
+                Ok(promise) => JsFuture::from(promise).await,
+                Err(e) => Err(e),
+            };
+            match result {
+                Ok(value) => {
+                    error.try_set(None);
+                    f(value);
+                }
+                Err(e) => {
+                    let message = match e.dyn_ref::<js_sys::Error>() {
+                        Some(e) => e.message().into(),
+                        None => {
+                            e.as_string().unwrap_or_else(|| format!("{e:?}"))
+                        }
+                    };
+                    error.try_set(Some(message));
+                }
+            }
+        });
+    }
+
+    pub(super) fn exit_fullscreen(element: &web_sys::Element) {
+        let document = leptos_dom::document();
+        if document.fullscreen_element().as_ref() == Some(element) {
+            document.exit_fullscreen();
+        }
+    }
+
+    /// `screen.orientation`, which is `undefined` if it is not supported.
+    pub(super) fn screen_orientation() -> JsValue {
+        Reflect::get(&leptos_dom::window(), &JsValue::from_str("screen"))
+            .and_then(|screen| {
+                Reflect::get(&screen, &JsValue::from_str("orientation"))
+            })
+            .unwrap_or(JsValue::UNDEFINED)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos_reactive::{create_runtime, SignalGet};
+    use web_sys::wasm_bindgen::JsValue;
+
+    #[test]
+    fn wake_locks_are_kept_only_while_requested() {
+        let runtime = create_runtime();
+        let wake_lock = use_wake_lock();
+        assert!(!wake_lock.is_wanted());
+
+        wake_lock.request();
+        assert!(wake_lock.is_wanted());
+        wake_lock.hold(JsValue::UNDEFINED);
+        assert!(wake_lock.is_active.get());
+        // a second lock granted meanwhile is not needed
+        assert!(!wake_lock.is_wanted());
+
+        // the page was hidden, and the lock is requested again once it is
+        // shown
+        wake_lock.revoke();
+        assert!(!wake_lock.is_active.get());
+        assert!(wake_lock.is_wanted());
+        wake_lock.hold(JsValue::UNDEFINED);
+        assert!(wake_lock.is_active.get());
+
+        wake_lock.release();
+        assert!(!wake_lock.is_active.get());
+        // a lock granted after it was released is released as well
+        assert!(!wake_lock.is_wanted());
+
+        runtime.dispose();
+    }
+
+    #[test]
+    fn orientations_are_read_from_their_type() {
+        assert_eq!(
+            Orientation::from_type("portrait-primary"),
+            Some(Orientation::PortraitPrimary)
+        );
+        assert_eq!(
+            Orientation::from_type("landscape-secondary"),
+            Some(Orientation::LandscapeSecondary)
+        );
+        assert_eq!(Orientation::from_type("landscape"), None);
+    }
+}
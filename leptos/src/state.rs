@@ -0,0 +1,264 @@
+use leptos_reactive::{
+    batch, current_runtime, on_cleanup, provide_context, RuntimeId, RwSignal,
+    SignalSet, SignalWithUntracked,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{Map, Value};
+use std::{
+    any::type_name,
+    cell::{Cell, RefCell},
+    collections::{BTreeMap, HashMap},
+    fmt,
+};
+
+type Export = Box<dyn Fn() -> serde_json::Result<Value>>;
+type Import = Box<dyn Fn(Value) -> serde_json::Result<Box<dyn FnOnce()>>>;
+
+struct Entry {
+    id: usize,
+    export: Export,
+    import: Import,
+}
+
+#[derive(Default)]
+struct Registry {
+    stores: BTreeMap<String, Entry>,
+    signals: BTreeMap<String, Entry>,
+}
+
+thread_local! {
+    static REGISTRIES: RefCell<HashMap<RuntimeId, Registry>> =
+        Default::default();
+    static NEXT_ID: Cell<usize> = const { Cell::new(0) };
+}
+
+#[derive(Clone, Copy)]
+enum Kind {
+    Store,
+    Signal,
+}
+
+impl Kind {
+    fn key(self) -> &'static str {
+        match self {
+            Kind::Store => "stores",
+            Kind::Signal => "signals",
+        }
+    }
+
+    fn entries(self, registry: &mut Registry) -> &mut BTreeMap<String, Entry> {
+        match self {
+            Kind::Store => &mut registry.stores,
+            Kind::Signal => &mut registry.signals,
+        }
+    }
+}
+
+fn register<T>(kind: Kind, name: String, signal: RwSignal<T>)
+where
+    T: Serialize + DeserializeOwned + 'static,
+{
+    let id = NEXT_ID.with(|next| {
+        let id = next.get();
+        next.set(id + 1);
+        id
+    });
+    let entry = Entry {
+        id,
+        export: Box::new(move || {
+            signal.with_untracked(|value| serde_json::to_value(value))
+        }),
+        import: Box::new(move |value| {
+            let value = serde_json::from_value::<T>(value)?;
+            Ok(Box::new(move || signal.set(value)))
+        }),
+    };
+    let runtime = current_runtime();
+    REGISTRIES.with(|registries| {
+        let mut registries = registries.borrow_mut();
+        let registry = registries.entry(runtime).or_default();
+        kind.entries(registry).insert(name.clone(), entry);
+    });
+    // unregister when the owner that registered it is disposed
+    on_cleanup(move || {
+        REGISTRIES.with(|registries| {
+            let mut registries = registries.borrow_mut();
+            if let Some(registry) = registries.get_mut(&runtime) {
+                let entries = kind.entries(registry);
+                if entries.get(&name).map(|entry| entry.id) == Some(id) {
+                    entries.remove(&name);
+                }
+                if registry.stores.is_empty() && registry.signals.is_empty() {
+                    registries.remove(&runtime);
+                }
+            }
+        })
+    });
+}
+
+/// Provides a signal as a global store: it is provided as context, like
+/// [`provide_context`], and is included in [`export_state`] and
+/// [`import_state`] under its type name.
+///
+/// This is usually called near the root of the app. Use
+/// [`use_context`](leptos_reactive::use_context) to access the store.
+///
+/// ```
+/// # use leptos::*;
+/// # use serde::{Deserialize, Serialize};
+/// # let runtime = create_runtime();
+/// #[derive(Clone, Default, Serialize, Deserialize)]
+/// struct Settings {
+///     dark_mode: bool,
+/// }
+///
+/// provide_global_store(create_rw_signal(Settings::default()));
+/// let settings = use_context::<RwSignal<Settings>>().unwrap();
+/// assert!(!settings.get_untracked().dark_mode);
+/// # runtime.dispose();
+/// ```
+pub fn provide_global_store<T>(store: RwSignal<T>)
+where
+    T: Serialize + DeserializeOwned + 'static,
+{
+    provide_context(store);
+    register(Kind::Store, type_name::<T>().to_string(), store);
+}
+
+/// Registers a signal under a name, so that it is included in
+/// [`export_state`] and [`import_state`].
+///
+/// Registering another signal with the same name replaces it. The signal is
+/// unregistered when the current reactive owner is disposed.
+pub fn register_signal<T>(name: impl Into<String>, signal: RwSignal<T>)
+where
+    T: Serialize + DeserializeOwned + 'static,
+{
+    register(Kind::Signal, name.into(), signal);
+}
+
+/// Takes a snapshot of the current values of all the global stores and named
+/// signals registered with [`provide_global_store`] and [`register_signal`].
+///
+/// The snapshot has the form
+/// `{ "stores": { <type name>: <value> }, "signals": { <name>: <value> } }`.
+/// It can be inspected by devtools, or passed to [`import_state`] to restore
+/// the state later. Values that fail to serialize are exported as `null`.
+///
+/// ```
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// let count = create_rw_signal(1);
+/// register_signal("count", count);
+///
+/// let snapshot = export_state();
+/// assert_eq!(snapshot["signals"]["count"], 1);
+///
+/// count.set(5);
+/// import_state(snapshot).unwrap();
+/// assert_eq!(count.get_untracked(), 1);
+/// # runtime.dispose();
+/// ```
+pub fn export_state() -> Value {
+    let runtime = current_runtime();
+    REGISTRIES.with(|registries| {
+        let mut registries = registries.borrow_mut();
+        let registry = registries.entry(runtime).or_default();
+        let mut state = Map::new();
+        for kind in [Kind::Store, Kind::Signal] {
+            let values = kind
+                .entries(registry)
+                .iter()
+                .map(|(name, entry)| {
+                    let value = (entry.export)().unwrap_or(Value::Null);
+                    (name.clone(), value)
+                })
+                .collect();
+            state.insert(kind.key().to_string(), Value::Object(values));
+        }
+        Value::Object(state)
+    })
+}
+
+/// Restores global stores and named signals from a snapshot in the format
+/// produced by [`export_state`], for example to seed a known state before an
+/// end-to-end test.
+///
+/// Stores and signals that are missing from the snapshot are left as they
+/// are. The snapshot is checked completely before anything is set, so if an
+/// error is returned, no state has changed; otherwise, all the values are set
+/// in a single [`batch`].
+pub fn import_state(state: Value) -> Result<(), StateError> {
+    let runtime = current_runtime();
+    let Value::Object(mut state) = state else {
+        return Err(StateError::InvalidSnapshot);
+    };
+    let updates = REGISTRIES.with(|registries| {
+        let mut registries = registries.borrow_mut();
+        let registry = registries.entry(runtime).or_default();
+        let mut updates = Vec::new();
+        for kind in [Kind::Store, Kind::Signal] {
+            let values = match state.remove(kind.key()) {
+                None => continue,
+                Some(Value::Object(values)) => values,
+                Some(_) => return Err(StateError::InvalidSnapshot),
+            };
+            let entries = kind.entries(registry);
+            for (name, value) in values {
+                let entry = entries
+                    .get(&name)
+                    .ok_or_else(|| StateError::Unregistered(name.clone()))?;
+                let update = (entry.import)(value).map_err(|e| {
+                    StateError::Deserialize {
+                        name,
+                        message: e.to_string(),
+                    }
+                })?;
+                updates.push(update);
+            }
+        }
+        Ok(updates)
+    })?;
+    // set the values after releasing the registry, as effects may register
+    // or unregister state
+    batch(move || {
+        for update in updates {
+            update();
+        }
+    });
+    Ok(())
+}
+
+/// An error that occurs when importing state with [`import_state`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StateError {
+    /// The snapshot is not in the format produced by [`export_state`].
+    InvalidSnapshot,
+    /// The snapshot contains a store or signal that is not registered.
+    Unregistered(String),
+    /// A value in the snapshot could not be deserialized.
+    Deserialize {
+        /// The name of the store or signal.
+        name: String,
+        /// The deserialization error.
+        message: String,
+    },
+}
+
+impl fmt::Display for StateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidSnapshot => {
+                write!(f, "the state snapshot is not in the expected format")
+            }
+            Self::Unregistered(name) => {
+                write!(f, "no store or signal is registered as `{name}`")
+            }
+            Self::Deserialize { name, message } => {
+                write!(f, "could not deserialize `{name}`: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for StateError {}
@@ -6,11 +6,12 @@ use leptos_reactive::SharedContext;
 #[cfg(any(feature = "csr", feature = "hydrate"))]
 use leptos_reactive::SignalGet;
 use leptos_reactive::{
-    create_memo, provide_context, SignalGetUntracked, SuspenseContext,
+    create_memo, provide_context, SignalGetUntracked, SuspenseBudget,
+    SuspenseContext, SuspenseStreaming,
 };
 #[cfg(not(any(feature = "csr", feature = "hydrate")))]
-use leptos_reactive::{with_owner, Owner};
-use std::rc::Rc;
+use leptos_reactive::{run_as_child, with_owner, Owner, SuspenseTimeout};
+use std::{rc::Rc, time::Duration};
 
 /// If any [`Resource`](leptos_reactive::Resource) is read in the `children` of this
 /// component, it will show the `fallback` while they are loading. Once all are resolved,
@@ -64,6 +65,22 @@ pub fn Suspense<V>(
     /// Returns a fallback UI that will be shown while `async` [`Resource`](leptos_reactive::Resource)s are still loading. By default this is the empty view.
     #[prop(optional, into)]
     fallback: ViewFn,
+    /// How the children are streamed during server rendering, if they are still loading once the
+    /// rest of the page has been rendered. By default, this depends on whether any blocking
+    /// resources are read under this suspense.
+    #[prop(optional)]
+    streaming: SuspenseStreaming,
+    /// How many of the resources that are read under this suspense can load at the same time.
+    /// The others wait until one of them has loaded. Unlimited by default.
+    #[prop(optional)]
+    max_concurrent: Option<usize>,
+    /// How long server rendering waits for the resources read under this suspense. After
+    /// that, the `fallback` is rendered in place of the `children`, with a
+    /// [`SuspenseTimeout`](leptos_reactive::SuspenseTimeout) that it can show with
+    /// [`use_suspense_timeout`](leptos_reactive::use_suspense_timeout). Waits as long as it
+    /// takes by default.
+    #[prop(optional)]
+    deadline: Option<Duration>,
     /// Children will be displayed once all `async` [`Resource`](leptos_reactive::Resource)s have resolved.
     children: Rc<dyn Fn() -> V>,
 ) -> impl IntoView
@@ -77,6 +94,11 @@ where
     let no_hydrate = SharedContext::no_hydrate();
     let orig_children = children;
     let context = SuspenseContext::new();
+    context.set_streaming(streaming);
+    context.set_budget(SuspenseBudget {
+        max_concurrent,
+        deadline,
+    });
 
     #[cfg(not(any(feature = "csr", feature = "hydrate")))]
     let owner =
@@ -112,6 +134,9 @@ where
         }
     });
 
+    #[cfg(not(any(feature = "csr", feature = "hydrate")))]
+    let timed_out_fallback = fallback.clone();
+
     // likewise for the fallback
     let fallback = create_memo({
         move |_| {
@@ -168,6 +193,8 @@ where
                             // out-of-order streaming
                             {
                                 let orig_children = Rc::clone(&orig_children);
+                                let timed_out_fallback =
+                                    timed_out_fallback.clone();
                                 move || {
                                     leptos_reactive::set_current_runtime(
                                         runtime,
@@ -188,12 +215,20 @@ where
                                             HydrationCtx::continue_from(
                                                 current_id,
                                             );
-                                            DynChild::new({
-                                                move || {
-                                                    orig_children().into_view()
-                                                }
-                                            })
-                                            .into_view()
+                                            if context.timed_out() {
+                                                render_timed_out(
+                                                    context,
+                                                    timed_out_fallback,
+                                                )
+                                            } else {
+                                                DynChild::new({
+                                                    move || {
+                                                        orig_children()
+                                                            .into_view()
+                                                    }
+                                                })
+                                                .into_view()
+                                            }
                                             .render_to_string()
                                             .to_string()
                                         }
@@ -211,6 +246,8 @@ where
                             // in-order streaming
                             {
                                 let orig_children = Rc::clone(&orig_children);
+                                let timed_out_fallback =
+                                    timed_out_fallback.clone();
                                 move || {
                                     leptos_reactive::set_current_runtime(
                                         runtime,
@@ -231,12 +268,20 @@ where
                                             HydrationCtx::continue_from(
                                                 current_id,
                                             );
-                                            DynChild::new({
-                                                move || {
-                                                    orig_children().into_view()
-                                                }
-                                            })
-                                            .into_view()
+                                            if context.timed_out() {
+                                                render_timed_out(
+                                                    context,
+                                                    timed_out_fallback,
+                                                )
+                                            } else {
+                                                DynChild::new({
+                                                    move || {
+                                                        orig_children()
+                                                            .into_view()
+                                                    }
+                                                })
+                                                .into_view()
+                                            }
                                             .into_stream_chunks()
                                         }
                                     });
@@ -270,3 +315,20 @@ where
 
     leptos_dom::View::Suspense(current_id, core_component)
 }
+
+// the fallback of a suspense whose resources missed its deadline, which can
+// show the timeout
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+fn render_timed_out(
+    context: SuspenseContext,
+    fallback: ViewFn,
+) -> leptos_dom::View {
+    let timeout = SuspenseTimeout {
+        deadline: context.budget().deadline.unwrap_or_default(),
+    };
+    run_as_child(move || {
+        provide_context(context);
+        provide_context(timeout);
+        DynChild::new(move || fallback.run()).into_view()
+    })
+}
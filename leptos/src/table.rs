@@ -0,0 +1,384 @@
+use leptos_reactive::{
+    create_memo, create_rw_signal, signal_prelude::*, store_value, Oco,
+    RwSignal, Signal, StoredValue,
+};
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+    fmt,
+    rc::Rc,
+};
+
+/// The direction a [`Table`] is sorted in.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SortDirection {
+    /// From the smallest value to the largest.
+    Ascending,
+    /// From the largest value to the smallest.
+    Descending,
+}
+
+/// A column of a [`Table`], which describes how rows are sorted and filtered
+/// by it. How the column is rendered is up to you.
+#[allow(clippy::type_complexity)]
+pub struct TableColumn<T> {
+    id: Oco<'static, str>,
+    compare: Option<Rc<dyn Fn(&T, &T) -> Ordering>>,
+    matches: Option<Rc<dyn Fn(&T, &str) -> bool>>,
+}
+
+impl<T> TableColumn<T> {
+    /// Creates a column, which can be referred to by its `id`. It can't be
+    /// sorted or filtered by until you add a comparison or a filter.
+    pub fn new(id: impl Into<Oco<'static, str>>) -> Self {
+        Self {
+            id: id.into(),
+            compare: None,
+            matches: None,
+        }
+    }
+
+    /// Sorts rows by this column with the given comparison function.
+    pub fn sort_by(
+        mut self,
+        compare: impl Fn(&T, &T) -> Ordering + 'static,
+    ) -> Self {
+        self.compare = Some(Rc::new(compare));
+        self
+    }
+
+    /// Sorts rows by this column by comparing the keys returned by `key`.
+    pub fn sort_by_key<K: Ord>(self, key: impl Fn(&T) -> K + 'static) -> Self {
+        self.sort_by(move |a, b| key(a).cmp(&key(b)))
+    }
+
+    /// Filters rows by this column: when the column has a non-empty filter
+    /// text, only rows for which `matches(row, text)` returns `true` are
+    /// shown.
+    pub fn filter(
+        mut self,
+        matches: impl Fn(&T, &str) -> bool + 'static,
+    ) -> Self {
+        self.matches = Some(Rc::new(matches));
+        self
+    }
+
+    /// The ID of this column.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl<T> Clone for TableColumn<T> {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id.clone(),
+            compare: self.compare.clone(),
+            matches: self.matches.clone(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for TableColumn<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TableColumn")
+            .field("id", &self.id)
+            .field("sortable", &self.compare.is_some())
+            .field("filterable", &self.matches.is_some())
+            .finish()
+    }
+}
+
+/// The state of a table created with [`use_table`].
+///
+/// The rows shown are computed from the source rows by applying the
+/// filters, then sorting, and then taking the current page. All of the state
+/// is held in signals, so it can be read and changed like any other signal,
+/// or through the helper methods.
+pub struct Table<T: 'static> {
+    /// The rows of the current page, after filtering and sorting.
+    pub rows: Signal<Vec<T>>,
+    /// The number of rows that pass the filters, across all pages.
+    pub filtered_count: Signal<usize>,
+    /// The number of pages, which is at least `1`.
+    pub page_count: Signal<usize>,
+    /// The IDs of the columns that are not hidden, in the order they should
+    /// be shown in.
+    pub visible_columns: Signal<Vec<Oco<'static, str>>>,
+    /// The column the rows are sorted by, and the direction.
+    pub sort: RwSignal<Option<(Oco<'static, str>, SortDirection)>>,
+    /// The filter text of each column that has one.
+    pub filters: RwSignal<HashMap<Oco<'static, str>, String>>,
+    /// The current page, starting from `0`. If it is past the last page,
+    /// the last page is shown.
+    pub page: RwSignal<usize>,
+    /// The number of rows on a page, or `None` to show all rows on a single
+    /// page.
+    pub page_size: RwSignal<Option<usize>>,
+    /// The IDs of all columns, in the order they should be shown in.
+    pub column_order: RwSignal<Vec<Oco<'static, str>>>,
+    /// The IDs of the columns that are hidden.
+    pub hidden_columns: RwSignal<HashSet<Oco<'static, str>>>,
+    columns: StoredValue<Vec<TableColumn<T>>>,
+}
+
+impl<T> Clone for Table<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Table<T> {}
+
+impl<T> fmt::Debug for Table<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Table")
+            .field("sort", &self.sort)
+            .field("filters", &self.filters)
+            .field("page", &self.page)
+            .field("page_size", &self.page_size)
+            .field("column_order", &self.column_order)
+            .field("hidden_columns", &self.hidden_columns)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> Table<T> {
+    /// Sorts by the column with the given ID, cycling from ascending to
+    /// descending to unsorted, and goes back to the first page.
+    pub fn toggle_sort(&self, id: &str) {
+        self.sort.update(|sort| {
+            *sort = match sort.take() {
+                Some((current, SortDirection::Ascending)) if current == id => {
+                    Some((current, SortDirection::Descending))
+                }
+                Some((current, SortDirection::Descending)) if current == id => {
+                    None
+                }
+                _ => self.column(id).map(|id| (id, SortDirection::Ascending)),
+            }
+        });
+        self.page.set(0);
+    }
+
+    /// Sets the filter text of the column with the given ID, and goes back
+    /// to the first page. An empty text removes the filter.
+    pub fn set_filter(&self, id: &str, text: impl Into<String>) {
+        let text = text.into();
+        if let Some(id) = self.column(id) {
+            self.filters.update(|filters| {
+                if text.is_empty() {
+                    filters.remove(&id);
+                } else {
+                    filters.insert(id, text);
+                }
+            });
+        }
+        self.page.set(0);
+    }
+
+    /// Goes to the next page, if there is one.
+    pub fn next_page(&self) {
+        let last = self.page_count.get_untracked() - 1;
+        self.page.update(|page| *page = (*page + 1).min(last));
+    }
+
+    /// Goes to the previous page, if there is one.
+    pub fn previous_page(&self) {
+        let last = self.page_count.get_untracked() - 1;
+        self.page
+            .update(|page| *page = (*page).min(last).saturating_sub(1));
+    }
+
+    /// Hides the column with the given ID if it is shown, and shows it if it
+    /// is hidden.
+    pub fn toggle_column(&self, id: &str) {
+        if let Some(id) = self.column(id) {
+            self.hidden_columns.update(|hidden| {
+                if !hidden.remove(&id) {
+                    hidden.insert(id);
+                }
+            });
+        }
+    }
+
+    /// Moves the column with the given ID to `index` in the column order.
+    pub fn move_column(&self, id: &str, index: usize) {
+        self.column_order.update(|order| {
+            if let Some(from) = order.iter().position(|c| c == id) {
+                let id = order.remove(from);
+                order.insert(index.min(order.len()), id);
+            }
+        });
+    }
+
+    fn column(&self, id: &str) -> Option<Oco<'static, str>> {
+        self.columns.with_value(|columns| {
+            columns.iter().find(|c| c.id == id).map(|c| c.id.clone())
+        })
+    }
+}
+
+/// Creates the state of a headless table: the rows of `rows` are filtered,
+/// sorted, and paginated according to the [`TableColumn`]s, and you decide
+/// how to render them.
+///
+/// The current page is a plain `Signal<Vec<T>>`, so it can be rendered with
+/// a keyed [`For`](crate::For), which only updates the rows that changed
+/// when sorting or changing pages.
+///
+/// ```rust
+/// # use leptos::*;
+/// #[derive(Clone)]
+/// struct User {
+///     id: u32,
+///     name: String,
+///     age: u32,
+/// }
+///
+/// #[component]
+/// fn Users(users: ReadSignal<Vec<User>>) -> impl IntoView {
+///     let table = use_table(
+///         users,
+///         vec![
+///             TableColumn::new("name")
+///                 .sort_by_key(|user: &User| user.name.clone())
+///                 .filter(|user, text| user.name.contains(text)),
+///             TableColumn::new("age").sort_by_key(|user: &User| user.age),
+///         ],
+///     );
+///     table.page_size.set(Some(20));
+///
+///     view! {
+///         <input on:input=move |ev| {
+///             table.set_filter("name", event_target_value(&ev))
+///         }/>
+///         <table>
+///             <tr>
+///                 <th on:click=move |_| table.toggle_sort("name")>"Name"</th>
+///                 <th on:click=move |_| table.toggle_sort("age")>"Age"</th>
+///             </tr>
+///             <For each=move || table.rows.get() key=|user| user.id let:user>
+///                 <tr>
+///                     <td>{user.name}</td>
+///                     <td>{user.age}</td>
+///                 </tr>
+///             </For>
+///         </table>
+///         <button on:click=move |_| table.previous_page()>"Previous"</button>
+///         <button on:click=move |_| table.next_page()>"Next"</button>
+///     }
+/// }
+/// ```
+pub fn use_table<T>(
+    rows: impl Into<Signal<Vec<T>>>,
+    columns: Vec<TableColumn<T>>,
+) -> Table<T>
+where
+    T: Clone + 'static,
+{
+    let source = rows.into();
+    let sort = create_rw_signal(None);
+    let filters = create_rw_signal(HashMap::new());
+    let page = create_rw_signal(0);
+    let page_size = create_rw_signal(None);
+    let column_order =
+        create_rw_signal(columns.iter().map(|c| c.id.clone()).collect());
+    let hidden_columns = create_rw_signal(HashSet::new());
+    let columns = store_value(columns);
+
+    // the indices of the rows that pass the filters, in sorted order
+    let filtered = create_memo(move |_| {
+        source.with(|rows| {
+            filters.with(|filters: &HashMap<Oco<'static, str>, String>| {
+                sort.with(
+                    |sort: &Option<(Oco<'static, str>, SortDirection)>| {
+                        columns.with_value(|columns| {
+                            filter_and_sort(rows, columns, filters, sort)
+                        })
+                    },
+                )
+            })
+        })
+    });
+    let filtered_count = Signal::derive(move || filtered.with(Vec::len));
+    let page_count = create_memo(move |_| match page_size.get() {
+        Some(size) if size > 0 => filtered_count.get().div_ceil(size).max(1),
+        _ => 1,
+    });
+    let rows = Signal::derive(move || {
+        let range = match page_size.get() {
+            Some(size) if size > 0 => {
+                let start = page.get().min(page_count.get() - 1) * size;
+                start..start + size
+            }
+            _ => 0..usize::MAX,
+        };
+        filtered.with(|filtered| {
+            let page = &filtered[range.start.min(filtered.len())
+                ..range.end.min(filtered.len())];
+            source.with(|rows| page.iter().map(|&i| rows[i].clone()).collect())
+        })
+    });
+    let visible_columns = create_memo(move |_| {
+        hidden_columns.with(|hidden| {
+            column_order.with(|order: &Vec<Oco<'static, str>>| {
+                order
+                    .iter()
+                    .filter(|id| !hidden.contains(*id))
+                    .cloned()
+                    .collect()
+            })
+        })
+    });
+
+    Table {
+        rows,
+        filtered_count,
+        page_count: page_count.into(),
+        visible_columns: visible_columns.into(),
+        sort,
+        filters,
+        page,
+        page_size,
+        column_order,
+        hidden_columns,
+        columns,
+    }
+}
+
+fn filter_and_sort<T>(
+    rows: &[T],
+    columns: &[TableColumn<T>],
+    filters: &HashMap<Oco<'static, str>, String>,
+    sort: &Option<(Oco<'static, str>, SortDirection)>,
+) -> Vec<usize> {
+    let filters = columns
+        .iter()
+        .filter_map(|column| {
+            let text = filters.get(&column.id)?;
+            Some((column.matches.as_ref()?, text.as_str()))
+        })
+        .collect::<Vec<_>>();
+    let mut indices = rows
+        .iter()
+        .enumerate()
+        .filter(|(_, row)| {
+            filters.iter().all(|(matches, text)| matches(row, text))
+        })
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+
+    let compare = sort.as_ref().and_then(|(id, direction)| {
+        let column = columns.iter().find(|c| &c.id == id)?;
+        Some((column.compare.as_ref()?, *direction))
+    });
+    if let Some((compare, direction)) = compare {
+        // `sort_by` is stable, so equal rows keep their original order
+        indices.sort_by(|&a, &b| match direction {
+            SortDirection::Ascending => compare(&rows[a], &rows[b]),
+            SortDirection::Descending => compare(&rows[b], &rows[a]),
+        });
+    }
+    indices
+}
@@ -0,0 +1,184 @@
+use crate::{AttributeValue, For};
+use leptos_dom::{helpers::window_event_listener, html, IntoView, NodeRef};
+use leptos_macro::{component, view};
+use leptos_reactive::{
+    create_effect, create_memo, create_memo_with_compare, create_rw_signal,
+    on_cleanup, signal_prelude::*, Changed,
+};
+use std::{hash::Hash, ops::Range};
+
+/// Like [`For`], but only renders the items that are visible in a scrollable
+/// viewport, so that lists with many thousands of items stay fast.
+///
+/// The items are placed in a scroll container, and a spacer gives it the
+/// height the whole list would have. As the user scrolls, items that scroll
+/// out of view are unmounted, and the ones that scroll into view are mounted;
+/// items that stay visible keep their DOM nodes, because they are keyed like
+/// in `<For/>`.
+///
+/// Every item should have about the same height. Until the rendered items
+/// have been measured, the height of each item is estimated as
+/// `item_height`.
+///
+/// ```
+/// # use leptos::*;
+/// #[component]
+/// fn Log(lines: ReadSignal<Vec<(usize, String)>>) -> impl IntoView {
+///     view! {
+///         <VirtualFor
+///             each=move || lines.get()
+///             key=|(id, _)| *id
+///             item_height=24.0
+///             height="400px"
+///             let:line
+///         >
+///             <p>{line.1}</p>
+///         </VirtualFor>
+///     }
+/// }
+/// ```
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    tracing::instrument(level = "trace", skip_all)
+)]
+#[component]
+pub fn VirtualFor<IF, I, T, EF, N, KF, K>(
+    /// Items over which the component should iterate.
+    each: IF,
+    /// A key function that will be applied to each item.
+    key: KF,
+    /// A function that takes the item, and returns the view that will be
+    /// displayed for each item.
+    children: EF,
+    /// The estimated height of each item, in pixels.
+    item_height: f64,
+    /// The height of the scroll container, as a CSS value. Defaults to
+    /// `100%`.
+    #[prop(into, default = "100%".into())]
+    height: String,
+    /// How many items to render above and below the visible ones, so that
+    /// scrolling quickly doesn't reveal empty space. Defaults to `3`.
+    #[prop(default = 3)]
+    overscan: usize,
+    /// How many items to render before the size of the viewport is known,
+    /// like when rendering on the server. Defaults to `20`.
+    #[prop(default = 20)]
+    initial_items: usize,
+    /// Optional CSS class for the scroll container.
+    #[prop(optional, into)]
+    class: Option<AttributeValue>,
+) -> impl IntoView
+where
+    IF: Fn() -> I + 'static,
+    I: IntoIterator<Item = T>,
+    EF: Fn(T) -> N + 'static,
+    N: IntoView + 'static,
+    KF: Fn(&T) -> K + 'static,
+    K: Eq + Hash + 'static,
+    T: Clone + 'static,
+{
+    let items = create_memo_with_compare(
+        move |_| each().into_iter().collect::<Vec<_>>(),
+        Changed::Always,
+    );
+    let scroll_top = create_rw_signal(0.0);
+    let viewport_height = create_rw_signal(None::<f64>);
+    let measured_height = create_rw_signal(None::<f64>);
+    let estimated_height =
+        move || measured_height.get().unwrap_or(item_height).max(1.0);
+
+    let range = create_memo(move |_| {
+        visible_range(
+            items.with(Vec::len),
+            estimated_height(),
+            scroll_top.get(),
+            viewport_height.get(),
+            overscan,
+            initial_items,
+        )
+    });
+
+    let viewport = NodeRef::<html::Div>::new();
+    let content = NodeRef::<html::Div>::new();
+    let update_viewport = move || {
+        if let Some(viewport) = viewport.get_untracked() {
+            scroll_top.set(f64::from(viewport.scroll_top()));
+            viewport_height.set(Some(f64::from(viewport.client_height())));
+        }
+    };
+    viewport.on_load(move |_| update_viewport());
+    let resize = window_event_listener(leptos_dom::ev::resize, move |_| {
+        update_viewport()
+    });
+    on_cleanup(move || resize.remove());
+
+    // refine the estimate from the items that have actually been rendered
+    create_effect(move |_| {
+        let count = range.with(Range::len);
+        if let (Some(content), true) = (content.get(), count > 0) {
+            let height = f64::from(content.offset_height()) / count as f64;
+            let changed = measured_height
+                .get_untracked()
+                .map_or(true, |measured| (measured - height).abs() > 0.5);
+            if height > 0.0 && changed {
+                measured_height.set(Some(height));
+            }
+        }
+    });
+
+    view! {
+        <div
+            node_ref=viewport
+            class=class
+            style:overflow-y="auto"
+            style:height=height
+            on:scroll=move |_| update_viewport()
+        >
+            <div
+                style:position="relative"
+                style:height=move || {
+                    format!("{}px", items.with(Vec::len) as f64 * estimated_height())
+                }
+            >
+                <div
+                    node_ref=content
+                    style:position="absolute"
+                    style:top="0"
+                    style:left="0"
+                    style:right="0"
+                    style:transform=move || {
+                        format!("translateY({}px)", range.get().start as f64 * estimated_height())
+                    }
+                >
+                    <For
+                        each=move || items.with(|items| items[range.get()].to_vec())
+                        key=key
+                        children=children
+                    />
+                </div>
+            </div>
+        </div>
+    }
+}
+
+/// Returns the range of items to render: the ones that are visible, and
+/// `overscan` more on each side.
+fn visible_range(
+    len: usize,
+    item_height: f64,
+    scroll_top: f64,
+    viewport_height: Option<f64>,
+    overscan: usize,
+    initial_items: usize,
+) -> Range<usize> {
+    let (first, count) = match viewport_height {
+        Some(viewport_height) => (
+            (scroll_top / item_height).floor() as usize,
+            (viewport_height / item_height).ceil() as usize + 1,
+        ),
+        None => (0, initial_items),
+    };
+    let start = first.saturating_sub(overscan).min(len);
+    let end = (first + count + overscan).min(len);
+    start..end
+}
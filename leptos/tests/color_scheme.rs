@@ -0,0 +1,42 @@
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn color_scheme_is_read_from_and_written_to_the_cookie() {
+    use leptos::*;
+    use std::{cell::RefCell, rc::Rc};
+
+    let runtime = create_runtime();
+    let set_cookies = Rc::new(RefCell::new(Vec::new()));
+    provide_server_cookies("session=abc; color-scheme=dark", {
+        let set_cookies = Rc::clone(&set_cookies);
+        move |cookie| set_cookies.borrow_mut().push(cookie.to_string())
+    });
+    let (color_scheme, set_color_scheme) = use_color_scheme();
+    assert_eq!(color_scheme.get(), ColorScheme::Dark);
+
+    set_color_scheme.set(Some(ColorScheme::Light));
+    assert_eq!(color_scheme.get(), ColorScheme::Light);
+    set_color_scheme.set(None);
+    assert_eq!(color_scheme.get(), ColorScheme::Light);
+    assert_eq!(
+        *set_cookies.borrow(),
+        [
+            "color-scheme=light; Path=/; Max-Age=31536000; SameSite=Lax",
+            "color-scheme=; Path=/; Max-Age=0; SameSite=Lax"
+        ]
+    );
+
+    runtime.dispose();
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn color_scheme_is_light_without_a_cookie() {
+    use leptos::*;
+
+    let runtime = create_runtime();
+    provide_server_cookies("session=abc", |_| {});
+    let (color_scheme, _) = use_color_scheme();
+    assert_eq!(color_scheme.get(), ColorScheme::Light);
+    assert_eq!(use_cookie("session").0.get().as_deref(), Some("abc"));
+    runtime.dispose();
+}
@@ -0,0 +1,34 @@
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn device_signals_use_client_hints_on_the_server() {
+    use leptos::*;
+
+    let runtime = create_runtime();
+    assert!(!use_prefers_reduced_motion().get());
+    assert_eq!(use_pointer_type().get(), PointerType::Fine);
+    assert_eq!(
+        use_network_information().get(),
+        NetworkInformation::default()
+    );
+
+    provide_client_hints(ClientHints::from_headers(|name| match name {
+        "sec-ch-prefers-reduced-motion" => Some("reduce"),
+        "sec-ch-ua-mobile" => Some("?1"),
+        "downlink" => Some("1.5"),
+        "rtt" => Some("300"),
+        _ => None,
+    }));
+    assert!(use_prefers_reduced_motion().get());
+    assert_eq!(use_pointer_type().get(), PointerType::Coarse);
+    assert_eq!(
+        use_network_information().get(),
+        NetworkInformation {
+            effective_type: None,
+            downlink: Some(1.5),
+            rtt: Some(300),
+            save_data: false,
+        }
+    );
+
+    runtime.dispose();
+}
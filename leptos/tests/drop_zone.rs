@@ -0,0 +1,25 @@
+#[test]
+fn drop_zone_accepts_extensions_and_mime_types() {
+    use leptos::DropZoneOptions;
+
+    let everything = DropZoneOptions::default();
+    assert!(everything.accepts("anything.bin", ""));
+
+    let options = DropZoneOptions {
+        accept: vec![
+            ".tar.gz".into(),
+            " image/* ".into(),
+            "application/json".into(),
+        ],
+        ..Default::default()
+    };
+    assert!(options.accepts("backup.TAR.GZ", "application/gzip"));
+    assert!(!options.accepts(".tar.gz", ""));
+    assert!(!options.accepts("archive.gz", "application/gzip"));
+    assert!(options.accepts("photo", "IMAGE/JPEG"));
+    assert!(!options.accepts("image.txt", "text/plain"));
+    assert!(options.accepts("data", "application/json"));
+    assert!(!options.accepts("data.json", "application/jsonl"));
+    // multi-byte names don't split a character
+    assert!(!options.accepts("файл", ""));
+}
@@ -0,0 +1,66 @@
+#[cfg(not(any(feature = "csr", feature = "hydrate", feature = "ssr")))]
+#[test]
+fn feature_flags_can_be_read_and_overridden() {
+    use leptos::*;
+
+    let runtime = create_runtime();
+    let flags = provide_feature_flags(|| async {
+        let mut flags = FlagValues::new();
+        flags.insert("new-checkout".into(), true.into());
+        flags.insert("theme".into(), "dark".into());
+        flags
+    });
+    let new_checkout = use_flag("new-checkout");
+    let beta = use_flag("beta");
+    let theme = use_flag_value::<String>("theme");
+
+    assert!(new_checkout.get());
+    assert!(!beta.get());
+    assert_eq!(theme.get().as_deref(), Some("dark"));
+
+    flags.set("new-checkout", false);
+    flags.set("beta", true);
+    flags.set("theme", 1);
+    assert!(!new_checkout.get());
+    assert!(beta.get());
+    assert_eq!(theme.get(), None);
+
+    runtime.dispose();
+}
+
+#[cfg(feature = "ssr")]
+#[test]
+fn feature_flags_are_serialized_with_the_page() {
+    use futures::StreamExt;
+    use leptos::*;
+    use tokio::task;
+    use tokio_test::block_on;
+
+    #[component]
+    fn App() -> impl IntoView {
+        provide_feature_flags(|| async {
+            task::yield_now().await;
+            let mut flags = FlagValues::new();
+            flags.insert("new-checkout".into(), true.into());
+            flags.insert("theme".into(), "dark".into());
+            flags
+        });
+        view! {
+            <Flag name="new-checkout" fallback=|| "old checkout">
+                "new checkout"
+            </Flag>
+        }
+    }
+
+    block_on(task::LocalSet::new().run_until(async {
+        let (stream, runtime) = ssr::render_to_stream_with_prefix_undisposed(
+            || view! { <App/> }.into_view(),
+            || "".into(),
+        );
+        let html = stream.collect::<String>().await;
+        assert!(html.contains("new checkout"));
+        assert!(html.contains(r#"\"new-checkout\":true"#), "{html}");
+        assert!(html.contains(r#"\"theme\":\"dark\""#), "{html}");
+        runtime.dispose();
+    }));
+}
@@ -0,0 +1,83 @@
+#[cfg(not(any(feature = "csr", feature = "hydrate", feature = "ssr")))]
+#[test]
+fn form_tracks_fields_and_validates() {
+    use leptos::{form::use_form, *};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+    struct Address {
+        city: String,
+        zip: u32,
+    }
+
+    #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+    struct Signup {
+        email: String,
+        nickname: Option<String>,
+        #[serde(default)]
+        tags: Vec<String>,
+        address: Address,
+    }
+
+    let runtime = create_runtime();
+    let form = use_form::<Signup>().validate("email", |email| {
+        if email.contains('@') {
+            Ok(())
+        } else {
+            Err("Enter an email address.".into())
+        }
+    });
+    let email = form.field("email");
+    let nickname = form.field("nickname");
+
+    assert!(!form.is_dirty());
+    assert!(!form.is_touched());
+    assert_eq!(email.error().as_deref(), Some("Enter an email address."));
+    assert!(!form.is_valid());
+    // `None` is left out rather than parsed from an empty input
+    assert_eq!(form.value().unwrap(), Signup::default());
+
+    email.value().set("ada@example.com".into());
+    email.touch();
+    form.field("tags[0]").value().set("rust".into());
+    form.field("address[city]").value().set("London".into());
+    form.field("address[zip]").value().set("12345".into());
+    assert!(email.is_dirty());
+    assert!(!nickname.is_dirty());
+    assert!(form.is_touched());
+    assert_eq!(email.error(), None);
+    assert!(form.is_valid());
+    assert_eq!(
+        form.value().unwrap(),
+        Signup {
+            email: "ada@example.com".into(),
+            nickname: None,
+            tags: vec!["rust".into()],
+            address: Address {
+                city: "London".into(),
+                zip: 12345,
+            },
+        }
+    );
+
+    nickname.value().set("ada".into());
+    assert_eq!(form.value().unwrap().nickname.as_deref(), Some("ada"));
+
+    form.field("address[zip]").value().set("E1".into());
+    assert!(form.value().is_err());
+    assert!(!form.is_valid());
+
+    form.reset();
+    assert!(!form.is_dirty());
+    assert!(!form.is_touched());
+    assert_eq!(email.value().get(), "");
+
+    form.set(&Signup {
+        email: "grace@example.com".into(),
+        ..Default::default()
+    });
+    assert_eq!(email.value().get(), "grace@example.com");
+    assert!(email.is_dirty());
+
+    runtime.dispose();
+}
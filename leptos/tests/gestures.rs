@@ -0,0 +1,15 @@
+#[test]
+fn swipe_direction_follows_the_longer_axis() {
+    use leptos::{Point, SwipeDirection};
+
+    let direction = |x, y| SwipeDirection::from_delta(Point::new(x, y), 30.0);
+    assert_eq!(direction(0.0, 0.0), None);
+    assert_eq!(direction(29.0, -29.0), None);
+    assert_eq!(direction(31.0, -29.0), Some(SwipeDirection::Right));
+    assert_eq!(direction(-20.0, -45.0), Some(SwipeDirection::Up));
+    assert_eq!(direction(5.0, 30.0), Some(SwipeDirection::Down));
+    assert_eq!(
+        SwipeDirection::from_delta(Point::new(-1.0, 0.0), 0.0),
+        Some(SwipeDirection::Left)
+    );
+}
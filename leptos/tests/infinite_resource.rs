@@ -0,0 +1,50 @@
+#[cfg(not(any(feature = "csr", feature = "hydrate", feature = "ssr")))]
+#[test]
+fn infinite_resource_loads_pages() {
+    use leptos::*;
+    use std::{cell::Cell, rc::Rc};
+
+    let runtime = create_runtime();
+    let fail = Rc::new(Cell::new(false));
+    let posts = create_infinite_resource({
+        let fail = Rc::clone(&fail);
+        move |page: usize| {
+            let fail = fail.get();
+            async move {
+                if fail {
+                    Err("offline".to_string())
+                } else if page <= 2 {
+                    Ok(vec![page * 10, page * 10 + 1])
+                } else {
+                    Ok(vec![])
+                }
+            }
+        }
+    });
+    assert_eq!(posts.items.get(), [10, 11]);
+    assert!(posts.has_more.get());
+
+    fail.set(true);
+    posts.load_more();
+    assert_eq!(posts.error.get().as_deref(), Some("offline"));
+    assert_eq!(posts.items.get(), [10, 11]);
+
+    // loading more after an error fetches the failed page again
+    fail.set(false);
+    posts.load_more();
+    assert_eq!(posts.error.get(), None);
+    assert_eq!(posts.items.get(), [10, 11, 20, 21]);
+
+    posts.load_more();
+    assert_eq!(
+        posts.pages.get(),
+        [
+            PageState::Loaded { len: 2 },
+            PageState::Loaded { len: 2 },
+            PageState::Loaded { len: 0 },
+        ]
+    );
+    assert!(!posts.has_more.get());
+
+    runtime.dispose();
+}
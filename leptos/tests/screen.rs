@@ -0,0 +1,9 @@
+#[test]
+fn orientations_are_portrait_or_landscape() {
+    use leptos::Orientation;
+
+    assert!(Orientation::PortraitPrimary.is_portrait());
+    assert!(Orientation::PortraitSecondary.is_portrait());
+    assert!(Orientation::LandscapePrimary.is_landscape());
+    assert!(!Orientation::LandscapeSecondary.is_portrait());
+}
@@ -150,6 +150,90 @@ fn test_classes() {
     runtime.dispose();
 }
 
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_spread_bindings() {
+    use leptos::*;
+
+    let runtime = create_runtime();
+    let (pressed, _) = create_signal(true);
+    let bindings = vec![
+        Binding::attr("aria-pressed", move || pressed.get().to_string()),
+        Binding::class("pressed", pressed),
+        Binding::class("disabled", false),
+        ("data-size", "big".into_attribute()).into(),
+        EventHandlerFn::Click(Box::new(|_| {})).into(),
+    ];
+    let rendered = view! { <button {..bindings}>"Toggle"</button> };
+
+    assert!(rendered.into_view().render_to_string().starts_with(
+        "<button aria-pressed=\"true\" data-size=\"big\" \
+         class=\"pressed\""
+    ));
+    runtime.dispose();
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_class_list() {
+    use leptos::*;
+
+    let runtime = create_runtime();
+    let (active, _) = create_signal(true);
+    let rendered = view! {
+        <button
+            class=move || classes! { "active" => active.get(), "dark" => false }
+            class:large=true
+        >
+            "Toggle"
+        </button>
+    };
+    assert!(rendered
+        .into_view()
+        .render_to_string()
+        .contains("active large\">Toggle</button>"));
+
+    let built = html::button()
+        .class("large", true)
+        .attr("class", move || classes! { "active" => active.get() })
+        .into_view()
+        .render_to_string();
+    assert!(built.starts_with("<button class=\"active large\""));
+    runtime.dispose();
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_style_map() {
+    use leptos::*;
+
+    let runtime = create_runtime();
+    let (width, _) = create_signal(Length::px(10.0));
+    let rendered = view! {
+        <div
+            style=style! {
+                width: width,
+                background_color: move || "blue !important",
+                "--accent": "red",
+                opacity: None::<f64>,
+            }
+            style:color="green"
+        />
+    };
+    assert!(rendered.into_view().render_to_string().contains(
+        "style=\" width: 10px; background-color: blue !important; \
+         --accent: red;; color: green;\""
+    ));
+
+    let built = html::div()
+        .style("color", "green")
+        .attr("style", style! { width: width })
+        .into_view()
+        .render_to_string();
+    assert!(built.contains("style=\"width: 10px; color: green;\""));
+    runtime.dispose();
+}
+
 #[cfg(not(any(feature = "csr", feature = "hydrate")))]
 #[test]
 fn ssr_with_styles() {
@@ -206,3 +290,648 @@ fn ssr_option() {
 
     runtime.dispose();
 }
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_bind_value_parse() {
+    use leptos::*;
+
+    let runtime = create_runtime();
+    let cents = create_rw_signal(Some(123456_u64));
+    let parse = |text: &str| {
+        let digits = text
+            .chars()
+            .filter(char::is_ascii_digit)
+            .collect::<String>();
+        digits.parse().ok()
+    };
+    let format = |cents: &u64| format!("{}.{:02}", cents / 100, cents % 100);
+    let rendered = view! {
+        <input bind:value:parse=(cents, parse, format)/>
+    };
+    assert!(rendered
+        .into_view()
+        .render_to_string()
+        .contains(r#"value="1234.56""#));
+
+    cents.set(None);
+    let rendered = view! {
+        <input bind:value:parse=(cents, parse, format)/>
+    };
+    assert!(!rendered.into_view().render_to_string().contains("value"));
+
+    runtime.dispose();
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_bind() {
+    use leptos::*;
+
+    let runtime = create_runtime();
+    let name = create_rw_signal("Alice & Bob".to_string());
+    let subscribed = create_rw_signal(true);
+    let plan = create_rw_signal("pro".to_string());
+    let toppings = create_rw_signal(vec!["olives".to_string()]);
+    let olives = "olives".to_string();
+
+    let rendered = view! {
+        <form>
+            <input type="text" bind:value=name/>
+            <input type="search" bind:value:eager=name/>
+            <input type="checkbox" bind:checked=subscribed/>
+            <input type="radio" bind:group=plan value="free"/>
+            <input type="radio" value="pro" bind:group=plan/>
+            <input type="checkbox" value="cheese" bind:group=toppings/>
+            <input type="checkbox" value=olives bind:group=toppings/>
+        </form>
+    };
+    let html = rendered.into_view().render_to_string();
+    assert_eq!(html.matches(r#"value="Alice &amp; Bob""#).count(), 2);
+    assert!(html.contains(r#"type="checkbox" checked"#));
+    assert!(html.contains(r#"type="radio" value="free""#));
+    assert!(!html.contains(r#"value="free" checked"#));
+    assert!(html.contains(r#"type="radio" value="pro" checked"#));
+    assert!(html.contains(r#"value="cheese""#));
+    assert!(!html.contains(r#"value="cheese" checked"#));
+    assert!(html.contains(r#"value="olives" checked"#));
+
+    // the builder syntax renders the same attributes
+    let built = html::input()
+        .attr("type", "radio")
+        .bind_group(plan, "pro")
+        .into_view()
+        .render_to_string();
+    assert!(built.contains(r#"value="pro" checked"#));
+    let built = html::input()
+        .bind_value_with(name, html::BindValueMode::Lazy)
+        .into_view()
+        .render_to_string();
+    assert!(built.contains(r#"value="Alice &amp; Bob""#));
+
+    runtime.dispose();
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_bind_select() {
+    use leptos::*;
+
+    let runtime = create_runtime();
+    let size = create_rw_signal("medium".to_string());
+    let topics = create_rw_signal(vec!["news".to_string(), "tips".to_string()]);
+    let tips = "tips".to_string();
+
+    let rendered = view! {
+        <form>
+            <select bind:value=size>
+                <option value="small">"Small"</option>
+                <option value="medium">"Medium"</option>
+            </select>
+            <select multiple bind:value=topics>
+                <option>"news"</option>
+                <option value="offers">"Offers"</option>
+                {move || view! { <option value=tips.clone()>"Tips"</option> }}
+            </select>
+            <option value="medium">"Outside"</option>
+        </form>
+    };
+    let html = rendered.into_view().render_to_string();
+    assert!(!html.contains("<select value"));
+    assert!(!html.contains(r#"value="small" selected"#));
+    assert!(html.contains(r#"value="medium" selected"#));
+    assert_eq!(html.matches(" selected").count(), 3);
+    assert!(!html.contains(r#"value="offers" selected"#));
+    assert!(html.contains(r#"value="tips" selected"#));
+
+    // the builder syntax marks the options that have been added
+    let built = html::select()
+        .child(html::option().attr("value", "small").child("Small"))
+        .child(html::option().child("medium"))
+        .bind_value(size)
+        .into_view()
+        .render_to_string();
+    assert!(!built.contains("<select value"));
+    assert!(!built.contains(r#"value="small" selected"#));
+    assert_eq!(built.matches(" selected").count(), 1);
+
+    runtime.dispose();
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_image_srcset() {
+    use leptos::*;
+
+    let runtime = create_runtime();
+    provide_image_config(ImageConfig {
+        widths: vec![640, 1280],
+        ..ImageConfig::with_loader(ImageLoader::endpoint("/_image"))
+    });
+    let rendered = view! {
+        <Image src="/cat.png" alt="A cat" width=600 height=400/>
+    }
+    .into_view()
+    .render_to_string();
+
+    assert!(rendered.contains("width=\"600\" height=\"400\""));
+    assert!(rendered.contains(
+        "srcset=\"/_image?url=%2Fcat.png&amp;w=640&amp;q=75 1x, \
+         /_image?url=%2Fcat.png&amp;w=1280&amp;q=75 2x\""
+    ));
+    assert!(
+        rendered.contains("src=\"/_image?url=%2Fcat.png&amp;w=1280&amp;q=75\"")
+    );
+    assert!(rendered.contains("loading=\"lazy\""));
+
+    runtime.dispose();
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_render_dynamic() {
+    use leptos::{dynamic_view::*, *};
+
+    let runtime = create_runtime();
+    let registry = provide_component_registry();
+    registry.register(DynComponent::deserializing(
+        "Badge",
+        |label: String| view! { <b>{label}</b> },
+    ));
+    let count = create_rw_signal("1".to_string());
+    provide_dynamic_signals(DynamicSignals::new().with("count", count));
+
+    let description: ViewDescription =
+        serde_json::from_value(serde_json::json!({
+            "type": "element",
+            "tag": "div",
+            "attrs": { "id": "root", "data-count": { "signal": "count" } },
+            "children": [
+                { "type": "component", "name": "Badge", "props": "new" },
+                { "type": "signal", "name": "count" }
+            ]
+        }))
+        .unwrap();
+    let rendered = render_dynamic(&description).unwrap().render_to_string();
+    assert!(rendered.contains("data-count=\"1\""));
+    assert!(rendered.contains("id=\"root\""));
+    assert!(rendered.contains("<b"));
+    assert!(rendered.contains("new</b>"));
+
+    let script: ViewDescription = serde_json::from_str(
+        r#"{ "type": "element", "tag": "script", "children": [] }"#,
+    )
+    .unwrap();
+    assert_eq!(
+        render_dynamic(&script).unwrap_err(),
+        DynamicViewError::DisallowedElement("script".into())
+    );
+
+    runtime.dispose();
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_code_block() {
+    use leptos::*;
+
+    let runtime = create_runtime();
+    let rendered = view! {
+        <CodeBlock code="if a < b { a }" language="rust" class="code"/>
+    }
+    .into_view()
+    .render_to_string();
+
+    assert!(rendered.contains("code\"><code"));
+    assert!(rendered.contains("class=\"language-rust\""));
+    if cfg!(feature = "syntax-highlighting") {
+        assert!(rendered.contains("<span class=\"source rust\">"));
+    } else {
+        assert!(rendered.contains("if a &lt; b { a }</code></pre>"));
+    }
+
+    runtime.dispose();
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_region() {
+    use leptos::*;
+
+    let runtime = create_runtime();
+    let rendered = view! {
+        <main>
+            <Region id="outer">
+                <p>"outer"</p>
+                <Region id="inner">"inner"</Region>
+                <p>"after"</p>
+            </Region>
+        </main>
+    }
+    .into_view()
+    .render_to_string();
+
+    let outer = extract_region(&rendered, "outer").unwrap();
+    assert!(outer.starts_with("<leptos-region"));
+    assert!(outer.contains("id=\"outer\""));
+    assert!(outer.contains("after</p>"));
+    assert!(outer.ends_with("</leptos-region>"));
+
+    let inner = extract_region(&rendered, "inner").unwrap();
+    assert!(inner.contains("id=\"inner\""));
+    assert!(inner.contains(">inner<"));
+    assert!(!inner.contains("after"));
+
+    assert_eq!(extract_region(&rendered, "missing"), None);
+
+    runtime.dispose();
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_use_id() {
+    use leptos::*;
+
+    #[component]
+    fn Field(label: &'static str) -> impl IntoView {
+        let id = use_id();
+        view! {
+            <label for=id.clone()>{label}</label>
+            <input id=id/>
+        }
+    }
+
+    let runtime = create_runtime();
+    let render = || {
+        leptos_dom::HydrationCtx::reset_id();
+        view! {
+            <Field label="First"/>
+            <Field label="Second"/>
+        }
+        .into_view()
+        .render_to_string()
+        .to_string()
+    };
+    let rendered = render();
+
+    let ids = rendered
+        .split("id=\"")
+        .skip(1)
+        .filter_map(|rest| rest.split('"').next())
+        .collect::<Vec<_>>();
+    assert_eq!(ids.len(), 2);
+    assert_ne!(ids[0], ids[1]);
+    for id in ids {
+        assert!(rendered.contains(&format!("for=\"{id}\"")));
+    }
+    assert_eq!(render(), rendered);
+
+    runtime.dispose();
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_request_scope() {
+    use leptos::*;
+    use std::time::Duration;
+
+    #[component]
+    fn Dice() -> impl IntoView {
+        let mut rng = use_request_rng();
+        view! { <span>{rng.below(1_000_000)}</span> }
+    }
+
+    let render = |scope: RequestScope| {
+        let runtime = create_runtime();
+        leptos_dom::HydrationCtx::reset_id();
+        provide_request_scope(scope);
+        let rendered = view! {
+            <Dice/>
+            <Dice/>
+            <time>{use_request_time().as_secs()}</time>
+        }
+        .into_view()
+        .render_to_string()
+        .to_string();
+        runtime.dispose();
+        rendered
+    };
+    let scope = RequestScope::with_seed(42, Duration::from_secs(1_700_000_000));
+    let rendered = render(scope);
+
+    let rolls = rendered
+        .split("<span")
+        .skip(1)
+        .filter_map(|rest| rest.split('>').nth(1))
+        .collect::<Vec<_>>();
+    assert_eq!(rolls.len(), 2);
+    assert_ne!(rolls[0], rolls[1]);
+    assert!(rendered.contains(">1700000000<"));
+    assert_eq!(render(scope), rendered);
+    assert_ne!(
+        render(RequestScope::with_seed(
+            7,
+            Duration::from_secs(1_700_000_000)
+        )),
+        rendered
+    );
+    assert_eq!(
+        scope.meta_tag(),
+        r#"<meta name="leptos-request-scope" content="42:1700000000000"/>"#
+    );
+}
+
+// effects only stay inert on the server with the `ssr` feature
+#[cfg(feature = "ssr")]
+#[test]
+fn ssr_browser_only_value() {
+    use leptos::*;
+
+    let runtime = create_runtime();
+    let rendered = view! {
+        <p>
+            <BrowserOnlyValue fallback=|| "loading">"client value"</BrowserOnlyValue>
+        </p>
+    }
+    .into_view()
+    .render_to_string();
+
+    assert!(rendered.contains("loading"));
+    assert!(!rendered.contains("client value"));
+
+    runtime.dispose();
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_for_item_context() {
+    use leptos::*;
+
+    #[component]
+    fn Item(name: &'static str) -> impl IntoView {
+        let position = expect_context::<ForItemContext>();
+        let label = move || {
+            format!(
+                "{name}: {} of {}{}{}",
+                position.index.get() + 1,
+                position.len.get(),
+                if position.is_first.get() {
+                    ", first"
+                } else {
+                    ""
+                },
+                if position.is_last.get() { ", last" } else { "" },
+            )
+        };
+        view! { <li>{label}</li> }
+    }
+
+    let rendered = leptos::ssr::render_to_string(|| {
+        view! {
+            <ul>
+                <For each=|| ["a", "b", "c"] key=|name| *name item_context=true let:name>
+                    <Item name/>
+                </For>
+            </ul>
+        }
+    });
+
+    assert!(rendered.contains("a: 1 of 3, first<"));
+    assert!(rendered.contains("b: 2 of 3<"));
+    assert!(rendered.contains("c: 3 of 3, last<"));
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_print_only() {
+    use leptos::*;
+
+    let rendered = leptos::ssr::render_to_string(|| {
+        view! {
+            <ScreenOnly fallback=|| "screen fallback">"screen view"</ScreenOnly>
+            <PrintOnly fallback=|| "print fallback">"print view"</PrintOnly>
+        }
+    });
+
+    assert!(rendered.contains("screen view"));
+    assert!(!rendered.contains("screen fallback"));
+    assert!(rendered.contains("print fallback"));
+    assert!(!rendered.contains("print view"));
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_iterators_without_collecting() {
+    use leptos::*;
+
+    let names = ["Alice", "Bob"];
+    let rendered = leptos::ssr::render_to_string(move || {
+        view! {
+            <ul>{names.iter().map(|name| view! { <li>{*name}</li> })}</ul>
+            <ol>{Unkeyed([3, 8, 5].into_iter().filter(|score| *score > 4))}</ol>
+        }
+    });
+
+    assert!(rendered.contains(">Alice</li>"));
+    assert!(rendered.contains(">Bob</li>"));
+    assert!(rendered.contains("-->85<!--"));
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_virtual_for_renders_the_first_items() {
+    use leptos::*;
+
+    let rendered = leptos::ssr::render_to_string(|| {
+        view! {
+            <VirtualFor
+                each=|| 0..100
+                key=|n| *n
+                item_height=20.0
+                height="200px"
+                initial_items=5
+                let:n
+            >
+                <p>"Item " {n}</p>
+            </VirtualFor>
+        }
+    });
+
+    assert!(rendered.contains("height: 2000px"));
+    assert!(rendered.contains(">Item 7<"));
+    assert!(!rendered.contains(">Item 8<"));
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_animated_for_renders_items() {
+    use leptos::*;
+
+    let rendered = leptos::ssr::render_to_string(|| {
+        view! {
+            <ul>
+                <AnimatedFor
+                    each=|| vec!["a", "b"]
+                    key=|item| *item
+                    enter_class="fade-in"
+                    leave_class="fade-out"
+                    let:item
+                >
+                    <li>{item}</li>
+                </AnimatedFor>
+            </ul>
+        }
+    });
+
+    assert!(rendered.contains(">a</li>"));
+    assert!(rendered.contains(">b</li>"));
+    assert!(!rendered.contains("fade-in"));
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_for_reports_item_errors_to_error_boundary() {
+    use leptos::*;
+
+    let render = |items: Vec<&'static str>| {
+        leptos::ssr::render_to_string(move || {
+            view! {
+                <ErrorBoundary fallback=|errors| {
+                    let count = errors.with_untracked(|errors| errors.iter().count());
+                    view! { <p>{format!("{count} errors")}</p> }
+                }>
+                    <For
+                        each=move || items.clone()
+                        key=|item| *item
+                        children=|item| {
+                            item.parse::<u32>().map(|n| view! { <li>{n}</li> })
+                        }
+                    />
+                </ErrorBoundary>
+            }
+        })
+        .to_string()
+    };
+
+    let rendered = render(vec!["1", "2"]);
+    assert!(rendered.contains(">1</li>"));
+    assert!(!rendered.contains("errors</p>"));
+
+    let rendered = render(vec!["1", "two", "three"]);
+    assert!(rendered.contains(">2 errors</p>"));
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_for_enumerate_passes_the_index() {
+    use leptos::*;
+
+    let rendered = leptos::ssr::render_to_string(|| {
+        view! {
+            <ForEnumerate
+                each=|| vec!["a", "b"]
+                key=|item| *item
+                children=|index, item| {
+                    view! { <p>{index.get_untracked()} ": " {item}</p> }
+                }
+            />
+        }
+    });
+
+    assert!(rendered.contains(">0: a</p>"));
+    assert!(rendered.contains(">1: b</p>"));
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_renders_colors_and_lengths() {
+    use leptos::*;
+
+    let rendered = leptos::ssr::render_to_string(|| {
+        let accent = Color::rgb(51, 102, 255);
+        view! {
+            <div
+                style:color=accent
+                style:width=Length::percent(100.0) - Length::px(20.0)
+                data-accent=accent.with_alpha(0.5)
+            />
+        }
+    });
+
+    assert!(rendered.contains("color: #3366ff;"));
+    assert!(rendered.contains("width: calc(100% - 20px);"));
+    assert!(rendered.contains(r#"data-accent="rgba(51, 102, 255, 0.5)""#));
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_targeted_event_listeners() {
+    use leptos::*;
+
+    let runtime = create_runtime();
+    let (value, set_value) = create_signal(String::new());
+
+    // the handlers only need to type-check on the server
+    let rendered = view! {
+        <div>
+            <input on:input:target=move |ev| set_value.set(ev.target().value())/>
+            <select on:change:undelegated:target=move |ev| {
+                set_value.set(ev.target().value())
+            }></select>
+            <svg>
+                <circle on:click:target=move |ev| {
+                    set_value.set(ev.target().id())
+                }/>
+            </svg>
+            <my-element on:click:target=move |ev| {
+                set_value.set(ev.target().inner_text())
+            }></my-element>
+        </div>
+    };
+    let html = rendered.into_view().render_to_string();
+    assert!(html.contains("<input"));
+    assert!(!html.contains("target"));
+
+    let built = html::textarea()
+        .on_target(ev::input, move |ev| set_value.set(ev.target().value()))
+        .into_view()
+        .render_to_string();
+    assert!(built.contains("<textarea"));
+    assert!(value.get_untracked().is_empty());
+
+    runtime.dispose();
+}
+
+#[cfg(all(feature = "experimental-islands", feature = "ssr"))]
+#[test]
+fn ssr_island_report() {
+    use leptos::*;
+    use std::sync::{Arc, Mutex};
+
+    #[island]
+    fn ReportedCounter(initial: i32) -> impl IntoView {
+        view! { <p>{initial}</p> }
+    }
+
+    let reported = Arc::new(Mutex::new(Vec::new()));
+    set_island_report_hook({
+        let reported = Arc::clone(&reported);
+        move |island| {
+            if island.component == "ReportedCounter" {
+                reported.lock().unwrap().push(island.clone());
+            }
+        }
+    });
+    let html = leptos::ssr::render_to_string(|| {
+        view! { <ReportedCounter initial=10/> }
+    });
+    clear_island_report_hook();
+
+    let reported = reported.lock().unwrap();
+    assert_eq!(reported.len(), 1);
+    let props = r#"{"initial":10}"#;
+    assert_eq!(reported[0].props_bytes, props.len());
+    assert!(
+        html.contains(&format!(r#"data-hkc="{}""#, reported[0].hydration_key))
+    );
+}
@@ -0,0 +1,66 @@
+#[cfg(not(any(feature = "csr", feature = "hydrate", feature = "ssr")))]
+#[test]
+fn store_notifies_only_readers_of_changed_path() {
+    use leptos::*;
+
+    #[derive(Store)]
+    struct State {
+        user: User,
+        count: usize,
+    }
+
+    #[derive(Store)]
+    struct User {
+        name: String,
+        email: String,
+    }
+
+    let runtime = create_runtime();
+    let store = create_store(State {
+        user: User {
+            name: "Ada".into(),
+            email: "ada@example.com".into(),
+        },
+        count: 0,
+    });
+
+    let runs = |read: Box<dyn Fn()>| {
+        let runs = create_rw_signal(0);
+        create_isomorphic_effect(move |_| {
+            read();
+            runs.update(|n| *n += 1);
+        });
+        runs
+    };
+    let name_runs = runs(Box::new(move || store.user().name().track()));
+    let email_runs = runs(Box::new(move || store.user().email().track()));
+    let user_runs = runs(Box::new(move || store.user().track()));
+    let count_runs = runs(Box::new(move || store.count().track()));
+    let counts = move || {
+        [name_runs, email_runs, user_runs, count_runs]
+            .map(|runs| runs.get_untracked())
+    };
+    assert_eq!(counts(), [1, 1, 1, 1]);
+
+    store.user().name().set("Grace".into());
+    assert_eq!(counts(), [2, 1, 2, 1]);
+    assert_eq!(store.user().name().get(), "Grace");
+
+    store.count().update(|count| *count += 1);
+    assert_eq!(counts(), [2, 1, 2, 2]);
+
+    store
+        .user()
+        .update(|user| user.email = "grace@example.com".into());
+    assert_eq!(counts(), [3, 2, 3, 2]);
+
+    store.update(|state| state.count = 5);
+    assert_eq!(counts(), [4, 3, 4, 3]);
+    assert_eq!(store.count().get_untracked(), 5);
+    assert_eq!(
+        store.user().email().with(String::clone),
+        "grace@example.com"
+    );
+
+    runtime.dispose();
+}
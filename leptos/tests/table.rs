@@ -0,0 +1,111 @@
+use leptos::*;
+
+#[derive(Clone, Debug, PartialEq)]
+struct User {
+    name: &'static str,
+    age: u32,
+}
+
+fn names(table: Table<User>) -> Vec<&'static str> {
+    table.rows.get().iter().map(|user| user.name).collect()
+}
+
+#[test]
+fn use_table_filters_sorts_and_paginates() {
+    let runtime = create_runtime();
+    let users = create_rw_signal(vec![
+        User {
+            name: "Carol",
+            age: 35,
+        },
+        User {
+            name: "alice",
+            age: 30,
+        },
+        User {
+            name: "Bob",
+            age: 25,
+        },
+        User {
+            name: "Dave",
+            age: 30,
+        },
+    ]);
+    let table = use_table(
+        users,
+        vec![
+            TableColumn::new("name")
+                .sort_by_key(|user: &User| user.name.to_lowercase())
+                .filter(|user, text| {
+                    user.name.to_lowercase().contains(&text.to_lowercase())
+                }),
+            TableColumn::new("age").sort_by_key(|user: &User| user.age),
+        ],
+    );
+    assert_eq!(names(table), ["Carol", "alice", "Bob", "Dave"]);
+
+    table.toggle_sort("name");
+    assert_eq!(names(table), ["alice", "Bob", "Carol", "Dave"]);
+    table.toggle_sort("name");
+    assert_eq!(names(table), ["Dave", "Carol", "Bob", "alice"]);
+    table.toggle_sort("name");
+    assert_eq!(table.sort.get(), None);
+
+    // sorting is stable, so rows with the same age keep their order
+    table.toggle_sort("age");
+    assert_eq!(names(table), ["Bob", "alice", "Dave", "Carol"]);
+
+    table.page_size.set(Some(3));
+    assert_eq!(table.page_count.get(), 2);
+    table.next_page();
+    assert_eq!(names(table), ["Carol"]);
+    table.next_page();
+    assert_eq!(table.page.get(), 1);
+
+    // filtering goes back to the first page
+    table.set_filter("name", "A");
+    assert_eq!(table.page.get(), 0);
+    assert_eq!(table.filtered_count.get(), 3);
+    assert_eq!(names(table), ["alice", "Dave", "Carol"]);
+
+    // the rows follow the source signal
+    users.update(|users| {
+        users.push(User {
+            name: "Erin",
+            age: 20,
+        })
+    });
+    assert_eq!(table.filtered_count.get(), 3);
+    table.set_filter("name", "");
+    assert_eq!(names(table), ["Erin", "Bob", "alice"]);
+
+    runtime.dispose();
+}
+
+#[test]
+fn use_table_column_state() {
+    let runtime = create_runtime();
+    let table = use_table(
+        create_rw_signal(Vec::<User>::new()),
+        vec![
+            TableColumn::new("name"),
+            TableColumn::new("age"),
+            TableColumn::new("email"),
+        ],
+    );
+    assert_eq!(table.visible_columns.get(), ["name", "age", "email"]);
+
+    table.toggle_column("age");
+    assert_eq!(table.visible_columns.get(), ["name", "email"]);
+    table.move_column("email", 0);
+    assert_eq!(table.visible_columns.get(), ["email", "name"]);
+    table.toggle_column("age");
+    assert_eq!(table.visible_columns.get(), ["email", "name", "age"]);
+
+    // columns that can't be sorted by are ignored
+    table.toggle_sort("name");
+    assert_eq!(table.rows.get(), []);
+    assert_eq!(table.page_count.get(), 1);
+
+    runtime.dispose();
+}
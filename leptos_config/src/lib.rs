@@ -76,6 +76,13 @@ pub struct LeptosOptions {
     #[builder(default = default_hash_files())]
     #[serde(default = "default_hash_files")]
     pub hash_files: bool,
+    /// If true, the scripts that load the WASM and hydrate the page are inlined into each page.
+    /// Otherwise, the page references them, and the server integrations serve them, so they can be
+    /// used under a Content Security Policy that forbids inline scripts.
+    /// Defaults to `true`.
+    #[builder(default = default_inline_hydration_scripts())]
+    #[serde(default = "default_inline_hydration_scripts")]
+    pub inline_hydration_scripts: bool,
 }
 
 impl LeptosOptions {
@@ -116,6 +123,11 @@ impl LeptosOptions {
             not_found_path: env_w_default("LEPTOS_NOT_FOUND_PATH", "/404")?,
             hash_file: env_w_default("LEPTOS_HASH_FILE_NAME", "hash.txt")?,
             hash_files: env_w_default("LEPTOS_HASH_FILES", "false")?.parse()?,
+            inline_hydration_scripts: env_w_default(
+                "LEPTOS_INLINE_HYDRATION_SCRIPTS",
+                "true",
+            )?
+            .parse()?,
         })
     }
 }
@@ -162,6 +174,10 @@ fn default_hash_files() -> bool {
     false
 }
 
+fn default_inline_hydration_scripts() -> bool {
+    true
+}
+
 fn env_wo_default(key: &str) -> Result<Option<String>, LeptosConfigError> {
     match std::env::var(key) {
         Ok(val) => Ok(Some(val)),
@@ -235,6 +235,7 @@ fn leptos_options_builder_default() {
     );
     assert_eq!(conf.reload_port, 3001);
     assert_eq!(conf.reload_external_port, None);
+    assert!(conf.inline_hydration_scripts);
 }
 
 #[test]
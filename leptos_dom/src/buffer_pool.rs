@@ -0,0 +1,210 @@
+//! A pool of `String` buffers for streaming server rendering.
+//!
+//! Each time a `<Suspense/>` resolves while streaming, its HTML is copied into
+//! a new chunk, and the `String` it was rendered into is dropped. Instead,
+//! those strings are returned to a thread-local pool here, and the scratch
+//! buffers the renderer needs while building chunks are taken from it, so a
+//! server that renders many pages on the same thread allocates far fewer
+//! buffers.
+//!
+//! The chunks themselves are sent in the response and never come back, so
+//! they are allocated at their own size rather than taken from the pool.
+
+use leptos_reactive::Oco;
+use std::cell::{Cell, RefCell};
+
+/// The most buffers kept in the pool of each thread.
+const MAX_POOLED: usize = 32;
+
+/// Buffers that have grown larger than this are dropped rather than pooled,
+/// so a single very large page does not keep its memory alive.
+const MAX_CAPACITY: usize = 64 * 1024;
+
+thread_local! {
+    static POOL: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    static ENABLED: Cell<bool> = const { Cell::new(true) };
+    static STATS: Cell<BufferPoolStats> =
+        const { Cell::new(BufferPoolStats::new()) };
+}
+
+/// Counts how the streaming renderer's chunk buffers have been used on the
+/// current thread, as returned by [`buffer_pool_stats`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BufferPoolStats {
+    /// How many buffers were taken from the pool, each of which is an
+    /// allocation that was avoided.
+    pub reused: usize,
+    /// How many buffers had to be allocated because the pool had none that
+    /// was large enough.
+    pub allocated: usize,
+    /// How many buffers were returned to the pool.
+    pub recycled: usize,
+    /// How many buffers were dropped because the pool was full, or they were
+    /// too large to keep.
+    pub discarded: usize,
+}
+
+impl BufferPoolStats {
+    const fn new() -> Self {
+        Self {
+            reused: 0,
+            allocated: 0,
+            recycled: 0,
+            discarded: 0,
+        }
+    }
+}
+
+/// Returns how often the streaming renderer has reused a pooled buffer to
+/// build a chunk of HTML on the current thread, rather than allocating a new
+/// one, since the statistics were last reset.
+///
+/// ```
+/// # cfg_if::cfg_if! { if #[cfg(not(any(feature = "csr", feature = "hydrate")))] {
+/// # use leptos::ssr::*;
+/// reset_buffer_pool_stats();
+/// let stats = buffer_pool_stats();
+/// assert_eq!(stats.reused + stats.allocated, 0);
+/// # }}
+/// ```
+pub fn buffer_pool_stats() -> BufferPoolStats {
+    STATS.with(Cell::get)
+}
+
+/// Resets the statistics returned by [`buffer_pool_stats`] to zero. The
+/// buffers that are already pooled are kept.
+pub fn reset_buffer_pool_stats() {
+    STATS.with(|stats| stats.set(BufferPoolStats::new()));
+}
+
+fn record(f: impl FnOnce(&mut BufferPoolStats)) {
+    STATS.with(|stats| {
+        let mut current = stats.get();
+        f(&mut current);
+        stats.set(current);
+    });
+}
+
+/// Turns the pool on or off for the current thread, so that its effect can be
+/// measured. Turning it off drops the buffers that are already pooled.
+#[doc(hidden)]
+pub fn set_buffer_pool_enabled(enabled: bool) {
+    ENABLED.with(|e| e.set(enabled));
+    if !enabled {
+        POOL.with(|pool| pool.borrow_mut().clear());
+    }
+}
+
+/// Takes an empty buffer that can hold at least `capacity` bytes from the
+/// pool, or allocates a new one. The smallest pooled buffer that is large
+/// enough is used, so that small buffers don't take up large ones.
+///
+/// Only use this for buffers that are recycled once they have been copied
+/// from, not for chunks that leave the renderer.
+pub(crate) fn take(capacity: usize) -> String {
+    let pooled = POOL.with(|pool| {
+        let mut pool = pool.borrow_mut();
+        let (idx, _) = pool
+            .iter()
+            .enumerate()
+            .filter(|(_, buf)| buf.capacity() >= capacity)
+            .min_by_key(|(_, buf)| buf.capacity())?;
+        Some(pool.swap_remove(idx))
+    });
+    match pooled {
+        Some(buf) => {
+            record(|stats| stats.reused += 1);
+            buf
+        }
+        None => {
+            record(|stats| stats.allocated += 1);
+            String::with_capacity(capacity)
+        }
+    }
+}
+
+/// Returns a buffer to the pool once its contents have been copied elsewhere.
+pub(crate) fn recycle(mut buf: String) {
+    if buf.capacity() == 0 {
+        return;
+    }
+    let kept = buf.capacity() <= MAX_CAPACITY
+        && ENABLED.with(Cell::get)
+        && POOL.with(|pool| {
+            let mut pool = pool.borrow_mut();
+            if pool.len() < MAX_POOLED {
+                buf.clear();
+                pool.push(std::mem::take(&mut buf));
+                true
+            } else {
+                false
+            }
+        });
+    record(|stats| {
+        if kept {
+            stats.recycled += 1;
+        } else {
+            stats.discarded += 1;
+        }
+    });
+}
+
+/// Returns the buffer of an owned string to the pool.
+pub(crate) fn recycle_oco(value: Oco<'static, str>) {
+    if let Oco::Owned(buf) = value {
+        recycle(buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reuses_recycled_buffers() {
+        reset_buffer_pool_stats();
+        let buf = take(100);
+        let ptr = buf.as_ptr();
+        recycle(buf);
+
+        let reused = take(50);
+        assert_eq!(reused.as_ptr(), ptr);
+        assert!(reused.is_empty());
+        // nothing large enough is left in the pool
+        let new = take(1000);
+        assert!(new.capacity() >= 1000);
+
+        assert_eq!(
+            buffer_pool_stats(),
+            BufferPoolStats {
+                reused: 1,
+                allocated: 2,
+                recycled: 1,
+                discarded: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn takes_the_smallest_buffer_that_fits() {
+        let large = String::with_capacity(4096);
+        let small = String::with_capacity(64);
+        let (large_ptr, small_ptr) = (large.as_ptr(), small.as_ptr());
+        recycle(large);
+        recycle(small);
+
+        let first = take(10);
+        let second = take(10);
+        assert_eq!(first.as_ptr(), small_ptr);
+        assert_eq!(second.as_ptr(), large_ptr);
+    }
+
+    #[test]
+    fn discards_large_buffers() {
+        reset_buffer_pool_stats();
+        recycle(String::with_capacity(MAX_CAPACITY + 1));
+        assert_eq!(buffer_pool_stats().discarded, 1);
+        assert!(take(MAX_CAPACITY + 1).capacity() > MAX_CAPACITY);
+        assert_eq!(buffer_pool_stats().reused, 0);
+    }
+}
@@ -14,7 +14,7 @@ pub use dyn_child::*;
 pub use each::*;
 pub use errors::*;
 pub use fragment::*;
-use leptos_reactive::{untrack_with_diagnostics, Oco};
+use leptos_reactive::{untrack_with_diagnostics, ComponentStack, Oco};
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 use once_cell::unsync::OnceCell;
 use std::fmt;
@@ -275,10 +275,12 @@ where
             children_fn,
         } = self;
 
-        let mut repr = ComponentRepr::new_with_id(name, id);
+        let mut repr = ComponentRepr::new_with_id(name.clone(), id);
 
         // disposed automatically when the parent scope is disposed
-        let child = untrack_with_diagnostics(|| children_fn().into_view());
+        let child = ComponentStack::with_component(name, || {
+            untrack_with_diagnostics(|| children_fn().into_view())
+        });
 
         repr.children.push(child);
 
@@ -252,10 +252,8 @@ where
                             else {
                                 if !was_child_moved && child != new_child {
                                     // Remove the text
-                                    closing
-                                        .previous_non_view_marker_sibling()
-                                        .unwrap()
-                                        .unchecked_into::<web_sys::Element>()
+                                    prev_t
+                                        .unchecked_ref::<web_sys::Element>()
                                         .remove();
                                 }
 
@@ -343,10 +341,7 @@ where
                         let new_child = if HydrationCtx::is_hydrating()
                             && new_child.get_text().is_some()
                         {
-                            let t = closing
-                                .previous_non_view_marker_sibling()
-                                .unwrap()
-                                .unchecked_into::<web_sys::Text>();
+                            let t = hydrated_text(&closing);
 
                             let new_child = match new_child {
                                 View::Text(text) => text,
@@ -409,8 +404,46 @@ cfg_if! {
 
         pub(crate) trait NonViewMarkerSibling {
             fn next_non_view_marker_sibling(&self) -> Option<Node>;
+        }
+
+        /// Finds the text node that the server rendered for a `DynChild`
+        /// whose closing marker is `closing`, while hydrating.
+        ///
+        /// The server always renders a comment before the text: the opening
+        /// marker in debug builds, and an empty comment in release builds.
+        /// Nodes between that comment and the closing marker that are not
+        /// text, like elements injected by password managers or translation
+        /// extensions, are skipped over. If the text node is gone, a new one
+        /// is inserted before the closing marker.
+        fn hydrated_text(closing: &Node) -> web_sys::Text {
+            let mut node = closing.previous_sibling();
+            while let Some(curr) = node {
+                if let Some(text) = curr.dyn_ref::<web_sys::Text>() {
+                    return text.clone();
+                }
+                if curr.node_type() == Node::COMMENT_NODE
+                    && !curr
+                        .text_content()
+                        .unwrap_or_default()
+                        .trim()
+                        .starts_with("leptos-view")
+                {
+                    break;
+                }
+                node = curr.previous_sibling();
+            }
 
-            fn previous_non_view_marker_sibling(&self) -> Option<Node>;
+            crate::warn!(
+                "the text rendered on the server was not found while \
+                 hydrating, so it is rendered again{}",
+                leptos_reactive::ComponentStack::current()
+            );
+            let text = crate::document().create_text_node("");
+            closing
+                .unchecked_ref::<web_sys::Element>()
+                .before_with_node_1(&text)
+                .expect("before to not err");
+            text
         }
 
         impl NonViewMarkerSibling for web_sys::Node {
@@ -430,23 +463,6 @@ cfg_if! {
                     }
                 }
             }
-
-            #[cfg_attr(not(debug_assertions), inline(always))]
-            fn previous_non_view_marker_sibling(&self) -> Option<Node> {
-                cfg_if! {
-                    if #[cfg(debug_assertions)] {
-                        self.previous_sibling().and_then(|node| {
-                            if node.text_content().unwrap_or_default().trim().starts_with("leptos-view") {
-                                node.previous_sibling()
-                            } else {
-                                Some(node)
-                            }
-                        })
-                    } else {
-                        self.previous_sibling()
-                    }
-                }
-            }
         }
     }
 }
@@ -1,7 +1,10 @@
 #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
 use crate::hydration::HydrationKey;
 use crate::{hydration::HydrationCtx, Comment, CoreComponent, IntoView, View};
-use leptos_reactive::{as_child_of_current_owner, Disposer};
+use leptos_reactive::{
+    as_child_of_current_owner, create_memo, create_rw_signal, provide_context,
+    Disposer, RwSignal, Signal, SignalGet, SignalSet,
+};
 use std::{cell::RefCell, fmt, hash::Hash, ops::Deref, rc::Rc};
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 use web::*;
@@ -12,7 +15,9 @@ mod web {
         mount_child, prepare_to_move, MountKind, Mountable, RANGE,
     };
     pub use drain_filter_polyfill::VecExt as VecDrainFilterExt;
-    pub use leptos_reactive::create_render_effect;
+    pub use leptos_reactive::{
+        batch, create_render_effect, SignalGetUntracked,
+    };
     pub use std::cell::OnceCell;
     pub use wasm_bindgen::JsCast;
 }
@@ -117,6 +122,8 @@ impl Default for EachRepr {
             closing: markers.0,
             #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
             id,
+            #[cfg(all(target_arch = "wasm32", feature = "web"))]
+            index,
         }
     }
 }
@@ -176,6 +183,8 @@ pub(crate) struct EachItem {
     closing: Option<Comment>,
     #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
     pub(crate) id: Option<HydrationKey>,
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    index: Option<RwSignal<usize>>,
 }
 
 impl fmt::Debug for EachItem {
@@ -193,7 +202,14 @@ impl fmt::Debug for EachItem {
 }
 
 impl EachItem {
-    fn new(disposer: Disposer, child: View) -> Self {
+    fn new(
+        disposer: Disposer,
+        child: View,
+        index: Option<RwSignal<usize>>,
+    ) -> Self {
+        #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+        let _ = index;
+
         let id = HydrationCtx::id();
         let needs_closing = !matches!(child, View::Element(_));
 
@@ -331,6 +347,92 @@ impl EachItem {
             node.unchecked_into::<web_sys::Element>().remove();
         }
     }
+
+    /// Returns the first element of this item, which is the one that is
+    /// animated.
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    fn element(&self) -> Option<web_sys::Element> {
+        if self.document_fragment.is_none() {
+            return self.child.get_mountable_node().dyn_into().ok();
+        }
+
+        let end = self.get_closing_node();
+        let mut node = Some(self.get_opening_node());
+        while let Some(current) = node {
+            if current == end {
+                break;
+            }
+            if let Some(element) = current.dyn_ref::<web_sys::Element>() {
+                return Some(element.clone());
+            }
+            node = current.next_sibling();
+        }
+        None
+    }
+}
+
+/// Hooks that [`Each`] calls to animate its items as they are added,
+/// removed, and moved. They are set with [`Each::animation`].
+///
+/// Each hook is called with the first element of an item, so an item that
+/// doesn't render any element is not animated. The hooks are only called in
+/// the browser, when the items change after they were first rendered.
+#[derive(Clone, Default)]
+#[allow(clippy::type_complexity)]
+pub struct EachAnimation {
+    on_enter: Option<Rc<dyn Fn(&web_sys::Element)>>,
+    on_leave: Option<Rc<dyn Fn(&web_sys::Element, Box<dyn FnOnce()>)>>,
+    on_move: Option<Rc<dyn Fn(&web_sys::Element, f64, f64)>>,
+}
+
+impl EachAnimation {
+    /// Creates a set of hooks that don't animate anything.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Calls `f` with the element of each item right after it is added.
+    pub fn on_enter(mut self, f: impl Fn(&web_sys::Element) + 'static) -> Self {
+        self.on_enter = Some(Rc::new(f));
+        self
+    }
+
+    /// Calls `f` with the element of each item that is removed, instead of
+    /// removing it right away. The item stays in the DOM until the function
+    /// passed to `f` is called, which removes the item and disposes of its
+    /// reactive scope.
+    pub fn on_leave(
+        mut self,
+        f: impl Fn(&web_sys::Element, Box<dyn FnOnce()>) + 'static,
+    ) -> Self {
+        self.on_leave = Some(Rc::new(f));
+        self
+    }
+
+    /// Calls `f` after the items have changed, with the element of each item
+    /// that has moved on the page and how far it moved, as `(dx, dy)` in
+    /// pixels from its new position to its old one.
+    ///
+    /// Translating the element by `(dx, dy)` puts it back where it was, so
+    /// a transition from there to no translation animates the move (the
+    /// FLIP technique).
+    pub fn on_move(
+        mut self,
+        f: impl Fn(&web_sys::Element, f64, f64) + 'static,
+    ) -> Self {
+        self.on_move = Some(Rc::new(f));
+        self
+    }
+}
+
+impl fmt::Debug for EachAnimation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EachAnimation")
+            .field("on_enter", &self.on_enter.is_some())
+            .field("on_leave", &self.on_leave.is_some())
+            .field("on_move", &self.on_move.is_some())
+            .finish()
+    }
 }
 
 /// A component for efficiently rendering an iterable.
@@ -347,6 +449,8 @@ where
     pub(crate) items_fn: IF,
     pub(crate) each_fn: EF,
     key_fn: KF,
+    item_context: bool,
+    animation: EachAnimation,
 }
 
 impl<IF, I, T, EF, N, KF, K> Each<IF, I, T, EF, N, KF, K>
@@ -366,6 +470,56 @@ where
             items_fn,
             each_fn,
             key_fn,
+            item_context: false,
+            animation: EachAnimation {
+                on_enter: None,
+                on_leave: None,
+                on_move: None,
+            },
+        }
+    }
+
+    /// Provides a [`ForItemContext`] via context to the view of each item,
+    /// which is kept up to date as items are added, removed, and moved.
+    #[inline(always)]
+    pub fn item_context(mut self, enabled: bool) -> Self {
+        self.item_context = enabled;
+        self
+    }
+
+    /// Animates items as they are added, removed, and moved with the given
+    /// hooks.
+    #[inline(always)]
+    pub fn animation(mut self, animation: EachAnimation) -> Self {
+        self.animation = animation;
+        self
+    }
+}
+
+/// The position of an item rendered by [`Each`], provided via context to the
+/// view of each item when it is enabled with [`Each::item_context`].
+///
+/// All of its signals are updated when the items change, so reading them
+/// only re-renders the parts of a row that depend on its position.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ForItemContext {
+    /// The current index of the item.
+    pub index: Signal<usize>,
+    /// The current number of items.
+    pub len: Signal<usize>,
+    /// Whether the item is the first item.
+    pub is_first: Signal<bool>,
+    /// Whether the item is the last item.
+    pub is_last: Signal<bool>,
+}
+
+impl ForItemContext {
+    fn new(index: RwSignal<usize>, len: RwSignal<usize>) -> Self {
+        Self {
+            index: index.read_only().into(),
+            len: len.read_only().into(),
+            is_first: create_memo(move |_| index.get() == 0).into(),
+            is_last: create_memo(move |_| index.get() + 1 == len.get()).into(),
         }
     }
 }
@@ -389,10 +543,12 @@ where
             items_fn,
             each_fn,
             key_fn,
+            item_context,
+            animation,
         } = self;
 
         #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
-        let _ = key_fn;
+        let _ = (key_fn, animation);
 
         let component = EachRepr::default();
 
@@ -403,111 +559,155 @@ where
         let (children, closing) =
             (component.children.clone(), component.closing.node.clone());
 
-        let each_fn = as_child_of_current_owner(each_fn);
+        // the number of items, shared by the contexts of all the items
+        let len = item_context.then(|| create_rw_signal(0));
+        let each_fn =
+            as_child_of_current_owner(move |(item, index): (T, usize)| {
+                let index = len.map(|len| {
+                    let index = create_rw_signal(index);
+                    provide_context(ForItemContext::new(index, len));
+                    index
+                });
+                (each_fn(item), index)
+            });
 
         #[cfg(all(target_arch = "wasm32", feature = "web"))]
         create_render_effect(
             move |prev_hash_run: Option<HashRun<FxIndexSet<K>>>| {
-                let mut children_borrow = children.borrow_mut();
-
-                #[cfg(all(
-                    not(debug_assertions),
-                    target_arch = "wasm32",
-                    feature = "web"
-                ))]
-                let opening = if let Some(Some(child)) = children_borrow.get(0)
-                {
-                    // correctly remove opening <!--<EachItem/>-->
-                    let child_opening = child.get_opening_node();
-                    #[cfg(debug_assertions)]
-                    {
-                        use crate::components::dyn_child::NonViewMarkerSibling;
-                        child_opening
-                            .previous_non_view_marker_sibling()
-                            .unwrap_or(child_opening)
+                // updating `len` and the indices can rerun the views of many
+                // items, so they only rerun once the whole diff is applied
+                batch(|| {
+                    let mut children_borrow = children.borrow_mut();
+
+                    #[cfg(all(
+                        not(debug_assertions),
+                        target_arch = "wasm32",
+                        feature = "web"
+                    ))]
+                    let opening =
+                        if let Some(Some(child)) = children_borrow.get(0) {
+                            child.get_opening_node()
+                        } else {
+                            closing.clone()
+                        };
+
+                    let items_iter = items_fn().into_iter();
+
+                    let (capacity, _) = items_iter.size_hint();
+                    let mut hashed_items = FxIndexSet::with_capacity_and_hasher(
+                        capacity,
+                        Default::default(),
+                    );
+
+                    // the number of items has to be known before rendering the
+                    // new items, so they start out with the right `len`
+                    let mut items = Vec::with_capacity(capacity);
+                    for item in items_iter {
+                        hashed_items.insert(key_fn(&item));
+                        items.push(Some(item));
                     }
-                    #[cfg(not(debug_assertions))]
-                    {
-                        child_opening
+                    if let Some(len) = len {
+                        if len.get_untracked() != items.len() {
+                            len.set(items.len());
+                        }
                     }
-                } else {
-                    closing.clone()
-                };
 
-                let items_iter = items_fn().into_iter();
+                    // items are only animated once they have been rendered
+                    let animate = prev_hash_run.is_some();
+                    if let Some(HashRun(prev_hash_run)) = prev_hash_run {
+                        if !prev_hash_run.is_empty() {
+                            let cmds = diff(&prev_hash_run, &hashed_items);
+                            let positions = animation
+                                .on_move
+                                .as_ref()
+                                .map(|_| element_positions(&children_borrow));
+
+                            apply_diff(
+                                #[cfg(all(
+                                    target_arch = "wasm32",
+                                    feature = "web"
+                                ))]
+                                &opening,
+                                #[cfg(all(
+                                    target_arch = "wasm32",
+                                    feature = "web"
+                                ))]
+                                &closing,
+                                cmds,
+                                &mut children_borrow,
+                                items,
+                                &each_fn,
+                                &animation,
+                            );
+                            if let (Some(on_move), Some(positions)) =
+                                (&animation.on_move, positions)
+                            {
+                                animate_moves(
+                                    &children_borrow,
+                                    positions,
+                                    on_move,
+                                );
+                            }
+                            update_item_indices(&children_borrow);
+                            return HashRun(hashed_items);
+                        }
+                    }
 
-                let (capacity, _) = items_iter.size_hint();
-                let mut hashed_items = FxIndexSet::with_capacity_and_hasher(
-                    capacity,
-                    Default::default(),
-                );
+                    // if previous run is empty
+                    *children_borrow = Vec::with_capacity(capacity);
+                    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+                    let fragment = crate::document().create_document_fragment();
 
-                if let Some(HashRun(prev_hash_run)) = prev_hash_run {
-                    if !prev_hash_run.is_empty() {
-                        let mut items = Vec::with_capacity(capacity);
-                        for item in items_iter {
-                            hashed_items.insert(key_fn(&item));
-                            items.push(Some(item));
+                    for (index, item) in items.into_iter().flatten().enumerate()
+                    {
+                        let ((child, index), disposer) = each_fn((item, index));
+                        let each_item =
+                            EachItem::new(disposer, child.into_view(), index);
+
+                        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+                        {
+                            _ = fragment
+                                .append_child(&each_item.get_mountable_node());
                         }
 
-                        let cmds = diff(&prev_hash_run, &hashed_items);
-
-                        apply_diff(
-                            #[cfg(all(
-                                target_arch = "wasm32",
-                                feature = "web"
-                            ))]
-                            &opening,
-                            #[cfg(all(
-                                target_arch = "wasm32",
-                                feature = "web"
-                            ))]
-                            &closing,
-                            cmds,
-                            &mut children_borrow,
-                            items,
-                            &each_fn,
-                        );
-                        return HashRun(hashed_items);
+                        children_borrow.push(Some(each_item));
                     }
-                }
-
-                // if previous run is empty
-                *children_borrow = Vec::with_capacity(capacity);
-                #[cfg(all(target_arch = "wasm32", feature = "web"))]
-                let fragment = crate::document().create_document_fragment();
-
-                for item in items_iter {
-                    hashed_items.insert(key_fn(&item));
-                    let (child, disposer) = each_fn(item);
-                    let each_item = EachItem::new(disposer, child.into_view());
 
                     #[cfg(all(target_arch = "wasm32", feature = "web"))]
+                    closing
+                        .unchecked_ref::<web_sys::Element>()
+                        .before_with_node_1(&fragment)
+                        .expect("before to not err");
+
+                    if let (true, Some(on_enter)) =
+                        (animate, &animation.on_enter)
                     {
-                        _ = fragment
-                            .append_child(&each_item.get_mountable_node());
+                        for element in children_borrow
+                            .iter()
+                            .flatten()
+                            .filter_map(EachItem::element)
+                        {
+                            on_enter(&element);
+                        }
                     }
 
-                    children_borrow.push(Some(each_item));
-                }
-
-                #[cfg(all(target_arch = "wasm32", feature = "web"))]
-                closing
-                    .unchecked_ref::<web_sys::Element>()
-                    .before_with_node_1(&fragment)
-                    .expect("before to not err");
-
-                HashRun(hashed_items)
+                    HashRun(hashed_items)
+                })
             },
         );
 
         #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
         {
-            *component.children.borrow_mut() = (items_fn)()
+            let items = (items_fn)().into_iter().collect::<Vec<_>>();
+            if let Some(len) = len {
+                len.set(items.len());
+            }
+            *component.children.borrow_mut() = items
                 .into_iter()
-                .map(|child| {
-                    let (item, disposer) = each_fn(child);
-                    Some(EachItem::new(disposer, item.into_view()))
+                .enumerate()
+                .map(|(index, child)| {
+                    let ((item, index), disposer) = each_fn((child, index));
+                    Some(EachItem::new(disposer, item.into_view(), index))
                 })
                 .collect();
         }
@@ -552,7 +752,6 @@ fn diff<K: Eq + Hash>(from: &FxIndexSet<K>, to: &FxIndexSet<K>) -> Diff {
     }
 
     let mut removed = vec![];
-    let mut moved = vec![];
     let mut added = vec![];
     let max_len = std::cmp::max(from.len(), to.len());
 
@@ -575,30 +774,35 @@ fn diff<K: Eq + Hash>(from: &FxIndexSet<K>, to: &FxIndexSet<K>) -> Diff {
                 };
                 added.push(op);
             }
-            // if it's in both old and new, it can either
-            // 1) be moved (and need to move in the DOM)
-            // 2) be moved (but not need to move in the DOM)
-            //    * this would happen if, for example, 2 items
-            //      have been added before it, and it has moved by 2
-            if let Some(from_item) = from_item {
-                if let Some(to_item) = to.get_full(from_item) {
-                    let moves_forward_by = (to_item.0 as i32) - (index as i32);
-                    let move_in_dom = moves_forward_by
-                        != (added.len() as i32) - (removed.len() as i32);
-
-                    let op = DiffOpMove {
-                        from: index,
-                        len: 1,
-                        to: to_item.0,
-                        move_in_dom,
-                    };
-                    moved.push(op);
-                }
-            }
         }
     }
 
-    moved = group_adjacent_moves(moved);
+    // the items that are in both old and new, in their old order, with the
+    // index they are moving to
+    let kept = from
+        .iter()
+        .enumerate()
+        .filter_map(|(from, item)| Some((from, to.get_index_of(item)?)))
+        .collect::<Vec<_>>();
+    // the items of the longest run that is already in the right order can
+    // stay where they are in the DOM, and only the others have to be moved
+    // around them
+    let in_order = longest_increasing_subsequence(
+        &kept.iter().map(|(_, to)| *to).collect::<Vec<_>>(),
+    );
+    let moved = kept
+        .into_iter()
+        .zip(in_order)
+        .filter(|((from, to), in_order)| !in_order || from != to)
+        .map(|((from, to), in_order)| DiffOpMove {
+            from,
+            len: 1,
+            to,
+            move_in_dom: !in_order,
+        })
+        .collect();
+
+    let moved = group_adjacent_moves(moved);
 
     Diff {
         removed,
@@ -609,6 +813,34 @@ fn diff<K: Eq + Hash>(from: &FxIndexSet<K>, to: &FxIndexSet<K>) -> Diff {
     }
 }
 
+/// Marks the items of one of the longest strictly increasing subsequences of
+/// `values`.
+fn longest_increasing_subsequence(values: &[usize]) -> Vec<bool> {
+    // `tails[len]` is the index of the smallest value that ends an increasing
+    // subsequence of length `len + 1`
+    let mut tails: Vec<usize> = Vec::new();
+    let mut prev = vec![None; values.len()];
+    for (i, value) in values.iter().enumerate() {
+        let len = tails.partition_point(|&tail| values[tail] < *value);
+        if len > 0 {
+            prev[i] = Some(tails[len - 1]);
+        }
+        if len == tails.len() {
+            tails.push(i);
+        } else {
+            tails[len] = i;
+        }
+    }
+
+    let mut in_subsequence = vec![false; values.len()];
+    let mut next = tails.last().copied();
+    while let Some(i) = next {
+        in_subsequence[i] = true;
+        next = prev[i];
+    }
+    in_subsequence
+}
+
 /// Group adjacent items that are being moved as a group.
 /// For example from `[2, 3, 5, 6]` to `[1, 2, 3, 4, 5, 6]` should result
 /// in a move for `2,3` and `5,6` rather than 4 individual moves.
@@ -618,7 +850,10 @@ fn group_adjacent_moves(moved: Vec<DiffOpMove>) -> Vec<DiffOpMove> {
     for m in moved {
         match prev {
             Some(mut p) => {
-                if (m.from == p.from + p.len) && (m.to == p.to + p.len) {
+                if (m.from == p.from + p.len)
+                    && (m.to == p.to + p.len)
+                    && m.move_in_dom == p.move_in_dom
+                {
                     p.len += 1;
                     prev = Some(p);
                 } else {
@@ -702,8 +937,9 @@ fn apply_diff<T, EF, V>(
     children: &mut Vec<Option<EachItem>>,
     mut items: Vec<Option<T>>,
     each_fn: &EF,
+    animation: &EachAnimation,
 ) where
-    EF: Fn(T) -> (V, Disposer),
+    EF: Fn((T, usize)) -> ((V, Option<RwSignal<usize>>), Disposer),
     V: IntoView,
 {
     let range = RANGE.with(|range| (*range).clone());
@@ -716,7 +952,16 @@ fn apply_diff<T, EF, V>(
     // 5. Move in
     // 6. Additions
     // 7. Removes holes
-    if diff.clear {
+    if diff.clear && animation.on_leave.is_some() {
+        // each item has to leave on its own
+        for item in children.drain(..).flatten() {
+            remove_item(item, animation);
+        }
+
+        if diff.added.is_empty() {
+            return;
+        }
+    } else if diff.clear {
         if opening.previous_sibling().is_none()
             && closing.next_sibling().is_none()
         {
@@ -752,7 +997,7 @@ fn apply_diff<T, EF, V>(
     for DiffOpRemove { at } in &diff.removed {
         let item_to_remove = children[*at].take().unwrap();
 
-        item_to_remove.prepare_for_move();
+        remove_item(item_to_remove, animation);
     }
 
     let (move_cmds, add_cmds) = unpack_moves(&diff);
@@ -796,8 +1041,9 @@ fn apply_diff<T, EF, V>(
     }
 
     for DiffOpAdd { at, mode } in add_cmds {
-        let (item, disposer) = each_fn(items[at].take().unwrap());
-        let each_item = EachItem::new(disposer, item.into_view());
+        let ((item, index), disposer) =
+            each_fn((items[at].take().unwrap(), at));
+        let each_item = EachItem::new(disposer, item.into_view(), index);
 
         match mode {
             DiffOpAddMode::Normal => {
@@ -814,6 +1060,12 @@ fn apply_diff<T, EF, V>(
             }
         }
 
+        if let Some(on_enter) = &animation.on_enter {
+            if let Some(element) = each_item.element() {
+                on_enter(&element);
+            }
+        }
+
         children[at] = Some(each_item);
     }
 
@@ -821,6 +1073,72 @@ fn apply_diff<T, EF, V>(
     children.drain_filter(|c| c.is_none());
 }
 
+/// Removes an item from the DOM, and disposes of it, unless it has to leave
+/// first.
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+fn remove_item(item: EachItem, animation: &EachAnimation) {
+    match (&animation.on_leave, item.element()) {
+        (Some(on_leave), Some(element)) => on_leave(
+            &element,
+            Box::new(move || {
+                item.prepare_for_move();
+                drop(item);
+            }),
+        ),
+        _ => item.prepare_for_move(),
+    }
+}
+
+/// Returns the position on the page of the element of each item.
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+fn element_positions(
+    children: &[Option<EachItem>],
+) -> Vec<(web_sys::Element, f64, f64)> {
+    children
+        .iter()
+        .flatten()
+        .filter_map(EachItem::element)
+        .map(|element| {
+            let rect = element.get_bounding_client_rect();
+            (element, rect.left(), rect.top())
+        })
+        .collect()
+}
+
+/// Calls `on_move` for each item whose element is no longer at the position
+/// it had before the items changed.
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+fn animate_moves(
+    children: &[Option<EachItem>],
+    positions: Vec<(web_sys::Element, f64, f64)>,
+    on_move: &Rc<dyn Fn(&web_sys::Element, f64, f64)>,
+) {
+    for element in children.iter().flatten().filter_map(EachItem::element) {
+        let Some((_, left, top)) =
+            positions.iter().find(|(prev, ..)| *prev == element)
+        else {
+            continue;
+        };
+        let rect = element.get_bounding_client_rect();
+        let (dx, dy) = (left - rect.left(), top - rect.top());
+        if dx != 0.0 || dy != 0.0 {
+            on_move(&element, dx, dy);
+        }
+    }
+}
+
+/// Updates the index in the [`ForItemContext`] of each item that has moved.
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+fn update_item_indices(children: &[Option<EachItem>]) {
+    for (i, child) in children.iter().flatten().enumerate() {
+        if let Some(index) = child.index {
+            if index.get_untracked() != i {
+                index.set(i);
+            }
+        }
+    }
+}
+
 /// Unpacks adds and moves into a sequence of interleaved
 /// add and move commands. Move commands will always return
 /// with a `len == 1`.
@@ -1294,3 +1612,64 @@ fn unpack_moves(diff: &Diff) -> (Vec<DiffOpMove>, Vec<DiffOpAdd>) {
 //         );
 //     }
 // }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diff_of(from: &[u32], to: &[u32]) -> Diff {
+        diff(
+            &from.iter().copied().collect(),
+            &to.iter().copied().collect(),
+        )
+    }
+
+    #[test]
+    fn moving_one_item_to_the_front_moves_only_it_in_the_dom() {
+        let diff = diff_of(&[1, 2, 3, 4], &[4, 1, 2, 3]);
+
+        assert_eq!(
+            diff.moved,
+            vec![
+                DiffOpMove {
+                    from: 0,
+                    len: 3,
+                    to: 1,
+                    move_in_dom: false,
+                },
+                DiffOpMove {
+                    from: 3,
+                    len: 1,
+                    to: 0,
+                    move_in_dom: true,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn reversing_keeps_one_item_in_place() {
+        let diff = diff_of(&[1, 2, 3], &[3, 2, 1]);
+
+        let in_dom = diff.moved.iter().filter(|m| m.move_in_dom).count();
+        assert_eq!(in_dom, 2);
+    }
+
+    #[test]
+    fn items_that_shift_with_additions_and_removals_do_not_move_in_the_dom() {
+        let diff = diff_of(&[1, 2, 3, 4], &[0, 1, 3, 4, 5]);
+
+        assert!(diff.moved.iter().all(|m| !m.move_in_dom));
+        assert_eq!(diff.removed, vec![DiffOpRemove { at: 1 }]);
+        assert_eq!(diff.added.len(), 2);
+    }
+
+    #[test]
+    fn longest_increasing_subsequence_is_marked() {
+        assert_eq!(
+            longest_increasing_subsequence(&[3, 0, 1, 4, 2]),
+            [false, true, true, false, true]
+        );
+        assert_eq!(longest_increasing_subsequence(&[]), Vec::<bool>::new());
+    }
+}
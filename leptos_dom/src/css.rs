@@ -0,0 +1,393 @@
+use std::{
+    error::Error,
+    fmt,
+    ops::{Add, Div, Mul, Neg, Sub},
+    str::FromStr,
+};
+
+/// Values that can be interpolated, for example to animate between them.
+pub trait Interpolate {
+    /// Returns the value `t` of the way from `self` to `to`, where `t` is
+    /// usually between `0.0` (`self`) and `1.0` (`to`).
+    fn interpolate(&self, to: &Self, t: f64) -> Self;
+}
+
+impl Interpolate for f64 {
+    fn interpolate(&self, to: &Self, t: f64) -> Self {
+        self + (to - self) * t
+    }
+}
+
+/// An RGB color with an alpha channel, which can be parsed from and is
+/// formatted as CSS, and used as the value of a style or attribute.
+///
+/// ```
+/// # use leptos_dom::{Color, Interpolate};
+/// let accent = "#3366ff".parse::<Color>().unwrap();
+/// assert_eq!(accent.darken(0.5).to_string(), "#1a3380");
+/// assert_eq!(accent.with_alpha(0.5).to_string(), "rgba(51, 102, 255, 0.5)");
+/// assert_eq!(Color::BLACK.interpolate(&Color::WHITE, 0.5), Color::rgb(128, 128, 128));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Color {
+    /// The red channel.
+    pub r: u8,
+    /// The green channel.
+    pub g: u8,
+    /// The blue channel.
+    pub b: u8,
+    /// The opacity, from `0.0` (transparent) to `1.0` (opaque).
+    pub a: f64,
+}
+
+impl Color {
+    /// Opaque black.
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    /// Opaque white.
+    pub const WHITE: Color = Color::rgb(255, 255, 255);
+    /// Fully transparent black.
+    pub const TRANSPARENT: Color = Color::rgba(0, 0, 0, 0.0);
+
+    /// Creates an opaque color.
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self::rgba(r, g, b, 1.0)
+    }
+
+    /// Creates a color with the given opacity.
+    pub const fn rgba(r: u8, g: u8, b: u8, a: f64) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Returns this color with the given opacity.
+    pub fn with_alpha(self, a: f64) -> Self {
+        Self {
+            a: a.clamp(0.0, 1.0),
+            ..self
+        }
+    }
+
+    /// Mixes this color with white, by `amount` from `0.0` to `1.0`.
+    pub fn lighten(self, amount: f64) -> Self {
+        self.interpolate(&Color::WHITE.with_alpha(self.a), amount)
+    }
+
+    /// Mixes this color with black, by `amount` from `0.0` to `1.0`.
+    pub fn darken(self, amount: f64) -> Self {
+        self.interpolate(&Color::BLACK.with_alpha(self.a), amount)
+    }
+}
+
+impl Interpolate for Color {
+    fn interpolate(&self, to: &Self, t: f64) -> Self {
+        let channel = |from: u8, to: u8| {
+            f64::from(from)
+                .interpolate(&f64::from(to), t)
+                .round()
+                .clamp(0.0, 255.0) as u8
+        };
+        Self {
+            r: channel(self.r, to.r),
+            g: channel(self.g, to.g),
+            b: channel(self.b, to.b),
+            a: self.a.interpolate(&to.a, t).clamp(0.0, 1.0),
+        }
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { r, g, b, a } = *self;
+        if a >= 1.0 {
+            write!(f, "#{r:02x}{g:02x}{b:02x}")
+        } else {
+            write!(f, "rgba({r}, {g}, {b}, {a})")
+        }
+    }
+}
+
+/// The error returned when a [`Color`] can't be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseColorError(String);
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid color `{}`", self.0)
+    }
+}
+
+impl Error for ParseColorError {}
+
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    /// Parses a color in hex notation (`#rgb`, `#rgba`, `#rrggbb`, or
+    /// `#rrggbbaa`), in functional notation (`rgb(51, 102, 255)`,
+    /// `rgba(51 102 255 / 0.5)`), or one of `black`, `white`, and
+    /// `transparent`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseColorError(s.to_string());
+        let value = s.trim().to_ascii_lowercase();
+
+        if let Some(hex) = value.strip_prefix('#') {
+            let digits = hex
+                .chars()
+                .map(|c| c.to_digit(16).map(|d| d as u8))
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(error)?;
+            let channels = match digits.len() {
+                3 | 4 => digits.iter().map(|d| d * 17).collect::<Vec<_>>(),
+                6 | 8 => digits.chunks(2).map(|d| d[0] * 16 + d[1]).collect(),
+                _ => return Err(error()),
+            };
+            let a = channels.get(3).map_or(1.0, |a| f64::from(*a) / 255.0);
+            return Ok(Color::rgba(channels[0], channels[1], channels[2], a));
+        }
+
+        let args = value
+            .strip_prefix("rgba(")
+            .or_else(|| value.strip_prefix("rgb("))
+            .and_then(|rest| rest.strip_suffix(')'));
+        if let Some(args) = args {
+            let args = args
+                .split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+                .filter(|arg| !arg.is_empty())
+                .collect::<Vec<_>>();
+            let channel = |arg: &str| arg.parse::<u8>().map_err(|_| error());
+            return match args[..] {
+                [r, g, b] => {
+                    Ok(Color::rgb(channel(r)?, channel(g)?, channel(b)?))
+                }
+                [r, g, b, a] => {
+                    let a = a.parse::<f64>().map_err(|_| error())?;
+                    Ok(Color::rgb(channel(r)?, channel(g)?, channel(b)?)
+                        .with_alpha(a))
+                }
+                _ => Err(error()),
+            };
+        }
+
+        match value.as_str() {
+            "black" => Ok(Color::BLACK),
+            "white" => Ok(Color::WHITE),
+            "transparent" => Ok(Color::TRANSPARENT),
+            _ => Err(error()),
+        }
+    }
+}
+
+/// A CSS length, which can be parsed from and is formatted as CSS, and used
+/// as the value of a style or attribute.
+///
+/// A length can combine several units, so lengths can always be added,
+/// subtracted, and scaled; lengths with more than one unit are formatted
+/// with `calc()`.
+///
+/// ```
+/// # use leptos_dom::{Interpolate, Length};
+/// let gap = Length::rem(1.0);
+/// assert_eq!((gap * 2.0).to_string(), "2rem");
+/// assert_eq!((Length::percent(100.0) - gap).to_string(), "calc(100% - 1rem)");
+/// assert_eq!("12px".parse::<Length>().unwrap(), Length::px(12.0));
+/// assert_eq!(Length::px(0.0).interpolate(&Length::px(10.0), 0.5), Length::px(5.0));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Length {
+    px: f64,
+    em: f64,
+    rem: f64,
+    percent: f64,
+    vw: f64,
+    vh: f64,
+}
+
+macro_rules! length_unit {
+    ($($unit:ident => $doc:literal),* $(,)?) => {
+        impl Length {
+            $(
+                #[doc = $doc]
+                pub const fn $unit(value: f64) -> Self {
+                    Self {
+                        px: 0.0,
+                        em: 0.0,
+                        rem: 0.0,
+                        percent: 0.0,
+                        vw: 0.0,
+                        vh: 0.0,
+                    }
+                    .with(Unit::$unit, value)
+                }
+            )*
+        }
+    };
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy)]
+enum Unit {
+    px,
+    em,
+    rem,
+    percent,
+    vw,
+    vh,
+}
+
+impl Unit {
+    const ALL: [(Unit, &'static str); 6] = [
+        (Unit::px, "px"),
+        (Unit::em, "em"),
+        (Unit::rem, "rem"),
+        (Unit::percent, "%"),
+        (Unit::vw, "vw"),
+        (Unit::vh, "vh"),
+    ];
+}
+
+length_unit! {
+    px => "A length in pixels.",
+    em => "A length relative to the font size of the element.",
+    rem => "A length relative to the font size of the root element.",
+    percent => "A percentage of the size of the parent element.",
+    vw => "A percentage of the width of the viewport.",
+    vh => "A percentage of the height of the viewport.",
+}
+
+impl Length {
+    /// A length of zero.
+    pub const ZERO: Length = Length::px(0.0);
+
+    const fn with(mut self, unit: Unit, value: f64) -> Self {
+        match unit {
+            Unit::px => self.px = value,
+            Unit::em => self.em = value,
+            Unit::rem => self.rem = value,
+            Unit::percent => self.percent = value,
+            Unit::vw => self.vw = value,
+            Unit::vh => self.vh = value,
+        }
+        self
+    }
+
+    fn get(&self, unit: Unit) -> f64 {
+        match unit {
+            Unit::px => self.px,
+            Unit::em => self.em,
+            Unit::rem => self.rem,
+            Unit::percent => self.percent,
+            Unit::vw => self.vw,
+            Unit::vh => self.vh,
+        }
+    }
+
+    fn map(self, other: Self, f: impl Fn(f64, f64) -> f64) -> Self {
+        Unit::ALL.iter().fold(Length::ZERO, |length, (unit, _)| {
+            length.with(*unit, f(self.get(*unit), other.get(*unit)))
+        })
+    }
+}
+
+impl Add for Length {
+    type Output = Length;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.map(rhs, |a, b| a + b)
+    }
+}
+
+impl Sub for Length {
+    type Output = Length;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.map(rhs, |a, b| a - b)
+    }
+}
+
+impl Mul<f64> for Length {
+    type Output = Length;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        self.map(self, |a, _| a * rhs)
+    }
+}
+
+impl Div<f64> for Length {
+    type Output = Length;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        self.map(self, |a, _| a / rhs)
+    }
+}
+
+impl Neg for Length {
+    type Output = Length;
+
+    fn neg(self) -> Self::Output {
+        self * -1.0
+    }
+}
+
+impl Interpolate for Length {
+    fn interpolate(&self, to: &Self, t: f64) -> Self {
+        self.map(*to, |a, b| a.interpolate(&b, t))
+    }
+}
+
+impl fmt::Display for Length {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut terms = Unit::ALL
+            .iter()
+            .map(|(unit, suffix)| (self.get(*unit), suffix))
+            .filter(|(value, _)| *value != 0.0)
+            .collect::<Vec<_>>();
+        // start with a positive term, so that `100% - 1rem` isn't written as
+        // `-1rem + 100%`
+        terms.sort_by_key(|(value, _)| *value < 0.0);
+        match &terms[..] {
+            [] => f.write_str("0"),
+            [(value, suffix)] => write!(f, "{value}{suffix}"),
+            [(value, suffix), rest @ ..] => {
+                write!(f, "calc({value}{suffix}")?;
+                for (value, suffix) in rest {
+                    let sign = if *value < 0.0 { '-' } else { '+' };
+                    write!(f, " {sign} {}{suffix}", value.abs())?;
+                }
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+/// The error returned when a [`Length`] can't be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseLengthError(String);
+
+impl fmt::Display for ParseLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid length `{}`", self.0)
+    }
+}
+
+impl Error for ParseLengthError {}
+
+impl FromStr for Length {
+    type Err = ParseLengthError;
+
+    /// Parses a number followed by one of the units `px`, `em`, `rem`, `%`,
+    /// `vw`, and `vh`, or `0`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.trim();
+        if value == "0" {
+            return Ok(Length::ZERO);
+        }
+        // `rem` has to be tried before `em`
+        let mut units = Unit::ALL;
+        units.sort_by_key(|(_, suffix)| std::cmp::Reverse(suffix.len()));
+        units
+            .iter()
+            .find_map(|(unit, suffix)| {
+                let number = value.strip_suffix(suffix)?.parse().ok()?;
+                Some(Length::ZERO.with(*unit, number))
+            })
+            .ok_or_else(|| ParseLengthError(s.to_string()))
+    }
+}
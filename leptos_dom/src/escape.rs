@@ -0,0 +1,138 @@
+//! HTML escaping for server rendering.
+//!
+//! Most text and attribute values contain few or no characters that need to
+//! be escaped, so rather than checking every byte, these functions search for
+//! the next special character with [`memchr`] (which uses SIMD instructions
+//! where they are available) and copy everything before it in bulk.
+
+use leptos_reactive::Oco;
+use memchr::{memchr, memchr3};
+use std::borrow::Cow;
+
+/// Escapes `&`, `<`, `>`, `"`, `'`, and `/` in text content.
+///
+/// This produces the same output as `html_escape::encode_safe`.
+pub(crate) fn escape_text(text: &str) -> Cow<'_, str> {
+    escape_with(
+        text,
+        |bytes| memchr3(b'&', b'<', b'>', bytes),
+        |bytes| memchr3(b'"', b'\'', b'/', bytes),
+    )
+}
+
+/// Escapes `&`, `<`, `>`, and `"` in the value of a double-quoted attribute.
+///
+/// This produces the same output as
+/// `html_escape::encode_double_quoted_attribute`.
+pub(crate) fn escape_attr(value: &str) -> Cow<'_, str> {
+    escape_with(
+        value,
+        |bytes| memchr3(b'&', b'<', b'>', bytes),
+        |bytes| memchr(b'"', bytes),
+    )
+}
+
+/// Escapes text content, reusing `text` if nothing needs to be escaped.
+pub(crate) fn escape_text_oco(text: Oco<'static, str>) -> Oco<'static, str> {
+    match escape_text(&text) {
+        Cow::Borrowed(_) => None,
+        Cow::Owned(escaped) => Some(escaped),
+    }
+    .map(Oco::from)
+    .unwrap_or(text)
+}
+
+// `memchr` can search for at most three bytes at once, so the characters to
+// escape are split into two sets, and the next match of each set is only
+// searched for again once it has been escaped
+fn escape_with(
+    text: &str,
+    find_a: impl Fn(&[u8]) -> Option<usize>,
+    find_b: impl Fn(&[u8]) -> Option<usize>,
+) -> Cow<'_, str> {
+    let bytes = text.as_bytes();
+    let mut next_a = find_a(bytes);
+    let mut next_b = find_b(bytes);
+    if next_a.is_none() && next_b.is_none() {
+        return Cow::Borrowed(text);
+    }
+
+    // leave room for a few entities, so short values that contain one or two
+    // of them don't need to reallocate
+    let mut escaped =
+        String::with_capacity(text.len() + (text.len() / 8).max(16));
+    let mut start = 0;
+    loop {
+        let at = match (next_a, next_b) {
+            (Some(a), Some(b)) => a.min(b),
+            (Some(at), None) | (None, Some(at)) => at,
+            (None, None) => break,
+        };
+        // every character that is escaped is ASCII, so `at` and `at + 1` are
+        // always on a character boundary
+        escaped.push_str(&text[start..at]);
+        escaped.push_str(entity(bytes[at]));
+        start = at + 1;
+
+        let rest = &bytes[start..];
+        if next_a == Some(at) {
+            next_a = find_a(rest).map(|i| i + start);
+        } else {
+            next_b = find_b(rest).map(|i| i + start);
+        }
+    }
+    escaped.push_str(&text[start..]);
+    Cow::Owned(escaped)
+}
+
+fn entity(byte: u8) -> &'static str {
+    match byte {
+        b'&' => "&amp;",
+        b'<' => "&lt;",
+        b'>' => "&gt;",
+        b'"' => "&quot;",
+        b'\'' => "&#x27;",
+        b'/' => "&#x2F;",
+        _ => unreachable!("no entity for {:?}", byte as char),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLES: &[&str] = &[
+        "",
+        "plain text",
+        "<",
+        "&&&",
+        "Here's some <b>introductory</b> text & more.",
+        "https://leptos.dev/docs?a=1&b=\"2\"",
+        "</script><script>alert('xss')</script>",
+        "ünïcödé & emoji 🦀 <3 \"quotes\" 'single' a/b",
+        "trailing special>",
+    ];
+
+    #[test]
+    fn matches_html_escape() {
+        for sample in SAMPLES {
+            assert_eq!(
+                escape_text(sample),
+                html_escape::encode_safe(sample),
+                "text: {sample:?}"
+            );
+            assert_eq!(
+                escape_attr(sample),
+                html_escape::encode_double_quoted_attribute(sample),
+                "attribute: {sample:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn borrows_when_nothing_is_escaped() {
+        assert!(matches!(escape_text("plain text"), Cow::Borrowed(_)));
+        assert!(matches!(escape_attr("it's a/b"), Cow::Borrowed(_)));
+        assert!(matches!(escape_attr("a&b"), Cow::Owned(_)));
+    }
+}
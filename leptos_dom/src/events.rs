@@ -59,10 +59,11 @@ pub fn add_event_listener<E>(
     cfg_if::cfg_if! {
       if #[cfg(debug_assertions)] {
         let span = ::tracing::Span::current();
+        let component_stack = leptos_reactive::ComponentStack::current();
         let cb = Box::new(move |e| {
           let prev = leptos_reactive::SpecialNonReactiveZone::enter();
           let _guard = span.enter();
-          cb(e);
+          component_stack.run(|| cb(e));
           leptos_reactive::SpecialNonReactiveZone::exit(prev);
         });
       }
@@ -94,10 +95,11 @@ pub(crate) fn add_event_listener_undelegated<E>(
     cfg_if::cfg_if! {
       if #[cfg(debug_assertions)] {
         let span = ::tracing::Span::current();
+        let component_stack = leptos_reactive::ComponentStack::current();
         let cb = Box::new(move |e| {
           let prev = leptos_reactive::SpecialNonReactiveZone::enter();
           let _guard = span.enter();
-          cb(e);
+          component_stack.run(|| cb(e));
           leptos_reactive::SpecialNonReactiveZone::exit(prev);
         });
       }
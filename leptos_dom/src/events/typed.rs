@@ -127,6 +127,181 @@ impl<E: FromWasmAbi> Custom<E> {
     }
 }
 
+/// Options for an event listener, which [`WithOptions`] adds to an event, and
+/// which the `:capture`, `:passive`, and `:once` modifiers of an `on:{event}`
+/// attribute in the `view` macro set.
+///
+/// See [`addEventListener`](https://developer.mozilla.org/en-US/docs/Web/API/EventTarget/addEventListener#options)
+/// for what each option does.
+#[derive(Clone, Debug, Default)]
+pub struct EventOptions {
+    capture: bool,
+    passive: Option<bool>,
+    once: bool,
+    signal: Option<web_sys::AbortSignal>,
+}
+
+impl EventOptions {
+    /// Creates options with the browser's defaults.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Runs the listener while the event is captured on its way to the
+    /// target, before the listeners of its descendants.
+    pub fn capture(mut self) -> Self {
+        self.capture = true;
+        self
+    }
+
+    /// Sets whether the listener may call `preventDefault()`. Passive
+    /// listeners for `scroll`, `touchmove`, or `wheel` don't hold up
+    /// scrolling.
+    pub fn passive(mut self, passive: bool) -> Self {
+        self.passive = Some(passive);
+        self
+    }
+
+    /// Removes the listener after it runs once.
+    pub fn once(mut self) -> Self {
+        self.once = true;
+        self
+    }
+
+    /// Removes the listener when the signal is aborted.
+    pub fn signal(mut self, signal: web_sys::AbortSignal) -> Self {
+        self.signal = Some(signal);
+        self
+    }
+
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    fn to_js(&self) -> web_sys::AddEventListenerOptions {
+        let options = web_sys::AddEventListenerOptions::new();
+        options.set_capture(self.capture);
+        options.set_once(self.once);
+        if let Some(passive) = self.passive {
+            options.set_passive(passive);
+        }
+        if let Some(signal) = &self.signal {
+            options.set_signal(signal);
+        }
+        options
+    }
+}
+
+/// An event whose listener is added with [`EventOptions`]. This is what
+/// [`HtmlElement::on_with`](crate::HtmlElement::on_with) adds.
+///
+/// Listeners with options are always added to the element itself, like
+/// [`undelegated`] events, as the options of a delegated listener would apply
+/// to every listener for the same event.
+///
+/// ```rust
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// let scroll =
+///     ev::WithOptions::new(ev::scroll, ev::EventOptions::new().passive(true));
+/// let list = html::ul().on(scroll, |_| { /* ... */ });
+/// # runtime.dispose();
+/// ```
+#[derive(Clone, Debug)]
+pub struct WithOptions<Ev: EventDescriptor> {
+    event: Ev,
+    options: Option<web_sys::AddEventListenerOptions>,
+}
+
+impl<Ev: EventDescriptor> WithOptions<Ev> {
+    /// Adds the options to the listener for the event.
+    pub fn new(event: Ev, options: EventOptions) -> Self {
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        let options = Some(options.to_js());
+        #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+        let options = {
+            _ = options;
+            None
+        };
+        Self { event, options }
+    }
+}
+
+impl<Ev: EventDescriptor> EventDescriptor for WithOptions<Ev> {
+    type EventType = Ev::EventType;
+
+    #[inline(always)]
+    fn name(&self) -> Oco<'static, str> {
+        self.event.name()
+    }
+
+    #[inline(always)]
+    fn event_delegation_key(&self) -> Oco<'static, str> {
+        self.event.event_delegation_key()
+    }
+
+    const BUBBLES: bool = false;
+
+    #[inline(always)]
+    fn options(&self) -> &Option<web_sys::AddEventListenerOptions> {
+        &self.options
+    }
+}
+
+/// An event along with the element its listener was added to, already cast
+/// to the type of that element.
+///
+/// This is what the handler of
+/// [`HtmlElement::on_target`](crate::HtmlElement::on_target), or of an
+/// `on:{event}:target` attribute in the `view` macro, receives. It
+/// dereferences to the event itself.
+///
+/// ```rust
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// let (name, set_name) = create_signal(String::new());
+/// let input = view! {
+///     <input on:input:target=move |ev| set_name.set(ev.target().value())/>
+/// };
+/// # runtime.dispose();
+/// ```
+#[derive(Clone, Debug)]
+pub struct Targeted<E, T> {
+    event: E,
+    target: T,
+}
+
+impl<E, T> Targeted<E, T> {
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    pub(crate) fn new(event: E, target: T) -> Self {
+        Self { event, target }
+    }
+
+    /// The element the event listener was added to.
+    ///
+    /// Because most events are delegated, this is not always the same as
+    /// [`Event.target`](https://developer.mozilla.org/en-US/docs/Web/API/Event/target),
+    /// which may be one of its descendants.
+    pub fn target(&self) -> &T {
+        &self.target
+    }
+
+    /// The underlying event.
+    pub fn event(&self) -> &E {
+        &self.event
+    }
+
+    /// Returns the underlying event.
+    pub fn into_inner(self) -> E {
+        self.event
+    }
+}
+
+impl<E, T> std::ops::Deref for Targeted<E, T> {
+    type Target = E;
+
+    fn deref(&self) -> &Self::Target {
+        &self.event
+    }
+}
+
 /// Type that can respond to DOM events
 pub trait DOMEventResponder: Sized {
     /// Adds handler to specified event
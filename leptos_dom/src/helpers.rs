@@ -1,8 +1,13 @@
 //! A variety of DOM utility functions.
 
 use crate::{events::typed as ev, is_server, window};
-use leptos_reactive::on_cleanup;
-use std::time::Duration;
+use leptos_reactive::{
+    on_cleanup, Callable, Callback, SyncAsyncCallback, SyncCallback,
+};
+use std::{
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::Duration,
+};
 use wasm_bindgen::{prelude::Closure, JsCast, JsValue, UnwrapThrowExt};
 
 /// Sets a property on a DOM element.
@@ -78,16 +83,97 @@ where
         .value()
 }
 
+/// Helper function to extract `event.target.value` from an event and parse it
+/// into any type that implements [`FromStr`](std::str::FromStr).
+///
+/// This is useful in the `on:input` or `on:change` listeners for an
+/// `<input type="number">` element.
+/// ```
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// let (count, set_count) = create_signal(0);
+/// let input = view! {
+///     <input
+///         type="number"
+///         on:input=move |ev| {
+///             if let Ok(value) = event_target_value_as::<i32>(&ev) {
+///                 set_count.set(value);
+///             }
+///         }
+///     />
+/// };
+/// # runtime.dispose();
+/// ```
+pub fn event_target_value_as<T>(
+    event: &impl JsCast,
+) -> Result<T, <T as std::str::FromStr>::Err>
+where
+    T: std::str::FromStr,
+{
+    event_target_value(event).parse()
+}
+
 /// Helper function to extract `event.target.checked` from an event.
 ///
 /// This is useful in the `on:change` listeners for an `<input type="checkbox">` element.
-pub fn event_target_checked(ev: &web_sys::Event) -> bool {
-    ev.target()
-        .unwrap()
+pub fn event_target_checked<T>(event: &T) -> bool
+where
+    T: JsCast,
+{
+    event
+        .unchecked_ref::<web_sys::Event>()
+        .target()
+        .unwrap_throw()
         .unchecked_into::<web_sys::HtmlInputElement>()
         .checked()
 }
 
+/// Helper function to extract the files that were chosen in an
+/// `<input type="file">` element from an event.
+///
+/// This is useful in the `on:change` listeners for an `<input type="file">` element.
+pub fn event_target_files<T>(event: &T) -> Vec<web_sys::File>
+where
+    T: JsCast,
+{
+    event
+        .unchecked_ref::<web_sys::Event>()
+        .target()
+        .unwrap_throw()
+        .unchecked_into::<web_sys::HtmlInputElement>()
+        .files()
+        .map(|files| {
+            (0..files.length())
+                .filter_map(|idx| files.get(idx))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Helper function to extract the values of the selected options of a
+/// `<select>` element from an event.
+///
+/// This is useful in the `on:change` listeners for a `<select multiple>` element.
+pub fn event_target_selected_values<T>(event: &T) -> Vec<String>
+where
+    T: JsCast,
+{
+    let selected = event
+        .unchecked_ref::<web_sys::Event>()
+        .target()
+        .unwrap_throw()
+        .unchecked_into::<web_sys::HtmlSelectElement>()
+        .selected_options();
+    (0..selected.length())
+        .filter_map(|idx| selected.item(idx))
+        .map(|option| {
+            option
+                .unchecked_into::<web_sys::HtmlOptionElement>()
+                .value()
+        })
+        .collect()
+}
+
 /// Handle that is generated by [request_animation_frame_with_handle] and can
 /// be used to cancel the animation frame request.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
@@ -338,6 +424,223 @@ pub fn debounce<T: 'static>(
     }
 }
 
+/// Debounces and throttles callbacks, scheduling the calls with
+/// [`set_timeout_with_handle`].
+///
+/// A call that is still waiting when the reactive owner in which the callback
+/// was debounced or throttled is cleaned up is canceled. On the server, where
+/// there are no timers, the callbacks are called right away.
+///
+/// ```
+/// use leptos::*;
+/// use std::time::Duration;
+///
+/// #[component]
+/// fn Search(#[prop(into)] on_search: Callback<String>) -> impl IntoView {
+///     // only search once the user has stopped typing for 300ms
+///     let on_search = on_search.debounce(Duration::from_millis(300));
+///
+///     view! {
+///       <input on:input=move |ev| on_search.call(event_target_value(&ev))/>
+///     }
+/// }
+/// ```
+pub trait CallbackTiming {
+    /// The type of the debounced or throttled callback.
+    type Output;
+
+    /// Returns a callback that waits for `delay` after it is called before
+    /// calling this one. If it is called again in the meantime, the previous
+    /// call is dropped, and it waits for `delay` again.
+    fn debounce(&self, delay: Duration) -> Self::Output;
+
+    /// Returns a callback that calls this one right away, and then at most
+    /// once per `interval`: when it is called again during the interval, it
+    /// calls this one at the end of the interval with the latest input, and
+    /// drops the others.
+    fn throttle(&self, interval: Duration) -> Self::Output;
+}
+
+impl<In: 'static> CallbackTiming for Callback<In> {
+    type Output = Callback<In>;
+
+    fn debounce(&self, delay: Duration) -> Self::Output {
+        let callback = *self;
+        let timed = Timed::new(false, delay, move |input| callback.call(input));
+        Callback::new(move |input| timed.call(input))
+    }
+
+    fn throttle(&self, interval: Duration) -> Self::Output {
+        let callback = *self;
+        let timed =
+            Timed::new(true, interval, move |input| callback.call(input));
+        Callback::new(move |input| timed.call(input))
+    }
+}
+
+impl<In: 'static> CallbackTiming for SyncCallback<In> {
+    type Output = SyncCallback<In>;
+
+    fn debounce(&self, delay: Duration) -> Self::Output {
+        let callback = self.clone();
+        let timed = Timed::new(false, delay, move |input| callback.call(input));
+        SyncCallback::new(move |input| timed.call(input))
+    }
+
+    fn throttle(&self, interval: Duration) -> Self::Output {
+        let callback = self.clone();
+        let timed =
+            Timed::new(true, interval, move |input| callback.call(input));
+        SyncCallback::new(move |input| timed.call(input))
+    }
+}
+
+/// The futures of calls that are dropped resolve to `None`.
+impl<In: Send + 'static, Out: 'static> CallbackTiming
+    for SyncAsyncCallback<In, Out>
+{
+    type Output = SyncAsyncCallback<In, Option<Out>>;
+
+    fn debounce(&self, delay: Duration) -> Self::Output {
+        timed_async(false, delay, self.clone())
+    }
+
+    fn throttle(&self, interval: Duration) -> Self::Output {
+        timed_async(true, interval, self.clone())
+    }
+}
+
+fn timed_async<In: Send + 'static, Out: 'static>(
+    throttle: bool,
+    delay: Duration,
+    callback: SyncAsyncCallback<In, Out>,
+) -> SyncAsyncCallback<In, Option<Out>> {
+    use futures::channel::oneshot;
+
+    // a call that is dropped drops its sender, which resolves its future
+    let timed = Timed::new(
+        throttle,
+        delay,
+        |(input, tx): (In, oneshot::Sender<In>)| {
+            _ = tx.send(input);
+        },
+    );
+    SyncAsyncCallback::new(move |input| {
+        let (tx, rx) = oneshot::channel();
+        timed.call((input, tx));
+        let callback = callback.clone();
+        async move {
+            let input = rx.await.ok()?;
+            Some(callback.call(input).await)
+        }
+    })
+}
+
+/// Schedules the calls of a debounced or throttled callback.
+struct Timed<T, F> {
+    throttle: bool,
+    delay: Duration,
+    f: F,
+    state: Arc<Mutex<TimedState<T>>>,
+}
+
+struct TimedState<T> {
+    // while this is set, a throttled callback is in its interval
+    timer: Option<TimeoutHandle>,
+    pending: Option<T>,
+}
+
+impl<T, F: Clone> Clone for Timed<T, F> {
+    fn clone(&self) -> Self {
+        Self {
+            throttle: self.throttle,
+            delay: self.delay,
+            f: self.f.clone(),
+            state: Arc::clone(&self.state),
+        }
+    }
+}
+
+impl<T, F> Timed<T, F>
+where
+    T: 'static,
+    F: Fn(T) + Clone + 'static,
+{
+    fn new(throttle: bool, delay: Duration, f: F) -> Self {
+        let state = Arc::new(Mutex::new(TimedState {
+            timer: None,
+            pending: None,
+        }));
+        on_cleanup({
+            let state = Arc::clone(&state);
+            move || {
+                let mut state = lock(&state);
+                if let Some(timer) = state.timer.take() {
+                    timer.clear();
+                }
+                state.pending = None;
+            }
+        });
+        Self {
+            throttle,
+            delay,
+            f,
+            state,
+        }
+    }
+
+    fn call(&self, input: T) {
+        if is_server() {
+            (self.f)(input);
+            return;
+        }
+        let mut state = lock(&self.state);
+        if self.throttle {
+            if state.timer.is_some() {
+                state.pending = Some(input);
+            } else {
+                drop(state);
+                (self.f)(input);
+                self.start_timer();
+            }
+        } else {
+            if let Some(timer) = state.timer.take() {
+                timer.clear();
+            }
+            state.pending = Some(input);
+            drop(state);
+            self.start_timer();
+        }
+    }
+
+    fn start_timer(&self) {
+        let this = self.clone();
+        let timer = set_timeout_with_handle(
+            move || {
+                let pending = {
+                    let mut state = lock(&this.state);
+                    state.timer = None;
+                    state.pending.take()
+                };
+                if let Some(input) = pending {
+                    (this.f)(input);
+                    // a throttled callback waits another interval after each
+                    // call
+                    if this.throttle {
+                        this.start_timer();
+                    }
+                }
+            },
+            self.delay,
+        );
+        lock(&self.state).timer = timer.ok();
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
 /// Handle that is generated by [set_interval] and can be used to clear the interval.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct IntervalHandle(i32);
@@ -505,3 +808,187 @@ pub fn ssr_event_listener<E: crate::ev::EventDescriptor + 'static>(
     _ = event;
     _ = event_handler;
 }
+
+#[doc(hidden)]
+/// This exists only to enable type inference on `on:{event}:target` listeners
+/// when in SSR mode. The element is never created.
+pub fn ssr_targeted_event_listener<El, E>(
+    element: impl FnOnce() -> crate::HtmlElement<El>,
+    event: E,
+    event_handler: impl FnMut(ev::Targeted<E::EventType, El::Target>) + 'static,
+) where
+    El: crate::html::ElementDescriptor + std::ops::Deref,
+    El::Target: Clone + 'static,
+    E: ev::EventDescriptor + 'static,
+{
+    _ = element;
+    _ = event;
+    _ = event_handler;
+}
+
+#[doc(hidden)]
+/// Renders the `value` attribute for `bind:value:parse` in SSR mode, which
+/// also enables type inference on the `parse` and `format` functions.
+pub fn ssr_bind_value_parse<T, P, F>(
+    (value, parse, format): (leptos_reactive::RwSignal<Option<T>>, P, F),
+) -> String
+where
+    T: 'static,
+    P: Fn(&str) -> Option<T> + 'static,
+    F: Fn(&T) -> String + 'static,
+{
+    use leptos_reactive::SignalWith;
+
+    _ = parse;
+    value
+        .with(|value| value.as_ref().map(format))
+        .map(|value| format!(" value=\"{}\"", crate::ssr::escape_attr(&value)))
+        .unwrap_or_default()
+}
+
+#[doc(hidden)]
+/// Renders the `value` attribute for `bind:value` in SSR mode.
+pub fn ssr_bind_value<T: crate::html::BindValue>(
+    value: leptos_reactive::RwSignal<T>,
+) -> String {
+    use leptos_reactive::SignalWithUntracked;
+
+    value
+        .with_untracked(T::value_attribute)
+        .map(|value| format!(" value=\"{}\"", crate::ssr::escape_attr(&value)))
+        .unwrap_or_default()
+}
+
+thread_local! {
+    static BOUND_SELECTS: std::cell::RefCell<Vec<Box<dyn Fn(&str) -> bool>>> =
+        Default::default();
+}
+
+#[doc(hidden)]
+/// Starts a `<select>` with `bind:value` in SSR mode, so that the options
+/// rendered until [`ssr_end_select`] are selected if they match the signal.
+/// A `<select>` has no `value` attribute, so this renders nothing.
+pub fn ssr_bind_select<T: crate::html::BindValue>(
+    value: leptos_reactive::RwSignal<T>,
+) -> &'static str {
+    use leptos_reactive::SignalWithUntracked;
+
+    BOUND_SELECTS.with(|selects| {
+        selects.borrow_mut().push(Box::new(move |option| {
+            value.with_untracked(|value| value.is_selected(option))
+        }))
+    });
+    ""
+}
+
+#[doc(hidden)]
+/// Ends the `<select>` started by the last [`ssr_bind_select`].
+pub fn ssr_end_select() -> &'static str {
+    BOUND_SELECTS.with(|selects| selects.borrow_mut().pop());
+    ""
+}
+
+#[doc(hidden)]
+/// Renders the `selected` attribute in SSR mode for an `<option>` with the
+/// given value, if it is inside a `<select>` with a matching `bind:value`.
+pub fn ssr_option_selected(value: &str) -> &'static str {
+    let selected = BOUND_SELECTS.with(|selects| {
+        selects
+            .borrow()
+            .last()
+            .map(|is_selected| is_selected(value))
+            .unwrap_or(false)
+    });
+    if selected {
+        " selected"
+    } else {
+        ""
+    }
+}
+
+#[doc(hidden)]
+/// Renders the `value` attribute of an `<option>` in SSR mode, along with
+/// its `selected` attribute.
+pub fn ssr_option_value(value: impl crate::IntoAttribute) -> String {
+    value
+        .into_attribute()
+        .as_nameless_value_string()
+        .map(|value| {
+            format!(
+                "value=\"{}\"{}",
+                crate::ssr::escape_attr(&value),
+                ssr_option_selected(&value)
+            )
+        })
+        .unwrap_or_default()
+}
+
+#[doc(hidden)]
+/// Renders the `checked` attribute for `bind:checked` in SSR mode.
+pub fn ssr_bind_checked(checked: leptos_reactive::RwSignal<bool>) -> String {
+    use leptos_reactive::SignalGetUntracked;
+
+    if checked.get_untracked() {
+        " checked".to_string()
+    } else {
+        String::new()
+    }
+}
+
+#[doc(hidden)]
+/// Renders the `value` and `checked` attributes for `bind:group` in SSR
+/// mode, given the `value` attribute of the same element.
+pub fn ssr_bind_group<T: crate::html::BindGroup>(
+    group: leptos_reactive::RwSignal<T>,
+    value: impl crate::IntoAttribute,
+) -> String {
+    use leptos_reactive::SignalWithUntracked;
+
+    let value = value.into_attribute().as_nameless_value_string();
+    let checked = group.with_untracked(|group| {
+        group.is_checked(value.as_deref().unwrap_or(""))
+    });
+    let mut attrs = value
+        .map(|value| format!(" value=\"{}\"", crate::ssr::escape_attr(&value)))
+        .unwrap_or_default();
+    if checked {
+        attrs.push_str(" checked");
+    }
+    attrs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CallbackTiming;
+    use leptos_reactive::{
+        create_runtime, Callable, Callback, SyncAsyncCallback,
+    };
+    use std::{cell::RefCell, rc::Rc, time::Duration};
+
+    #[test]
+    fn timed_callbacks_are_called_right_away_on_the_server() {
+        let rt = create_runtime();
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let callback = Callback::new({
+            let calls = Rc::clone(&calls);
+            move |n: u32| calls.borrow_mut().push(n)
+        });
+        let debounced = callback.debounce(Duration::from_millis(100));
+        let throttled = callback.throttle(Duration::from_millis(100));
+        debounced.call(1);
+        debounced.call(2);
+        throttled.call(3);
+        throttled.call(4);
+        assert_eq!(*calls.borrow(), [1, 2, 3, 4]);
+
+        let double = SyncAsyncCallback::new(|n: u32| async move { n * 2 })
+            .debounce(Duration::from_millis(100));
+        let output = std::thread::spawn(move || {
+            futures::executor::block_on(double.call(21))
+        })
+        .join()
+        .unwrap();
+        assert_eq!(output, Some(42));
+        rt.dispose();
+    }
+}
@@ -1,5 +1,13 @@
 //! Exports types for working with HTML elements.
 
+mod aria;
+mod bind;
+
+pub use aria::{
+    AriaAutocomplete, AriaCurrent, AriaHasPopup, AriaInvalid, AriaLive,
+    AriaOrientation, AriaSort, AriaTristate, AriaValue,
+};
+pub use bind::{BindGroup, BindValue, BindValueMode};
 use cfg_if::cfg_if;
 
 cfg_if! {
@@ -63,14 +71,16 @@ cfg_if! {
 
 use crate::{
     create_node_ref,
-    ev::{EventDescriptor, EventHandlerFn},
+    ev::{
+        EventDescriptor, EventHandlerFn, EventOptions, Targeted, WithOptions,
+    },
     hydration::HydrationCtx,
     macro_helpers::{
-        Attribute, IntoAttribute, IntoClass, IntoProperty, IntoStyle,
+        Attribute, Class, IntoAttribute, IntoClass, IntoProperty, IntoStyle,
     },
     Directive, Element, Fragment, IntoView, NodeRef, Text, View,
 };
-use leptos_reactive::{create_effect, Oco};
+use leptos_reactive::{create_effect, Oco, RwSignal, SignalWith};
 use std::{fmt, rc::Rc};
 
 /// Trait which allows creating an element tag.
@@ -206,13 +216,7 @@ impl Custom {
             #[cfg(feature = "hydrate")]
             if let Some(el) = crate::hydration::get_element(&id.to_string()) {
                 #[cfg(debug_assertions)]
-                assert_eq!(
-                    el.node_name().to_ascii_uppercase(),
-                    name.to_ascii_uppercase(),
-                    "SSR and CSR elements have the same hydration key but \
-                     different node kinds. Check out the docs for information \
-                     about this kind of hydration bug: https://leptos-rs.github.io/leptos/ssr/24_hydration_bugs.html"
-                );
+                crate::hydration::check_element(&id, &name, &el);
 
                 //el.remove_attribute(wasm_bindgen::intern("id")).unwrap();
 
@@ -221,7 +225,8 @@ impl Custom {
                 if !is_meta_tag() {
                     crate::warn!(
                         "element with id {id} not found, ignoring it for \
-                         hydration",
+                         hydration{}",
+                        leptos_reactive::ComponentStack::current()
                     );
                 }
 
@@ -243,13 +248,16 @@ impl Custom {
     }
 }
 
-#[cfg(all(target_arch = "wasm32", feature = "web"))]
 impl std::ops::Deref for Custom {
     type Target = web_sys::HtmlElement;
 
     #[inline(always)]
     fn deref(&self) -> &Self::Target {
-        &self.element
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        return &self.element;
+
+        #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+        unimplemented!("{HTML_ELEMENT_DEREF_UNIMPLEMENTED_MSG}");
     }
 }
 
@@ -366,7 +374,7 @@ where
     }
 }
 
-/// Bind data through attributes, or behavior through event handlers, to an element.
+/// Bind data through attributes and classes, or behavior through event handlers, to an element.
 /// A value of any type able to provide an iterator of bindings (like a: `Vec<Binding>`),
 /// can be spread onto an element using the spread syntax `view! { <div {..bindings} /> }`.
 pub enum Binding {
@@ -377,16 +385,65 @@ pub enum Binding {
         /// Value of the attribute, possibly reactive.
         value: Attribute,
     },
+    /// A statically named class.
+    ///
+    /// On the server, the classes that are spread onto an element with the
+    /// `view` macro are rendered in a `class` attribute of their own, so they
+    /// should not be combined with `class` attributes on the same element.
+    Class {
+        /// Name of the class.
+        name: &'static str,
+        /// Whether the element has the class, possibly reactive.
+        value: Class,
+    },
     /// A statically typed event handler.
     EventHandler(EventHandlerFn),
 }
 
+impl Binding {
+    /// Creates a binding that sets the attribute `name` to `value`.
+    ///
+    /// ```
+    /// # use leptos_dom::{html::Binding, *};
+    /// # use leptos_reactive::*;
+    /// # let runtime = create_runtime();
+    /// let (active, set_active) = create_signal(false);
+    /// let bindings = vec![
+    ///     Binding::attr("aria-pressed", move || active.get().to_string()),
+    ///     Binding::class("active", active),
+    ///     EventHandlerFn::Click(Box::new(move |_| set_active.set(true))).into(),
+    /// ];
+    /// # runtime.dispose();
+    /// ```
+    pub fn attr(name: &'static str, value: impl IntoAttribute) -> Self {
+        Self::Attribute {
+            name,
+            value: value.into_attribute(),
+        }
+    }
+
+    /// Creates a binding that adds the class `name` to the element whenever
+    /// `value` is `true`.
+    pub fn class(name: &'static str, value: impl IntoClass) -> Self {
+        Self::Class {
+            name,
+            value: value.into_class(),
+        }
+    }
+}
+
 impl From<(&'static str, Attribute)> for Binding {
     fn from((name, value): (&'static str, Attribute)) -> Self {
         Self::Attribute { name, value }
     }
 }
 
+impl From<(&'static str, Class)> for Binding {
+    fn from((name, value): (&'static str, Class)) -> Self {
+        Self::Class { name, value }
+    }
+}
+
 impl From<EventHandlerFn> for Binding {
     fn from(handler: EventHandlerFn) -> Self {
         Self::EventHandler(handler)
@@ -658,6 +715,27 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
                 attr = f();
             }
             match attr {
+                // merged with the classes added by .class()
+                Attribute::String(value) if name == "class" => {
+                    if let Some((_, ref mut classes)) =
+                        this.attrs.iter_mut().find(|(name, _)| name == "class")
+                    {
+                        *classes = format!("{value} {classes}").into();
+                    } else {
+                        this.attrs.push((name, value));
+                    }
+                }
+                // merged with the styles added by .style()
+                Attribute::String(value) if name == "style" => {
+                    if let Some((_, ref mut styles)) =
+                        this.attrs.iter_mut().find(|(name, _)| name == "style")
+                    {
+                        let value = value.trim_end().trim_end_matches(';');
+                        *styles = format!("{value}; {styles}").into();
+                    } else {
+                        this.attrs.push((name, value));
+                    }
+                }
                 Attribute::String(value) => {
                     this.attrs.push((name, value));
                 }
@@ -690,7 +768,8 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
         self
     }
 
-    /// Adds multiple bindings (attributes or event handlers) to the element.
+    /// Adds multiple bindings (attributes, classes or event handlers) to the
+    /// element.
     #[track_caller]
     pub fn bindings<B: Into<Binding>>(
         mut self,
@@ -702,11 +781,13 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
         self
     }
 
-    /// Add a single binding (attribute or event handler) to the element.
+    /// Add a single binding (attribute, class or event handler) to the
+    /// element.
     #[track_caller]
     fn binding(self, binding: Binding) -> Self {
         match binding {
             Binding::Attribute { name, value } => self.attr(name, value),
+            Binding::Class { name, value } => self.class(name, value),
             Binding::EventHandler(handler) => match handler {
                 EventHandlerFn::Keydown(handler) => {
                     self.on(crate::events::typed::keydown, handler)
@@ -819,9 +900,9 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
 
     /// Adds a class to an element.
     ///
-    /// **Note**: In the builder syntax, this will be overwritten by the `class`
-    /// attribute if you use `.attr("class", /* */)`. In the `view` macro, they
-    /// are automatically re-ordered so that this over-writing does not happen.
+    /// Classes added this way are kept when the `class` attribute is set with
+    /// `.attr("class", /* */)`, as only the class names in its value are added
+    /// to or removed from the element’s `classList`.
     ///
     /// # Panics
     /// This directly uses the browser’s `classList` API, which means it will throw
@@ -1185,6 +1266,84 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
         }
     }
 
+    /// Adds an event listener to this element with the given options, which
+    /// is what the `:capture`, `:passive`, and `:once` modifiers of an
+    /// `on:{event}` attribute in the `view` macro expand to.
+    ///
+    /// The listener is not delegated, even if the event bubbles.
+    ///
+    /// ```rust
+    /// # use leptos::*;
+    /// # let runtime = create_runtime();
+    /// let (offset, set_offset) = create_signal(0);
+    /// let feed = html::div().on_with(
+    ///     ev::scroll,
+    ///     ev::EventOptions::new().passive(true),
+    ///     move |ev| set_offset.set(event_target::<web_sys::Element>(&ev).scroll_top()),
+    /// );
+    /// # runtime.dispose();
+    /// ```
+    #[track_caller]
+    #[inline(always)]
+    pub fn on_with<E: EventDescriptor + 'static>(
+        self,
+        event: E,
+        options: EventOptions,
+        event_handler: impl FnMut(E::EventType) + 'static,
+    ) -> Self {
+        self.on(WithOptions::new(event, options), event_handler)
+    }
+
+    /// Adds an event listener to this element, whose handler also receives
+    /// this element, already cast to its [`web_sys`] type. This is what the
+    /// `on:{event}:target` syntax in the `view` macro expands to.
+    ///
+    /// ```rust
+    /// # use leptos::*;
+    /// #[component]
+    /// fn Volume() -> impl IntoView {
+    ///     let (volume, set_volume) = create_signal(50);
+    ///     view! {
+    ///         <input
+    ///             type="range"
+    ///             on:input:target=move |ev| {
+    ///                 set_volume.set(ev.target().value_as_number() as i32)
+    ///             }
+    ///         />
+    ///         <p>{volume}</p>
+    ///     }
+    /// }
+    /// ```
+    #[track_caller]
+    #[inline(always)]
+    pub fn on_target<E: EventDescriptor + 'static>(
+        self,
+        event: E,
+        #[allow(unused_mut)] // used on the web only
+        mut event_handler: impl FnMut(Targeted<E::EventType, El::Target>)
+            + 'static,
+    ) -> Self
+    where
+        El: std::ops::Deref,
+        El::Target: Clone + 'static,
+    {
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        {
+            let target = std::ops::Deref::deref(&self.element).clone();
+            self.on(event, move |ev| {
+                event_handler(Targeted::new(ev, target.clone()))
+            })
+        }
+
+        #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+        {
+            _ = event;
+            _ = event_handler;
+
+            self
+        }
+    }
+
     /// Optionally adds an event listener to this element.
     ///
     /// ## Example
@@ -1218,6 +1377,126 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
         }
     }
 
+    /// Binds the `value` of an `<input>` to a typed signal. This is what the
+    /// `bind:value:parse=(value, parse, format)` syntax in the `view` macro
+    /// expands to.
+    ///
+    /// Whenever the user types, the text is passed to `parse`, and the signal
+    /// is set to the result. If that changes the value, the text is replaced
+    /// with `format(&value)`, keeping the caret after the same number of
+    /// letters and digits, so that separators added by `format` don't move
+    /// it. Text that parses to the current value, like a trailing decimal
+    /// point, is left as the user typed it until the input loses focus. Text
+    /// that is being composed with an input method editor is only parsed
+    /// once the composition has ended.
+    ///
+    /// ```rust
+    /// # use leptos::*;
+    /// #[component]
+    /// fn Phone() -> impl IntoView {
+    ///     let number = create_rw_signal(None::<u64>);
+    ///     let parse = |text: &str| {
+    ///         let digits = text
+    ///             .chars()
+    ///             .filter(char::is_ascii_digit)
+    ///             .collect::<String>();
+    ///         digits.parse().ok()
+    ///     };
+    ///     let format = |number: &u64| {
+    ///         let digits = number.to_string();
+    ///         match digits.len() {
+    ///             0..=3 => digits,
+    ///             4..=6 => format!("({}) {}", &digits[..3], &digits[3..]),
+    ///             _ => format!(
+    ///                 "({}) {}-{}",
+    ///                 &digits[..3],
+    ///                 &digits[3..6],
+    ///                 &digits[6..]
+    ///             ),
+    ///         }
+    ///     };
+    ///     view! {
+    ///         <input type="tel" bind:value:parse=(number, parse, format)/>
+    ///     }
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn bind_value_parse<T, P, F>(
+        self,
+        (value, parse, format): (RwSignal<Option<T>>, P, F),
+    ) -> Self
+    where
+        T: PartialEq + 'static,
+        P: Fn(&str) -> Option<T> + 'static,
+        F: Fn(&T) -> String + 'static,
+    {
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        {
+            use crate::ev::{change, undelegated};
+            use leptos_reactive::{SignalSet, SignalWithUntracked};
+
+            let input = self
+                .element
+                .as_ref()
+                .unchecked_ref::<web_sys::HtmlInputElement>()
+                .clone();
+            let format = Rc::new(format);
+            // set while the signal is updated from the input, so that the
+            // text the user typed is not replaced
+            let from_input = Rc::new(Cell::new(false));
+
+            leptos_reactive::create_render_effect({
+                let input = input.clone();
+                let format = Rc::clone(&format);
+                let from_input = Rc::clone(&from_input);
+                move |_| {
+                    let text = value.with(|value| value.as_ref().map(&*format));
+                    if !from_input.get() {
+                        input.set_value(&text.unwrap_or_default());
+                    }
+                }
+            });
+
+            self.on_composed_input({
+                let input = input.clone();
+                let format = Rc::clone(&format);
+                move || {
+                    let typed = input.value();
+                    let parsed = parse(&typed);
+                    if value.with_untracked(|value| *value == parsed) {
+                        return;
+                    }
+                    let formatted = parsed.as_ref().map(&*format);
+                    from_input.set(true);
+                    value.set(parsed);
+                    from_input.set(false);
+                    if let Some(formatted) = formatted {
+                        if formatted != typed {
+                            set_value_keeping_caret(&input, &typed, &formatted);
+                        }
+                    }
+                }
+            })
+            .on(undelegated(change), move |_| {
+                let text =
+                    value.with_untracked(|value| value.as_ref().map(&*format));
+                if let Some(text) = text {
+                    if text != input.value() {
+                        input.set_value(&text);
+                    }
+                }
+            })
+        }
+
+        #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+        {
+            _ = parse;
+            self.attr("value", move || {
+                value.with(|value| value.as_ref().map(&format))
+            })
+        }
+    }
+
     /// Adds a child to this element.
     #[track_caller]
     pub fn child(self, child: impl IntoView) -> Self {
@@ -1330,6 +1609,47 @@ impl<El: ElementDescriptor + Clone + 'static> HtmlElement<El> {
     }
 }
 
+// replaces the text of an input, and moves the caret to after the same number
+// of letters and digits as before
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+fn set_value_keeping_caret(
+    input: &web_sys::HtmlInputElement,
+    typed: &str,
+    formatted: &str,
+) {
+    // `selectionStart` counts UTF-16 code units, and is not available for
+    // every type of input
+    let caret = input.selection_start().ok().flatten();
+    input.set_value(formatted);
+
+    let is_focused = crate::document().active_element().as_ref()
+        == Some(input.unchecked_ref::<web_sys::Element>());
+    let Some(caret) = caret.filter(|_| is_focused) else {
+        return;
+    };
+    let mut offset = 0;
+    let kept = typed
+        .chars()
+        .take_while(|c| {
+            offset += c.len_utf16() as u32;
+            offset <= caret
+        })
+        .filter(|c| c.is_alphanumeric())
+        .count();
+    let mut seen = 0;
+    let mut position = 0;
+    for c in formatted.chars() {
+        if seen == kept {
+            break;
+        }
+        if c.is_alphanumeric() {
+            seen += 1;
+        }
+        position += c.len_utf16() as u32;
+    }
+    _ = input.set_selection_range(position, position);
+}
+
 impl<El: ElementDescriptor> IntoView for HtmlElement<El> {
     #[cfg_attr(any(debug_assertions, feature = "ssr"), instrument(level = "trace", name = "<HtmlElement />", skip_all, fields(tag = %self.element.name())))]
     #[cfg_attr(all(target_arch = "wasm32", feature = "web"), inline(always))]
@@ -1543,19 +1863,15 @@ fn create_leptos_element(
         let id = id.unwrap();
         if let Some(el) = crate::hydration::get_element(&id.to_string()) {
             #[cfg(debug_assertions)]
-            assert_eq!(
-                &el.node_name().to_ascii_uppercase(),
-                tag,
-                "SSR and CSR elements have the same hydration key but \
-                different node kinds. Check out the docs for information \
-                about this kind of hydration bug: https://leptos-rs.github.io/leptos/ssr/24_hydration_bugs.html"
-            );
+            crate::hydration::check_element(&id, tag, &el);
 
             el.unchecked_into()
         } else {
             if !is_meta_tag() {
                 crate::warn!(
-                    "element with id {id} not found, ignoring it for hydration"
+                    "element with id {id} not found, ignoring it for \
+                     hydration{}",
+                    leptos_reactive::ComponentStack::current()
                 );
             }
 
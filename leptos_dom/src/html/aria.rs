@@ -0,0 +1,381 @@
+//! Typed builders for `data-*` and [ARIA](https://www.w3.org/TR/wai-aria-1.2/#state_prop_def)
+//! attributes.
+//!
+//! ARIA attributes are set to `"true"` or `"false"` rather than being present
+//! or absent like boolean HTML attributes, and many of them only accept a few
+//! values. The builder methods here take values of the right type, so that
+//! neither can go wrong.
+
+use super::{ElementDescriptor, HtmlElement};
+use crate::macro_helpers::{Attribute, IntoAttribute};
+use leptos_reactive::{MaybeSignal, Oco, SignalGet};
+use std::rc::Rc;
+
+/// A value that an ARIA attribute can be set to with the builder methods of
+/// [`HtmlElement`], like [`HtmlElement::aria_expanded`].
+pub trait AriaValue: Clone + 'static {
+    /// The value of the attribute, or `None` to remove it.
+    fn aria_value(&self) -> Option<Oco<'static, str>>;
+}
+
+impl AriaValue for bool {
+    fn aria_value(&self) -> Option<Oco<'static, str>> {
+        Some(Oco::Borrowed(if *self { "true" } else { "false" }))
+    }
+}
+
+impl AriaValue for String {
+    fn aria_value(&self) -> Option<Oco<'static, str>> {
+        Some(Oco::Owned(self.clone()))
+    }
+}
+
+impl AriaValue for Oco<'static, str> {
+    fn aria_value(&self) -> Option<Oco<'static, str>> {
+        Some(self.clone())
+    }
+}
+
+impl AriaValue for &'static str {
+    fn aria_value(&self) -> Option<Oco<'static, str>> {
+        Some(Oco::Borrowed(self))
+    }
+}
+
+impl AriaValue for usize {
+    fn aria_value(&self) -> Option<Oco<'static, str>> {
+        Some(Oco::Owned(self.to_string()))
+    }
+}
+
+impl AriaValue for i32 {
+    fn aria_value(&self) -> Option<Oco<'static, str>> {
+        Some(Oco::Owned(self.to_string()))
+    }
+}
+
+impl AriaValue for f64 {
+    fn aria_value(&self) -> Option<Oco<'static, str>> {
+        Some(Oco::Owned(self.to_string()))
+    }
+}
+
+impl<T: AriaValue> AriaValue for Option<T> {
+    fn aria_value(&self) -> Option<Oco<'static, str>> {
+        self.as_ref().and_then(AriaValue::aria_value)
+    }
+}
+
+macro_rules! aria_enum {
+    (
+        $(#[$meta:meta])*
+        $name:ident { $($(#[$variant_meta:meta])* $variant:ident => $value:literal),* $(,)? }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )*
+        }
+
+        impl $name {
+            /// The value of the attribute.
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $value,)*
+                }
+            }
+        }
+
+        impl AriaValue for $name {
+            fn aria_value(&self) -> Option<Oco<'static, str>> {
+                Some(Oco::Borrowed(self.as_str()))
+            }
+        }
+
+        impl IntoAttribute for $name {
+            #[inline(always)]
+            fn into_attribute(self) -> Attribute {
+                Attribute::String(Oco::Borrowed(self.as_str()))
+            }
+
+            #[inline(always)]
+            fn into_attribute_boxed(self: Box<Self>) -> Attribute {
+                self.into_attribute()
+            }
+        }
+    };
+}
+
+aria_enum! {
+    /// The value of a tristate attribute, like `aria-checked` or
+    /// `aria-pressed`.
+    AriaTristate {
+        /// Not checked or pressed.
+        False => "false",
+        /// Checked or pressed.
+        True => "true",
+        /// Partly checked or pressed.
+        Mixed => "mixed",
+    }
+}
+
+aria_enum! {
+    /// The value of `aria-current`.
+    AriaCurrent {
+        /// Not the current item.
+        False => "false",
+        /// The current item in a set.
+        True => "true",
+        /// The current page.
+        Page => "page",
+        /// The current step in a process.
+        Step => "step",
+        /// The current location in a flow chart or map.
+        Location => "location",
+        /// The current date in a calendar.
+        Date => "date",
+        /// The current time in a timetable.
+        Time => "time",
+    }
+}
+
+aria_enum! {
+    /// The value of `aria-live`.
+    AriaLive {
+        /// Updates to the region are not announced.
+        Off => "off",
+        /// Updates are announced when the user is idle.
+        Polite => "polite",
+        /// Updates are announced immediately.
+        Assertive => "assertive",
+    }
+}
+
+aria_enum! {
+    /// The value of `aria-haspopup`.
+    AriaHasPopup {
+        /// The element has no popup.
+        False => "false",
+        /// The element has a menu, like `Menu`.
+        True => "true",
+        /// The element has a menu.
+        Menu => "menu",
+        /// The element has a listbox.
+        Listbox => "listbox",
+        /// The element has a tree.
+        Tree => "tree",
+        /// The element has a grid.
+        Grid => "grid",
+        /// The element has a dialog.
+        Dialog => "dialog",
+    }
+}
+
+aria_enum! {
+    /// The value of `aria-invalid`.
+    AriaInvalid {
+        /// The value is valid.
+        False => "false",
+        /// The value is invalid.
+        True => "true",
+        /// The value has a grammatical error.
+        Grammar => "grammar",
+        /// The value has a spelling error.
+        Spelling => "spelling",
+    }
+}
+
+aria_enum! {
+    /// The value of `aria-autocomplete`.
+    AriaAutocomplete {
+        /// No suggestions are made.
+        None => "none",
+        /// The suggestion is completed inline.
+        Inline => "inline",
+        /// Suggestions are shown in a list.
+        List => "list",
+        /// Suggestions are shown in a list and completed inline.
+        Both => "both",
+    }
+}
+
+aria_enum! {
+    /// The value of `aria-orientation`.
+    AriaOrientation {
+        /// The element is horizontal.
+        Horizontal => "horizontal",
+        /// The element is vertical.
+        Vertical => "vertical",
+    }
+}
+
+aria_enum! {
+    /// The value of `aria-sort`.
+    AriaSort {
+        /// The column is not sorted.
+        None => "none",
+        /// The column is sorted in ascending order.
+        Ascending => "ascending",
+        /// The column is sorted in descending order.
+        Descending => "descending",
+        /// The column is sorted in another order.
+        Other => "other",
+    }
+}
+
+macro_rules! aria_methods {
+    ($($(#[$meta:meta])* $method:ident($attr:literal): $ty:ty;)*) => {
+        impl<El: ElementDescriptor + 'static> HtmlElement<El> {
+            $(
+                $(#[$meta])*
+                #[doc = ""]
+                #[doc = concat!("Sets `", $attr, "`, reactively if `value` is a signal.")]
+                #[track_caller]
+                pub fn $method(self, value: impl Into<MaybeSignal<$ty>>) -> Self {
+                    self.aria::<$ty>($attr, value)
+                }
+            )*
+        }
+    };
+}
+
+aria_methods! {
+    /// Identifies the element that has focus inside a composite widget.
+    aria_activedescendant("aria-activedescendant"): String;
+    /// Whether assistive technologies present all of a live region when it changes.
+    aria_atomic("aria-atomic"): bool;
+    /// Whether input suggestions are shown, and how.
+    aria_autocomplete("aria-autocomplete"): AriaAutocomplete;
+    /// Whether the element is being updated.
+    aria_busy("aria-busy"): bool;
+    /// The checked state of a checkbox, radio button or similar widget.
+    aria_checked("aria-checked"): AriaTristate;
+    /// The number of columns in a table or grid.
+    aria_colcount("aria-colcount"): i32;
+    /// The column index of a cell.
+    aria_colindex("aria-colindex"): usize;
+    /// The number of columns a cell spans.
+    aria_colspan("aria-colspan"): usize;
+    /// The ids of the elements whose contents or presence are controlled by this one.
+    aria_controls("aria-controls"): String;
+    /// Whether this is the current item in a set.
+    aria_current("aria-current"): AriaCurrent;
+    /// The ids of the elements that describe this one.
+    aria_describedby("aria-describedby"): String;
+    /// The id of the element that provides details about this one.
+    aria_details("aria-details"): String;
+    /// Whether the element is disabled.
+    aria_disabled("aria-disabled"): bool;
+    /// The id of the element that provides an error message for this one.
+    aria_errormessage("aria-errormessage"): String;
+    /// Whether the element, or the element it controls, is expanded.
+    aria_expanded("aria-expanded"): bool;
+    /// The ids of the elements that are read next, in an alternate reading order.
+    aria_flowto("aria-flowto"): String;
+    /// The kind of popup the element can open.
+    aria_haspopup("aria-haspopup"): AriaHasPopup;
+    /// Whether the element is hidden from assistive technologies.
+    aria_hidden("aria-hidden"): bool;
+    /// Whether the value of the element is invalid.
+    aria_invalid("aria-invalid"): AriaInvalid;
+    /// The keyboard shortcuts that activate or focus the element.
+    aria_keyshortcuts("aria-keyshortcuts"): String;
+    /// The label of the element.
+    aria_label("aria-label"): String;
+    /// The ids of the elements that label this one.
+    aria_labelledby("aria-labelledby"): String;
+    /// The hierarchical level of the element.
+    aria_level("aria-level"): usize;
+    /// How updates to a live region are announced.
+    aria_live("aria-live"): AriaLive;
+    /// Whether the element is modal when it is shown.
+    aria_modal("aria-modal"): bool;
+    /// Whether a text box accepts more than one line.
+    aria_multiline("aria-multiline"): bool;
+    /// Whether more than one item can be selected.
+    aria_multiselectable("aria-multiselectable"): bool;
+    /// Whether the element is horizontal or vertical.
+    aria_orientation("aria-orientation"): AriaOrientation;
+    /// The ids of the elements that are children of this one, but not in the DOM tree.
+    aria_owns("aria-owns"): String;
+    /// A hint shown while the element has no value.
+    aria_placeholder("aria-placeholder"): String;
+    /// The position of the element in a set of items.
+    aria_posinset("aria-posinset"): usize;
+    /// The pressed state of a toggle button.
+    aria_pressed("aria-pressed"): AriaTristate;
+    /// Whether the element is read-only.
+    aria_readonly("aria-readonly"): bool;
+    /// The kinds of changes to a live region that are announced.
+    aria_relevant("aria-relevant"): String;
+    /// Whether the element must have a value before its form is submitted.
+    aria_required("aria-required"): bool;
+    /// A description of the role of the element.
+    aria_roledescription("aria-roledescription"): String;
+    /// The number of rows in a table or grid.
+    aria_rowcount("aria-rowcount"): i32;
+    /// The row index of a cell or row.
+    aria_rowindex("aria-rowindex"): usize;
+    /// The number of rows a cell spans.
+    aria_rowspan("aria-rowspan"): usize;
+    /// Whether the element is selected.
+    aria_selected("aria-selected"): bool;
+    /// The number of items in the set the element belongs to.
+    aria_setsize("aria-setsize"): i32;
+    /// The order the column or row is sorted in.
+    aria_sort("aria-sort"): AriaSort;
+    /// The maximum value of a range widget.
+    aria_valuemax("aria-valuemax"): f64;
+    /// The minimum value of a range widget.
+    aria_valuemin("aria-valuemin"): f64;
+    /// The current value of a range widget.
+    aria_valuenow("aria-valuenow"): f64;
+    /// The current value of a range widget, as text.
+    aria_valuetext("aria-valuetext"): String;
+}
+
+impl<El: ElementDescriptor + 'static> HtmlElement<El> {
+    /// Sets the `data-*` attribute with the given name, which is read in
+    /// JavaScript from `element.dataset`.
+    ///
+    /// ```
+    /// # use leptos_dom::*;
+    /// # use leptos_reactive::*;
+    /// # let runtime = create_runtime();
+    /// let (user_id, _) = create_signal(7);
+    /// let html = html::div()
+    ///     .data("user-id", user_id)
+    ///     .aria_expanded(false)
+    ///     .aria_current(html::AriaCurrent::Page)
+    ///     .into_view()
+    ///     .render_to_string();
+    /// assert!(html.contains(r#"data-user-id="7""#));
+    /// assert!(html.contains(r#"aria-expanded="false""#));
+    /// assert!(html.contains(r#"aria-current="page""#));
+    /// # runtime.dispose();
+    /// ```
+    #[track_caller]
+    pub fn data(self, name: &str, value: impl IntoAttribute) -> Self {
+        self.attr(format!("data-{name}"), value)
+    }
+
+    /// Sets the ARIA attribute `name` to `value`, reactively if it is a
+    /// signal.
+    #[track_caller]
+    pub fn aria<T: AriaValue>(
+        self,
+        name: &'static str,
+        value: impl Into<MaybeSignal<T>>,
+    ) -> Self {
+        let value = match value.into() {
+            MaybeSignal::Static(value) => Attribute::Option(value.aria_value()),
+            MaybeSignal::Dynamic(signal) => Attribute::Fn(Rc::new(move || {
+                Attribute::Option(signal.get().aria_value())
+            })),
+        };
+        self.attr(name, value)
+    }
+}
@@ -0,0 +1,510 @@
+//! Two-way bindings between the state of form controls and signals, which
+//! the `bind:` attributes in the `view` macro expand to.
+//!
+//! The bindings listen to their events without delegation, so that they
+//! don't replace an `on:input` or `on:change` handler on the same element.
+
+#[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+use super::ElementChildren;
+use super::{ElementDescriptor, HtmlElement};
+use crate::macro_helpers::IntoAttribute;
+use leptos_reactive::RwSignal;
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+use std::{cell::Cell, rc::Rc};
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+use wasm_bindgen::JsCast;
+
+/// A value that the `value` of a form control can be bound to with
+/// `bind:value`.
+///
+/// A [`String`] is bound to the text of an `<input>` or a `<textarea>`, or to
+/// the selected option of a `<select>`. A [`Vec<String>`] is bound to the
+/// options that are selected in a `<select multiple>`.
+pub trait BindValue: PartialEq + 'static {
+    #[doc(hidden)]
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    fn read(el: &web_sys::Element) -> Self
+    where
+        Self: Sized;
+
+    #[doc(hidden)]
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    fn write(&self, el: &web_sys::Element);
+
+    /// The `value` attribute that is rendered on the server, if any.
+    #[doc(hidden)]
+    fn value_attribute(&self) -> Option<String>;
+
+    /// Returns `true` if the `<option>` with the given value is rendered as
+    /// selected on the server.
+    #[doc(hidden)]
+    fn is_selected(&self, option: &str) -> bool;
+}
+
+impl BindValue for String {
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    fn read(el: &web_sys::Element) -> Self {
+        js_sys::Reflect::get(el, &wasm_bindgen::intern("value").into())
+            .ok()
+            .and_then(|value| value.as_string())
+            .unwrap_or_default()
+    }
+
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    fn write(&self, el: &web_sys::Element) {
+        // setting the same text again would move the caret to the end
+        if Self::read(el) != *self {
+            _ = js_sys::Reflect::set(
+                el,
+                &wasm_bindgen::intern("value").into(),
+                &self.as_str().into(),
+            );
+        }
+    }
+
+    fn value_attribute(&self) -> Option<String> {
+        Some(self.clone())
+    }
+
+    fn is_selected(&self, option: &str) -> bool {
+        self == option
+    }
+}
+
+impl BindValue for Vec<String> {
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    fn read(el: &web_sys::Element) -> Self {
+        let options = el.unchecked_ref::<web_sys::HtmlSelectElement>();
+        let selected = options.selected_options();
+        (0..selected.length())
+            .filter_map(|idx| selected.item(idx))
+            .map(|option| {
+                option
+                    .unchecked_into::<web_sys::HtmlOptionElement>()
+                    .value()
+            })
+            .collect()
+    }
+
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    fn write(&self, el: &web_sys::Element) {
+        let select = el.unchecked_ref::<web_sys::HtmlSelectElement>();
+        for option in (0..select.length()).filter_map(|idx| select.item(idx)) {
+            let option = option.unchecked_into::<web_sys::HtmlOptionElement>();
+            option.set_selected(self.contains(&option.value()));
+        }
+    }
+
+    fn value_attribute(&self) -> Option<String> {
+        None
+    }
+
+    fn is_selected(&self, option: &str) -> bool {
+        self.iter().any(|selected| selected == option)
+    }
+}
+
+/// A value that a group of radio buttons or checkboxes can be bound to with
+/// `bind:group`, given the `value` of each of them.
+///
+/// A [`String`] holds the value of the radio button that is checked, and an
+/// [`Option<String>`] does the same but can also start with none checked. A
+/// [`Vec<String>`] holds the values of all the checkboxes that are checked.
+pub trait BindGroup: 'static {
+    /// Returns `true` if the input with the given value is checked.
+    fn is_checked(&self, value: &str) -> bool;
+
+    /// Updates the group after the input with the given value has been
+    /// checked or unchecked.
+    fn set_checked(&mut self, value: &str, checked: bool);
+}
+
+impl BindGroup for String {
+    fn is_checked(&self, value: &str) -> bool {
+        self == value
+    }
+
+    fn set_checked(&mut self, value: &str, checked: bool) {
+        if checked {
+            value.clone_into(self);
+        }
+    }
+}
+
+impl BindGroup for Option<String> {
+    fn is_checked(&self, value: &str) -> bool {
+        self.as_deref() == Some(value)
+    }
+
+    fn set_checked(&mut self, value: &str, checked: bool) {
+        if checked {
+            *self = Some(value.to_string());
+        } else if self.is_checked(value) {
+            *self = None;
+        }
+    }
+}
+
+impl BindGroup for Vec<String> {
+    fn is_checked(&self, value: &str) -> bool {
+        self.iter().any(|checked| checked == value)
+    }
+
+    fn set_checked(&mut self, value: &str, checked: bool) {
+        if !checked {
+            self.retain(|checked| checked != value);
+        } else if !self.is_checked(value) {
+            self.push(value.to_string());
+        }
+    }
+}
+
+/// When a `bind:value` binding updates its signal from the control.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BindValueMode {
+    /// On every `input` event, except while the user is composing text with
+    /// an input method editor (IME), e.g., for Chinese, Japanese, or Korean,
+    /// when the signal is only updated once the composition has ended. This
+    /// is what `bind:value` does.
+    #[default]
+    Composed,
+    /// On every `input` event, including the intermediate text of a
+    /// composition. This is what `bind:value:eager` does.
+    Eager,
+    /// Only on `change` events, i.e., once the control loses focus or the
+    /// user presses Enter. This is what `bind:value:lazy` does.
+    Lazy,
+}
+
+impl<El: ElementDescriptor + 'static> HtmlElement<El> {
+    /// Binds the `value` of an `<input>`, a `<textarea>`, or a `<select>` to
+    /// a signal, so that it is updated as the user types or selects, and the
+    /// control is updated when the signal changes. This is what the
+    /// `bind:value` syntax in the `view` macro expands to.
+    ///
+    /// While the user is composing text with an input method editor, the
+    /// signal is only updated once the composition has ended; see
+    /// [`bind_value_with`](HtmlElement::bind_value_with) for the other
+    /// [modes](BindValueMode). A `<select multiple>` can be bound to a
+    /// [`Vec<String>`] of the values of its selected options.
+    ///
+    /// On the server, a `<select>` is rendered with its matching options
+    /// marked as `selected`. With the builder syntax, this only marks the
+    /// options that have already been added, so bind the `<select>` after
+    /// adding its children.
+    ///
+    /// ```rust
+    /// # use leptos::*;
+    /// #[component]
+    /// fn Signup() -> impl IntoView {
+    ///     let name = create_rw_signal(String::new());
+    ///     let topics = create_rw_signal(vec!["news".to_string()]);
+    ///     view! {
+    ///         <input type="text" bind:value=name/>
+    ///         <select multiple bind:value=topics>
+    ///             <option value="news">"News"</option>
+    ///             <option value="offers">"Offers"</option>
+    ///         </select>
+    ///         <p>"Hello, " {name}</p>
+    ///     }
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn bind_value<T: BindValue>(self, value: RwSignal<T>) -> Self {
+        self.bind_value_with(value, BindValueMode::default())
+    }
+
+    /// Binds the `value` of a control to a signal like
+    /// [`bind_value`](HtmlElement::bind_value), updating the signal as given
+    /// by the [`BindValueMode`]. This is what the `bind:value:eager` and
+    /// `bind:value:lazy` syntax in the `view` macro expands to.
+    ///
+    /// ```rust
+    /// # use leptos::*;
+    /// #[component]
+    /// fn Search() -> impl IntoView {
+    ///     // shows suggestions for the text being composed, too
+    ///     let query = create_rw_signal(String::new());
+    ///     // only saved once the user is done
+    ///     let title = create_rw_signal(String::new());
+    ///     view! {
+    ///         <input type="search" bind:value:eager=query/>
+    ///         <input type="text" bind:value:lazy=title/>
+    ///     }
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn bind_value_with<T: BindValue>(
+        self,
+        value: RwSignal<T>,
+        mode: BindValueMode,
+    ) -> Self {
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        {
+            use crate::ev::{change, input, undelegated};
+            use leptos_reactive::{SignalSet, SignalWith, SignalWithUntracked};
+
+            let el = self
+                .element
+                .as_ref()
+                .unchecked_ref::<web_sys::Element>()
+                .clone();
+            let read = {
+                let el = el.clone();
+                move || {
+                    let new = T::read(&el);
+                    if value.with_untracked(|value| *value != new) {
+                        value.set(new);
+                    }
+                }
+            };
+
+            if self.element.name() == "select" {
+                // the options are only added after the binding, so they are
+                // selected once the children have been mounted
+                leptos_reactive::create_render_effect(move |_| {
+                    value.track();
+                    let el = el.clone();
+                    leptos_reactive::queue_microtask(move || {
+                        value.with_untracked(|value| value.write(&el))
+                    });
+                });
+            } else {
+                leptos_reactive::create_render_effect(move |_| {
+                    value.with(|value| value.write(&el))
+                });
+            }
+
+            match mode {
+                BindValueMode::Composed => self.on_composed_input(read),
+                BindValueMode::Eager => {
+                    self.on(undelegated(input), move |_| read())
+                }
+                BindValueMode::Lazy => {
+                    self.on(undelegated(change), move |_| read())
+                }
+            }
+        }
+
+        #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+        {
+            use leptos_reactive::SignalWithUntracked;
+
+            _ = mode;
+            if self.element.name() == "select" {
+                // a `<select>` has no `value` attribute, so its options are
+                // marked as selected instead
+                let mut this = self;
+                if let ElementChildren::Children(children) = &mut this.children
+                {
+                    value.with_untracked(|value| {
+                        select_options(children, &|option| {
+                            value.is_selected(option)
+                        })
+                    });
+                }
+                this
+            } else {
+                self.attr("value", value.with_untracked(T::value_attribute))
+            }
+        }
+    }
+
+    /// Calls `f` on every `input` event, except while the user is composing
+    /// text with an input method editor, and once more when the composition
+    /// has ended.
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    pub(crate) fn on_composed_input(self, f: impl Fn() + 'static) -> Self {
+        use crate::ev::{compositionend, compositionstart, input, undelegated};
+
+        let composing = Rc::new(Cell::new(false));
+        let f = Rc::new(f);
+        self.on(undelegated(compositionstart), {
+            let composing = Rc::clone(&composing);
+            move |_| composing.set(true)
+        })
+        .on(undelegated(compositionend), {
+            let composing = Rc::clone(&composing);
+            let f = Rc::clone(&f);
+            move |_| {
+                composing.set(false);
+                f();
+            }
+        })
+        .on(undelegated(input), move |_| {
+            // browsers differ in whether the last `input` event of a
+            // composition comes before or after `compositionend`
+            if !composing.get() {
+                f();
+            }
+        })
+    }
+
+    /// Binds whether a checkbox is checked to a signal. This is what the
+    /// `bind:checked` syntax in the `view` macro expands to.
+    ///
+    /// ```rust
+    /// # use leptos::*;
+    /// #[component]
+    /// fn Terms() -> impl IntoView {
+    ///     let accepted = create_rw_signal(false);
+    ///     view! {
+    ///         <label>
+    ///             <input type="checkbox" bind:checked=accepted/>
+    ///             "I accept the terms"
+    ///         </label>
+    ///         <button disabled=move || !accepted.get()>"Continue"</button>
+    ///     }
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn bind_checked(self, checked: RwSignal<bool>) -> Self {
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        {
+            use crate::ev::{change, undelegated};
+            use leptos_reactive::{SignalGet, SignalSet};
+
+            let input = self
+                .element
+                .as_ref()
+                .unchecked_ref::<web_sys::HtmlInputElement>()
+                .clone();
+
+            leptos_reactive::create_render_effect({
+                let input = input.clone();
+                move |_| input.set_checked(checked.get())
+            });
+
+            self.on(undelegated(change), move |_| checked.set(input.checked()))
+        }
+
+        #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+        {
+            use leptos_reactive::SignalGetUntracked;
+
+            self.attr("checked", checked.get_untracked())
+        }
+    }
+
+    /// Binds a radio button or a checkbox to a signal that it shares with the
+    /// others in its group, and sets its `value`. This is what the
+    /// `bind:group` syntax in the `view` macro expands to, with the `value`
+    /// attribute of the same element.
+    ///
+    /// See [`BindGroup`] for the types that a group can be bound to.
+    ///
+    /// ```rust
+    /// # use leptos::*;
+    /// #[component]
+    /// fn Preferences() -> impl IntoView {
+    ///     let size = create_rw_signal("medium".to_string());
+    ///     let toppings = create_rw_signal(Vec::<String>::new());
+    ///     view! {
+    ///         <input type="radio" value="small" bind:group=size/>
+    ///         <input type="radio" value="medium" bind:group=size/>
+    ///         <input type="checkbox" value="cheese" bind:group=toppings/>
+    ///         <input type="checkbox" value="olives" bind:group=toppings/>
+    ///     }
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn bind_group<T: BindGroup>(
+        self,
+        group: RwSignal<T>,
+        value: impl IntoAttribute,
+    ) -> Self {
+        let this = self.attr("value", value);
+
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        {
+            use crate::ev::{change, undelegated};
+            use leptos_reactive::{SignalUpdate, SignalWith};
+
+            let input = this
+                .element
+                .as_ref()
+                .unchecked_ref::<web_sys::HtmlInputElement>()
+                .clone();
+
+            leptos_reactive::create_render_effect({
+                let input = input.clone();
+                move |_| {
+                    let checked =
+                        group.with(|group| group.is_checked(&input.value()));
+                    input.set_checked(checked);
+                }
+            });
+
+            this.on(undelegated(change), move |_| {
+                group.update(|group| {
+                    group.set_checked(&input.value(), input.checked())
+                })
+            })
+        }
+
+        #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+        {
+            use leptos_reactive::SignalWithUntracked;
+
+            let value = this
+                .attrs
+                .iter()
+                .rev()
+                .find(|(name, _)| name.as_str() == "value")
+                .map(|(_, value)| value.to_string())
+                .unwrap_or_default();
+            this.attr(
+                "checked",
+                group.with_untracked(|group| group.is_checked(&value)),
+            )
+        }
+    }
+}
+
+/// Marks the `<option>`s among the given views as `selected` if their value,
+/// or their text without a `value` attribute, is selected.
+#[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+fn select_options(
+    views: &mut [crate::View],
+    is_selected: &dyn Fn(&str) -> bool,
+) {
+    use crate::View;
+
+    for view in views {
+        match view {
+            View::Element(el) if el.name == "option" => {
+                let value = match el
+                    .attrs
+                    .iter()
+                    .rev()
+                    .find(|(name, _)| name == "value")
+                {
+                    Some((_, value)) => value.to_string(),
+                    None => match &el.children {
+                        ElementChildren::Children(children) => children
+                            .iter()
+                            .filter_map(|child| match child {
+                                View::Text(text) => Some(text.content.as_str()),
+                                _ => None,
+                            })
+                            .collect(),
+                        _ => String::new(),
+                    },
+                };
+                if is_selected(&value) {
+                    el.attrs.push(("selected".into(), "".into()));
+                }
+            }
+            View::Element(el) if el.name == "optgroup" => {
+                if let ElementChildren::Children(children) = &mut el.children {
+                    select_options(children, is_selected);
+                }
+            }
+            // fragments and components
+            View::Component(component) => {
+                select_options(&mut component.children, is_selected)
+            }
+            _ => {}
+        }
+    }
+}
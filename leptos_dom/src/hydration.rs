@@ -3,6 +3,11 @@
     any(feature = "hydrate", feature = "ssr")
 ))]
 use leptos_reactive::SharedContext;
+#[cfg(any(
+    not(all(target_arch = "wasm32", feature = "web")),
+    all(feature = "hydrate", debug_assertions)
+))]
+use std::cell::Cell;
 use std::{cell::RefCell, fmt::Display};
 
 #[cfg(feature = "hydrate")]
@@ -54,6 +59,9 @@ mod hydrate_only {
       });
 
       pub static IS_HYDRATING: Cell<bool> = const { Cell::new(true) };
+
+      pub static MISMATCH_POLICY: Cell<super::HydrationMismatch> =
+        const { Cell::new(super::HydrationMismatch::Panic) };
     }
 
     #[allow(unused)]
@@ -70,6 +78,64 @@ mod hydrate_only {
 #[cfg(feature = "hydrate")]
 pub(crate) use hydrate_only::*;
 
+/// What happens during hydration, in debug builds, when the element the
+/// server rendered at a hydration key is not the kind of element the browser
+/// expects there, e.g., because the browser corrected invalid HTML, a browser
+/// extension changed the page, or the server and the browser rendered
+/// different views.
+///
+/// Either way, the mismatch is reported with both elements, an excerpt of the
+/// server's HTML, and the components that were being rendered. Release builds
+/// don't check for mismatches.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum HydrationMismatch {
+    /// Panics with the report. This is the default.
+    #[default]
+    Panic,
+    /// Logs the report as a warning, and continues hydrating with the
+    /// element the server rendered.
+    Warn,
+}
+
+// checks that the element the server rendered is the one the browser expects
+#[cfg(all(feature = "hydrate", debug_assertions))]
+#[allow(unused)]
+pub(crate) fn check_element(
+    id: &HydrationKey,
+    expected: &str,
+    found: &web_sys::Element,
+) {
+    let found_name = found.node_name();
+    if found_name.eq_ignore_ascii_case(expected) {
+        return;
+    }
+    let report = format!(
+        "hydration mismatch at hydration key {id}: expected <{}>, but the \
+         server rendered <{}>:\n    {}\nCheck out the docs for information \
+         about this kind of hydration bug: \
+         https://leptos-rs.github.io/leptos/ssr/24_hydration_bugs.html{}",
+        expected.to_ascii_lowercase(),
+        found_name.to_ascii_lowercase(),
+        excerpt(&found.outer_html()),
+        leptos_reactive::ComponentStack::current()
+    );
+    match MISMATCH_POLICY.with(Cell::get) {
+        HydrationMismatch::Panic => panic!("{report}"),
+        HydrationMismatch::Warn => crate::warn!("{report}"),
+    }
+}
+
+// the start of an element's HTML, which is enough to recognize it
+#[cfg(any(test, all(feature = "hydrate", debug_assertions)))]
+fn excerpt(html: &str) -> std::borrow::Cow<'_, str> {
+    const MAX_CHARS: usize = 160;
+
+    match html.char_indices().nth(MAX_CHARS) {
+        Some((end, _)) => format!("{}…", &html[..end]).into(),
+        None => html.into(),
+    }
+}
+
 /// A stable identifier within the server-rendering or hydration process.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct HydrationKey {
@@ -131,10 +197,53 @@ mod tests {
             })
         )
     }
+
+    #[test]
+    fn mismatch_excerpt_is_truncated() {
+        use super::excerpt;
+
+        assert_eq!(excerpt("<p>short</p>"), "<p>short</p>");
+        let long = format!("<p>{}</p>", "é".repeat(200));
+        let cut = excerpt(&long);
+        assert!(cut.ends_with('…'));
+        assert_eq!(cut.chars().count(), 161);
+    }
+
+    #[test]
+    #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+    fn use_id_is_deterministic() {
+        use crate::{use_id, HydrationCtx};
+
+        let render = || {
+            HydrationCtx::reset_id();
+            let first = use_id();
+            let second = use_id();
+            HydrationCtx::id();
+            let third = use_id();
+            [first, second, third]
+        };
+        let ids = render();
+        assert_eq!(
+            ids,
+            [
+                "leptos-id-0-0-0-0",
+                "leptos-id-0-0-0-0-1",
+                "leptos-id-0-0-0-1"
+            ]
+        );
+        assert_eq!(render(), ids);
+    }
 }
 
 thread_local!(static ID: RefCell<HydrationKey> = const {RefCell::new(HydrationKey { outlet: 0, fragment: 0, error: 0, id: 0 })});
 
+// the last hydration key `use_id` was called at, and how many IDs it has
+// generated there
+thread_local!(static USE_ID: RefCell<(HydrationKey, usize)> = const {RefCell::new((HydrationKey { outlet: 0, fragment: 0, error: 0, id: 0 }, 0))});
+
+#[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+thread_local!(static NO_HYDRATION_KEYS: Cell<bool> = const { Cell::new(false) });
+
 /// Control and utility methods for hydration.
 pub struct HydrationCtx;
 
@@ -151,6 +260,8 @@ impl HydrationCtx {
             any(feature = "hydrate", feature = "ssr")
         )))]
         let no_hydrate = false;
+        #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+        let no_hydrate = no_hydrate || NO_HYDRATION_KEYS.with(Cell::get);
         if no_hydrate {
             None
         } else {
@@ -175,6 +286,8 @@ impl HydrationCtx {
             any(feature = "hydrate", feature = "ssr")
         )))]
         let no_hydrate = false;
+        #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+        let no_hydrate = no_hydrate || NO_HYDRATION_KEYS.with(Cell::get);
 
         if no_hydrate {
             None
@@ -220,14 +333,14 @@ impl HydrationCtx {
     #[doc(hidden)]
     #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
     pub fn reset_id() {
-        ID.with(|id| {
-            *id.borrow_mut() = HydrationKey {
-                outlet: 0,
-                fragment: 0,
-                error: 0,
-                id: 0,
-            }
-        });
+        let key = HydrationKey {
+            outlet: 0,
+            fragment: 0,
+            error: 0,
+            id: 0,
+        };
+        ID.with(|id| *id.borrow_mut() = key);
+        USE_ID.with(|last| *last.borrow_mut() = (key, 0));
     }
 
     /// Resumes hydration from the provided `id`. Useful for
@@ -249,6 +362,30 @@ impl HydrationCtx {
         });
     }
 
+    /// Runs the function without generating hydration keys, so that any HTML
+    /// rendered inside it contains no `data-hk` attributes or hydration markers.
+    ///
+    /// This is useful for rendering HTML that will never be hydrated.
+    #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+    pub fn without_hydration_keys<T>(f: impl FnOnce() -> T) -> T {
+        let prev = NO_HYDRATION_KEYS.with(|no_keys| no_keys.replace(true));
+        let value = f();
+        NO_HYDRATION_KEYS.with(|no_keys| no_keys.set(prev));
+        value
+    }
+
+    /// Wraps a `Future` so that hydration keys are not generated whenever it
+    /// is polled. See [`HydrationCtx::without_hydration_keys`].
+    #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+    pub fn without_hydration_keys_async<T>(
+        fut: impl std::future::Future<Output = T>,
+    ) -> impl std::future::Future<Output = T> {
+        let mut fut = Box::pin(fut);
+        futures::future::poll_fn(move |cx| {
+            Self::without_hydration_keys(|| fut.as_mut().poll(cx))
+        })
+    }
+
     #[doc(hidden)]
     pub fn stop_hydrating() {
         #[cfg(feature = "hydrate")]
@@ -285,6 +422,16 @@ impl HydrationCtx {
         value
     }
 
+    /// Sets what happens when an element rendered by the server doesn't match
+    /// the element the browser expects while hydrating. See
+    /// [`HydrationMismatch`].
+    pub fn set_mismatch_policy(policy: HydrationMismatch) {
+        #[cfg(feature = "hydrate")]
+        MISMATCH_POLICY.with(|current| current.set(policy));
+        #[cfg(not(feature = "hydrate"))]
+        let _ = policy;
+    }
+
     /// Whether the UI is currently in the process of hydrating from the server-sent HTML.
     #[inline(always)]
     pub fn is_hydrating() -> bool {
@@ -310,3 +457,42 @@ impl HydrationCtx {
         }
     }
 }
+
+/// Generates an ID that is unique within the page, and is the same when a
+/// component is rendered on the server and when it is hydrated in the browser.
+///
+/// This makes it safe to use in attributes that refer to other elements, like
+/// `for`, `aria-labelledby`, or `aria-describedby`. (A random ID, like a UUID,
+/// would differ between the server and the browser, breaking hydration.)
+///
+/// The ID is derived from the current position in the rendering process, so
+/// it should be called while creating the view, in the body of a component,
+/// not inside an effect or an event handler.
+///
+/// ```
+/// # use leptos::*;
+/// #[component]
+/// fn TextInput(label: &'static str) -> impl IntoView {
+///     let id = use_id();
+///     view! {
+///         <label for=id.clone()>{label}</label>
+///         <input type="text" id=id/>
+///     }
+/// }
+/// ```
+pub fn use_id() -> String {
+    let key = HydrationCtx::peek_always();
+    let count = USE_ID.with(|last| {
+        let mut last = last.borrow_mut();
+        if last.0 != key {
+            *last = (key, 0);
+        }
+        last.1 += 1;
+        last.1 - 1
+    });
+    if count == 0 {
+        format!("leptos-id-{key}")
+    } else {
+        format!("leptos-id-{key}-{count}")
+    }
+}
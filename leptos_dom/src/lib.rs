@@ -11,8 +11,13 @@
 #[cfg_attr(any(debug_assertions, feature = "ssr"), macro_use)]
 pub extern crate tracing;
 
+#[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+mod buffer_pool;
 mod components;
+mod css;
 mod directive;
+#[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+mod escape;
 mod events;
 pub mod helpers;
 pub mod html;
@@ -21,6 +26,7 @@ mod hydration;
 pub mod logging;
 mod macro_helpers;
 pub mod math;
+mod mount;
 mod node_ref;
 /// Utilities for exporting nonces to be used for a Content Security Policy.
 pub mod nonce;
@@ -31,6 +37,7 @@ mod transparent;
 
 use cfg_if::cfg_if;
 pub use components::*;
+pub use css::*;
 pub use directive::*;
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 pub use events::add_event_helper;
@@ -42,7 +49,7 @@ pub use events::{
 };
 pub use html::HtmlElement;
 use html::{AnyElement, ElementDescriptor};
-pub use hydration::{HydrationCtx, HydrationKey};
+pub use hydration::{use_id, HydrationCtx, HydrationKey, HydrationMismatch};
 #[cfg(not(feature = "nightly"))]
 use leptos_reactive::{
     MaybeProp, MaybeSignal, Memo, ReadSignal, Resource, RwSignal, Signal,
@@ -50,6 +57,7 @@ use leptos_reactive::{
 };
 use leptos_reactive::{Oco, TextProp};
 pub use macro_helpers::*;
+pub use mount::*;
 pub use node_ref::*;
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 use once_cell::unsync::Lazy as LazyCell;
@@ -485,7 +493,8 @@ impl Comment {
                     } else {
                         crate::warn!(
                             "component with id {id} not found, ignoring it for \
-                             hydration"
+                             hydration{}",
+                            leptos_reactive::ComponentStack::current()
                         );
                     }
                 }
@@ -1238,6 +1247,82 @@ where
     }
 }
 
+/// Renders the views produced by `.map()` over an iterator, so that it can be
+/// used in a view directly, without calling
+/// [`collect_view`](CollectView::collect_view) first.
+///
+/// Each item is converted into a [`View`] as it is produced, without first
+/// collecting the mapped values.
+///
+/// ```
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// let names = ["Alice", "Bob"];
+/// view! {
+///   <ul>{names.iter().map(|name| view! { <li>{*name}</li> })}</ul>
+/// }
+/// # ;
+/// # runtime.dispose();
+/// ```
+impl<I, F, V> IntoView for std::iter::Map<I, F>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> V,
+    V: IntoView,
+{
+    #[cfg_attr(
+        any(debug_assertions, feature = "ssr"),
+        instrument(level = "trace", name = "#text", skip_all)
+    )]
+    fn into_view(self) -> View {
+        self.map(IntoView::into_view)
+            .collect::<Fragment>()
+            .into_view()
+    }
+}
+
+/// Renders each item of an iterator or collection in order.
+///
+/// This works for any [`IntoIterator`] whose items implement [`IntoView`],
+/// like a [`Filter`](std::iter::Filter) or a
+/// [`HashSet`](std::collections::HashSet), which can't be used in a view
+/// directly. Unlike [`<For/>`](crate::Each), the items are not keyed: when this
+/// is returned from a reactive closure, every item is created again each time
+/// the closure runs. This is fine for short or rarely changing lists.
+///
+/// ```
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// let scores = [3, 8, 5];
+/// view! {
+///   <ol>
+///     {Unkeyed(scores.into_iter().filter(|score| *score > 4))}
+///   </ol>
+/// }
+/// # ;
+/// # runtime.dispose();
+/// ```
+#[derive(Clone, Debug)]
+pub struct Unkeyed<I>(pub I);
+
+impl<I> IntoView for Unkeyed<I>
+where
+    I: IntoIterator,
+    I::Item: IntoView,
+{
+    #[cfg_attr(
+        any(debug_assertions, feature = "ssr"),
+        instrument(level = "trace", name = "#text", skip_all)
+    )]
+    fn into_view(self) -> View {
+        self.0
+            .into_iter()
+            .map(IntoView::into_view)
+            .collect::<Fragment>()
+            .into_view()
+    }
+}
+
 impl IntoView for core::fmt::Arguments<'_> {
     #[cfg_attr(
         any(debug_assertions, feature = "ssr"),
@@ -1,3 +1,4 @@
+use crate::{Color, Length};
 #[cfg(not(feature = "nightly"))]
 use leptos_reactive::{
     MaybeProp, MaybeSignal, Memo, ReadSignal, RwSignal, Signal, SignalGet,
@@ -301,6 +302,8 @@ attr_type!(i128);
 attr_type!(f32);
 attr_type!(f64);
 attr_type!(char);
+attr_type!(Color);
+attr_type!(Length);
 
 attr_signal_type!(ReadSignal<T>);
 attr_signal_type!(RwSignal<T>);
@@ -318,15 +321,24 @@ pub fn attribute_helper(
     name: Oco<'static, str>,
     value: Attribute,
 ) {
+    if name == "class" {
+        return super::into_class::class_attribute_helper(el, value);
+    }
+    if name == "style" {
+        return super::into_style::style_attribute_helper(el, value);
+    }
+
     #[cfg(debug_assertions)]
     let called_at = std::panic::Location::caller();
+    use crate::macro_helpers::WriteSlot;
     use leptos_reactive::create_render_effect;
     match value {
         Attribute::Fn(f) => {
             let el = el.clone();
+            let slot = WriteSlot::default();
             create_render_effect(move |old| {
                 let new = f();
-                if old.as_ref() != Some(&new) {
+                if old.is_none() {
                     attribute_expression(
                         &el,
                         &name,
@@ -335,6 +347,20 @@ pub fn attribute_helper(
                         #[cfg(debug_assertions)]
                         called_at,
                     );
+                } else if old.as_ref() != Some(&new) {
+                    // updates may be buffered until the end of the microtask
+                    let el = el.clone();
+                    let name = name.clone();
+                    slot.queue(new.clone(), move |new| {
+                        attribute_expression(
+                            &el,
+                            &name,
+                            new,
+                            true,
+                            #[cfg(debug_assertions)]
+                            called_at,
+                        )
+                    });
                 }
                 new
             });
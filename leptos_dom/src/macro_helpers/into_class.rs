@@ -1,3 +1,4 @@
+use crate::{Attribute, IntoAttribute};
 #[cfg(not(feature = "nightly"))]
 use leptos_reactive::{
     MaybeProp, MaybeSignal, Memo, ReadSignal, RwSignal, Signal, SignalGet,
@@ -26,6 +27,17 @@ pub trait IntoClass {
     fn into_class_boxed(self: Box<Self>) -> Class;
 }
 
+impl IntoClass for Class {
+    #[inline(always)]
+    fn into_class(self) -> Class {
+        self
+    }
+
+    fn into_class_boxed(self: Box<Self>) -> Class {
+        *self
+    }
+}
+
 impl IntoClass for bool {
     #[inline(always)]
     fn into_class(self) -> Class {
@@ -75,6 +87,170 @@ impl Class {
     }
 }
 
+/// A set of class names, each of which is either included or not, as built
+/// by the [`classes!`](crate::classes) macro.
+///
+/// When it is used as the value of the `class` attribute, and especially when
+/// it is returned from a closure (`class=move || classes! { .. }`), the
+/// element’s [`classList`](https://developer.mozilla.org/en-US/docs/Web/API/Element/classList)
+/// is updated by adding and removing only the names that changed, instead of
+/// overwriting its `className`. Classes toggled with `class:name=...`, or
+/// added by other JavaScript, are left alone.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClassList(Vec<(leptos_reactive::Oco<'static, str>, bool)>);
+
+impl ClassList {
+    /// Creates an empty class list.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a class name, which is only included if `include` is `true`.
+    /// The name may contain several classes separated by whitespace.
+    pub fn with(
+        mut self,
+        name: impl Into<leptos_reactive::Oco<'static, str>>,
+        include: bool,
+    ) -> Self {
+        self.0.push((name.into(), include));
+        self
+    }
+
+    /// Iterates over the names of the classes that are included.
+    pub fn included(&self) -> impl Iterator<Item = &str> {
+        self.0
+            .iter()
+            .filter(|(_, include)| *include)
+            .flat_map(|(name, _)| name.split_ascii_whitespace())
+    }
+}
+
+impl IntoAttribute for ClassList {
+    fn into_attribute(self) -> Attribute {
+        Attribute::String(self.included().collect::<Vec<_>>().join(" ").into())
+    }
+
+    fn into_attribute_boxed(self: Box<Self>) -> Attribute {
+        (*self).into_attribute()
+    }
+}
+
+/// Builds a [`ClassList`] from class names and the conditions under which
+/// they are included, for use as the value of the `class` attribute.
+///
+/// ```
+/// # use leptos_dom::*;
+/// # use leptos_reactive::*;
+/// # let runtime = create_runtime();
+/// let is_active = create_rw_signal(true);
+/// let dark = create_rw_signal(false);
+/// let classes = move || {
+///     classes! {
+///         "button" => true,
+///         "active" => is_active.get(),
+///         "dark" => dark.get(),
+///     }
+/// };
+/// assert_eq!(classes().included().collect::<Vec<_>>(), ["button", "active"]);
+/// # runtime.dispose();
+/// ```
+#[macro_export]
+macro_rules! classes {
+    ($($name:expr => $include:expr),* $(,)?) => {
+        $crate::ClassList::new()$(.with($name, $include))*
+    };
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+use leptos_reactive::Oco;
+
+// the class names in the value of a `class` attribute
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+fn class_names(mut value: Attribute) -> smallvec::SmallVec<[String; 4]> {
+    while let Attribute::Fn(f) = value {
+        value = f();
+    }
+    value
+        .as_nameless_value_string()
+        .map(|value| {
+            value
+                .split_ascii_whitespace()
+                .map(ToOwned::to_owned)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Sets the `class` attribute by adding and removing the names in its value
+/// from the element’s `classList`, so that the classes the element gets in
+/// other ways are not overwritten.
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+pub(crate) fn class_attribute_helper(el: &web_sys::Element, value: Attribute) {
+    use crate::{macro_helpers::WriteSlot, HydrationCtx};
+    use leptos_reactive::create_render_effect;
+    use std::{cell::RefCell, rc::Rc};
+
+    let class_list = el.class_list();
+    match value {
+        Attribute::Fn(f) => {
+            // the names that have been written to the DOM, which can lag
+            // behind the latest value while writes are buffered
+            let written = Rc::new(RefCell::new(Default::default()));
+            let slot = WriteSlot::default();
+            create_render_effect(move |old| {
+                let new = class_names(f());
+                if old.is_none() {
+                    update_class_list(
+                        &class_list,
+                        &mut written.borrow_mut(),
+                        &new,
+                    );
+                } else if old.as_ref() != Some(&new) {
+                    // updates may be buffered until the end of the microtask
+                    let class_list = class_list.clone();
+                    let written = Rc::clone(&written);
+                    slot.queue(new.clone(), move |new| {
+                        update_class_list(
+                            &class_list,
+                            &mut written.borrow_mut(),
+                            &new,
+                        )
+                    });
+                }
+                new
+            });
+        }
+        value => {
+            if !HydrationCtx::is_hydrating() {
+                update_class_list(
+                    &class_list,
+                    &mut Default::default(),
+                    &class_names(value),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+fn update_class_list(
+    class_list: &web_sys::DomTokenList,
+    written: &mut smallvec::SmallVec<[String; 4]>,
+    new: &[String],
+) {
+    for name in written.iter() {
+        if !new.contains(name) {
+            class_expression(class_list, name, false, true);
+        }
+    }
+    for name in new {
+        if !written.contains(name) {
+            class_expression(class_list, name, true, true);
+        }
+    }
+    *written = new.iter().cloned().collect();
+}
+
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 use leptos_reactive::Oco;
 
@@ -86,15 +262,26 @@ pub fn class_helper(
     name: Oco<'static, str>,
     value: Class,
 ) {
+    use crate::macro_helpers::WriteSlot;
     use leptos_reactive::create_render_effect;
 
     let class_list = el.class_list();
     match value {
         Class::Fn(f) => {
+            let slot = WriteSlot::default();
             create_render_effect(move |old| {
                 let new = f();
-                if old.as_ref() != Some(&new) && (old.is_some() || new) {
-                    class_expression(&class_list, &name, new, true)
+                if old.is_none() {
+                    if new {
+                        class_expression(&class_list, &name, new, true)
+                    }
+                } else if old != Some(new) {
+                    // updates may be buffered until the end of the microtask
+                    let class_list = class_list.clone();
+                    let name = name.clone();
+                    slot.queue(new, move |new| {
+                        class_expression(&class_list, &name, new, true)
+                    });
                 }
                 new
             });
@@ -1,3 +1,4 @@
+use crate::{Attribute, Color, IntoAttribute, Length};
 use leptos_reactive::Oco;
 #[cfg(not(feature = "nightly"))]
 use leptos_reactive::{
@@ -177,6 +178,13 @@ impl Style {
     pub fn as_value_string(
         &self,
         style_name: &'static str,
+    ) -> Option<Oco<'static, str>> {
+        self.as_property_string(style_name)
+    }
+
+    fn as_property_string(
+        &self,
+        style_name: &str,
     ) -> Option<Oco<'static, str>> {
         match self {
             Style::Value(value) => {
@@ -190,12 +198,127 @@ impl Style {
                 while let Style::Fn(f) = value {
                     value = f();
                 }
-                value.as_value_string(style_name)
+                value.as_property_string(style_name)
             }
         }
     }
 }
 
+/// A set of style properties, each with its own value, as built by the
+/// [`style!`](crate::style) macro.
+///
+/// When it is used as the value of the `style` attribute, only the properties
+/// whose values changed are updated with
+/// [`setProperty`](https://developer.mozilla.org/en-US/docs/Web/API/CSSStyleDeclaration/setProperty),
+/// instead of the whole attribute being rewritten, so properties set with
+/// `style:name=...` are left alone. A value that ends with `!important` is set
+/// with that priority, and custom properties (`--name`) are supported.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StyleMap(Vec<(Oco<'static, str>, Style)>);
+
+impl StyleMap {
+    /// Creates an empty style map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a property. If its value is reactive, the property is updated
+    /// when it changes; if it is `None`, the property is not set.
+    pub fn with(
+        mut self,
+        name: impl Into<Oco<'static, str>>,
+        value: impl IntoStyle,
+    ) -> Self {
+        self.0.push((name.into(), value.into_style()));
+        self
+    }
+
+    /// Converts the name of a property written as a Rust identifier, like
+    /// `background_color`, into its CSS name, like `background-color`.
+    #[doc(hidden)]
+    pub fn property_name(name: &'static str) -> Oco<'static, str> {
+        if name.contains('_') {
+            Oco::Owned(name.replace('_', "-"))
+        } else {
+            Oco::Borrowed(name)
+        }
+    }
+
+    fn to_style_string(&self) -> String {
+        self.0
+            .iter()
+            .filter_map(|(name, value)| value.as_property_string(name))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl IntoAttribute for StyleMap {
+    fn into_attribute(self) -> Attribute {
+        if self
+            .0
+            .iter()
+            .any(|(_, value)| matches!(value, Style::Fn(_)))
+        {
+            Attribute::Fn(Rc::new(move || {
+                Attribute::String(self.to_style_string().into())
+            }))
+        } else {
+            Attribute::String(self.to_style_string().into())
+        }
+    }
+
+    fn into_attribute_boxed(self: Box<Self>) -> Attribute {
+        (*self).into_attribute()
+    }
+}
+
+/// Builds a [`StyleMap`] from style properties and their values, for use as
+/// the value of the `style` attribute.
+///
+/// Properties can be written as identifiers, with underscores in place of
+/// hyphens, or as string literals, which is needed for custom properties.
+///
+/// ```
+/// # use leptos_dom::*;
+/// # use leptos_reactive::*;
+/// # let runtime = create_runtime();
+/// let width = create_rw_signal(Length::px(120.0));
+/// let styles = style! {
+///     width: width,
+///     background_color: "white !important",
+///     "--accent": "rebeccapurple",
+///     opacity: None::<f64>,
+/// };
+/// assert_eq!(
+///     styles.into_attribute().as_nameless_value_string().as_deref(),
+///     Some(
+///         "width: 120px; background-color: white !important; --accent: \
+///          rebeccapurple;"
+///     )
+/// );
+/// # runtime.dispose();
+/// ```
+#[macro_export]
+macro_rules! style {
+    (@map $map:expr;) => { $map };
+    (@map $map:expr; $name:literal : $value:expr $(, $($rest:tt)*)?) => {
+        $crate::style!(@map $map.with($name, $value); $($($rest)*)?)
+    };
+    (@map $map:expr; $name:ident : $value:expr $(, $($rest:tt)*)?) => {
+        $crate::style!(
+            @map $map.with(
+                $crate::StyleMap::property_name(stringify!($name)),
+                $value
+            );
+            $($($rest)*)?
+        )
+    };
+    ($($rest:tt)*) => {
+        $crate::style!(@map $crate::StyleMap::new(); $($rest)*)
+    };
+}
+
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 #[doc(hidden)]
 #[inline(never)]
@@ -204,6 +327,7 @@ pub fn style_helper(
     name: Oco<'static, str>,
     value: Style,
 ) {
+    use crate::macro_helpers::WriteSlot;
     use leptos_reactive::create_render_effect;
     use std::ops::Deref;
     use wasm_bindgen::JsCast;
@@ -212,6 +336,7 @@ pub fn style_helper(
     let style_list = el.style();
     match value {
         Style::Fn(f) => {
+            let slot = WriteSlot::default();
             create_render_effect(move |old| {
                 let mut new = f();
                 while let Style::Fn(f) = new {
@@ -222,8 +347,20 @@ pub fn style_helper(
                     Style::Option(value) => value,
                     _ => unreachable!(),
                 };
-                if old.as_ref() != Some(&new) {
+                if old.is_none() {
                     style_expression(&style_list, &name, new.as_deref(), true)
+                } else if old.as_ref() != Some(&new) {
+                    // updates may be buffered until the end of the microtask
+                    let style_list = style_list.clone();
+                    let name = name.clone();
+                    slot.queue(new.clone(), move |new| {
+                        style_expression(
+                            &style_list,
+                            &name,
+                            new.as_deref(),
+                            true,
+                        )
+                    });
                 }
                 new
             });
@@ -251,7 +388,15 @@ pub(crate) fn style_expression(
         let style_name = wasm_bindgen::intern(style_name);
 
         if let Some(value) = value {
-            if let Err(e) = style_list.set_property(style_name, value) {
+            let result = match value.trim_end().strip_suffix("!important") {
+                Some(value) => style_list.set_property_with_priority(
+                    style_name,
+                    value.trim_end(),
+                    "important",
+                ),
+                None => style_list.set_property(style_name, value),
+            };
+            if let Err(e) = result {
                 crate::error!("[HtmlElement::style()] {e:?}");
             }
         } else {
@@ -262,6 +407,100 @@ pub(crate) fn style_expression(
     }
 }
 
+// the properties and values in the value of a `style` attribute
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+fn style_declarations(
+    mut value: Attribute,
+) -> smallvec::SmallVec<[(String, String); 4]> {
+    while let Attribute::Fn(f) = value {
+        value = f();
+    }
+    value
+        .as_nameless_value_string()
+        .map(|value| {
+            value
+                .split(';')
+                .filter_map(|declaration| {
+                    let (name, value) = declaration.split_once(':')?;
+                    Some((name.trim().to_owned(), value.trim().to_owned()))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Sets the `style` attribute by setting and removing the properties in its
+/// value one by one, so that only the ones that changed are updated and the
+/// properties the element gets in other ways are not overwritten.
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+pub(crate) fn style_attribute_helper(el: &web_sys::Element, value: Attribute) {
+    use crate::{macro_helpers::WriteSlot, HydrationCtx};
+    use leptos_reactive::create_render_effect;
+    use std::cell::RefCell;
+    use wasm_bindgen::JsCast;
+
+    let style_list = el.unchecked_ref::<web_sys::HtmlElement>().style();
+    match value {
+        Attribute::Fn(f) => {
+            // the declarations that have been written to the DOM, which can
+            // lag behind the latest value while writes are buffered
+            let written = Rc::new(RefCell::new(Default::default()));
+            let slot = WriteSlot::default();
+            create_render_effect(move |old| {
+                let new = style_declarations(f());
+                if old.is_none() {
+                    update_style_list(
+                        &style_list,
+                        &mut written.borrow_mut(),
+                        &new,
+                    );
+                } else if old.as_ref() != Some(&new) {
+                    // updates may be buffered until the end of the microtask
+                    let style_list = style_list.clone();
+                    let written = Rc::clone(&written);
+                    slot.queue(new.clone(), move |new| {
+                        update_style_list(
+                            &style_list,
+                            &mut written.borrow_mut(),
+                            &new,
+                        )
+                    });
+                }
+                new
+            });
+        }
+        value => {
+            if !HydrationCtx::is_hydrating() {
+                update_style_list(
+                    &style_list,
+                    &mut Default::default(),
+                    &style_declarations(value),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+fn update_style_list(
+    style_list: &web_sys::CssStyleDeclaration,
+    written: &mut smallvec::SmallVec<[(String, String); 4]>,
+    new: &[(String, String)],
+) {
+    for (name, _) in written.iter() {
+        if !new.iter().any(|(new_name, _)| new_name == name) {
+            style_expression(style_list, name, None, true);
+        }
+    }
+    for declaration in new {
+        if !written.contains(declaration) {
+            let (name, value) = declaration;
+            style_expression(style_list, name, Some(value.as_str()), true);
+        }
+    }
+    *written = new.iter().cloned().collect();
+}
+
 macro_rules! style_type {
     ($style_type:ty) => {
         impl IntoStyle for $style_type {
@@ -341,6 +580,8 @@ style_type!(i128);
 style_type!(f32);
 style_type!(f64);
 style_type!(char);
+style_type!(Color);
+style_type!(Length);
 
 style_signal_type!(ReadSignal<T>);
 style_signal_type!(RwSignal<T>);
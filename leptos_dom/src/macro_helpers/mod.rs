@@ -5,7 +5,11 @@ mod into_style;
 #[cfg(feature = "trace-component-props")]
 #[doc(hidden)]
 pub mod tracing_property;
+mod write_buffer;
 pub use into_attribute::*;
 pub use into_class::*;
 pub use into_property::*;
 pub use into_style::*;
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+pub(crate) use write_buffer::WriteSlot;
+pub use write_buffer::{buffer_dom_writes, flush_dom_writes};
@@ -0,0 +1,170 @@
+use std::cell::{Cell, RefCell};
+#[cfg(any(test, all(target_arch = "wasm32", feature = "web")))]
+use std::rc::Rc;
+
+thread_local! {
+    static BUFFER_WRITES: Cell<bool> = const { Cell::new(false) };
+    static PENDING_WRITES: RefCell<Vec<Box<dyn FnOnce()>>> =
+        Default::default();
+    #[cfg(any(test, all(target_arch = "wasm32", feature = "web")))]
+    static NEXT_WRITE: Cell<u64> = const { Cell::new(0) };
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    static FLUSH_SCHEDULED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Holds the next value of a reactive attribute, class, or style until the
+/// pending DOM writes are flushed.
+///
+/// If the value changes several times before the flush, only the last value
+/// is written, so bursts of updates to the same element (for example, from
+/// several signals set in the same tick) result in one pass of DOM writes.
+/// The write happens at the position of the last change, so writes to
+/// different slots are applied in the same order as they would have been
+/// without buffering.
+#[cfg(any(test, all(target_arch = "wasm32", feature = "web")))]
+pub(crate) struct WriteSlot<T>(Rc<RefCell<(u64, Option<T>)>>);
+
+#[cfg(any(test, all(target_arch = "wasm32", feature = "web")))]
+impl<T> Default for WriteSlot<T> {
+    fn default() -> Self {
+        Self(Rc::new(RefCell::new((0, None))))
+    }
+}
+
+#[cfg(any(test, all(target_arch = "wasm32", feature = "web")))]
+impl<T: 'static> WriteSlot<T> {
+    /// Passes `value` to `write`, either immediately or, if
+    /// [`buffer_dom_writes`] is enabled, when the pending writes are flushed.
+    pub fn queue(&self, value: T, write: impl FnOnce(T) + 'static) {
+        if !BUFFER_WRITES.with(Cell::get) {
+            write(value);
+            return;
+        }
+
+        let id = NEXT_WRITE.with(|next| {
+            let id = next.get() + 1;
+            next.set(id);
+            id
+        });
+        // an earlier queued write for this slot is skipped when flushed
+        *self.0.borrow_mut() = (id, Some(value));
+        let slot = Rc::clone(&self.0);
+        PENDING_WRITES.with(|pending| {
+            pending.borrow_mut().push(Box::new(move || {
+                let value = {
+                    let mut slot = slot.borrow_mut();
+                    if slot.0 == id {
+                        slot.1.take()
+                    } else {
+                        None
+                    }
+                };
+                if let Some(value) = value {
+                    write(value);
+                }
+            }))
+        });
+
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        if !FLUSH_SCHEDULED.with(|scheduled| scheduled.replace(true)) {
+            leptos_reactive::queue_microtask(flush_dom_writes);
+        }
+    }
+}
+
+/// Sets whether updates to reactive attributes, classes, and styles are
+/// buffered and written to the DOM together at the end of the microtask.
+///
+/// This is disabled by default, and every update is written as soon as the
+/// signal it depends on changes. Enabling it avoids unnecessary style
+/// recalculations when many attributes change at once (for example, on a
+/// dashboard with many bound attributes), but code that reads the DOM right
+/// after setting a signal will see the old values until
+/// [`flush_dom_writes`] is called. Disabling it flushes any pending writes.
+pub fn buffer_dom_writes(enabled: bool) {
+    BUFFER_WRITES.with(|buffer| buffer.set(enabled));
+    if !enabled {
+        flush_dom_writes();
+    }
+}
+
+/// Applies all pending updates to reactive attributes, classes, and styles
+/// immediately.
+///
+/// When [`buffer_dom_writes`] is enabled, the attributes, classes, and styles
+/// that depend on a signal are not written to the DOM as soon as it changes;
+/// instead, they are written together in a single pass at the end of the
+/// current microtask, in the order of their last change. Call this function
+/// if you need to read the updated DOM synchronously (for example, to measure
+/// an element right after changing its class).
+pub fn flush_dom_writes() {
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    FLUSH_SCHEDULED.with(|scheduled| scheduled.set(false));
+    let writes = PENDING_WRITES.with(|pending| pending.take());
+    for write in writes {
+        write();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // mirrors how `class=` and `class:foo` write to the same class list
+    #[test]
+    fn class_and_class_name_writes_replay_in_last_write_order() {
+        let class_list = Rc::new(RefCell::new(Vec::<&str>::new()));
+        let set_class = {
+            let class_list = Rc::clone(&class_list);
+            move || {
+                let class_list = Rc::clone(&class_list);
+                move |names: Vec<&'static str>| {
+                    let mut class_list = class_list.borrow_mut();
+                    class_list.retain(|name| names.contains(name));
+                    for name in names {
+                        if !class_list.contains(&name) {
+                            class_list.push(name);
+                        }
+                    }
+                }
+            }
+        };
+        let set_foo = {
+            let class_list = Rc::clone(&class_list);
+            move || {
+                let class_list = Rc::clone(&class_list);
+                move |on: bool| {
+                    let mut class_list = class_list.borrow_mut();
+                    class_list.retain(|name| *name != "foo");
+                    if on {
+                        class_list.push("foo");
+                    }
+                }
+            }
+        };
+        let class = WriteSlot::default();
+        let foo = WriteSlot::default();
+
+        buffer_dom_writes(true);
+        class.queue(vec!["a", "foo"], set_class());
+        foo.queue(false, set_foo());
+        class.queue(vec!["a"], set_class());
+        foo.queue(true, set_foo());
+        assert!(class_list.borrow().is_empty());
+        flush_dom_writes();
+        // `class=` was written first and `class:foo` last
+        assert_eq!(*class_list.borrow(), ["a", "foo"]);
+
+        class.queue(vec!["b"], set_class());
+        foo.queue(false, set_foo());
+        foo.queue(true, set_foo());
+        class.queue(vec!["c"], set_class());
+        flush_dom_writes();
+        // `class=` was written last, so it removes `foo`
+        assert_eq!(*class_list.borrow(), ["c"]);
+
+        buffer_dom_writes(false);
+        foo.queue(true, set_foo());
+        assert_eq!(*class_list.borrow(), ["c", "foo"]);
+    }
+}
@@ -0,0 +1,285 @@
+use crate::IntoView;
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+use crate::{HydrationCtx, Mountable, View};
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+use leptos_reactive::{as_child_of_current_owner, Disposer, Owner};
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+use std::cell::RefCell;
+use std::fmt;
+
+/// A handle to a root mounted with [`mount_root`], [`mount_root_to_body`], or
+/// [`mount_to_multiple`].
+///
+/// Each root has its own reactive owner, so context provided inside one root
+/// is not visible in the others, but all roots share the same reactive
+/// runtime, so they can share signals created outside of them.
+///
+/// A root can be taken off the page with [`unmount`](MountedApp::unmount)
+/// and put back with [`remount`](MountedApp::remount) without losing any of
+/// its state. Dropping the handle leaves the root mounted; call
+/// [`dispose`](MountedApp::dispose) to remove it for good.
+pub struct MountedApp {
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    root: Option<Root>,
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+struct Root {
+    view: View,
+    opening: web_sys::Node,
+    closing: web_sys::Node,
+    parent: web_sys::Node,
+    owner: Option<Owner>,
+    disposer: Disposer,
+    // the nodes of an unmounted root, and the node they were mounted before
+    detached:
+        RefCell<Option<(web_sys::DocumentFragment, Option<web_sys::Node>)>>,
+}
+
+impl MountedApp {
+    /// Takes the root’s nodes off the page. Its reactive state is kept, so
+    /// signals and resources keep their values until it is remounted.
+    pub fn unmount(&self) {
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        if let Some(root) = &self.root {
+            let mut detached = root.detached.borrow_mut();
+            if detached.is_none() {
+                let next = root.closing.next_sibling();
+                let frag = crate::document().create_document_fragment();
+                crate::prepare_to_move(&frag, &root.opening, &root.closing);
+                *detached = Some((frag, next));
+            }
+        }
+    }
+
+    /// Puts the nodes of a root that was unmounted with
+    /// [`unmount`](MountedApp::unmount) back where they were.
+    pub fn remount(&self) {
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        if let Some(root) = &self.root {
+            if let Some((frag, next)) = root.detached.take() {
+                // the node it was mounted before may have been removed since
+                let next = next.filter(|next| {
+                    next.parent_node().as_ref() == Some(&root.parent)
+                });
+                root.parent
+                    .insert_before(&frag, next.as_ref())
+                    .expect("remounting to not err");
+            }
+        }
+    }
+
+    /// Whether the root is on the page, i.e., it hasn’t been
+    /// [unmounted](MountedApp::unmount).
+    pub fn is_mounted(&self) -> bool {
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        {
+            self.root
+                .as_ref()
+                .is_some_and(|root| root.detached.borrow().is_none())
+        }
+        #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+        {
+            false
+        }
+    }
+
+    /// Like [`dispose`](MountedApp::dispose), but first runs `save` in the
+    /// root’s reactive owner, so that it can read context and signals, and
+    /// returns what it returns.
+    ///
+    /// This can be used to carry state over to the next root, for example
+    /// when an app is replaced after a hot reload.
+    pub fn dispose_with<T>(self, save: impl FnOnce() -> T) -> T {
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        let value = match self.root.as_ref().and_then(|root| root.owner) {
+            Some(owner) => leptos_reactive::with_owner(owner, save),
+            None => save(),
+        };
+        #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+        let value = save();
+        self.dispose();
+        value
+    }
+
+    /// Removes the root’s nodes from the page, and disposes of everything
+    /// that was created in it, running its cleanup functions.
+    pub fn dispose(self) {
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        {
+            let mut this = self;
+            if let Some(root) = this.root.take() {
+                let frag = crate::document().create_document_fragment();
+                crate::prepare_to_move(&frag, &root.opening, &root.closing);
+                drop(root.disposer);
+                drop(root.view);
+            }
+        }
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+impl Drop for MountedApp {
+    fn drop(&mut self) {
+        // like `mount_to`, keep the root alive for the rest of the app
+        if let Some(root) = self.root.take() {
+            std::mem::forget(root);
+        }
+    }
+}
+
+impl fmt::Debug for MountedApp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MountedApp").finish_non_exhaustive()
+    }
+}
+
+/// Runs the provided closure and mounts the result to the provided element,
+/// like [`mount_to`](crate::mount_to), as an independent root that can be
+/// disposed of later.
+///
+/// The closure runs with its own reactive owner, so that several roots can be
+/// mounted on the same page, sharing one reactive runtime, without their
+/// context or cleanup functions affecting each other.
+pub fn mount_root<F, N>(parent: web_sys::HtmlElement, f: F) -> MountedApp
+where
+    F: FnOnce() -> N + 'static,
+    N: IntoView,
+{
+    cfg_if::cfg_if! {
+      if #[cfg(all(target_arch = "wasm32", feature = "web"))] {
+            let ((view, owner), disposer) =
+                as_child_of_current_owner(|f: F| {
+                    (f().into_view(), Owner::current())
+                })(f);
+            HydrationCtx::stop_hydrating();
+            let opening = view.get_opening_node();
+            let closing = view.get_closing_node();
+            if cfg!(feature = "csr") {
+                parent.append_child(&view.get_mountable_node()).unwrap();
+            }
+            MountedApp {
+                root: Some(Root {
+                    view,
+                    opening,
+                    closing,
+                    parent: parent.into(),
+                    owner,
+                    disposer,
+                    detached: Default::default(),
+                }),
+            }
+      } else {
+        _ = parent;
+        _ = f;
+        crate::warn!("`mount_root` should not be called outside the browser.");
+        MountedApp {}
+      }
+    }
+}
+
+/// Runs the provided closure and mounts the result to the `<body>`, like
+/// [`mount_to_body`](crate::mount_to_body), and returns a handle to it.
+///
+/// With the handle, embedding environments like test runners, component
+/// explorers, or hot-reloading harnesses can unmount, remount, and dispose
+/// of the app, without leaking anything it created.
+///
+/// ```rust
+/// # use leptos::*;
+/// # if false {
+/// #[component]
+/// fn App(#[prop(optional)] initial: i32) -> impl IntoView {
+///     let count = create_rw_signal(initial);
+///     provide_context(count);
+///     view! {
+///         <button on:click=move |_| count.update(|n| *n += 1)>{count}</button>
+///     }
+/// }
+///
+/// let app = mount_root_to_body(|| view! { <App/> });
+/// // keep the count when the app is replaced
+/// let count = app.dispose_with(|| {
+///     expect_context::<RwSignal<i32>>().get_untracked()
+/// });
+/// let app = mount_root_to_body(move || view! { <App initial=count/> });
+/// # }
+/// ```
+pub fn mount_root_to_body<F, N>(f: F) -> MountedApp
+where
+    F: FnOnce() -> N + 'static,
+    N: IntoView,
+{
+    cfg_if::cfg_if! {
+      if #[cfg(all(target_arch = "wasm32", feature = "web"))] {
+        mount_root(crate::document().body().expect("body element to exist"), f)
+      } else {
+        _ = f;
+        crate::warn!(
+            "`mount_root_to_body` should not be called outside the browser."
+        );
+        MountedApp {}
+      }
+    }
+}
+
+/// Mounts a separate root, with [`mount_root`], to each element on the page
+/// that matches the CSS `selector`.
+///
+/// This makes it possible to adopt Leptos incrementally in a page that was
+/// rendered by another framework: each widget on the page gets its own
+/// root, and `f` is called with the element it is mounted to, so the view
+/// can read its attributes for configuration.
+///
+/// ```rust
+/// # use leptos::*;
+/// # if false {
+/// let count = create_rw_signal(0);
+/// // every widget shares the same signal
+/// let widgets = mount_to_multiple(".counter-widget", move |el| {
+///     let label = el.get_attribute("data-label").unwrap_or_default();
+///     view! {
+///         <button on:click=move |_| count.update(|n| *n += 1)>
+///             {label} ": " {count}
+///         </button>
+///     }
+/// });
+/// // later, remove the widgets from the page
+/// for widget in widgets {
+///     widget.dispose();
+/// }
+/// # }
+/// ```
+pub fn mount_to_multiple<F, N>(selector: &str, f: F) -> Vec<MountedApp>
+where
+    F: Fn(web_sys::HtmlElement) -> N + 'static,
+    N: IntoView,
+{
+    cfg_if::cfg_if! {
+      if #[cfg(all(target_arch = "wasm32", feature = "web"))] {
+            use std::rc::Rc;
+            use wasm_bindgen::JsCast;
+
+            let Ok(elements) = crate::document().query_selector_all(selector)
+            else {
+                crate::error!("invalid selector `{selector}`");
+                return Vec::new();
+            };
+            let f = Rc::new(f);
+            (0..elements.length())
+                .filter_map(|i| elements.item(i)?.dyn_into().ok())
+                .map(|el: web_sys::HtmlElement| {
+                    let f = Rc::clone(&f);
+                    mount_root(el.clone(), move || f(el))
+                })
+                .collect()
+      } else {
+        _ = selector;
+        _ = f;
+        crate::warn!(
+            "`mount_to_multiple` should not be called outside the browser."
+        );
+        Vec::new()
+      }
+    }
+}
@@ -2,18 +2,63 @@
 
 //! Server-side HTML rendering utilities.
 
+pub use crate::buffer_pool::{
+    buffer_pool_stats, reset_buffer_pool_stats, set_buffer_pool_enabled,
+    BufferPoolStats,
+};
 use crate::{
+    buffer_pool,
+    escape::{self, escape_text_oco},
     html::{ElementChildren, StringOrView},
     CoreComponent, HydrationCtx, HydrationKey, IntoView, View,
 };
 use cfg_if::cfg_if;
-use futures::{stream::FuturesUnordered, Future, Stream, StreamExt};
+use futures::{stream::FuturesUnordered, Future, FutureExt, Stream, StreamExt};
 use itertools::Itertools;
 use leptos_reactive::*;
-use std::pin::Pin;
+use std::{cell::RefCell, fmt::Write, pin::Pin, rc::Rc};
 
 type PinnedFuture<T> = Pin<Box<dyn Future<Output = T>>>;
 
+/// Renders the attributes and classes spread onto an element with
+/// `{..bindings}` in the `view` macro. Event handlers are skipped, and the
+/// classes that are present are rendered in a `class` attribute of their own.
+#[doc(hidden)]
+pub fn render_spread_bindings<B: Into<crate::html::Binding>>(
+    bindings: impl IntoIterator<Item = B>,
+) -> String {
+    use crate::html::Binding;
+
+    let mut attrs = Vec::new();
+    let mut classes = Vec::new();
+    for binding in bindings {
+        match binding.into() {
+            Binding::Attribute { name, value } => {
+                if let Some(value) = value.as_nameless_value_string() {
+                    attrs.push(format!(
+                        "{name}=\"{}\"",
+                        escape::escape_attr(&value)
+                    ));
+                }
+            }
+            Binding::Class { name, value } => {
+                let class = value.as_value_string(name);
+                if !class.is_empty() {
+                    classes.push(class);
+                }
+            }
+            Binding::EventHandler(_) => {}
+        }
+    }
+    if !classes.is_empty() {
+        attrs.push(format!(
+            "class=\"{}\"",
+            escape::escape_attr(&classes.join(" "))
+        ));
+    }
+    attrs.join(" ")
+}
+
 /// Renders the given function to a static HTML string.
 ///
 /// ```
@@ -208,11 +253,27 @@ pub fn render_to_stream_with_prefix_undisposed_with_context_and_block_replacemen
 
     let mut blocking_fragments = FuturesUnordered::new();
     let fragments = FuturesUnordered::new();
+    // blocking fragments that were not ready within their `max_wait`
+    let late_fragments = Rc::new(RefCell::new(Vec::new()));
 
     for (fragment_id, data) in pending_fragments {
         if data.should_block {
-            blocking_fragments
-                .push(async move { (fragment_id, data.out_of_order.await) });
+            let late_fragments = Rc::clone(&late_fragments);
+            blocking_fragments.push(async move {
+                let mut out_of_order = data.out_of_order;
+                if let Some(is_ready) = data.is_ready {
+                    is_ready.await;
+                    if let Some(html) = (&mut out_of_order).now_or_never() {
+                        return Some((fragment_id, html));
+                    }
+                    late_fragments
+                        .borrow_mut()
+                        .push((fragment_id, out_of_order));
+                    None
+                } else {
+                    Some((fragment_id, out_of_order.await))
+                }
+            });
         } else {
             fragments.push(Box::pin(async move {
                 (fragment_id, data.out_of_order.await)
@@ -236,10 +297,8 @@ pub fn render_to_stream_with_prefix_undisposed_with_context_and_block_replacemen
                 if replace_blocks {
                     let mut blocks =
                         Vec::with_capacity(blocking_fragments.len());
-                    while let Some((blocked_id, blocked_fragment)) =
-                        blocking_fragments.next().await
-                    {
-                        blocks.push((blocked_id, blocked_fragment));
+                    while let Some(block) = blocking_fragments.next().await {
+                        blocks.extend(block);
                     }
 
                     let prefix = prefix();
@@ -255,28 +314,57 @@ pub fn render_to_stream_with_prefix_undisposed_with_context_and_block_replacemen
                         let (_fallback, rest) =
                             rest.split_once(&close).unwrap_or_default();
 
-                        shell =
-                            format!("{first}{blocked_fragment}{rest}").into();
+                        let mut replaced = buffer_pool::take(
+                            first.len() + blocked_fragment.len() + rest.len(),
+                        );
+                        replaced.push_str(first);
+                        replaced.push_str(&blocked_fragment);
+                        replaced.push_str(rest);
+                        buffer_pool::recycle(blocked_fragment);
+                        buffer_pool::recycle_oco(std::mem::replace(
+                            &mut shell,
+                            replaced.into(),
+                        ));
                     }
 
-                    format!("{prefix}{shell}{resolvers}")
+                    let html = concat_chunk(&[&prefix, &shell, &resolvers]);
+                    buffer_pool::recycle_oco(shell);
+                    buffer_pool::recycle(resolvers);
+                    html
                 } else {
-                    let mut blocking = String::new();
-                    let mut blocking_fragments = fragments_to_chunks(
+                    let blocking = fragments_to_chunks(
                         nonce_str.clone(),
-                        blocking_fragments,
-                    );
-
-                    while let Some(fragment) = blocking_fragments.next().await {
-                        blocking.push_str(&fragment);
-                    }
+                        blocking_fragments.filter_map(futures::future::ready),
+                    )
+                    .collect::<Vec<_>>()
+                    .await;
                     let prefix = prefix();
-                    format!("{prefix}{shell}{resolvers}{blocking}")
+                    let mut parts = vec![&*prefix, &*shell, &*resolvers];
+                    parts.extend(blocking.iter().map(String::as_str));
+                    let html = concat_chunk(&parts);
+                    buffer_pool::recycle_oco(shell);
+                    buffer_pool::recycle(resolvers);
+                    for fragment in blocking {
+                        buffer_pool::recycle(fragment);
+                    }
+                    html
                 }
             }
         },
     )
-    .chain(ooo_body_stream_recurse(nonce_str, fragments, serializers));
+    .chain(
+        futures::stream::once(async move {
+            // the fallbacks of late fragments were sent, so they're streamed
+            for (fragment_id, out_of_order) in late_fragments.take() {
+                fragments.push(Box::pin(async move {
+                    (fragment_id, out_of_order.await)
+                })
+                    as Pin<Box<dyn Future<Output = (String, String)>>>);
+            }
+            ooo_body_stream_recurse(nonce_str, fragments, serializers)
+        })
+        .flatten(),
+    );
 
     (stream, runtime)
 }
@@ -325,6 +413,19 @@ fn ooo_body_stream_recurse(
     )
 }
 
+/// Roughly the length of the script that swaps a resolved fragment into place.
+const FRAGMENT_SCRIPT_LEN: usize = 1024;
+
+// joins the parts of a chunk into a buffer of exactly its size
+pub(crate) fn concat_chunk(parts: &[&str]) -> String {
+    let mut buf =
+        String::with_capacity(parts.iter().map(|part| part.len()).sum());
+    for part in parts {
+        buf.push_str(part);
+    }
+    buf
+}
+
 #[cfg_attr(
     any(debug_assertions, feature = "ssr"),
     instrument(level = "trace", skip_all,)
@@ -334,7 +435,11 @@ fn fragments_to_chunks(
     fragments: impl Stream<Item = (String, String)>,
 ) -> impl Stream<Item = String> {
     fragments.map(move |(fragment_id, html)| {
-      format!(
+      // the chunk is written into a pooled buffer and then copied, so that
+      // both that buffer and the fragment's HTML can be reused
+      let mut scratch = buffer_pool::take(html.len() + FRAGMENT_SCRIPT_LEN);
+      _ = write!(
+        scratch,
         r#"
                 <template id="{fragment_id}f">{html}</template>
                 <script{nonce_str}>
@@ -357,7 +462,11 @@ fn fragments_to_chunks(
                     close.parentNode.insertBefore(tpl.content.cloneNode(true), close);}})()
                 </script>
                 "#
-      )
+      );
+      buffer_pool::recycle(html);
+      let chunk = concat_chunk(&[&scratch]);
+      buffer_pool::recycle(scratch);
+      chunk
     })
 }
 
@@ -398,7 +507,7 @@ impl View {
                 if dont_escape_text {
                     node.content
                 } else {
-                    html_escape::encode_safe(&node.content).to_string().into()
+                    escape_text_oco(node.content)
                 }
             }
             View::Component(node) => {
@@ -476,9 +585,7 @@ impl View {
                                         let content = if dont_escape_text {
                                             content
                                         } else {
-                                            html_escape::encode_safe(&content)
-                                                .to_string()
-                                                .into()
+                                            escape_text_oco(content)
                                         };
                                         // On debug builds, `DynChild` has two marker nodes,
                                         // so there is no way for the text to be merged with
@@ -609,7 +716,7 @@ impl View {
                                     Some(
                                         format!(
                                             " {name}=\"{}\"",
-                                            html_escape::encode_double_quoted_attribute(&value)
+                                            escape::escape_attr(&value)
                                         )
                                         .into(),
                                     )
@@ -706,18 +813,30 @@ pub(crate) fn render_serializers(
 ) -> impl Stream<Item = String> {
     serializers.map(move |(id, json)| {
         let id = serde_json::to_string(&id).unwrap();
-        let json = json.replace('<', "\\u003c");
+        let mut escaped = buffer_pool::take(json.len() + 16);
+        for (i, part) in json.split('<').enumerate() {
+            if i > 0 {
+                escaped.push_str("\\u003c");
+            }
+            escaped.push_str(part);
+        }
+        buffer_pool::recycle(json);
 
-        format!(
+        // the JSON is escaped again as a string literal, so leave some room
+        let mut chunk = String::with_capacity(escaped.len() * 5 / 4 + 256);
+        _ = write!(
+            chunk,
             r#"<script{nonce_str}>
-                  (function() {{ let val = {json:?};
+                  (function() {{ let val = {escaped:?};
                   if(__LEPTOS_RESOURCE_RESOLVERS.get({id})) {{
                       __LEPTOS_RESOURCE_RESOLVERS.get({id})(val)
                   }} else {{
                       __LEPTOS_RESOLVED_RESOURCES.set({id}, val);
                   }} }})();
               </script>"#,
-        )
+        );
+        buffer_pool::recycle(escaped);
+        chunk
     })
 }
 
@@ -726,7 +845,15 @@ pub fn escape_attr<T>(value: &T) -> Oco<'_, str>
 where
     T: AsRef<str>,
 {
-    html_escape::encode_double_quoted_attribute(value).into()
+    escape::escape_attr(value.as_ref()).into()
+}
+
+#[doc(hidden)]
+pub fn escape_text<T>(value: &T) -> Oco<'_, str>
+where
+    T: AsRef<str>,
+{
+    escape::escape_text(value.as_ref()).into()
 }
 
 pub(crate) trait ToMarker {
@@ -3,8 +3,10 @@
 //! Server-side HTML rendering utilities for in-order streaming and async rendering.
 
 use crate::{
+    buffer_pool,
+    escape::{self, escape_text_oco},
     html::{ElementChildren, StringOrView},
-    ssr::{render_serializers, ToMarker},
+    ssr::{concat_chunk, render_serializers, ToMarker},
     CoreComponent, HydrationCtx, View,
 };
 use async_recursion::async_recursion;
@@ -36,6 +38,52 @@ pub async fn render_to_string_async(
     buf
 }
 
+/// Renders a view to static HTML, waiting to return until all `async` [Resource](leptos_reactive::Resource)s
+/// loaded in `<Suspense/>` elements have finished loading.
+///
+/// Unlike [`render_to_string_async`], the HTML contains no hydration keys or markers, and no
+/// scripts to serialize resources, so it is only suitable for documents that will never be
+/// hydrated (for example, as the input to an HTML-to-PDF tool).
+///
+/// `additional_context` is injected before the `view` is rendered. The runtime is returned
+/// without being disposed, so that context set while rendering can still be read; it should be
+/// disposed by the caller.
+#[tracing::instrument(level = "trace", skip_all)]
+pub async fn render_to_static_string_undisposed_with_context(
+    view: impl FnOnce() -> View + 'static,
+    additional_context: impl FnOnce() + 'static,
+) -> (String, RuntimeId) {
+    HydrationCtx::reset_id();
+
+    let runtime = create_runtime();
+    additional_context();
+
+    let chunks =
+        HydrationCtx::without_hydration_keys(|| view().into_stream_chunks());
+    let mut buf = String::new();
+    HydrationCtx::without_hydration_keys_async(collect_static_chunks(
+        &mut buf, chunks,
+    ))
+    .await;
+
+    (buf, runtime)
+}
+
+#[async_recursion(?Send)]
+async fn collect_static_chunks(
+    buf: &mut String,
+    chunks: VecDeque<StreamChunk>,
+) {
+    for chunk in chunks {
+        match chunk {
+            StreamChunk::Sync(sync) => buf.push_str(&sync),
+            StreamChunk::Async { chunks, .. } => {
+                collect_static_chunks(buf, chunks.await).await
+            }
+        }
+    }
+}
+
 /// Renders an in-order HTML stream, pausing at `<Suspense/>` components. The stream contains,
 /// in order:
 /// 1. HTML from the `view` in order, pausing to wait for each `<Suspense/>`
@@ -163,18 +211,24 @@ async fn handle_blocking_chunks(
     tx: UnboundedSender<String>,
     mut queued_chunks: VecDeque<StreamChunk>,
 ) -> VecDeque<StreamChunk> {
-    let mut buffer = String::new();
+    // the HTML is collected in a pooled buffer, and each chunk that is sent
+    // is copied from it
+    let mut buffer = buffer_pool::take(sync_len(&queued_chunks));
     while let Some(chunk) = queued_chunks.pop_front() {
         match chunk {
-            StreamChunk::Sync(sync) => buffer.push_str(&sync),
+            StreamChunk::Sync(sync) => {
+                buffer.push_str(&sync);
+                buffer_pool::recycle_oco(sync);
+            }
             StreamChunk::Async {
                 chunks,
                 should_block,
             } => {
                 if should_block {
                     // add static HTML before the Suspense and stream it down
-                    tx.unbounded_send(std::mem::take(&mut buffer))
+                    tx.unbounded_send(concat_chunk(&[&buffer]))
                         .expect("failed to send async HTML chunk");
+                    buffer.clear();
 
                     // send the inner stream
                     let suspended = chunks.await;
@@ -192,8 +246,9 @@ async fn handle_blocking_chunks(
     }
 
     // send final sync chunk
-    tx.unbounded_send(std::mem::take(&mut buffer))
+    tx.unbounded_send(concat_chunk(&[&buffer]))
         .expect("failed to send final HTML chunk");
+    buffer_pool::recycle(buffer);
 
     queued_chunks
 }
@@ -202,16 +257,22 @@ async fn handle_blocking_chunks(
 #[async_recursion(?Send)]
 async fn handle_chunks(
     tx: UnboundedSender<String>,
-    chunks: VecDeque<StreamChunk>,
+    mut queued_chunks: VecDeque<StreamChunk>,
 ) {
-    let mut buffer = String::new();
-    for chunk in chunks {
+    // the HTML is collected in a pooled buffer, and each chunk that is sent
+    // is copied from it
+    let mut buffer = buffer_pool::take(sync_len(&queued_chunks));
+    while let Some(chunk) = queued_chunks.pop_front() {
         match chunk {
-            StreamChunk::Sync(sync) => buffer.push_str(&sync),
+            StreamChunk::Sync(sync) => {
+                buffer.push_str(&sync);
+                buffer_pool::recycle_oco(sync);
+            }
             StreamChunk::Async { chunks, .. } => {
                 // add static HTML before the Suspense and stream it down
-                tx.unbounded_send(std::mem::take(&mut buffer))
+                tx.unbounded_send(concat_chunk(&[&buffer]))
                     .expect("failed to send async HTML chunk");
+                buffer.clear();
 
                 // send the inner stream
 
@@ -222,8 +283,21 @@ async fn handle_chunks(
         }
     }
     // send final sync chunk
-    tx.unbounded_send(std::mem::take(&mut buffer))
+    tx.unbounded_send(concat_chunk(&[&buffer]))
         .expect("failed to send final HTML chunk");
+    buffer_pool::recycle(buffer);
+}
+
+// the length of the HTML before the next `<Suspense/>`, which is sent to the
+// response as one chunk
+fn sync_len(chunks: &VecDeque<StreamChunk>) -> usize {
+    chunks
+        .iter()
+        .map_while(|chunk| match chunk {
+            StreamChunk::Sync(sync) => Some(sync.len()),
+            StreamChunk::Async { .. } => None,
+        })
+        .sum()
 }
 
 impl View {
@@ -320,7 +394,7 @@ impl View {
                                     Some(
                                         format!(
                                             " {name}=\"{}\"",
-                                            html_escape::encode_double_quoted_attribute(&value)
+                                            escape::escape_attr(&value)
                                         )
                                         .into(),
                                     )
@@ -417,11 +491,7 @@ impl View {
                                             let content = if dont_escape_text {
                                                 content
                                             } else {
-                                                html_escape::encode_safe(
-                                                    &content,
-                                                )
-                                                .to_string()
-                                                .into()
+                                                escape_text_oco(content)
                                             };
                                             // On debug builds, `DynChild` has two marker nodes,
                                             // so there is no way for the text to be merged with
@@ -435,16 +505,18 @@ impl View {
                                                     StreamChunk::Sync(
                                                         format!(
                                                             "<!>{}",
-                                                            html_escape::encode_safe(
+                                                            escape::escape_text(
                                                                 &content
                                                             )
                                                         )
                                                         .into(),
                                                     )
                                                 } else {
-                                                    StreamChunk::Sync(html_escape::encode_safe(
-                                                        &content
-                                                    ).to_string().into())
+                                                    StreamChunk::Sync(
+                                                        escape_text_oco(
+                                                            content,
+                                                        ),
+                                                    )
                                                 },
                                             );
                                         } else {
@@ -60,17 +60,12 @@ macro_rules! generate_svg_tags {
               let id = id.unwrap();
               if let Some(el) = crate::hydration::get_element(&id.to_string()) {
                 #[cfg(debug_assertions)]
-                assert_eq!(
-                  el.node_name().to_ascii_uppercase(),
-                  stringify!([<$tag:upper $(_ $second:upper $(_ $third:upper)?)?>]),
-                  "SSR and CSR elements have the same hydration key but \
-                  different node kinds. Check out the docs for information \
-                  about this kind of hydration bug: https://leptos-rs.github.io/leptos/ssr/24_hydration_bugs.html"
-                );
+                crate::hydration::check_element(&id, stringify!([<$tag:upper $(_ $second:upper $(_ $third:upper)?)?>]), &el);
                 el.unchecked_into()
               } else {
                 crate::warn!(
-                  "element with id {id} not found, ignoring it for hydration"
+                  "element with id {id} not found, ignoring it for hydration{}",
+                  leptos_reactive::ComponentStack::current()
                 );
 
                 [<$tag:upper $(_ $second:upper $(_ $third:upper)?)?>]
@@ -22,6 +22,7 @@ pub struct Model {
     vis: Visibility,
     name: Ident,
     props: Vec<Prop>,
+    route_props: Vec<Prop>,
     body: ItemFn,
     ret: ReturnType,
 }
@@ -33,13 +34,26 @@ impl Parse for Model {
 
         let docs = Docs::new(&item.attrs);
 
-        let props = item
+        let (route_props, props): (Vec<_>, Vec<_>) = item
             .sig
             .inputs
             .clone()
             .into_iter()
             .map(Prop::new)
-            .collect::<Vec<_>>();
+            .partition(|prop| prop.prop_opts.param || prop.prop_opts.query);
+
+        // route components are rendered by the router without any props, so
+        // they can't take props that are not read from the URL
+        if let (Some(route_prop), Some(prop)) =
+            (route_props.first(), props.first())
+        {
+            abort!(
+                prop.name,
+                "a component with `#[prop(param)]` or `#[prop(query)]` \
+                 props can't take any other props";
+                note = route_prop.name.span() => "this prop is read from the URL"
+            );
+        }
 
         // We need to remove the `#[doc = ""]` and `#[builder(_)]`
         // attrs from the function signature
@@ -74,6 +88,7 @@ impl Parse for Model {
             vis: item.vis.clone(),
             name: convert_from_snake_case(&item.sig.ident),
             props,
+            route_props,
             ret: item.sig.output.clone(),
             body: item,
         })
@@ -114,10 +129,19 @@ impl ToTokens for Model {
             vis,
             name,
             props,
+            route_props,
             body,
             ret,
         } = self;
 
+        if *is_island && !route_props.is_empty() {
+            abort!(
+                route_props[0].name,
+                "`#[prop(param)]` and `#[prop(query)]` are not supported in \
+                 islands"
+            );
+        }
+
         let no_props = props.is_empty();
 
         // check for components that end ;
@@ -246,7 +270,9 @@ impl ToTokens for Model {
         };
 
         let body_name = unmodified_fn_name_from_fn_name(&body_name);
-        let body_expr = if *is_island {
+        let body_expr = if !route_props.is_empty() {
+            route_props_body_expr(route_props, &body_name)
+        } else if *is_island {
             quote! {
                 ::leptos::SharedContext::with_hydration(move || {
                     #body_name(#prop_names)
@@ -273,17 +299,34 @@ impl ToTokens for Model {
             }
         };
 
+        // islands rendered on the server are reported to the island report hook
+        let report_island = if cfg!(feature = "ssr") {
+            let props_bytes = if is_island_with_other_props {
+                quote! { _leptos_ser_props.len() }
+            } else {
+                quote! { 0 }
+            };
+            quote! {
+                ::leptos::report_island(#component_id, _leptos_hkc, #props_bytes);
+            }
+        } else {
+            quote! {}
+        };
+
         // add island wrapper if island
         let component = if *is_island {
             quote! {
                 {
-                    ::leptos::leptos_dom::html::custom(
+                    let _leptos_island = ::leptos::leptos_dom::html::custom(
                         ::leptos::leptos_dom::html::Custom::new("leptos-island"),
-                    )
-                    .attr("data-component", #component_id)
-                    .attr("data-hkc", ::leptos::leptos_dom::HydrationCtx::peek_always().to_string())
-                    #island_serialized_props
-                    .child(#component)
+                    );
+                    let _leptos_hkc = ::leptos::leptos_dom::HydrationCtx::peek_always();
+                    #report_island
+                    _leptos_island
+                        .attr("data-component", #component_id)
+                        .attr("data-hkc", _leptos_hkc.to_string())
+                        #island_serialized_props
+                        .child(#component)
                 }
             }
         } else {
@@ -487,6 +530,14 @@ impl ToTokens for Model {
                 quote! {}
             };
 
+            let provide_island_props = if is_island_with_other_props {
+                quote! {
+                    ::leptos::provide_island_props(&el);
+                }
+            } else {
+                quote! {}
+            };
+
             quote! {
                 #[::leptos::wasm_bindgen::prelude::wasm_bindgen(wasm_bindgen = ::leptos::wasm_bindgen)]
                 #[allow(non_snake_case)]
@@ -497,6 +548,7 @@ impl ToTokens for Model {
                     #deserialize_island_props
                     _ = ::leptos::run_as_child(move || {
                         ::leptos::SharedContext::register_island(&el);
+                        #provide_island_props
                         ::leptos::leptos_dom::mount_to_with_stop_hydrating(el, false, move || {
                             #name(#island_props)
                         })
@@ -873,6 +925,10 @@ struct PropOpt {
     default: Option<syn::Expr>,
     into: bool,
     attrs: bool,
+    #[attribute(conflicts = [query])]
+    param: bool,
+    #[attribute(conflicts = [param])]
+    query: bool,
 }
 
 struct TypedBuilderOpts {
@@ -880,15 +936,27 @@ struct TypedBuilderOpts {
     default_with_value: Option<syn::Expr>,
     strip_option: bool,
     into: bool,
+    tuple_callback_setter: Option<TokenStream>,
 }
 
 impl TypedBuilderOpts {
-    fn from_opts(opts: &PropOpt, is_ty_option: bool) -> Self {
+    fn from_opts(opts: &PropOpt, ty: &Type) -> Self {
+        let is_ty_option = is_option(ty);
+        let strip_option = opts.strip_option || opts.optional && is_ty_option;
+        let setter_ty = if strip_option && is_ty_option {
+            unwrap_option(ty)
+        } else {
+            ty.clone()
+        };
         Self {
             default: opts.optional || opts.optional_no_strip || opts.attrs,
             default_with_value: opts.default.clone(),
-            strip_option: opts.strip_option || opts.optional && is_ty_option,
+            strip_option,
             into: opts.into,
+            tuple_callback_setter: opts
+                .into
+                .then(|| tuple_callback_setter(&setter_ty, strip_option))
+                .flatten(),
         }
     }
 }
@@ -935,7 +1003,9 @@ impl ToTokens for TypedBuilderOpts {
             quote! {}
         };
 
-        let setter = if !strip_option.is_empty() || !into.is_empty() {
+        let setter = if let Some(setter) = &self.tuple_callback_setter {
+            setter.clone()
+        } else if !strip_option.is_empty() || !into.is_empty() {
             quote! { setter(#strip_option #into) }
         } else {
             quote! {}
@@ -966,8 +1036,7 @@ fn prop_builder_fields(
                 ty,
             } = prop;
 
-            let builder_attrs =
-                TypedBuilderOpts::from_opts(prop_opts, is_option(ty));
+            let builder_attrs = TypedBuilderOpts::from_opts(prop_opts, ty);
 
             let builder_docs = prop_to_doc(prop, PropDocStyle::Inline);
 
@@ -1012,8 +1081,7 @@ fn prop_serializer_fields(vis: &Visibility, props: &[Prop]) -> TokenStream {
                     ty,
                 } = prop;
 
-                let builder_attrs =
-                    TypedBuilderOpts::from_opts(prop_opts, is_option(ty));
+                let builder_attrs = TypedBuilderOpts::from_opts(prop_opts, ty);
                 let serde_attrs = builder_attrs.to_serde_tokens();
 
                 let PatIdent { ident, by_ref, .. } = &name;
@@ -1040,6 +1108,78 @@ fn prop_names(props: &[Prop]) -> TokenStream {
         .collect()
 }
 
+/// Reads the `#[prop(param)]` and `#[prop(query)]` props of a route
+/// component from the URL, and renders the component again whenever they
+/// change. If one of them can't be parsed, the `ParamsError` is rendered
+/// instead, so that it is caught by the nearest `<ErrorBoundary/>`.
+fn route_props_body_expr(
+    route_props: &[Prop],
+    body_name: &Ident,
+) -> TokenStream {
+    let prop_names = prop_names(route_props);
+    let parse_props = route_props
+        .iter()
+        .map(
+            |Prop {
+                 name,
+                 ty,
+                 prop_opts,
+                 ..
+             }| {
+                let key = name.ident.to_string();
+                let map = if prop_opts.param {
+                    quote! { __params }
+                } else {
+                    quote! { __query }
+                };
+                let value = quote! {
+                    #map.get(#key).map(::std::string::String::as_str)
+                };
+                // `IntoParam` is only implemented for types that aren't
+                // `Option`s on nightly, so required values are parsed as
+                // `Option`s and then unwrapped
+                if is_option(ty) {
+                    quote_spanned! {name.span()=>
+                        <#ty as ::leptos_router::IntoParam>::into_param(
+                            #value,
+                            #key,
+                        )?,
+                    }
+                } else {
+                    quote_spanned! {name.span()=>
+                        <::std::option::Option<#ty> as ::leptos_router::IntoParam>::into_param(
+                            #value,
+                            #key,
+                        )?
+                        .ok_or_else(|| ::leptos_router::ParamsError::MissingParam(
+                            ::std::string::ToString::to_string(#key),
+                        ))?,
+                    }
+                }
+            },
+        )
+        .collect::<TokenStream>();
+
+    quote! {{
+        let __params = ::leptos_router::use_params_map();
+        let __query = ::leptos_router::use_query_map();
+        let __route_props = ::leptos::create_memo(move |_| {
+            ::leptos::SignalWith::with(&__params, |__params| {
+                ::leptos::SignalWith::with(&__query, |__query| -> ::std::result::Result<_, ::leptos_router::ParamsError> {
+                    ::std::result::Result::Ok((#parse_props))
+                })
+            })
+        });
+        move || {
+            ::leptos::SignalGet::get(&__route_props).map(|(#prop_names)| {
+                ::leptos::untrack(move || {
+                    ::leptos::IntoView::into_view(#body_name(#prop_names))
+                })
+            })
+        }
+    }}
+}
+
 fn generate_component_fn_prop_docs(props: &[Prop]) -> TokenStream {
     let required_prop_docs = props
         .iter()
@@ -1134,6 +1274,81 @@ pub fn unwrap_option(ty: &Type) -> Type {
     );
 }
 
+/// If the type is a callback that takes a tuple of several arguments, like
+/// `Callback<(A, B), Out>`, returns the types of the arguments and the output,
+/// so that a closure that takes the arguments one by one can be converted
+/// into it.
+pub fn tuple_callback_args(ty: &Type) -> Option<(Vec<Type>, Type)> {
+    let Type::Path(TypePath { path, .. }) = ty else {
+        return None;
+    };
+    let last = path.segments.last()?;
+    if last.ident != "Callback" && last.ident != "SyncCallback" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(AngleBracketedGenericArguments {
+        args,
+        ..
+    }) = &last.arguments
+    else {
+        return None;
+    };
+    let mut args = args.iter();
+    let Some(GenericArgument::Type(Type::Tuple(input))) = args.next() else {
+        return None;
+    };
+    if input.elems.len() < 2 {
+        return None;
+    }
+    let output = match args.next() {
+        Some(GenericArgument::Type(output)) => output.clone(),
+        _ => parse_quote!(()),
+    };
+    Some((input.elems.iter().cloned().collect(), output))
+}
+
+/// The setter of a `#[prop(into)]` prop with a callback type that takes a
+/// tuple of several arguments, which takes a closure that takes the arguments
+/// one by one. `Into` can't be implemented for those closures, because it is
+/// already implemented for any closure that takes one argument.
+pub fn tuple_callback_setter(
+    ty: &Type,
+    strip_option: bool,
+) -> Option<TokenStream> {
+    let (args, output) = tuple_callback_args(ty)?;
+    let names = (0..args.len())
+        .map(|i| format_ident!("arg{i}"))
+        .collect::<Vec<_>>();
+    let callback = quote! {
+        <#ty>::new(move |(#(#names,)*)| value(#(#names),*))
+    };
+    let callback = if strip_option {
+        quote! { ::core::option::Option::Some(#callback) }
+    } else {
+        callback
+    };
+    Some(quote! {
+        setter(transform = |value: impl ::core::ops::Fn(#(#args),*) -> #output + 'static| #callback)
+    })
+}
+
+/// Formats the type like it is written in Rust code.
+pub fn pretty_type(ty: &Type) -> String {
+    let type_item: syn::Item = parse_quote! {
+        type SomeType = #ty;
+    };
+
+    let file = syn::File {
+        shebang: None,
+        attrs: vec![],
+        items: vec![type_item],
+    };
+
+    let pretty_ty = prettyplease::unparse(&file);
+
+    pretty_ty[16..pretty_ty.len() - 2].to_string()
+}
+
 #[derive(Clone, Copy)]
 enum PropDocStyle {
     List,
@@ -1156,24 +1371,23 @@ fn prop_to_doc(
         ty.to_owned()
     };
 
-    let type_item: syn::Item = parse_quote! {
-        type SomeType = #ty;
-    };
-
-    let file = syn::File {
-        shebang: None,
-        attrs: vec![],
-        items: vec![type_item],
-    };
-
-    let pretty_ty = prettyplease::unparse(&file);
+    let pretty_ty = pretty_type(&ty);
+    let pretty_ty = pretty_ty.as_str();
 
-    let pretty_ty = &pretty_ty[16..&pretty_ty.len() - 2];
+    // a callback that takes several arguments is set with a closure that
+    // takes them one by one
+    let closure_ty = tuple_callback_args(&ty).filter(|_| prop_opts.into).map(
+        |(args, output)| {
+            pretty_type(&parse_quote!(impl Fn(#(#args),*) -> #output))
+        },
+    );
 
     match style {
         PropDocStyle::List => {
             let arg_ty_doc = LitStr::new(
-                &if !prop_opts.into {
+                &if let Some(closure_ty) = &closure_ty {
+                    format!(" - **{}**: `{closure_ty}`", quote!(#name))
+                } else if !prop_opts.into {
                     format!(" - **{}**: [`{pretty_ty}`]", quote!(#name))
                 } else {
                     format!(
@@ -1193,7 +1407,13 @@ fn prop_to_doc(
         }
         PropDocStyle::Inline => {
             let arg_ty_doc = LitStr::new(
-                &if !prop_opts.into {
+                &if let Some(closure_ty) = &closure_ty {
+                    format!(
+                        "**{}**: `{closure_ty}`{}",
+                        quote!(#name),
+                        docs.typed_builder()
+                    )
+                } else if !prop_opts.into {
                     format!(
                         "**{}**: [`{}`]{}",
                         quote!(#name),
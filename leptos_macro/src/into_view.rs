@@ -0,0 +1,86 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{spanned::Spanned, Data, DeriveInput, Fields};
+
+pub fn into_view_impl(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &ast.ident;
+    let Data::Enum(data) = &ast.data else {
+        return Err(syn::Error::new_spanned(
+            &ast.ident,
+            "`IntoView` can only be derived for enums",
+        ));
+    };
+
+    let mut field_types = Vec::new();
+    let arms = data
+        .variants
+        .iter()
+        .map(|variant| {
+            let ident = &variant.ident;
+            let span = variant.span();
+            match &variant.fields {
+                Fields::Unit => Ok(quote_spanned! {span=>
+                    Self::#ident => ::leptos::View::default()
+                }),
+                Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                    field_types.push(&fields.unnamed[0].ty);
+                    Ok(quote_spanned! {span=>
+                        Self::#ident(view) => ::leptos::IntoView::into_view(view)
+                    })
+                }
+                Fields::Named(fields) if fields.named.len() == 1 => {
+                    let field = &fields.named[0];
+                    let field_name = &field.ident;
+                    field_types.push(&field.ty);
+                    Ok(quote_spanned! {span=>
+                        Self::#ident { #field_name: view } =>
+                            ::leptos::IntoView::into_view(view)
+                    })
+                }
+                _ => Err(syn::Error::new_spanned(
+                    variant,
+                    "each variant of an enum that derives `IntoView` must \
+                     have a single field, which is rendered, or no fields, \
+                     which renders nothing",
+                )),
+            }
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let (impl_generics, ty_generics, where_clause) =
+        ast.generics.split_for_impl();
+    let mut where_clause = where_clause
+        .cloned()
+        .unwrap_or_else(|| syn::parse_quote!(where));
+    // only bound the fields that use the enum's type parameters, so the
+    // errors for a concrete type that is not a view point at the variant
+    where_clause.predicates.extend(
+        field_types
+            .into_iter()
+            .filter(|ty| uses_type_params(ty, &ast.generics))
+            .map(|ty| -> syn::WherePredicate {
+                syn::parse_quote!(#ty: ::leptos::IntoView)
+            }),
+    );
+
+    Ok(quote! {
+        impl #impl_generics ::leptos::IntoView for #name #ty_generics
+        #where_clause
+        {
+            fn into_view(self) -> ::leptos::View {
+                match self {
+                    #(#arms,)*
+                }
+            }
+        }
+    })
+}
+
+fn uses_type_params(ty: &syn::Type, generics: &syn::Generics) -> bool {
+    let ty = quote!(#ty).to_string();
+    generics.type_params().any(|param| {
+        let param = param.ident.to_string();
+        ty.split(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .any(|segment| segment == param)
+    })
+}
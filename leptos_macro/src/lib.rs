@@ -35,13 +35,16 @@ impl Default for Mode {
     }
 }
 
+mod into_view;
 mod params;
+mod theme;
 mod view;
 use crate::component::unmodified_fn_name_from_fn_name;
 use view::{client_template::render_template, render_view};
 mod component;
 mod slice;
 mod slot;
+mod store;
 
 /// The `view` macro uses RSX (like JSX, but Rust!) It follows most of the
 /// same rules as HTML, with the following differences:
@@ -137,6 +140,45 @@ mod slot;
 /// # runtime.dispose();
 /// ```
 ///
+///    Adding `:target` to the event name hands the handler an `ev::Targeted`, whose
+///    `target()` is the element the listener was added to, already cast to its `web_sys` type.
+/// ```rust
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// # if !cfg!(any(feature = "csr", feature = "hydrate")) {
+/// let (name, set_name) = create_signal("Alice".to_string());
+///
+/// view! {
+///   <input
+///     type="text"
+///     on:input:target=move |ev| set_name.set(ev.target().value())
+///   />
+/// }
+/// # ;
+/// # };
+/// # runtime.dispose();
+/// ```
+///
+///    The `:capture`, `:passive`, and `:once` modifiers add the listener with those
+///    [options](https://developer.mozilla.org/en-US/docs/Web/API/EventTarget/addEventListener#options),
+///    and can be combined. Like `:undelegated`, they add the listener to the element itself,
+///    rather than delegating the event.
+/// ```rust
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// # if !cfg!(any(feature = "csr", feature = "hydrate")) {
+/// view! {
+///   <div class="feed" on:scroll:passive=|_| log::debug!("scrolled")>
+///     <form on:submit:capture:once=|_| log::debug!("first submit")>
+///       <button>"Send"</button>
+///     </form>
+///   </div>
+/// }
+/// # ;
+/// # };
+/// # runtime.dispose();
+/// ```
+///
 /// 6. DOM properties can be set with `prop:` attributes, which take any primitive type or `JsValue` (or a signal
 ///    that returns a primitive or JsValue). They can also take an `Option`, in which case `Some` sets the property
 ///    and `None` deletes the property.
@@ -160,6 +202,30 @@ mod slot;
 /// # runtime.dispose();
 /// ```
 ///
+///    Form controls can instead be bound to an `RwSignal` in both directions with `bind:value` (for inputs,
+///    textareas, and selects), `bind:checked` (for checkboxes), and `bind:group` (for radio buttons and checkboxes
+///    that share a signal, using their `value` attribute). While the user composes text with an input method
+///    editor, `bind:value` only updates the signal once the composition has ended; `bind:value:eager` updates it
+///    on every keystroke, and `bind:value:lazy` only once the control loses focus.
+/// ```rust
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// # if !cfg!(any(feature = "csr", feature = "hydrate")) {
+/// let name = create_rw_signal("Alice".to_string());
+/// let subscribed = create_rw_signal(true);
+/// let plan = create_rw_signal("free".to_string());
+///
+/// view! {
+///   <input type="text" bind:value=name/>
+///   <input type="checkbox" bind:checked=subscribed/>
+///   <input type="radio" value="free" bind:group=plan/>
+///   <input type="radio" value="pro" bind:group=plan/>
+/// }
+/// # ;
+/// # };
+/// # runtime.dispose();
+/// ```
+///
 /// 7. Classes can be toggled with `class:` attributes, which take a `bool` (or a signal that returns a `bool`).
 /// ```rust
 /// # use leptos::*;
@@ -586,6 +652,38 @@ pub fn template(tokens: TokenStream) -> TokenStream {
 ///     }
 /// }
 /// ```
+///
+/// ## Route Parameters
+/// A component that is rendered by a `<Route/>` can read its props from the URL with
+/// `#[prop(param)]` (the route params) and `#[prop(query)]` (the URL search query). Each prop is parsed
+/// with `leptos_router::IntoParam`, so it can be any type that implements [`FromStr`](std::str::FromStr),
+/// or an `Option` of one if the value may be missing. The component is rendered again whenever one of
+/// them changes, and if one of them can't be parsed, the `ParamsError` is rendered instead, to be shown
+/// by the nearest `<ErrorBoundary/>`.
+///
+/// A component with these props can't take any other props, because the router renders it
+/// without any.
+/// ```rust,ignore
+/// # use leptos::*;
+/// # use leptos_router::*;
+/// #[component]
+/// fn Post(#[prop(param)] id: u64, #[prop(query)] page: Option<u32>) -> impl IntoView {
+///     view! { <p>"Post " {id} ", page " {page.unwrap_or(1)}</p> }
+/// }
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     view! {
+///       <Router>
+///         <ErrorBoundary fallback=|_| "Not found">
+///           <Routes>
+///             <Route path="/posts/:id" view=Post/>
+///           </Routes>
+///         </ErrorBoundary>
+///       </Router>
+///     }
+/// }
+/// ```
 #[proc_macro_error::proc_macro_error]
 #[proc_macro_attribute]
 pub fn component(args: proc_macro::TokenStream, s: TokenStream) -> TokenStream {
@@ -992,6 +1090,123 @@ pub fn params_derive(
     }
 }
 
+/// Derives [`IntoView`](https://docs.rs/leptos/latest/leptos/trait.IntoView.html)
+/// for an enum whose variants each hold a single view, so that a value of the
+/// enum can be rendered directly, rather than matching on it and converting
+/// each arm into a [`View`](https://docs.rs/leptos/latest/leptos/enum.View.html).
+///
+/// This is useful for views that switch between many states, like the steps
+/// of a wizard. Each variant must have exactly one field, whose type
+/// implements `IntoView`, or no fields, in which case it renders nothing.
+///
+/// ```rust
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// #[derive(IntoView)]
+/// enum Step {
+///     Welcome(HtmlElement<html::P>),
+///     Details { form: View },
+///     Done(&'static str),
+///     Closed,
+/// }
+///
+/// let step = create_rw_signal(0);
+/// view! {
+///   <div>
+///     {move || match step.get() {
+///       0 => Step::Welcome(view! { <p>"Welcome!"</p> }),
+///       1 => Step::Details { form: view! { <input/> }.into_view() },
+///       2 => Step::Done("All done."),
+///       _ => Step::Closed,
+///     }}
+///   </div>
+/// }
+/// # ;
+/// # runtime.dispose();
+/// ```
+#[proc_macro_error::proc_macro_error]
+#[proc_macro_derive(IntoView)]
+pub fn into_view_derive(
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    match syn::parse(input).and_then(|ast| into_view::into_view_impl(&ast)) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Derives [`Theme`](https://docs.rs/leptos_meta/latest/leptos_meta/trait.Theme.html)
+/// for a struct, mapping each of its fields to a CSS custom property.
+///
+/// The name of the property is the name of the field in kebab case, prefixed
+/// with `--`, so that `primary_color` becomes `--primary-color`. It can be
+/// changed with `#[theme(rename = "--name")]`. The value of the property is
+/// the field's [`Display`](std::fmt::Display) output.
+///
+/// ```rust,ignore
+/// # use leptos::*;
+/// # use leptos_meta::*;
+/// #[derive(Clone, Theme)]
+/// struct AppTheme {
+///     primary_color: Color,
+///     #[theme(rename = "--radius")]
+///     border_radius: Length,
+/// }
+/// ```
+#[proc_macro_error::proc_macro_error]
+#[proc_macro_derive(Theme, attributes(theme))]
+pub fn theme_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    match syn::parse(input).and_then(|ast| theme::theme_impl(&ast)) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Derives the field accessors of a [`Store`](https://docs.rs/leptos/latest/leptos/struct.Store.html)
+/// for a struct with named fields.
+///
+/// This generates a trait named after the struct, with `StoreFields`
+/// appended, which gives the store and any field of the struct's type a
+/// method for each field. The method returns a
+/// [`Subfield`](https://docs.rs/leptos/latest/leptos/struct.Subfield.html),
+/// which can be read and updated like a signal, and only notifies the code
+/// that reads that field when it changes.
+///
+/// ```rust
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// #[derive(Store)]
+/// struct User {
+///     name: String,
+///     address: Address,
+/// }
+///
+/// #[derive(Store)]
+/// struct Address {
+///     city: String,
+/// }
+///
+/// let store = create_store(User {
+///     name: "Ada".into(),
+///     address: Address {
+///         city: "London".into(),
+///     },
+/// });
+/// let city = store.address().city();
+/// city.set("Paris".into());
+/// assert_eq!(store.with(|user| user.address.city.clone()), "Paris");
+/// assert_eq!(store.name().get(), "Ada");
+/// # runtime.dispose();
+/// ```
+#[proc_macro_error::proc_macro_error]
+#[proc_macro_derive(Store)]
+pub fn store_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    match syn::parse(input).and_then(|ast| store::store_impl(&ast)) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
 pub(crate) fn attribute_value(attr: &KeyedAttribute) -> &syn::Expr {
     match attr.value() {
         Some(value) => value,
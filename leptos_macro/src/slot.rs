@@ -1,5 +1,6 @@
 use crate::component::{
-    convert_from_snake_case, drain_filter, is_option, unwrap_option, Docs,
+    convert_from_snake_case, drain_filter, is_option, pretty_type,
+    tuple_callback_args, tuple_callback_setter, unwrap_option, Docs,
 };
 use attribute_derive::FromAttr;
 use proc_macro2::{Ident, TokenStream};
@@ -199,15 +200,27 @@ struct TypedBuilderOpts {
     default_with_value: Option<syn::Expr>,
     strip_option: bool,
     into: bool,
+    tuple_callback_setter: Option<TokenStream>,
 }
 
 impl TypedBuilderOpts {
-    pub fn from_opts(opts: &PropOpt, is_ty_option: bool) -> Self {
+    pub fn from_opts(opts: &PropOpt, ty: &Type) -> Self {
+        let is_ty_option = is_option(ty);
+        let strip_option = opts.strip_option || opts.optional && is_ty_option;
+        let setter_ty = if strip_option && is_ty_option {
+            unwrap_option(ty)
+        } else {
+            ty.clone()
+        };
         Self {
             default: opts.optional || opts.optional_no_strip || opts.attrs,
             default_with_value: opts.default.clone(),
-            strip_option: opts.strip_option || opts.optional && is_ty_option,
+            strip_option,
             into: opts.into,
+            tuple_callback_setter: opts
+                .into
+                .then(|| tuple_callback_setter(&setter_ty, strip_option))
+                .flatten(),
         }
     }
 }
@@ -235,7 +248,9 @@ impl ToTokens for TypedBuilderOpts {
             quote! {}
         };
 
-        let setter = if !strip_option.is_empty() || !into.is_empty() {
+        let setter = if let Some(setter) = &self.tuple_callback_setter {
+            setter.clone()
+        } else if !strip_option.is_empty() || !into.is_empty() {
             quote! { setter(#strip_option #into) }
         } else {
             quote! {}
@@ -262,8 +277,7 @@ fn prop_builder_fields(vis: &Visibility, props: &[Prop]) -> TokenStream {
                 ty,
             } = prop;
 
-            let builder_attrs =
-                TypedBuilderOpts::from_opts(prop_opts, is_option(ty));
+            let builder_attrs = TypedBuilderOpts::from_opts(prop_opts, ty);
 
             let builder_docs = prop_to_doc(prop, PropDocStyle::Inline);
 
@@ -340,24 +354,23 @@ fn prop_to_doc(
         ty.to_owned()
     };
 
-    let type_item: syn::Item = parse_quote! {
-        type SomeType = #ty;
-    };
-
-    let file = syn::File {
-        shebang: None,
-        attrs: vec![],
-        items: vec![type_item],
-    };
-
-    let pretty_ty = prettyplease::unparse(&file);
+    let pretty_ty = pretty_type(&ty);
+    let pretty_ty = pretty_ty.as_str();
 
-    let pretty_ty = &pretty_ty[16..&pretty_ty.len() - 2];
+    // a callback that takes several arguments is set with a closure that
+    // takes them one by one
+    let closure_ty = tuple_callback_args(&ty).filter(|_| prop_opts.into).map(
+        |(args, output)| {
+            pretty_type(&parse_quote!(impl Fn(#(#args),*) -> #output))
+        },
+    );
 
     match style {
         PropDocStyle::List => {
             let arg_ty_doc = LitStr::new(
-                &if !prop_opts.into {
+                &if let Some(closure_ty) = &closure_ty {
+                    format!("- **{}**: `{closure_ty}`", quote!(#name))
+                } else if !prop_opts.into {
                     format!("- **{}**: [`{}`]", quote!(#name), pretty_ty)
                 } else {
                     format!(
@@ -378,7 +391,13 @@ fn prop_to_doc(
         }
         PropDocStyle::Inline => {
             let arg_ty_doc = LitStr::new(
-                &if !prop_opts.into {
+                &if let Some(closure_ty) = &closure_ty {
+                    format!(
+                        "**{}**: `{closure_ty}`{}",
+                        quote!(#name),
+                        docs.typed_builder()
+                    )
+                } else if !prop_opts.into {
                     format!(
                         "**{}**: [`{}`]{}",
                         quote!(#name),
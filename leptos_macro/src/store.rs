@@ -0,0 +1,77 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields};
+
+pub fn store_impl(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &ast.ident;
+    let vis = &ast.vis;
+    let fields = match &ast.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &ast.ident,
+                    "`Store` can only be derived for structs with named \
+                     fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &ast.ident,
+                "`Store` can only be derived for structs",
+            ))
+        }
+    };
+    if !ast.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &ast.generics,
+            "`Store` cannot be derived for generic structs",
+        ));
+    }
+
+    let trait_name = format_ident!("{name}StoreFields");
+    let (signatures, bodies): (Vec<_>, Vec<_>) = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let field_name = field.ident.as_ref().unwrap();
+            let ty = &field.ty;
+            let signature = quote! {
+                fn #field_name(self) -> ::leptos::Subfield<S, #name, #ty>
+            };
+            let body = quote! {
+                #signature {
+                    ::leptos::Subfield::new(
+                        self,
+                        #index,
+                        |value| &value.#field_name,
+                        |value| &mut value.#field_name,
+                    )
+                }
+            };
+            (signature, body)
+        })
+        .unzip();
+    let doc = format!(
+        "The fields of a [`Store`](::leptos::Store) of [`{name}`], or of a \
+         field of that type."
+    );
+
+    Ok(quote! {
+        #[doc = #doc]
+        #vis trait #trait_name<S> {
+            #(
+                #[allow(missing_docs)]
+                #signatures;
+            )*
+        }
+
+        impl<S> #trait_name<S> for S
+        where
+            S: ::leptos::StoreField<Value = #name>,
+        {
+            #(#bodies)*
+        }
+    })
+}
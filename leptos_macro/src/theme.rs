@@ -0,0 +1,71 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{spanned::Spanned, Data, DeriveInput, Fields, LitStr};
+
+pub fn theme_impl(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &ast.ident;
+    let Data::Struct(data) = &ast.data else {
+        return Err(syn::Error::new_spanned(
+            &ast.ident,
+            "`Theme` can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            &ast.ident,
+            "`Theme` can only be derived for structs with named fields",
+        ));
+    };
+
+    let variables = fields
+        .named
+        .iter()
+        .map(|field| {
+            let ident = field.ident.as_ref().expect("named field");
+            let mut variable = format!(
+                "--{}",
+                ident.to_string().trim_start_matches("r#").replace('_', "-")
+            );
+            for attr in &field.attrs {
+                if !attr.path().is_ident("theme") {
+                    continue;
+                }
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("rename") {
+                        let value: LitStr = meta.value()?.parse()?;
+                        variable = value.value();
+                        if !variable.starts_with("--") {
+                            return Err(syn::Error::new_spanned(
+                                value,
+                                "the name of a CSS custom property must \
+                                 start with `--`",
+                            ));
+                        }
+                        Ok(())
+                    } else {
+                        Err(meta.error("expected `rename = \"--name\"`"))
+                    }
+                })?;
+            }
+            let span = field.span();
+            Ok(quote_spanned! {span=>
+                (#variable, ::std::string::ToString::to_string(&self.#ident))
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let (impl_generics, ty_generics, where_clause) =
+        ast.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::leptos_meta::Theme for #name #ty_generics
+        #where_clause
+        {
+            fn css_variables(
+                &self,
+            ) -> ::std::vec::Vec<(&'static str, ::std::string::String)> {
+                ::std::vec![#(#variables),*]
+            }
+        }
+    })
+}
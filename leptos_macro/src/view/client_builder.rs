@@ -1,10 +1,12 @@
 use super::{
+    bind_group_value,
     component_builder::component_to_tokens,
     expr_to_ident, fancy_class_name, fancy_style_name,
     ide_helper::IdeTagHelper,
     is_ambiguous_element, is_custom_element, is_math_ml_element,
-    is_self_closing, is_svg_element, parse_event_name,
+    is_self_closing, is_svg_element, parse_bind_name, parse_event_name,
     slot_helper::{get_slot, slot_to_tokens},
+    Binding,
 };
 use crate::{attribute_value, view::directive_call_from_attribute_node};
 use leptos_hot_reload::parsing::{is_component_node, value_to_string};
@@ -215,6 +217,7 @@ pub(crate) fn element_to_tokens(
             ide_helper_close_tag.save_tag_completion(close_tag)
         }
 
+        let group_value = bind_group_value(node);
         let attrs = node.attributes().iter().filter_map(|node| {
             if let NodeAttribute::Attribute(node) = node {
                 let name = node.key.to_string();
@@ -223,10 +226,12 @@ pub(crate) fn element_to_tokens(
                     || fancy_class_name(name, node).is_some()
                     || name.starts_with("style:")
                     || fancy_style_name(name, node).is_some()
+                    // set along with `bind:group`
+                    || (name == "value" && group_value.is_some())
                 {
                     None
                 } else {
-                    Some(attribute_to_tokens(node, global_class))
+                    Some(attribute_to_tokens(node, global_class, group_value))
                 }
             } else {
                 None
@@ -259,7 +264,7 @@ pub(crate) fn element_to_tokens(
                 if let Some((fancy, _, _)) = fancy_class_name(&name, node) {
                     Some(fancy)
                 } else if name.trim().starts_with("class:") {
-                    Some(attribute_to_tokens(node, global_class))
+                    Some(attribute_to_tokens(node, global_class, None))
                 } else {
                     None
                 }
@@ -273,7 +278,7 @@ pub(crate) fn element_to_tokens(
                 if let Some((fancy, _, _)) = fancy_style_name(&name, node) {
                     Some(fancy)
                 } else if name.trim().starts_with("style:") {
-                    Some(attribute_to_tokens(node, global_class))
+                    Some(attribute_to_tokens(node, global_class, None))
                 } else {
                     None
                 }
@@ -359,6 +364,7 @@ pub(crate) fn element_to_tokens(
 pub(crate) fn attribute_to_tokens(
     node: &KeyedAttribute,
     global_class: Option<&TokenTree>,
+    group_value: Option<&KeyedAttribute>,
 ) -> TokenStream {
     let span = node.key.span();
     let name = node.key.to_string();
@@ -374,8 +380,7 @@ pub(crate) fn attribute_to_tokens(
     } else if let Some(name) = name.strip_prefix("on:") {
         let handler = attribute_value(node);
 
-        let (event_type, is_custom, is_force_undelegated) =
-            parse_event_name(name);
+        let (event_type, is_custom, modifiers) = parse_event_name(name);
 
         let event_name_ident = match &node.key {
             NodeName::Punctuated(parts) => {
@@ -388,21 +393,24 @@ pub(crate) fn attribute_to_tokens(
             _ => unreachable!(),
         };
         let undelegated_ident = match &node.key {
-            NodeName::Punctuated(parts) => parts.last().and_then(|last| {
-                if last.to_string() == "undelegated" {
-                    Some(last)
-                } else {
-                    None
-                }
-            }),
+            NodeName::Punctuated(parts) => parts
+                .iter()
+                .skip(2)
+                .find(|part| part.to_string() == "undelegated"),
             _ => unreachable!(),
         };
         let on = match &node.key {
             NodeName::Punctuated(parts) => &parts[0],
             _ => unreachable!(),
         };
-        let on = quote_spanned! {
-            on.span()=> .on
+        let on = if modifiers.targeted {
+            quote_spanned! {
+                on.span()=> .on_target
+            }
+        } else {
+            quote_spanned! {
+                on.span()=> .on
+            }
         };
         let event_type = if is_custom {
             event_type
@@ -414,7 +422,7 @@ pub(crate) fn attribute_to_tokens(
             event_type
         };
 
-        let event_type = if is_force_undelegated {
+        let event_type = if modifiers.undelegated {
             let undelegated = if let Some(undelegated) = undelegated_ident {
                 quote_spanned! {
                     undelegated.span()=> #undelegated
@@ -426,10 +434,37 @@ pub(crate) fn attribute_to_tokens(
         } else {
             quote! { ::leptos::ev::#event_type }
         };
+        let event_type = modifiers.with_options(event_type);
 
         quote! {
             #on(#event_type, #handler)
         }
+    } else if let Some(name) = name.strip_prefix("bind:") {
+        let value = attribute_value(node);
+        match parse_bind_name(node, name) {
+            Binding::Value(None) => {
+                quote_spanned! { span=> .bind_value(#value) }
+            }
+            Binding::Value(Some(mode)) => {
+                let mode = Ident::new(mode, span);
+                quote_spanned! { span=>
+                    .bind_value_with(
+                        #value,
+                        ::leptos::leptos_dom::html::BindValueMode::#mode,
+                    )
+                }
+            }
+            Binding::ValueParse => {
+                quote_spanned! { span=> .bind_value_parse(#value) }
+            }
+            Binding::Checked => {
+                quote_spanned! { span=> .bind_checked(#value) }
+            }
+            Binding::Group => {
+                let group_value = group_value.map(attribute_value);
+                quote_spanned! { span=> .bind_group(#value, #group_value) }
+            }
+        }
     } else if let Some(name) = name.strip_prefix("prop:") {
         let value = attribute_value(node);
         let prop = match &node.key {
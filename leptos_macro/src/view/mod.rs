@@ -2,7 +2,7 @@ use crate::{attribute_value, Mode};
 use convert_case::{Case::Snake, Casing};
 use proc_macro2::{Ident, Span, TokenStream, TokenTree};
 use quote::{quote, quote_spanned};
-use rstml::node::{KeyedAttribute, Node, NodeElement, NodeName};
+use rstml::node::{KeyedAttribute, Node, NodeAttribute, NodeElement, NodeName};
 use syn::{
     spanned::Spanned,
     Expr::{self, Tuple},
@@ -203,8 +203,43 @@ const TYPED_EVENTS: [&str; 126] = [
 
 const CUSTOM_EVENT: &str = "Custom";
 
-pub(crate) fn parse_event_name(name: &str) -> (TokenStream, bool, bool) {
-    let (name, is_force_undelegated) = parse_event(name);
+/// The modifiers after the name of the event in an `on:{event}` attribute.
+#[derive(Default)]
+pub(crate) struct EventModifiers {
+    /// `:undelegated`
+    pub undelegated: bool,
+    /// `:target`
+    pub targeted: bool,
+    /// `:capture`, `:passive`, and `:once`
+    pub options: Vec<&'static str>,
+}
+
+impl EventModifiers {
+    /// Adds the listener options, if there are any, to the event.
+    pub fn with_options(&self, event: TokenStream) -> TokenStream {
+        if self.options.is_empty() {
+            return event;
+        }
+        let options = self.options.iter().map(|option| match *option {
+            "passive" => quote! { .passive(true) },
+            option => {
+                let option = Ident::new(option, Span::call_site());
+                quote! { .#option() }
+            }
+        });
+        quote! {
+            ::leptos::leptos_dom::ev::WithOptions::new(
+                #event,
+                ::leptos::leptos_dom::ev::EventOptions::new()#(#options)*
+            )
+        }
+    }
+}
+
+pub(crate) fn parse_event_name(
+    name: &str,
+) -> (TokenStream, bool, EventModifiers) {
+    let (name, modifiers) = parse_event(name);
 
     let (event_type, is_custom) = TYPED_EVENTS
         .binary_search(&name)
@@ -220,7 +255,61 @@ pub(crate) fn parse_event_name(name: &str) -> (TokenStream, bool, bool) {
     } else {
         event_type
     };
-    (event_type, is_custom, is_force_undelegated)
+    (event_type, is_custom, modifiers)
+}
+
+/// The two-way bindings that can be set with `bind:` attributes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Binding {
+    /// `bind:value=signal`, or with the name of a `BindValueMode` for
+    /// `bind:value:eager=signal` and `bind:value:lazy=signal`
+    Value(Option<&'static str>),
+    /// `bind:value:parse=(value, parse, format)`
+    ValueParse,
+    /// `bind:checked=signal`
+    Checked,
+    /// `bind:group=signal`, with the `value` attribute of the same element
+    Group,
+}
+
+pub(crate) fn parse_bind_name(node: &KeyedAttribute, name: &str) -> Binding {
+    match name {
+        "value" => Binding::Value(None),
+        "value:eager" => Binding::Value(Some("Eager")),
+        "value:lazy" => Binding::Value(Some("Lazy")),
+        "value:parse" => Binding::ValueParse,
+        "checked" => Binding::Checked,
+        "group" => Binding::Group,
+        _ => abort!(
+            node.key.span(),
+            "unknown binding `bind:{}`", name;
+            help = "use `bind:value`, `bind:value:eager`, `bind:value:lazy`, \
+                    `bind:value:parse`, `bind:checked`, or `bind:group`"
+        ),
+    }
+}
+
+/// Returns the `value` attribute of an element that has a `bind:group`
+/// attribute, which is set along with the binding instead of on its own.
+pub(crate) fn bind_group_value(node: &NodeElement) -> Option<&KeyedAttribute> {
+    let attr = |name: &str| {
+        node.attributes().iter().find_map(|attr| match attr {
+            NodeAttribute::Attribute(attr) if attr.key.to_string() == name => {
+                Some(attr)
+            }
+            _ => None,
+        })
+    };
+    let group = attr("bind:group")?;
+    match attr("value") {
+        Some(value) => Some(value),
+        None => abort!(
+            group.key.span(),
+            "`bind:group` needs a `value` attribute on the same element";
+            help = "add the value that the group has while this input is \
+                    checked, like `value=\"small\"`"
+        ),
+    }
 }
 
 fn expr_to_ident(expr: &syn::Expr) -> Option<&ExprPath> {
@@ -245,6 +334,30 @@ fn convert_to_snake_case(name: String) -> String {
     }
 }
 
+/// A function that creates the element an `on:{event}:target` listener is
+/// added to, which the server uses to infer the type of the handler.
+pub(crate) fn targeted_element_fn(node: &NodeElement) -> TokenStream {
+    let tag = node.name().to_string();
+    if is_custom_element(&tag) {
+        return quote! {
+            || ::leptos::leptos_dom::html::custom(
+                ::leptos::leptos_dom::html::Custom::new(#tag)
+            )
+        };
+    }
+    let name = Ident::new(
+        tag.trim_start_matches("svg::").trim_start_matches("math::"),
+        node.name().span(),
+    );
+    if tag.starts_with("svg::") || is_svg_element(&tag) {
+        quote! { ::leptos::leptos_dom::svg::#name }
+    } else if tag.starts_with("math::") || is_math_ml_element(&tag) {
+        quote! { ::leptos::leptos_dom::math::#name }
+    } else {
+        quote! { ::leptos::leptos_dom::html::#name }
+    }
+}
+
 fn is_custom_element(tag: &str) -> bool {
     tag.contains('-')
 }
@@ -386,11 +499,32 @@ fn is_ambiguous_element(tag: &str) -> bool {
     tag == "a" || tag == "script" || tag == "title"
 }
 
-fn parse_event(event_name: &str) -> (&str, bool) {
-    if let Some(event_name) = event_name.strip_suffix(":undelegated") {
-        (event_name, true)
-    } else {
-        (event_name, false)
+/// Splits the `:undelegated` and `:target` modifiers off an event name, in
+/// either order.
+fn parse_event(event_name: &str) -> (&str, EventModifiers) {
+    let mut event_name = event_name;
+    let mut modifiers = EventModifiers::default();
+    loop {
+        if let Some(name) = event_name.strip_suffix(":undelegated") {
+            event_name = name;
+            modifiers.undelegated = true;
+        } else if let Some(name) = event_name.strip_suffix(":target") {
+            event_name = name;
+            modifiers.targeted = true;
+        } else if let Some((name, option)) = ["capture", "passive", "once"]
+            .into_iter()
+            .find_map(|option| {
+                event_name
+                    .strip_suffix(option)
+                    .and_then(|name| name.strip_suffix(':'))
+                    .map(|name| (name, option))
+            })
+        {
+            event_name = name;
+            modifiers.options.push(option);
+        } else {
+            return (event_name, modifiers);
+        }
     }
 }
 
@@ -567,14 +701,21 @@ pub(crate) fn event_from_attribute_node(
 
     let handler = attribute_value(attr);
 
-    let (event_type, _, name_undelegated) = parse_event_name(&event_name);
+    let (event_type, _, modifiers) = parse_event_name(&event_name);
+    if modifiers.targeted {
+        abort!(
+            attr.key.span(),
+            "the `:target` modifier is only supported on elements created \
+             with the `view` macro"
+        );
+    }
 
-    let event_type = if force_undelegated || name_undelegated {
+    let event_type = if force_undelegated || modifiers.undelegated {
         quote! { ::leptos::leptos_dom::ev::undelegated(::leptos::leptos_dom::ev::#event_type) }
     } else {
         quote! { ::leptos::leptos_dom::ev::#event_type }
     };
-    (event_type, handler)
+    (modifiers.with_options(event_type), handler)
 }
 
 pub(crate) fn directive_call_from_attribute_node(
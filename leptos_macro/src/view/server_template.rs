@@ -1,11 +1,12 @@
 use super::{
-    camel_case_tag_name,
+    bind_group_value, camel_case_tag_name,
     component_builder::component_to_tokens,
     fancy_class_name, fancy_style_name,
     ide_helper::IdeTagHelper,
     is_custom_element, is_math_ml_element, is_self_closing, is_svg_element,
-    parse_event_name,
+    parse_bind_name, parse_event_name,
     slot_helper::{get_slot, slot_to_tokens},
+    targeted_element_fn, Binding,
 };
 use crate::attribute_value;
 use leptos_hot_reload::parsing::{
@@ -263,15 +264,27 @@ fn element_to_tokens_ssr(
         stmts_for_ide.save_element_completion(node);
 
         let mut inner_html = None;
+        let group_value = bind_group_value(node);
+        let is_bound_select = tag_name == "select"
+            && node.attributes().iter().any(|attr| {
+                matches!(
+                    attr,
+                    NodeAttribute::Attribute(attr)
+                        if attr.key.to_string().starts_with("bind:value")
+                            && attr.key.to_string() != "bind:value:parse"
+                )
+            });
 
         for attr in node.attributes() {
             if let NodeAttribute::Attribute(attr) = attr {
                 inner_html = attribute_to_tokens_ssr(
+                    node,
                     attr,
                     template,
                     holes,
                     exprs_for_compiler,
                     global_class,
+                    group_value,
                 );
             }
         }
@@ -292,21 +305,30 @@ fn element_to_tokens_ssr(
                     // should basically be the resolved attributes, joined on spaces, placed into
                     // the template
                     template.push_str(" {}");
-                    let end_into_iter =
-                        quote_spanned!(end.span()=> {#end}.into_iter());
-                    holes.push(quote_spanned! {block.span()=>
-                        #end_into_iter.filter_map(|(name, attr)| {
-                           Some(::std::format!(
-                                "{}=\"{}\"",
-                                name,
-                                ::leptos::leptos_dom::ssr::escape_attr(&attr.as_nameless_value_string()?)
-                            ))
-                        }).collect::<::std::vec::Vec<_>>().join(" ")
+                    holes.push(quote_spanned! {end.span()=>
+                        ::leptos::leptos_dom::ssr::render_spread_bindings({#end})
                     });
                 };
             }
         }
 
+        // an option without a `value` is selected by its text
+        if tag_name == "option" && option_has_no_value(node) {
+            let text = node
+                .children
+                .iter()
+                .filter_map(|child| match child {
+                    Node::Text(text) => Some(text.value_string()),
+                    Node::RawText(text) => Some(text.to_string_best()),
+                    _ => None,
+                })
+                .collect::<String>();
+            template.push_str("{}");
+            holes.push(quote! {
+                ::leptos::leptos_dom::helpers::ssr_option_selected(#text)
+            });
+        }
+
         // insert hydration ID
         let hydration_id = if is_root {
             quote! { ::leptos::leptos_dom::HydrationCtx::peek() }
@@ -404,6 +426,13 @@ fn element_to_tokens_ssr(
                 }
             }
 
+            if is_bound_select {
+                template.push_str("{}");
+                holes.push(quote! {
+                    ::leptos::leptos_dom::helpers::ssr_end_select()
+                });
+            }
+
             template.push_str("</");
             template.push_str(tag_name);
             template.push('>');
@@ -413,22 +442,33 @@ fn element_to_tokens_ssr(
 
 // returns `inner_html`
 fn attribute_to_tokens_ssr<'a>(
+    node: &NodeElement,
     attr: &'a KeyedAttribute,
     template: &mut String,
     holes: &mut Vec<TokenStream>,
     exprs_for_compiler: &mut Vec<TokenStream>,
     global_class: Option<&TokenTree>,
+    group_value: Option<&KeyedAttribute>,
 ) -> Option<&'a syn::Expr> {
     let name = attr.key.to_string();
     if name == "ref" || name == "_ref" || name == "ref_" || name == "node_ref" {
         // ignore refs on SSR
+    } else if name == "value" && group_value.is_some() {
+        // rendered along with `bind:group`
     } else if let Some(name) = name.strip_prefix("on:") {
         let handler = attribute_value(attr);
-        let (event_type, _, _) = parse_event_name(name);
+        let (event_type, _, modifiers) = parse_event_name(name);
 
-        exprs_for_compiler.push(quote! {
-            ::leptos::leptos_dom::helpers::ssr_event_listener(::leptos::ev::#event_type, #handler);
-        })
+        if modifiers.targeted {
+            let element = targeted_element_fn(node);
+            exprs_for_compiler.push(quote! {
+                ::leptos::leptos_dom::helpers::ssr_targeted_event_listener(#element, ::leptos::ev::#event_type, #handler);
+            })
+        } else {
+            exprs_for_compiler.push(quote! {
+                ::leptos::leptos_dom::helpers::ssr_event_listener(::leptos::ev::#event_type, #handler);
+            })
+        }
     } else if name.strip_prefix("prop:").is_some()
         || name.strip_prefix("class:").is_some()
         || name.strip_prefix("style:").is_some()
@@ -457,6 +497,36 @@ fn attribute_to_tokens_ssr<'a>(
                 #value
             }
         });
+    } else if let Some(name) = name.strip_prefix("bind:") {
+        let value = attribute_value(attr);
+        let attrs = match parse_bind_name(attr, name) {
+            // the mode only matters in the browser
+            Binding::Value(_) if node.name().to_string() == "select" => {
+                quote! {
+                    ::leptos::leptos_dom::helpers::ssr_bind_select(#value)
+                }
+            }
+            Binding::Value(_) => quote! {
+                ::leptos::leptos_dom::helpers::ssr_bind_value(#value)
+            },
+            Binding::ValueParse => quote! {
+                ::leptos::leptos_dom::helpers::ssr_bind_value_parse(#value)
+            },
+            Binding::Checked => quote! {
+                ::leptos::leptos_dom::helpers::ssr_bind_checked(#value)
+            },
+            Binding::Group => {
+                let group_value = group_value.map(attribute_value);
+                quote! {
+                    ::leptos::leptos_dom::helpers::ssr_bind_group(
+                        #value,
+                        #group_value
+                    )
+                }
+            }
+        };
+        template.push_str("{}");
+        holes.push(quote! { &#attrs });
     } else if name == "inner_html" {
         return attr.value();
     } else {
@@ -475,6 +545,9 @@ fn attribute_to_tokens_ssr<'a>(
         };
 
         if name != "class" && name != "style" {
+            // an option is selected along with its value
+            let is_option_value =
+                name == "value" && node.name().to_string() == "option";
             template.push(' ');
 
             if let Some(value) = attr.value() {
@@ -485,6 +558,17 @@ fn attribute_to_tokens_ssr<'a>(
                         &value,
                     ));
                     template.push('"');
+                    if is_option_value {
+                        template.push_str("{}");
+                        holes.push(quote! {
+                            ::leptos::leptos_dom::helpers::ssr_option_selected(#value)
+                        });
+                    }
+                } else if is_option_value {
+                    template.push_str("{}");
+                    holes.push(quote! {
+                        ::leptos::leptos_dom::helpers::ssr_option_value(#value)
+                    });
                 } else {
                     template.push_str("{}");
                     holes.push(quote! {
@@ -745,3 +829,14 @@ fn set_style_attribute_ssr(
         template.push('"');
     }
 }
+
+/// Returns `true` if an `<option>` has no `value` attribute, so that its
+/// text is its value.
+fn option_has_no_value(node: &NodeElement) -> bool {
+    !node.attributes().iter().any(|attr| {
+        matches!(
+            attr,
+            NodeAttribute::Attribute(attr) if attr.key.to_string() == "value"
+        )
+    })
+}
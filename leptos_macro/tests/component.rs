@@ -32,3 +32,78 @@ fn component() {
     assert_eq!(cp.into, "");
     assert_eq!((cp.impl_trait)(), 42);
 }
+
+// does not implement `Clone`
+#[derive(PartialEq)]
+struct Item(u32);
+
+#[component]
+fn SignalRefComponent(
+    #[prop(into)] items: SignalRef<Vec<Item>>,
+) -> impl IntoView {
+    _ = items;
+}
+
+#[test]
+fn signal_ref_prop() {
+    let runtime = create_runtime();
+    let total = |props: &SignalRefComponentProps| {
+        props
+            .items
+            .with(|items| items.iter().map(|item| item.0).sum::<u32>())
+    };
+
+    let (items, set_items) = create_signal(vec![Item(1), Item(2)]);
+    let memo = create_memo(move |_| {
+        items.with(|items| items.iter().map(|item| Item(item.0 * 2)).collect())
+    });
+    let derived =
+        Signal::derive(move || vec![Item(items.with(Vec::len) as u32)]);
+
+    let from_value = SignalRefComponentProps::builder()
+        .items(vec![Item(5)])
+        .build();
+    let from_signal = SignalRefComponentProps::builder().items(items).build();
+    let from_memo = SignalRefComponentProps::builder().items(memo).build();
+    let from_derived =
+        SignalRefComponentProps::builder().items(derived).build();
+
+    assert_eq!(total(&from_value), 5);
+    assert_eq!(total(&from_signal), 3);
+    assert_eq!(total(&from_memo), 6);
+    assert_eq!(total(&from_derived), 2);
+
+    set_items.update(|items| items.push(Item(3)));
+    assert_eq!(total(&from_signal), 6);
+    assert_eq!(total(&from_memo), 12);
+    assert_eq!(total(&from_derived), 3);
+    runtime.dispose();
+}
+
+#[component]
+fn Grid(
+    #[prop(into)] on_cell_edit: Callback<(usize, usize, String), bool>,
+    #[prop(optional, into)] on_move: Option<SyncCallback<(i32, i32)>>,
+) -> impl IntoView {
+    _ = on_cell_edit;
+    _ = on_move;
+}
+
+#[test]
+fn multi_argument_callback_props() {
+    let runtime = create_runtime();
+
+    let from_closure = GridProps::builder()
+        .on_cell_edit(|row, col, value| row == col && value.is_empty())
+        .on_move(|x, y| assert_eq!((x, y), (1, 2)))
+        .build();
+    assert!(from_closure.on_cell_edit.call((1, 1, String::new())));
+    assert!(!from_closure.on_cell_edit.call((1, 2, String::new())));
+    from_closure.on_move.unwrap().call((1, 2));
+
+    let without_optional =
+        GridProps::builder().on_cell_edit(|_, _, _| false).build();
+    assert!(without_optional.on_move.is_none());
+
+    runtime.dispose();
+}
@@ -0,0 +1,20 @@
+use leptos::*;
+
+#[derive(IntoView)]
+enum Step<T> {
+    Intro(&'static str),
+    Item { value: T },
+    Done,
+}
+
+#[test]
+fn into_view_derive() {
+    let intro = ssr::render_to_string(|| Step::<u8>::Intro("Welcome"));
+    assert!(intro.contains("Welcome"));
+
+    let item = ssr::render_to_string(|| Step::Item { value: 42 });
+    assert!(item.contains("42"));
+
+    let done = ssr::render_to_string(|| Step::<u8>::Done);
+    assert!(!done.contains("Welcome"));
+}
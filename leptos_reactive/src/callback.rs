@@ -32,21 +32,55 @@
 //! - Callbacks are most useful when you want optional generic props.
 //! - All callbacks implement the [`Callable`] trait, and can be invoked with `my_callback.call(input)`. On nightly, you can even do `my_callback(input)`
 //! - The callback types implement [`Copy`], so they can easily be moved into and out of other closures, just like signals.
+//! - A callback that takes several arguments takes them as a tuple, like `Callback<(usize, String)>`. With `#[prop(into)]`, it can be given a closure that takes them one by one, like `|index, name| ...`.
+//!
+//! ```
+//! # use leptos::*;
+//! #[component]
+//! fn Grid(
+//!     #[prop(into)] on_cell_edit: Callback<(usize, usize, String)>,
+//! ) -> impl IntoView {
+//!     let edit = move |_| on_cell_edit.call((0, 1, "new value".to_string()));
+//!     view! { <button on:click=edit>"Edit"</button> }
+//! }
+//!
+//! fn test() -> impl IntoView {
+//!     view! {
+//!         <Grid on_cell_edit=|row, col, value| {
+//!             logging::log!("({row}, {col}) = {}", value.trim())
+//!         }/>
+//!     }
+//! }
+//! ```
 //!
 //! # Types
-//! This modules implements 2 callback types:
+//! This modules implements 3 callback types:
 //! - [`Callback`]
 //! - [`SyncCallback`]
+//! - [`SyncAsyncCallback`]
 //!
 //! Use `SyncCallback` when you want the function to be `Sync` and `Send`.
+//! Use `SyncAsyncCallback` for an async function whose future is `Send`, so
+//! that it can be stored in props that are rendered on a multithreaded server.
 
 use crate::{store_value, StoredValue};
-use std::{fmt, sync::Arc};
+use futures::future::BoxFuture;
+use std::{fmt, future::Future, sync::Arc};
 
 /// A wrapper trait for calling callbacks.
 pub trait Callable<In: 'static, Out: 'static = ()> {
     /// calls the callback with the specified argument.
     fn call(&self, input: In) -> Out;
+
+    /// Calls the callback with the specified argument, or returns `None`
+    /// without calling it if it has been disposed, i.e., if the reactive
+    /// owner it was created in has been cleaned up.
+    ///
+    /// This is useful for callbacks that can be called after their component
+    /// has been unmounted, e.g., from a timer or by a JavaScript library.
+    fn try_call(&self, input: In) -> Option<Out> {
+        Some(self.call(input))
+    }
 }
 
 /// Callbacks define a standard way to store functions and closures.
@@ -99,12 +133,23 @@ impl<In, Out> Callback<In, Out> {
     {
         Self(store_value(Box::new(f)))
     }
+
+    /// Whether the callback has been disposed, because the reactive owner it
+    /// was created in has been cleaned up. Calling a disposed callback with
+    /// [`call`](Callable::call) panics.
+    pub fn is_disposed(&self) -> bool {
+        self.0.try_with_value(|_| ()).is_none()
+    }
 }
 
 impl<In: 'static, Out: 'static> Callable<In, Out> for Callback<In, Out> {
     fn call(&self, input: In) -> Out {
         self.0.with_value(|f| f(input))
     }
+
+    fn try_call(&self, input: In) -> Option<Out> {
+        self.0.try_with_value(|f| f(input))
+    }
 }
 
 macro_rules! impl_from_fn {
@@ -182,6 +227,10 @@ impl<In, Out> Callable<In, Out> for SyncCallback<In, Out> {
     fn call(&self, input: In) -> Out {
         self.0.with_value(|f| f(input))
     }
+
+    fn try_call(&self, input: In) -> Option<Out> {
+        self.0.try_with_value(|f| f(input))
+    }
 }
 
 impl<In, Out> Clone for SyncCallback<In, Out> {
@@ -198,6 +247,13 @@ impl<In: 'static, Out: 'static> SyncCallback<In, Out> {
     {
         Self(store_value(Arc::new(fun)))
     }
+
+    /// Whether the callback has been disposed, because the reactive owner it
+    /// was created in has been cleaned up. Calling a disposed callback with
+    /// [`call`](Callable::call) panics.
+    pub fn is_disposed(&self) -> bool {
+        self.0.try_with_value(|_| ()).is_none()
+    }
 }
 
 impl_from_fn!(SyncCallback);
@@ -225,10 +281,158 @@ impl<In, Out> Fn<(In,)> for SyncCallback<In, Out> {
     }
 }
 
+// Closures that take several arguments can be called with a tuple of them,
+// like a callback that takes the tuple. `From` can't be implemented for them
+// next to the conversion from closures that take one argument, which may be a
+// tuple, so `#[prop(into)]` converts them to callbacks in the component macro.
+macro_rules! impl_fn_args {
+    ($($arg:ident),+) => {
+        paste::paste! {
+            #[cfg(not(feature = "nightly"))]
+            impl<F, $($arg,)+ Out> Callable<($($arg,)+), Out> for F
+            where
+                F: Fn($($arg),+) -> Out,
+                $($arg: 'static,)+
+                Out: 'static,
+            {
+                fn call(&self, ($([<$arg:lower>],)+): ($($arg,)+)) -> Out {
+                    self($([<$arg:lower>]),+)
+                }
+            }
+
+            #[cfg(feature = "nightly")]
+            impl<F, $($arg,)+ Out> Callable<($($arg,)+), Out> for F
+            where
+                F: Fn($($arg),+) -> Out + NotRawCallback + NotRawSyncCallback,
+                $($arg: 'static,)+
+                Out: 'static,
+            {
+                fn call(&self, ($([<$arg:lower>],)+): ($($arg,)+)) -> Out {
+                    self($([<$arg:lower>]),+)
+                }
+            }
+        }
+
+        impl_fn_args!(@nightly Callback, $($arg),+);
+        impl_fn_args!(@nightly SyncCallback, $($arg),+);
+    };
+    (@nightly $ty:ident, $($arg:ident),+) => {
+        #[cfg(feature = "nightly")]
+        impl<$($arg,)+ Out> FnOnce<($($arg,)+)> for $ty<($($arg,)+), Out> {
+            type Output = Out;
+
+            extern "rust-call" fn call_once(
+                self,
+                args: ($($arg,)+),
+            ) -> Self::Output {
+                Callable::call(&self, args)
+            }
+        }
+
+        #[cfg(feature = "nightly")]
+        impl<$($arg,)+ Out> FnMut<($($arg,)+)> for $ty<($($arg,)+), Out> {
+            extern "rust-call" fn call_mut(
+                &mut self,
+                args: ($($arg,)+),
+            ) -> Self::Output {
+                Callable::call(&*self, args)
+            }
+        }
+
+        #[cfg(feature = "nightly")]
+        impl<$($arg,)+ Out> Fn<($($arg,)+)> for $ty<($($arg,)+), Out> {
+            extern "rust-call" fn call(
+                &self,
+                args: ($($arg,)+),
+            ) -> Self::Output {
+                Callable::call(self, args)
+            }
+        }
+    };
+}
+
+impl_fn_args!(P1, P2);
+impl_fn_args!(P1, P2, P3);
+impl_fn_args!(P1, P2, P3, P4);
+impl_fn_args!(P1, P2, P3, P4, P5);
+impl_fn_args!(P1, P2, P3, P4, P5, P6);
+
+/// An async callback that is `Send` and `Sync`, and returns a future that is
+/// `Send`.
+///
+/// Unlike [`Callback`] and [`SyncCallback`], it is not stored in the reactive
+/// runtime, so it can be cloned into and called from any thread, e.g., by a
+/// server function or a task spawned on a multithreaded server, and it is
+/// never disposed. It is [`Clone`] but not `Copy`.
+///
+/// # Example
+/// ```
+/// # use leptos::*;
+/// #[component]
+/// fn SaveButton(
+///     #[prop(into)] on_save: SyncAsyncCallback<String, bool>,
+/// ) -> impl IntoView {
+///     let save = create_action(move |text: &String| on_save.call(text.clone()));
+///     view! {
+///         <button on:click=move |_| save.dispatch("draft".into())>"Save"</button>
+///     }
+/// }
+///
+/// fn test() -> impl IntoView {
+///     view! {
+///         <SaveButton on_save=|text: String| async move { !text.is_empty() }/>
+///     }
+/// }
+/// ```
+pub struct SyncAsyncCallback<In: 'static, Out: 'static = ()>(
+    Arc<dyn Fn(In) -> BoxFuture<'static, Out> + Send + Sync>,
+);
+
+impl<In> fmt::Debug for SyncAsyncCallback<In> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        fmt.write_str("SyncAsyncCallback")
+    }
+}
+
+impl<In, Out> Clone for SyncAsyncCallback<In, Out> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<In: 'static, Out: 'static> SyncAsyncCallback<In, Out> {
+    /// Creates a new callback from the given async function.
+    pub fn new<F, Fut>(fun: F) -> Self
+    where
+        F: Fn(In) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Out> + Send + 'static,
+    {
+        Self(Arc::new(move |input| Box::pin(fun(input))))
+    }
+}
+
+impl<In, Out> Callable<In, BoxFuture<'static, Out>>
+    for SyncAsyncCallback<In, Out>
+{
+    fn call(&self, input: In) -> BoxFuture<'static, Out> {
+        (self.0)(input)
+    }
+}
+
+impl<F, Fut, In, Out> From<F> for SyncAsyncCallback<In, Out>
+where
+    F: Fn(In) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Out> + Send + 'static,
+{
+    fn from(f: F) -> Self {
+        Self::new(f)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
-        callback::{Callback, SyncCallback},
+        callback::{Callable, Callback, SyncAsyncCallback, SyncCallback},
         create_runtime,
     };
 
@@ -257,6 +461,14 @@ mod tests {
         rt.dispose();
     }
 
+    #[test]
+    fn multi_argument_closures_are_callable() {
+        let add = |a: i32, b: i32, c: i32| a + b + c;
+        assert_eq!(Callable::call(&add, (1, 2, 3)), 6);
+        let same = |a: &str, b: &str| a == b;
+        assert!(Callable::call(&same, ("a", "a")));
+    }
+
     #[test]
     fn callback_from_html() {
         let rt = create_runtime();
@@ -301,4 +513,41 @@ mod tests {
 
         rt.dispose();
     }
+
+    #[test]
+    fn sync_async_callback_from() {
+        let callback: SyncAsyncCallback<u32, String> =
+            (|n: u32| async move { n.to_string() }).into();
+        let cloned = callback.clone();
+        let output = std::thread::spawn(move || {
+            futures::executor::block_on(cloned.call(42))
+        })
+        .join()
+        .unwrap();
+        assert_eq!(output, "42");
+    }
+
+    #[test]
+    fn try_call_disposed_callback() {
+        use crate::as_child_of_current_owner;
+
+        let rt = create_runtime();
+        let ((callback, sync_callback), disposer) =
+            as_child_of_current_owner(|()| {
+                (
+                    Callback::new(|n: u32| n + 1),
+                    SyncCallback::new(|n: u32| n * 2),
+                )
+            })(());
+        assert!(!callback.is_disposed());
+        assert_eq!(callback.try_call(1), Some(2));
+        assert_eq!(sync_callback.try_call(2), Some(4));
+
+        drop(disposer);
+        assert!(callback.is_disposed());
+        assert!(sync_callback.is_disposed());
+        assert_eq!(callback.try_call(1), None);
+        assert_eq!(sync_callback.try_call(2), None);
+        rt.dispose();
+    }
 }
@@ -0,0 +1,56 @@
+use std::{any::Any, rc::Rc};
+
+/// Decides whether a new value of a memo or signal is equal to its previous
+/// value, in which case its subscribers are not notified.
+///
+/// This is implemented for closures that take the previous and the new value
+/// and return whether they are equal, and for [`Changed`], which ignores the
+/// values. It is used by [`create_memo_with_compare`](crate::create_memo_with_compare)
+/// and [`create_rw_signal_with_compare`](crate::create_rw_signal_with_compare),
+/// so that values which don't implement [`PartialEq`], or which should be
+/// compared in a different way, like floats within some epsilon, can still
+/// avoid needless updates.
+pub trait Compare<T>: 'static {
+    /// Returns `true` if `new` is equal to `old`.
+    fn is_equal(&self, old: &T, new: &T) -> bool;
+}
+
+impl<T, F> Compare<T> for F
+where
+    F: Fn(&T, &T) -> bool + 'static,
+{
+    fn is_equal(&self, old: &T, new: &T) -> bool {
+        self(old, new)
+    }
+}
+
+/// A [`Compare`] strategy that doesn't look at the values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Changed {
+    /// Every new value is treated as a change, so subscribers are notified
+    /// each time the value is recalculated or set.
+    Always,
+    /// No new value is treated as a change, so subscribers are never
+    /// notified after the initial value.
+    Never,
+}
+
+impl<T> Compare<T> for Changed {
+    fn is_equal(&self, _old: &T, _new: &T) -> bool {
+        matches!(self, Changed::Never)
+    }
+}
+
+/// A type-erased [`Compare`], as stored by the runtime for a signal.
+pub(crate) type AnyCompare = Rc<dyn Fn(&dyn Any, &dyn Any) -> bool>;
+
+pub(crate) fn erase_compare<T: 'static>(
+    compare: impl Compare<T>,
+) -> AnyCompare {
+    Rc::new(move |old: &dyn Any, new: &dyn Any| {
+        match (old.downcast_ref::<T>(), new.downcast_ref::<T>()) {
+            (Some(old), Some(new)) => compare.is_equal(old, new),
+            _ => false,
+        }
+    })
+}
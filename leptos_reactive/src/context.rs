@@ -314,9 +314,10 @@ where
 
     use_context().unwrap_or_else(|| {
         panic!(
-            "{:?} expected context of type {:?} to be present",
+            "{:?} expected context of type {:?} to be present{}",
             location,
-            std::any::type_name::<T>()
+            std::any::type_name::<T>(),
+            crate::ComponentStack::current()
         )
     })
 }
@@ -2,6 +2,10 @@
 // tries to access a reactive variable outside the reactive scope. They track when
 // you create a signal/memo, and where you access it non-reactively.
 
+use crate::Oco;
+#[cfg(debug_assertions)]
+use std::{cell::RefCell, rc::Rc};
+
 #[cfg(debug_assertions)]
 #[allow(dead_code)] // allowed for SSR
 #[derive(Copy, Clone)]
@@ -76,3 +80,325 @@ macro_rules! diagnostics {
         }
     }};
 }
+
+/// The names of the components that are being built or updated on the
+/// current thread, from the innermost one outward.
+///
+/// In debug builds, the stack is kept while a component builds its view,
+/// and restored while the effects it creates run (which is when its view is
+/// updated or hydrated) and while its event handlers run. It is added to the
+/// messages of panics like the one for reading a disposed signal, so that they
+/// say which component the problem is in. In release builds, it is always
+/// empty, and keeping it costs nothing.
+///
+/// A panic hook can add it to other panics with [`ComponentStack::current`],
+/// because the stack is only unwound after the hook has run.
+#[derive(Clone, Debug, Default)]
+pub struct ComponentStack {
+    #[cfg(debug_assertions)]
+    top: Option<Rc<ComponentFrame>>,
+}
+
+#[cfg(debug_assertions)]
+#[derive(Debug)]
+struct ComponentFrame {
+    name: Oco<'static, str>,
+    parent: Option<Rc<ComponentFrame>>,
+}
+
+#[cfg(debug_assertions)]
+thread_local! {
+    static COMPONENT_STACK: RefCell<Option<Rc<ComponentFrame>>> =
+        const { RefCell::new(None) };
+}
+
+impl ComponentStack {
+    /// Returns the stack of components that are currently being built or
+    /// updated.
+    pub fn current() -> Self {
+        Self {
+            #[cfg(debug_assertions)]
+            top: COMPONENT_STACK.with(|stack| stack.borrow().clone()),
+        }
+    }
+
+    /// Runs `f` with a component with the given name pushed onto the current
+    /// stack. This is used to build the view of a component.
+    #[inline(always)]
+    pub fn with_component<T>(
+        #[allow(unused)] name: impl Into<Oco<'static, str>>,
+        f: impl FnOnce() -> T,
+    ) -> T {
+        #[cfg(debug_assertions)]
+        {
+            let name = name.into();
+            crate::render_counts::component_built(&name);
+            let node = RenderNode::Component(name.clone());
+            let frame = ComponentFrame {
+                name,
+                parent: Self::current().top,
+            };
+            Self {
+                top: Some(Rc::new(frame)),
+            }
+            .run(|| with_render_node(Some(node), f))
+        }
+        #[cfg(not(debug_assertions))]
+        f()
+    }
+
+    /// Runs `f` with this as the current stack, and restores the previous one
+    /// afterwards, even if `f` panics. This is used to run code later on behalf
+    /// of the components that were being built when it was set up, like
+    /// effects and event handlers.
+    #[inline(always)]
+    pub fn run<T>(&self, f: impl FnOnce() -> T) -> T {
+        #[cfg(debug_assertions)]
+        let _restore = RestoreComponentStack(
+            COMPONENT_STACK.with(|stack| stack.replace(self.top.clone())),
+        );
+        f()
+    }
+
+    /// Returns the name of the innermost component, if any.
+    #[cfg(debug_assertions)]
+    pub(crate) fn innermost(&self) -> Option<&Oco<'static, str>> {
+        self.top.as_ref().map(|frame| &frame.name)
+    }
+
+    /// Returns the names of the components, from the innermost one outward.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        #[cfg(debug_assertions)]
+        {
+            std::iter::successors(self.top.as_deref(), |frame| {
+                frame.parent.as_deref()
+            })
+            .map(|frame| frame.name.as_str())
+        }
+        #[cfg(not(debug_assertions))]
+        std::iter::empty()
+    }
+
+    /// Returns `true` if no components are being built or updated, which is
+    /// always the case in release builds.
+    pub fn is_empty(&self) -> bool {
+        self.names().next().is_none()
+    }
+}
+
+/// Formats the stack as a note that can be added to the end of an error
+/// message, on lines of its own, or as nothing if it is empty.
+impl std::fmt::Display for ComponentStack {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return Ok(());
+        }
+        f.write_str("\ncomponent stack:")?;
+        for name in self.names() {
+            write!(f, "\n    <{name}/>")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(debug_assertions)]
+struct RestoreComponentStack(Option<Rc<ComponentFrame>>);
+
+#[cfg(debug_assertions)]
+impl Drop for RestoreComponentStack {
+    fn drop(&mut self) {
+        let prev = self.0.take();
+        COMPONENT_STACK.with(|stack| *stack.borrow_mut() = prev);
+    }
+}
+
+/// The computation whose body is being run synchronously on the current
+/// thread, while a view is built or a memo is evaluated.
+#[cfg(debug_assertions)]
+#[derive(Clone, Debug)]
+pub(crate) enum RenderNode {
+    /// The body of the component with this name.
+    Component(Oco<'static, str>),
+    /// The memo created at this location.
+    Memo(&'static std::panic::Location<'static>),
+}
+
+#[cfg(debug_assertions)]
+impl std::fmt::Display for RenderNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RenderNode::Component(name) => write!(f, "the body of <{name}/>"),
+            RenderNode::Memo(defined_at) => {
+                write!(f, "the memo created at {defined_at}")
+            }
+        }
+    }
+}
+
+#[cfg(debug_assertions)]
+thread_local! {
+    static RENDER_NODE: RefCell<Option<RenderNode>> = const { RefCell::new(None) };
+    static ALLOW_RENDER_WRITES: std::cell::Cell<bool> =
+        const { std::cell::Cell::new(false) };
+}
+
+/// Runs `f` with `node` as the computation being rendered, or with none for
+/// effects, in which writing to signals is expected, and restores the
+/// previous one afterwards.
+#[cfg(debug_assertions)]
+pub(crate) fn with_render_node<T>(
+    node: Option<RenderNode>,
+    f: impl FnOnce() -> T,
+) -> T {
+    struct Restore(Option<RenderNode>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let prev = self.0.take();
+            RENDER_NODE.with(|node| *node.borrow_mut() = prev);
+        }
+    }
+
+    let _restore = Restore(RENDER_NODE.with(|curr| curr.replace(node)));
+    f()
+}
+
+/// Runs `f`, in which signals may be written while a component is built or a
+/// memo is evaluated without a warning.
+///
+/// In debug builds, writing to a signal that something depends on from the
+/// body of a component or of a memo logs a warning, because doing so marks
+/// the view or memo that is being evaluated as out of date again, which often
+/// causes infinite loops. Writes like this should usually be moved into an
+/// effect or an event handler; this is the escape hatch for those that are
+/// intentional. In release builds, it simply calls `f`.
+///
+/// ```
+/// # use leptos_reactive::*;
+/// # let runtime = create_runtime();
+/// let (count, _) = create_signal(1);
+/// let (evaluations, set_evaluations) = create_signal(0);
+/// create_isomorphic_effect(move |_| evaluations.get());
+///
+/// // a memo that knowingly counts how often it runs
+/// let doubled = create_memo(move |_| {
+///     allow_writes_during_render(|| set_evaluations.update(|n| *n += 1));
+///     count.get() * 2
+/// });
+/// assert_eq!(doubled.get(), 2);
+/// assert_eq!(evaluations.get(), 1);
+/// # runtime.dispose();
+/// ```
+#[inline(always)]
+pub fn allow_writes_during_render<T>(f: impl FnOnce() -> T) -> T {
+    #[cfg(debug_assertions)]
+    {
+        struct Restore(bool);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                ALLOW_RENDER_WRITES.with(|allow| allow.set(self.0));
+            }
+        }
+
+        let _restore =
+            Restore(ALLOW_RENDER_WRITES.with(|allow| allow.replace(true)));
+        f()
+    }
+    #[cfg(not(debug_assertions))]
+    f()
+}
+
+/// A signal that something depends on was written while a component was
+/// being built or a memo was being evaluated.
+#[cfg(debug_assertions)]
+pub(crate) struct RenderWriteWarning {
+    pub written_at: &'static std::panic::Location<'static>,
+    pub defined_at: Option<&'static std::panic::Location<'static>>,
+    pub node: RenderNode,
+    pub component_stack: ComponentStack,
+}
+
+#[cfg(debug_assertions)]
+impl RenderWriteWarning {
+    /// Returns the warning for a write at `written_at` if it happens while a
+    /// component is built or a memo is evaluated, and it has not been
+    /// allowed with [`allow_writes_during_render`].
+    pub fn check(
+        written_at: &'static std::panic::Location<'static>,
+        defined_at: Option<&'static std::panic::Location<'static>>,
+    ) -> Option<Self> {
+        if ALLOW_RENDER_WRITES.with(|allow| allow.get()) {
+            return None;
+        }
+        let node = RENDER_NODE.with(|node| node.borrow().clone())?;
+        Some(Self {
+            written_at,
+            defined_at,
+            node,
+            component_stack: ComponentStack::current(),
+        })
+    }
+}
+
+#[cfg(debug_assertions)]
+impl std::fmt::Display for RenderWriteWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[Signal::update] At {}, a signal", self.written_at)?;
+        if let Some(defined_at) = self.defined_at {
+            write!(f, " (defined at {defined_at})")?;
+        }
+        write!(
+            f,
+            " was written while {} was being evaluated. Whatever depends on \
+             the signal will run again, which can cause an infinite loop if \
+             it includes this. Move the write into an effect or an event \
+             handler, or wrap it in `allow_writes_during_render` if it is \
+             intentional.{}",
+            self.node, self.component_stack
+        )
+    }
+}
+
+#[cfg(all(test, debug_assertions))]
+mod tests {
+    use super::{
+        allow_writes_during_render, ComponentStack, RenderWriteWarning,
+    };
+    use crate::{
+        create_isomorphic_effect, create_memo, create_runtime, SignalGet,
+    };
+    use std::{cell::Cell, panic::Location, rc::Rc};
+
+    fn check() -> Option<String> {
+        RenderWriteWarning::check(Location::caller(), None)
+            .map(|warning| warning.to_string())
+    }
+
+    #[test]
+    fn detects_writes_while_rendering() {
+        let rt = create_runtime();
+        assert_eq!(check(), None);
+
+        let in_component = ComponentStack::with_component("Counter", check);
+        assert!(in_component.unwrap().contains("the body of <Counter/>"));
+
+        let in_memo = create_memo(|_| check());
+        assert!(in_memo.get().unwrap().contains("the memo created at"));
+
+        let in_effect = Rc::new(Cell::new(None));
+        ComponentStack::with_component("Counter", || {
+            create_isomorphic_effect({
+                let in_effect = Rc::clone(&in_effect);
+                move |_| in_effect.set(Some(check()))
+            })
+        });
+        assert_eq!(in_effect.take(), Some(None));
+
+        let allowed = ComponentStack::with_component("Counter", || {
+            allow_writes_during_render(check)
+        });
+        assert_eq!(allowed, None);
+        rt.dispose();
+    }
+}
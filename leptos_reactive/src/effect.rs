@@ -1,6 +1,11 @@
 use crate::{node::NodeId, with_runtime, Disposer, Runtime, SignalDispose};
 use cfg_if::cfg_if;
-use std::{any::Any, cell::RefCell, marker::PhantomData, rc::Rc};
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    marker::PhantomData,
+    rc::Rc,
+};
 
 /// Effects run a certain chunk of code whenever the signals they depend on change.
 /// `create_effect` queues the given function to run once, tracks its dependence
@@ -20,7 +25,8 @@ use std::{any::Any, cell::RefCell, marker::PhantomData, rc::Rc};
 /// [`create_render_effect`].)
 ///
 /// The effect function is called with an argument containing whatever value it returned
-/// the last time it ran. On the initial run, this is `None`.
+/// the last time it ran. On the initial run, this is `None`. If it returns an
+/// [`EffectCleanup`], the cleanup is run before the effect runs again and when it is disposed.
 ///
 /// By default, effects **do not run on the server**. This means you can call browser-specific
 /// APIs within the effect function without causing issues. If you need an effect to run on
@@ -68,6 +74,9 @@ pub fn create_effect<T>(f: impl Fn(Option<T>) -> T + 'static) -> Effect<T>
 where
     T: 'static,
 {
+    #[cfg(debug_assertions)]
+    let f = strict_mode_effect(f, std::panic::Location::caller());
+
     cfg_if! {
         if #[cfg(not(feature = "ssr"))] {
             use crate::{Owner, queue_microtask, with_owner};
@@ -108,7 +117,8 @@ where
     /// derived signal or [`create_memo`](crate::create_memo)).
     ///
     /// The effect function is called with an argument containing whatever value it returned
-    /// the last time it ran. On the initial run, this is `None`.
+    /// the last time it ran. On the initial run, this is `None`. If it returns an
+    /// [`EffectCleanup`], the cleanup is run before the effect runs again and when it is disposed.
     ///
     /// By default, effects **do not run on the server**. This means you can call browser-specific
     /// APIs within the effect function without causing issues. If you need an effect to run on
@@ -203,6 +213,85 @@ where
         .ok()
         .flatten()
     }
+
+    /// Turns on explain mode for this effect, which helps to debug why it
+    /// reruns, or why it doesn't.
+    ///
+    /// After each run from now on, the effect logs where each signal, memo and
+    /// trigger it read was created, and which of them it read for the first
+    /// time or stopped reading compared to the run before. The same
+    /// information can be read with [`Effect::explanation`].
+    ///
+    /// This does nothing in release builds.
+    ///
+    /// ```
+    /// # use leptos_reactive::*;
+    /// # let runtime = create_runtime();
+    /// let show_details = RwSignal::new(false);
+    /// let details = RwSignal::new(String::new());
+    /// let effect = Effect::new_isomorphic(move |_| {
+    ///     if show_details.get() {
+    ///         details.track();
+    ///     }
+    /// });
+    /// effect.explain();
+    ///
+    /// show_details.set(true);
+    /// # if cfg!(debug_assertions) {
+    /// let explanation = effect.explanation().unwrap();
+    /// assert_eq!(explanation.read.len(), 2);
+    /// # }
+    ///
+    /// show_details.set(false);
+    /// # if cfg!(debug_assertions) {
+    /// let explanation = effect.explanation().unwrap();
+    /// assert_eq!(explanation.read.len(), 1);
+    /// // it will no longer rerun when `details` changes
+    /// assert_eq!(explanation.removed.len(), 1);
+    /// # }
+    /// # runtime.dispose();
+    /// ```
+    pub fn explain(&self) {
+        #[cfg(debug_assertions)]
+        let _ = with_runtime(|runtime| {
+            let defined_at = runtime.nodes.borrow().get(self.id).and_then(
+                |node| match &node.node_type {
+                    crate::node::ReactiveNodeType::Effect { f } => {
+                        f.defined_at()
+                    }
+                    _ => None,
+                },
+            );
+            let mut explained = runtime.explained_effects.borrow_mut();
+            if !explained.contains_key(self.id) {
+                explained.insert(
+                    self.id,
+                    crate::explain::ExplainState::new(defined_at),
+                );
+            }
+        });
+    }
+
+    /// Returns what the effect read during its last run, if it is in explain
+    /// mode (see [`Effect::explain`]) and has run since it was turned on.
+    ///
+    /// This always returns `None` in release builds.
+    pub fn explanation(&self) -> Option<crate::EffectExplanation> {
+        #[cfg(debug_assertions)]
+        {
+            with_runtime(|runtime| {
+                let explained = runtime.explained_effects.borrow();
+                let state = explained.get(self.id)?;
+                (state.runs() > 0).then(|| state.explanation())
+            })
+            .ok()
+            .flatten()
+        }
+        #[cfg(not(debug_assertions))]
+        {
+            None
+        }
+    }
 }
 
 /// Creates an effect; unlike effects created by [`create_effect`], isomorphic effects will run on
@@ -249,6 +338,9 @@ pub fn create_isomorphic_effect<T>(
 where
     T: 'static,
 {
+    #[cfg(debug_assertions)]
+    let f = strict_mode_effect(f, std::panic::Location::caller());
+
     let runtime = Runtime::current();
     let id = runtime.create_effect(f);
     //crate::macros::debug_warn!("creating effect {e:?}");
@@ -261,6 +353,83 @@ where
     }
 }
 
+thread_local! {
+    static STRICT_MODE: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Turns strict mode on or off for the effects that are created afterwards on
+/// the current thread. Strict mode only does anything in debug builds.
+///
+/// In strict mode, the first run of each effect created with
+/// [`create_effect`] or [`create_isomorphic_effect`] happens twice: after the
+/// first run, its [`on_cleanup`](crate::on_cleanup) functions are called,
+/// everything it created is disposed of, and the value it returned is
+/// dropped, as if the component it belongs to was unmounted, and then it runs
+/// again. Effects with side effects that are not idempotent, or that don't
+/// clean up after themselves, like event listeners that are never removed,
+/// show up right away, rather than only once their component is unmounted
+/// and remounted. A message with the location of each effect that is run
+/// twice, and the component that created it, is logged.
+///
+/// Only effects are run twice for now, not the bodies of components: a
+/// component takes ownership of its props, which may not be `Clone`, and
+/// building its view twice would give it different hydration keys than on
+/// the server. Running the bodies of components with `Clone` props twice is
+/// left as a follow-up.
+///
+/// ```rust
+/// # use leptos_reactive::*;
+/// # let runtime = create_runtime();
+/// set_strict_mode(cfg!(debug_assertions));
+/// // ... mount the app
+/// # set_strict_mode(false);
+/// # runtime.dispose();
+/// ```
+pub fn set_strict_mode(enabled: bool) {
+    STRICT_MODE.with(|strict| strict.set(enabled));
+}
+
+/// Whether [strict mode](set_strict_mode) is turned on.
+pub fn is_strict_mode() -> bool {
+    cfg!(debug_assertions) && STRICT_MODE.with(Cell::get)
+}
+
+#[cfg(debug_assertions)]
+fn strict_mode_effect<T: 'static>(
+    f: impl Fn(Option<T>) -> T + 'static,
+    defined_at: &'static std::panic::Location<'static>,
+) -> impl Fn(Option<T>) -> T + 'static {
+    let run_twice = Cell::new(is_strict_mode());
+    // named when the effect is created, inside the component's body
+    let component = run_twice
+        .get()
+        .then(|| {
+            crate::ComponentStack::current()
+                .names()
+                .next()
+                .map(|name| format!(" in <{name}/>"))
+        })
+        .flatten()
+        .unwrap_or_default();
+    move |prev| {
+        if !run_twice.replace(false) {
+            return f(prev);
+        }
+        let mut first = Some(f(prev));
+        // clean up the first run, like before the effect reruns
+        if let Some(owner) = crate::Owner::current() {
+            _ = with_runtime(|runtime| runtime.cleanup_node(owner.0));
+        }
+        run_effect_cleanup(&mut first);
+        drop(first);
+        crate::console_log(&format!(
+            "[strict mode] running the effect created at {defined_at}\
+             {component} again"
+        ));
+        f(None)
+    }
+}
+
 /// Creates an effect exactly like [`create_effect`], but runs immediately rather
 /// than being queued until the end of the current microtask. This is mostly used
 /// inside the renderer but is available for use cases in which scheduling the effect
@@ -298,6 +467,82 @@ where
     }
 }
 
+/// A cleanup function that an effect can return, which is run before the
+/// effect runs again and when the effect is disposed.
+///
+/// This keeps the code that sets something up next to the code that tears it
+/// down, without sharing state with a separate [`on_cleanup`](crate::on_cleanup).
+/// An effect can return an `EffectCleanup` or an `Option<EffectCleanup>`;
+/// because the cleanup has already run, the effect receives `None` instead of
+/// its previous value.
+///
+/// Like any other cleanup, it doesn't track the signals it reads.
+/// ```
+/// # use leptos_reactive::*;
+/// # use std::{cell::Cell, rc::Rc};
+/// # let runtime = create_runtime();
+/// let room = RwSignal::new("general");
+/// let joined = Rc::new(Cell::new(""));
+///
+/// let effect = Effect::new_isomorphic({
+///     let joined = Rc::clone(&joined);
+///     move |_| {
+///         joined.set(room.get());
+///         let joined = Rc::clone(&joined);
+///         EffectCleanup::new(move || joined.set(""))
+///     }
+/// });
+/// assert_eq!(joined.get(), "general");
+///
+/// room.set("random");
+/// assert_eq!(joined.get(), "random");
+///
+/// effect.dispose();
+/// assert_eq!(joined.get(), "");
+/// # runtime.dispose();
+/// ```
+pub struct EffectCleanup(Option<Box<dyn FnOnce()>>);
+
+impl EffectCleanup {
+    /// Creates a cleanup that calls `f`.
+    pub fn new(f: impl FnOnce() + 'static) -> Self {
+        Self(Some(Box::new(f)))
+    }
+}
+
+impl std::fmt::Debug for EffectCleanup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EffectCleanup").finish_non_exhaustive()
+    }
+}
+
+impl Drop for EffectCleanup {
+    fn drop(&mut self) {
+        if let Some(cleanup) = self.0.take() {
+            // untrack the cleanup, like the ones registered with `on_cleanup`
+            let prev_observer = with_runtime(|runtime| runtime.observer.take())
+                .ok()
+                .flatten();
+            cleanup();
+            if prev_observer.is_some() {
+                _ = with_runtime(|runtime| runtime.observer.set(prev_observer));
+            }
+        }
+    }
+}
+
+/// Runs the cleanup returned by the last run of an effect, if it returned one.
+fn run_effect_cleanup<T: 'static>(value: &mut Option<T>) {
+    let value: &mut dyn Any = value;
+    if let Some(cleanup) = value.downcast_mut::<Option<EffectCleanup>>() {
+        cleanup.take();
+    } else if let Some(cleanup) =
+        value.downcast_mut::<Option<Option<EffectCleanup>>>()
+    {
+        cleanup.take();
+    }
+}
+
 /// A handle to an effect, can be used to explicitly dispose of the effect.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
 pub struct Effect<T> {
@@ -326,10 +571,15 @@ where
     pub(crate) ty: PhantomData<T>,
     #[cfg(any(debug_assertions, feature = "ssr"))]
     pub(crate) defined_at: &'static std::panic::Location<'static>,
+    #[cfg(debug_assertions)]
+    pub(crate) component_stack: crate::ComponentStack,
 }
 
 pub(crate) trait AnyComputation {
     fn run(&self, value: Rc<RefCell<dyn Any>>) -> bool;
+
+    #[cfg(any(debug_assertions, feature = "long-task-warnings"))]
+    fn defined_at(&self) -> Option<&'static std::panic::Location<'static>>;
 }
 
 impl<T, F> AnyComputation for EffectState<T, F>
@@ -353,7 +603,7 @@ where
         // we defensively take and release the BorrowMut twice here
         // in case a change during the effect running schedules a rerun
         // ideally this should never happen, but this guards against panic
-        let curr_value = {
+        let mut curr_value = {
             // downcast value
             let mut value = value.borrow_mut();
             let value = value
@@ -361,8 +611,18 @@ where
                 .expect("to downcast effect value");
             value.take()
         };
+        run_effect_cleanup(&mut curr_value);
 
         // run the effect
+        #[cfg(debug_assertions)]
+        if curr_value.is_some() {
+            if let Some(name) = self.component_stack.innermost() {
+                crate::render_counts::component_rebuilt(name);
+            }
+        }
+        #[cfg(debug_assertions)]
+        let new_value = self.component_stack.run(|| (self.f)(curr_value));
+        #[cfg(not(debug_assertions))]
         let new_value = (self.f)(curr_value);
 
         // set new value
@@ -374,4 +634,16 @@ where
 
         true
     }
+
+    #[cfg(any(debug_assertions, feature = "long-task-warnings"))]
+    fn defined_at(&self) -> Option<&'static std::panic::Location<'static>> {
+        #[cfg(any(debug_assertions, feature = "ssr"))]
+        {
+            Some(self.defined_at)
+        }
+        #[cfg(not(any(debug_assertions, feature = "ssr")))]
+        {
+            None
+        }
+    }
 }
@@ -0,0 +1,132 @@
+use std::panic::Location;
+#[cfg(debug_assertions)]
+use {
+    crate::node::NodeId, core::hash::BuildHasherDefault, indexmap::IndexMap,
+    rustc_hash::FxHasher,
+};
+
+#[cfg(debug_assertions)]
+type Reads =
+    IndexMap<NodeId, &'static Location<'static>, BuildHasherDefault<FxHasher>>;
+
+/// The signals, memos and triggers that an effect read during its last run,
+/// compared to the run before it, as returned by
+/// [`Effect::explanation`](crate::Effect::explanation).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EffectExplanation {
+    /// Where the effect was created.
+    pub defined_at: Option<&'static Location<'static>>,
+    /// How many times the effect has run since explain mode was turned on.
+    pub runs: usize,
+    /// Where each reactive value that was read during the last run was
+    /// created, in the order they were first read.
+    pub read: Vec<&'static Location<'static>>,
+    /// The values that were read during the last run, but not the one before
+    /// it.
+    pub added: Vec<&'static Location<'static>>,
+    /// The values that were read during the run before the last one, but not
+    /// the last one. If the effect stopped reading a value, it will no longer
+    /// rerun when that value changes.
+    pub removed: Vec<&'static Location<'static>>,
+}
+
+impl std::fmt::Display for EffectExplanation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.defined_at {
+            Some(defined_at) => write!(f, "Effect defined at {defined_at}")?,
+            None => f.write_str("Effect")?,
+        }
+        write!(
+            f,
+            " ran (run {} since explain mode was turned on) and read {} \
+             reactive value(s):",
+            self.runs,
+            self.read.len()
+        )?;
+        for location in &self.read {
+            write!(f, "\n  - {location}")?;
+        }
+        for (label, locations) in [
+            ("newly read", &self.added),
+            ("no longer read", &self.removed),
+        ] {
+            if !locations.is_empty() {
+                write!(f, "\n{label}:")?;
+                for location in locations {
+                    write!(f, "\n  - {location}")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// What has been recorded about an effect in explain mode.
+#[cfg(debug_assertions)]
+#[derive(Default)]
+pub(crate) struct ExplainState {
+    defined_at: Option<&'static Location<'static>>,
+    runs: usize,
+    current: Reads,
+    // `None` until the effect has run twice in explain mode
+    previous: Option<Reads>,
+}
+
+#[cfg(debug_assertions)]
+impl ExplainState {
+    pub fn new(defined_at: Option<&'static Location<'static>>) -> Self {
+        Self {
+            defined_at,
+            ..Default::default()
+        }
+    }
+
+    pub fn runs(&self) -> usize {
+        self.runs
+    }
+
+    pub fn start_run(&mut self) {
+        let last = std::mem::take(&mut self.current);
+        if self.runs > 0 {
+            self.previous = Some(last);
+        }
+        self.runs += 1;
+    }
+
+    pub fn record_read(
+        &mut self,
+        source: NodeId,
+        defined_at: &'static Location<'static>,
+    ) {
+        self.current.entry(source).or_insert(defined_at);
+    }
+
+    pub fn explanation(&self) -> EffectExplanation {
+        let (added, removed) = match &self.previous {
+            Some(previous) => (
+                self.current
+                    .iter()
+                    .filter(|(id, _)| !previous.contains_key(*id))
+                    .map(|(_, location)| *location)
+                    .collect(),
+                previous
+                    .iter()
+                    .filter(|(id, _)| !self.current.contains_key(*id))
+                    .map(|(_, location)| *location)
+                    .collect(),
+            ),
+            None => Default::default(),
+        };
+        EffectExplanation {
+            defined_at: self.defined_at,
+            runs: self.runs,
+            read: self.current.values().copied().collect(),
+            added,
+            removed,
+        }
+    }
+
+    pub fn log(&self) {
+        crate::console_log(&format!("[LEPTOS EXPLAIN] {}", self.explanation()));
+    }
+}
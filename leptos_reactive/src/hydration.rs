@@ -2,7 +2,7 @@
 use crate::Owner;
 use crate::{
     runtime::PinnedFuture, suspense::StreamChunk, with_runtime, ResourceId,
-    SignalGet, SuspenseContext,
+    SignalGet, SuspenseContext, SuspenseStreaming,
 };
 use futures::stream::FuturesUnordered;
 #[cfg(feature = "experimental-islands")]
@@ -93,22 +93,46 @@ impl SharedContext {
                 }
             });
 
+            // a blocking fragment that isn't ready in time is streamed
+            let max_wait = match context.streaming() {
+                SuspenseStreaming::Block { max_wait } => max_wait,
+                _ => None,
+            };
+            // and one that misses its deadline renders its fallback
+            let deadline = context.budget().deadline;
+            let max_wait = match (max_wait, deadline) {
+                (Some(max_wait), Some(deadline)) => {
+                    Some(max_wait.min(deadline))
+                }
+                (max_wait, deadline) => max_wait.or(deadline),
+            };
             shared_context.pending_fragments.insert(
                 key.to_string(),
                 FragmentData {
                     out_of_order: Box::pin(async move {
-                        rx1.next().await;
+                        ready_by_deadline(rx1.next(), deadline, context).await;
 
                         out_of_order_resolver()
                     }),
                     in_order: Box::pin(async move {
-                        rx2.next().await;
+                        ready_by_deadline(rx2.next(), deadline, context).await;
 
                         in_order_resolver()
                     }),
                     should_block: context.should_block(),
                     is_ready: Some(Box::pin(async move {
-                        rx3.next().await;
+                        match max_wait {
+                            Some(max_wait) => {
+                                futures::future::select(
+                                    rx3.next(),
+                                    Box::pin(sleep(max_wait)),
+                                )
+                                .await;
+                            }
+                            None => {
+                                rx3.next().await;
+                            }
+                        }
                     })),
                     local_only: context.has_local_only(),
                 },
@@ -228,6 +252,41 @@ impl SharedContext {
     }
 }
 
+// resolves after the given time on the server, and never elsewhere
+async fn sleep(duration: std::time::Duration) {
+    cfg_if::cfg_if! {
+        if #[cfg(all(feature = "ssr", not(feature = "spin")))] {
+            tokio::time::sleep(duration).await;
+        } else {
+            _ = duration;
+            futures::future::pending::<()>().await;
+        }
+    }
+}
+
+// waits until the resources under the suspense are ready, or marks it as
+// timed out once its deadline has passed
+async fn ready_by_deadline(
+    ready: impl std::future::Future,
+    deadline: Option<std::time::Duration>,
+    context: SuspenseContext,
+) {
+    match deadline {
+        Some(deadline) => {
+            let ready = Box::pin(ready);
+            let timeout = Box::pin(sleep(deadline));
+            if let futures::future::Either::Right(_) =
+                futures::future::select(ready, timeout).await
+            {
+                context.set_timed_out();
+            }
+        }
+        None => {
+            ready.await;
+        }
+    }
+}
+
 /// Represents its pending `<Suspense/>` fragment.
 pub struct FragmentData {
     /// Future that represents how it should be render for an out-of-order stream.
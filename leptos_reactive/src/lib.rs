@@ -83,11 +83,15 @@ extern crate tracing;
 #[macro_use]
 mod signal;
 pub mod callback;
+mod compare;
 mod context;
 #[macro_use]
 mod diagnostics;
 mod effect;
+mod explain;
 mod hydration;
+#[cfg(feature = "long-task-warnings")]
+mod long_tasks;
 // contains "private" implementation details right now.
 // could make this unhidden in the future if needed.
 // macro_export makes it public from the crate root anyways
@@ -95,8 +99,12 @@ mod hydration;
 pub mod macros;
 mod memo;
 mod node;
+mod render_counts;
 mod resource;
+mod resource_graph;
+mod rows;
 mod runtime;
+mod scheduler;
 mod selector;
 #[cfg(any(doc, feature = "serde"))]
 mod serde;
@@ -106,30 +114,45 @@ mod signal_wrappers_write;
 mod slice;
 mod spawn;
 mod spawn_microtask;
+mod store;
 mod stored_value;
 pub mod suspense;
 mod trigger;
 mod watch;
 
 pub use callback::*;
+pub use compare::{Changed, Compare};
 pub use context::*;
-pub use diagnostics::SpecialNonReactiveZone;
+pub use diagnostics::{
+    allow_writes_during_render, ComponentStack, SpecialNonReactiveZone,
+};
 pub use effect::*;
+pub use explain::EffectExplanation;
 pub use hydration::{FragmentData, SharedContext};
+#[cfg(feature = "long-task-warnings")]
+pub use long_tasks::*;
 pub use memo::*;
 pub use node::Disposer;
 pub use oco::*;
 pub use oco_ref as oco;
+pub use render_counts::{
+    component_render_counts, reset_component_render_counts,
+    ComponentRenderCount,
+};
 pub use resource::*;
+pub use resource_graph::{resource_dependency_graph, ResourceDependency};
+pub use rows::*;
 use runtime::*;
 pub use runtime::{
     as_child_of_current_owner, batch, create_runtime, current_runtime,
-    on_cleanup, run_as_child, set_current_runtime,
-    spawn_local_with_current_owner, spawn_local_with_owner, try_batch,
-    try_spawn_local_with_current_owner, try_spawn_local_with_owner,
-    try_with_owner, untrack, untrack_with_diagnostics, with_current_owner,
-    with_owner, Owner, RuntimeId, ScopedFuture,
+    on_cleanup, on_cleanup_after_children, on_cleanup_before_children,
+    run_as_child, set_current_runtime, spawn_local_with_current_owner,
+    spawn_local_with_owner, try_batch, try_spawn_local_with_current_owner,
+    try_spawn_local_with_owner, try_with_owner, untrack,
+    untrack_with_diagnostics, with_current_owner, with_owner, Owner, RuntimeId,
+    ScopedFuture,
 };
+pub use scheduler::*;
 pub use selector::*;
 pub use serialization::*;
 pub use signal::{prelude as signal_prelude, *};
@@ -138,11 +161,26 @@ pub use signal_wrappers_write::*;
 pub use slice::*;
 pub use spawn::*;
 pub use spawn_microtask::*;
+pub use store::*;
 pub use stored_value::*;
-pub use suspense::{GlobalSuspenseContext, SuspenseContext};
+pub use suspense::{
+    use_suspense_timeout, GlobalSuspenseContext, SuspenseBudget,
+    SuspenseContext, SuspenseStreaming, SuspenseTimeout,
+};
 pub use trigger::*;
 pub use watch::*;
 
+#[cfg(debug_assertions)]
+pub(crate) fn console_log(s: &str) {
+    cfg_if::cfg_if! {
+        if #[cfg(all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate")))] {
+            web_sys::console::log_1(&wasm_bindgen::JsValue::from_str(s));
+        } else {
+            eprintln!("{s}");
+        }
+    }
+}
+
 #[doc(hidden)]
 pub fn console_warn(s: &str) {
     cfg_if::cfg_if! {
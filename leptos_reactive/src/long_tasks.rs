@@ -0,0 +1,221 @@
+use rustc_hash::FxHashMap;
+use std::{
+    cell::{Cell, RefCell},
+    panic::Location,
+    time::Duration,
+};
+
+thread_local! {
+    static THRESHOLD: Cell<Duration> =
+        const { Cell::new(Duration::from_millis(8)) };
+    static REPORT: RefCell<FxHashMap<LongTaskKey, LongTask>> =
+        Default::default();
+}
+
+type LongTaskKey = (ComputationKind, Option<&'static Location<'static>>);
+
+/// Whether a computation is an effect or a memo.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ComputationKind {
+    /// An effect, including the effects the renderer creates for the reactive
+    /// parts of a view.
+    Effect,
+    /// A memo.
+    Memo,
+}
+
+impl std::fmt::Display for ComputationKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ComputationKind::Effect => "effect",
+            ComputationKind::Memo => "memo",
+        })
+    }
+}
+
+/// The runs of a single effect or memo that took longer than the long task
+/// threshold, as returned by [`long_task_report`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LongTask {
+    /// Whether this is an effect or a memo.
+    pub kind: ComputationKind,
+    /// Where the effect or memo was created. This is only known in debug
+    /// builds, or with the `ssr` feature.
+    pub defined_at: Option<&'static Location<'static>>,
+    /// How many times it took longer than the threshold.
+    pub count: usize,
+    /// The total time taken by those runs.
+    pub total: Duration,
+    /// The time taken by the longest run.
+    pub max: Duration,
+}
+
+/// Sets how long an effect or memo can run before a warning is logged.
+/// Defaults to 8ms.
+///
+/// The time of a run includes the time taken by any memos it reads that need
+/// to re-run, so a slow memo is also reported for the effects that read it.
+pub fn set_long_task_threshold(threshold: Duration) {
+    THRESHOLD.with(|t| t.set(threshold));
+}
+
+/// Returns every effect and memo that has taken longer than the long task
+/// threshold since the report was last reset, starting with those that have
+/// taken the longest in total.
+///
+/// In the browser, the same report can be read from the devtools console
+/// by calling `__leptos_long_tasks()`.
+///
+/// ```
+/// # use leptos_reactive::*;
+/// # use std::time::Duration;
+/// # let runtime = create_runtime();
+/// set_long_task_threshold(Duration::from_millis(1));
+/// let count = create_rw_signal(0);
+/// create_isomorphic_effect(move |_| {
+///     count.track();
+///     std::thread::sleep(Duration::from_millis(5));
+/// });
+/// count.set(1);
+///
+/// let report = long_task_report();
+/// assert_eq!(report[0].kind, ComputationKind::Effect);
+/// assert_eq!(report[0].count, 2);
+/// assert!(report[0].max >= Duration::from_millis(5));
+///
+/// reset_long_task_report();
+/// assert!(long_task_report().is_empty());
+/// # runtime.dispose();
+/// ```
+pub fn long_task_report() -> Vec<LongTask> {
+    let mut report = REPORT
+        .with(|report| report.borrow().values().cloned().collect::<Vec<_>>());
+    report.sort_by_key(|task| std::cmp::Reverse(task.total));
+    report
+}
+
+/// Clears the report returned by [`long_task_report`].
+pub fn reset_long_task_report() {
+    REPORT.with(|report| report.borrow_mut().clear());
+}
+
+/// Measures a single run of an effect or memo.
+pub(crate) struct LongTaskTimer {
+    started_at: Option<f64>,
+}
+
+impl LongTaskTimer {
+    pub fn start() -> Self {
+        Self { started_at: now() }
+    }
+
+    pub fn finish(
+        self,
+        kind: ComputationKind,
+        defined_at: Option<&'static Location<'static>>,
+    ) {
+        let (Some(started_at), Some(finished_at)) = (self.started_at, now())
+        else {
+            return;
+        };
+        let elapsed = Duration::from_secs_f64(
+            (finished_at - started_at).max(0.0) / 1000.0,
+        );
+        if elapsed <= THRESHOLD.with(Cell::get) {
+            return;
+        }
+
+        let location = match defined_at {
+            Some(defined_at) => format!(" defined at {defined_at}"),
+            None => String::new(),
+        };
+        crate::console_warn(&format!(
+            "[LEPTOS WARNING] Slow {kind}{location}: it took {elapsed:?} to \
+             run, which is longer than the long task threshold of {:?}.",
+            THRESHOLD.with(Cell::get)
+        ));
+
+        REPORT.with(|report| {
+            let mut report = report.borrow_mut();
+            let task = report.entry((kind, defined_at)).or_insert(LongTask {
+                kind,
+                defined_at,
+                count: 0,
+                total: Duration::ZERO,
+                max: Duration::ZERO,
+            });
+            task.count += 1;
+            task.total += elapsed;
+            task.max = task.max.max(elapsed);
+        });
+        #[cfg(all(
+            target_arch = "wasm32",
+            any(feature = "csr", feature = "hydrate")
+        ))]
+        devtools::install();
+    }
+}
+
+// the current time in milliseconds, or `None` if it can't be measured
+fn now() -> Option<f64> {
+    cfg_if::cfg_if! {
+        if #[cfg(all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate")))] {
+            Some(js_sys::Date::now())
+        } else if #[cfg(not(target_arch = "wasm32"))] {
+            thread_local! {
+                static EPOCH: std::time::Instant = std::time::Instant::now();
+            }
+            Some(EPOCH.with(|epoch| epoch.elapsed().as_secs_f64() * 1000.0))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate")))]
+mod devtools {
+    use super::long_task_report;
+    use serde::Serialize;
+    use std::cell::Cell;
+    use wasm_bindgen::{closure::Closure, JsValue};
+
+    thread_local! {
+        static INSTALLED: Cell<bool> = const { Cell::new(false) };
+    }
+
+    #[derive(Serialize)]
+    struct Entry {
+        kind: String,
+        defined_at: Option<String>,
+        count: usize,
+        total_ms: f64,
+        max_ms: f64,
+    }
+
+    // defines `__leptos_long_tasks()` on the global object, so the report can
+    // be read from the devtools console
+    pub fn install() {
+        if INSTALLED.with(|installed| installed.replace(true)) {
+            return;
+        }
+        let report = Closure::<dyn Fn() -> JsValue>::new(|| {
+            let entries = long_task_report()
+                .into_iter()
+                .map(|task| Entry {
+                    kind: task.kind.to_string(),
+                    defined_at: task.defined_at.map(ToString::to_string),
+                    count: task.count,
+                    total_ms: task.total.as_secs_f64() * 1000.0,
+                    max_ms: task.max.as_secs_f64() * 1000.0,
+                })
+                .collect::<Vec<_>>();
+            serde_wasm_bindgen::to_value(&entries).unwrap_or(JsValue::NULL)
+        });
+        _ = js_sys::Reflect::set(
+            &js_sys::global(),
+            &JsValue::from_str("__leptos_long_tasks"),
+            report.as_ref(),
+        );
+        report.forget();
+    }
+}
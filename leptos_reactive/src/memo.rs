@@ -1,6 +1,6 @@
 use crate::{
     create_isomorphic_effect, diagnostics::AccessDiagnostics, node::NodeId,
-    on_cleanup, with_runtime, AnyComputation, Runtime, SignalDispose,
+    on_cleanup, with_runtime, AnyComputation, Compare, Runtime, SignalDispose,
     SignalGet, SignalGetUntracked, SignalStream, SignalWith,
     SignalWithUntracked,
 };
@@ -93,6 +93,78 @@ where
     })
 }
 
+/// Like [`create_memo`], but uses `compare` rather than [`PartialEq`] to
+/// decide whether the new value is different from the previous one, and so
+/// whether to notify its subscribers.
+///
+/// `compare` can be a closure that takes the previous and the new value and
+/// returns whether they are equal, or a [`Changed`](crate::Changed) strategy.
+/// This makes it possible to memoize values that don't implement `PartialEq`,
+/// or that should be compared differently, like floats within some epsilon.
+///
+/// ```
+/// # use leptos_reactive::*;
+/// # let runtime = create_runtime();
+/// let (value, set_value) = create_signal(1.0_f64);
+/// let rounded = create_memo_with_compare(
+///     move |_| value.get(),
+///     |a: &f64, b: &f64| (a - b).abs() < 0.5,
+/// );
+/// let runs = create_rw_signal(0);
+/// create_isomorphic_effect(move |_| {
+///     rounded.track();
+///     runs.update(|n| *n += 1);
+/// });
+///
+/// set_value.set(1.2);
+/// assert_eq!(runs.get_untracked(), 1);
+/// // the memo keeps the new value, even though it is not a change
+/// assert_eq!(rounded.get_untracked(), 1.2);
+///
+/// set_value.set(2.0);
+/// assert_eq!(runs.get_untracked(), 2);
+///
+/// // a value that is not `PartialEq` can notify on every run
+/// struct Opaque;
+/// let opaque = create_memo_with_compare(
+///     move |_| {
+///         value.track();
+///         Opaque
+///     },
+///     Changed::Always,
+/// );
+/// # _ = opaque;
+/// # runtime.dispose();
+/// ```
+#[cfg_attr(
+    any(debug_assertions, feature="ssr"),
+    instrument(
+        level = "trace",
+        skip_all,
+        fields(
+            ty = %std::any::type_name::<T>()
+        )
+    )
+)]
+#[track_caller]
+#[inline(always)]
+pub fn create_memo_with_compare<T>(
+    f: impl Fn(Option<&T>) -> T + 'static,
+    compare: impl Compare<T>,
+) -> Memo<T>
+where
+    T: 'static,
+{
+    Runtime::current().create_owning_memo(move |current_value| {
+        let new_value = f(current_value.as_ref());
+        let is_different = match &current_value {
+            Some(current_value) => !compare.is_equal(current_value, &new_value),
+            None => true,
+        };
+        (new_value, is_different)
+    })
+}
+
 /// Like [`create_memo`], `create_owning_memo` creates an efficient derived reactive value based on
 /// other reactive values, but with two differences:
 /// 1. The argument to the memo function is owned instead of borrowed.
@@ -298,6 +370,22 @@ impl<T> Memo<T> {
         create_memo(f)
     }
 
+    /// Creates a new memo that uses `compare` to decide whether its value has
+    /// changed.
+    ///
+    /// This is identical to [`create_memo_with_compare`].
+    #[inline(always)]
+    #[track_caller]
+    pub fn new_with_compare(
+        f: impl Fn(Option<&T>) -> T + 'static,
+        compare: impl Compare<T>,
+    ) -> Memo<T>
+    where
+        T: 'static,
+    {
+        create_memo_with_compare(f, compare)
+    }
+
     /// Creates a new owning memo from the given function.
     ///
     /// This is identical to [`create_owning_memo`].
@@ -704,6 +792,18 @@ where
 
         is_different
     }
+
+    #[cfg(any(debug_assertions, feature = "long-task-warnings"))]
+    fn defined_at(&self) -> Option<&'static std::panic::Location<'static>> {
+        #[cfg(any(debug_assertions, feature = "ssr"))]
+        {
+            Some(self.defined_at)
+        }
+        #[cfg(not(any(debug_assertions, feature = "ssr")))]
+        {
+            None
+        }
+    }
 }
 
 #[cold]
@@ -728,7 +828,10 @@ fn format_memo_warning(
         }
     };
 
-    format!("{msg}\n{defined_at_msg}warning happened here: {location}",)
+    format!(
+        "{msg}\n{defined_at_msg}warning happened here: {location}{}",
+        crate::ComponentStack::current()
+    )
 }
 
 #[cold]
@@ -1,4 +1,4 @@
-use crate::{with_runtime, AnyComputation};
+use crate::{compare::AnyCompare, with_runtime, AnyComputation};
 use std::{any::Any, cell::RefCell, rc::Rc};
 
 slotmap::new_key_type! {
@@ -38,7 +38,7 @@ impl ReactiveNode {
 #[derive(Clone)]
 pub(crate) enum ReactiveNodeType {
     Trigger,
-    Signal,
+    Signal { compare: Option<AnyCompare> },
     Memo { f: Rc<dyn AnyComputation> },
     Effect { f: Rc<dyn AnyComputation> },
 }
@@ -0,0 +1,132 @@
+use crate::Oco;
+use std::panic::Location;
+#[cfg(debug_assertions)]
+use {
+    crate::runtime::with_runtime, core::hash::BuildHasherDefault,
+    indexmap::IndexMap, rustc_hash::FxHasher,
+};
+
+/// How often the components with one name have been built and updated in
+/// the current runtime, as returned by [`component_render_counts`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ComponentRenderCount {
+    /// The name of the component.
+    pub name: Oco<'static, str>,
+    /// How many times the body of a component with this name has run.
+    pub builds: usize,
+    /// How many times an effect created while a component with this name was
+    /// being built has run again, which is when part of its view is updated.
+    /// Effects created by components nested in it are counted for those.
+    pub rebuilds: usize,
+    /// Where the signal or trigger that was last written before the latest
+    /// rebuild was created, if it is known. When several signals are written
+    /// in a [`batch`](crate::batch), this is the last one of them.
+    pub last_rebuild_cause: Option<&'static Location<'static>>,
+}
+
+/// Returns how often each component has been built and updated in the
+/// current runtime, in the order they were first built.
+///
+/// Components are counted by name, so every instance of a component adds to
+/// the same count. This can be used to find the components whose views are
+/// updated far more often than expected, and the signals that cause it. The
+/// counts are only kept in debug builds; in release builds, this always
+/// returns an empty list.
+///
+/// ```
+/// # use leptos_reactive::*;
+/// # let runtime = create_runtime();
+/// for count in component_render_counts() {
+///     println!(
+///         "<{}/> was built {} times and updated {} times",
+///         count.name, count.builds, count.rebuilds
+///     );
+/// }
+/// # runtime.dispose();
+/// ```
+pub fn component_render_counts() -> Vec<ComponentRenderCount> {
+    #[cfg(debug_assertions)]
+    {
+        with_runtime(|runtime| {
+            runtime
+                .render_counts
+                .borrow()
+                .counts
+                .values()
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default()
+    }
+    #[cfg(not(debug_assertions))]
+    {
+        Vec::new()
+    }
+}
+
+/// Resets the counts returned by [`component_render_counts`] for the current
+/// runtime, for example before the interaction that is being measured.
+pub fn reset_component_render_counts() {
+    #[cfg(debug_assertions)]
+    {
+        _ = with_runtime(|runtime| {
+            runtime.render_counts.borrow_mut().counts.clear();
+        });
+    }
+}
+
+#[cfg(debug_assertions)]
+#[derive(Default)]
+pub(crate) struct RenderCounts {
+    counts: IndexMap<
+        Oco<'static, str>,
+        ComponentRenderCount,
+        BuildHasherDefault<FxHasher>,
+    >,
+    // where the signal or trigger that was written last was created
+    last_write: Option<&'static Location<'static>>,
+}
+
+#[cfg(debug_assertions)]
+impl RenderCounts {
+    fn entry(&mut self, name: &Oco<'static, str>) -> &mut ComponentRenderCount {
+        self.counts.entry(name.clone()).or_insert_with(|| {
+            ComponentRenderCount {
+                name: name.clone(),
+                builds: 0,
+                rebuilds: 0,
+                last_rebuild_cause: None,
+            }
+        })
+    }
+}
+
+/// Records that the body of the component with this name is about to run.
+#[cfg(debug_assertions)]
+pub(crate) fn component_built(name: &Oco<'static, str>) {
+    _ = with_runtime(|runtime| {
+        runtime.render_counts.borrow_mut().entry(name).builds += 1;
+    });
+}
+
+/// Records that an effect created by the component with this name is about
+/// to run again.
+#[cfg(debug_assertions)]
+pub(crate) fn component_rebuilt(name: &Oco<'static, str>) {
+    _ = with_runtime(|runtime| {
+        let mut counts = runtime.render_counts.borrow_mut();
+        let cause = counts.last_write;
+        let count = counts.entry(name);
+        count.rebuilds += 1;
+        count.last_rebuild_cause = cause;
+    });
+}
+
+/// Records that a signal or trigger that something depends on is being
+/// written.
+#[cfg(debug_assertions)]
+pub(crate) fn signal_written(defined_at: Option<&'static Location<'static>>) {
+    _ = with_runtime(|runtime| {
+        runtime.render_counts.borrow_mut().last_write = defined_at;
+    });
+}
@@ -9,12 +9,13 @@ use crate::{
     suspense::LocalStatus, use_context, GlobalSuspenseContext, Memo,
     ReadSignal, ScopeProperty, Signal, SignalDispose, SignalGet,
     SignalGetUntracked, SignalSet, SignalUpdate, SignalWith,
-    SignalWithUntracked, SuspenseContext, WriteSignal,
+    SignalWithUntracked, SuspenseContext, Trigger, WriteSignal,
 };
+use futures::future::Either;
 use std::{
     any::Any,
     cell::{Cell, RefCell},
-    collections::HashSet,
+    collections::{HashSet, VecDeque},
     fmt::Debug,
     future::Future,
     marker::PhantomData,
@@ -94,6 +95,7 @@ use std::{
         )
     )
 )]
+#[track_caller]
 pub fn create_resource<S, T, Fu>(
     source: impl Fn() -> S + 'static,
     fetcher: impl Fn(S) -> Fu + 'static,
@@ -144,6 +146,72 @@ where
         fetcher,
         initial_value,
         ResourceSerialization::Serializable,
+        ResourcePriority::High,
+        |_| false,
+    )
+}
+
+/// Creates a [`Resource`](crate::Resource) that only starts loading once more
+/// important resources have loaded, according to its [`ResourcePriority`].
+///
+/// On the server, this also decides when the HTML of the `<Suspense/>` that
+/// reads the resource is streamed, so that the parts of the page that matter
+/// most arrive first. In the browser, it keeps data that is not needed yet
+/// from competing with the data that is.
+///
+/// ```
+/// # use leptos_reactive::*;
+/// # let runtime = create_runtime();
+/// # if false {
+/// # let post_id = create_rw_signal(1);
+/// # async fn load_post(_: usize) -> String { todo!() }
+/// # async fn load_comments(_: usize) -> Vec<String> { todo!() }
+/// let post = create_resource(move || post_id.get(), load_post);
+/// // starts once the post has loaded
+/// let comments = create_resource_with_priority(
+///     move || post_id.get(),
+///     load_comments,
+///     ResourcePriority::Low,
+/// );
+/// // starts once both have loaded, and shares the prefetch limit with
+/// // every other prefetch
+/// let next_post = create_resource_with_priority(
+///     move || post_id.get() + 1,
+///     load_post,
+///     ResourcePriority::Prefetch,
+/// );
+/// # }
+/// # runtime.dispose();
+/// ```
+#[cfg_attr(
+    any(debug_assertions, feature="ssr"),
+    instrument(
+        level = "trace",
+        skip_all,
+        fields(
+            ty = %std::any::type_name::<T>(),
+            signal_ty = %std::any::type_name::<S>(),
+        )
+    )
+)]
+#[track_caller]
+pub fn create_resource_with_priority<S, T, Fu>(
+    source: impl Fn() -> S + 'static,
+    fetcher: impl Fn(S) -> Fu + 'static,
+    priority: ResourcePriority,
+) -> Resource<S, T>
+where
+    S: PartialEq + Clone + 'static,
+    T: Serializable + 'static,
+    Fu: Future<Output = T> + 'static,
+{
+    create_resource_helper(
+        source,
+        fetcher,
+        None,
+        ResourceSerialization::Serializable,
+        priority,
+        |_| false,
     )
 }
 
@@ -192,14 +260,109 @@ where
         fetcher,
         None,
         ResourceSerialization::Blocking,
+        ResourcePriority::High,
+        |_| false,
     )
 }
 
+/// The source of a resource created with [`create_skippable_resource`]:
+/// either a key to fetch, or a signal that there is nothing to fetch yet.
+///
+/// `Option<S>` converts into this, with `None` meaning [`ResourceSource::Skip`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResourceSource<S> {
+    /// Fetch the data for this key.
+    Fetch(S),
+    /// Don't call the fetcher; the resource is idle.
+    Skip,
+}
+
+impl<S> ResourceSource<S> {
+    /// Whether this is [`ResourceSource::Skip`].
+    pub fn is_skip(&self) -> bool {
+        matches!(self, ResourceSource::Skip)
+    }
+}
+
+impl<S> From<Option<S>> for ResourceSource<S> {
+    fn from(value: Option<S>) -> Self {
+        match value {
+            Some(key) => ResourceSource::Fetch(key),
+            None => ResourceSource::Skip,
+        }
+    }
+}
+
+/// Creates a [`Resource`](crate::Resource) whose source can say that there is
+/// nothing to fetch yet, for example because it depends on data that another
+/// resource or the user still has to provide.
+///
+/// While the source is [`ResourceSource::Skip`], the `fetcher` isn't called:
+/// the resource has no value, is not [`loading`](Resource::loading), and does
+/// not hold up any `<Suspense/>` that reads it. Instead, it is
+/// [`idle`](Resource::idle). Once the source is [`ResourceSource::Fetch`], it
+/// loads like any other resource, and it becomes idle again, dropping its
+/// value, whenever the source goes back to `Skip`.
+///
+/// ```
+/// # use leptos_reactive::*;
+/// # let runtime = create_runtime();
+/// # if false {
+/// # let user_id = create_rw_signal(None::<u32>);
+/// # let page = create_rw_signal(1);
+/// # async fn load_posts(_: u32, _: usize) -> Vec<String> { todo!() }
+/// // nothing is loaded until a user has been chosen
+/// let posts = create_skippable_resource(
+///     move || user_id.get().map(|id| (id, page.get())).into(),
+///     |(id, page)| load_posts(id, page),
+/// );
+/// let showing_posts = move || !posts.idle().get();
+/// # }
+/// # runtime.dispose();
+/// ```
+#[cfg_attr(
+    any(debug_assertions, feature="ssr"),
+    instrument(
+        level = "trace",
+        skip_all,
+        fields(
+            ty = %std::any::type_name::<T>(),
+            signal_ty = %std::any::type_name::<S>(),
+        )
+    )
+)]
+#[track_caller]
+pub fn create_skippable_resource<S, T, Fu>(
+    source: impl Fn() -> ResourceSource<S> + 'static,
+    fetcher: impl Fn(S) -> Fu + 'static,
+) -> Resource<ResourceSource<S>, T>
+where
+    S: PartialEq + Clone + 'static,
+    T: Serializable + 'static,
+    Fu: Future<Output = T> + 'static,
+{
+    create_resource_helper(
+        source,
+        move |source| match source {
+            ResourceSource::Fetch(key) => Either::Left(fetcher(key)),
+            // never called: the resource doesn't load skipped sources
+            ResourceSource::Skip => Either::Right(futures::future::pending()),
+        },
+        None,
+        ResourceSerialization::Serializable,
+        ResourcePriority::High,
+        ResourceSource::is_skip,
+    )
+}
+
+#[track_caller]
 fn create_resource_helper<S, T, Fu>(
     source: impl Fn() -> S + 'static,
     fetcher: impl Fn(S) -> Fu + 'static,
     initial_value: Option<T>,
     serializable: ResourceSerialization,
+    priority: ResourcePriority,
+    skipped: fn(&S) -> bool,
 ) -> Resource<S, T>
 where
     S: PartialEq + Clone + 'static,
@@ -229,6 +392,8 @@ where
         version: Rc::new(Cell::new(0)),
         suspense_contexts: Default::default(),
         serializable,
+        priority,
+        skipped,
         #[cfg(feature = "experimental-islands")]
         should_send_to_client: Default::default(),
     });
@@ -240,6 +405,12 @@ where
         id
     })
     .expect("tried to create a Resource in a Runtime that has been disposed.");
+    #[cfg(debug_assertions)]
+    crate::resource_graph::resource_created(
+        id,
+        source.id,
+        std::panic::Location::caller(),
+    );
 
     create_isomorphic_effect({
         let r = Rc::clone(&r);
@@ -248,6 +419,7 @@ where
             load_resource(id, r.clone());
         }
     });
+    refetch_on_retry(id, &r);
 
     Resource {
         id,
@@ -316,6 +488,7 @@ where
         )
     )
 )]
+#[track_caller]
 pub fn create_local_resource<S, T, Fu>(
     source: impl Fn() -> S + 'static,
     fetcher: impl Fn(S) -> Fu + 'static,
@@ -349,6 +522,7 @@ where
         )
     )
 )]
+#[track_caller]
 pub fn create_local_resource_with_initial_value<S, T, Fu>(
     source: impl Fn() -> S + 'static,
     fetcher: impl Fn(S) -> Fu + 'static,
@@ -381,6 +555,8 @@ where
         version: Rc::new(Cell::new(0)),
         suspense_contexts: Default::default(),
         serializable: ResourceSerialization::Local,
+        priority: ResourcePriority::High,
+        skipped: |_| false,
         #[cfg(feature = "experimental-islands")]
         should_send_to_client: Default::default(),
     });
@@ -392,6 +568,12 @@ where
         id
     })
     .expect("tried to create a Resource in a runtime that has been disposed.");
+    #[cfg(debug_assertions)]
+    crate::resource_graph::resource_created(
+        id,
+        source.id,
+        std::panic::Location::caller(),
+    );
 
     // This is a local resource, so we're always going to handle it on the
     // client
@@ -402,6 +584,7 @@ where
             r.load(false, id)
         }
     });
+    refetch_on_retry(id, &r);
 
     Resource {
         id,
@@ -603,6 +786,25 @@ where
         }
     }
 
+    /// Returns a signal that indicates whether the resource is idle, which is
+    /// when its source says that there is nothing to fetch. Only resources
+    /// created with [`create_skippable_resource`] are ever idle.
+    ///
+    /// An idle resource is neither loading nor holding a value.
+    pub fn idle(&self) -> Signal<bool> {
+        let (source, skipped) = with_runtime(|runtime| {
+            runtime.resource(self.id, |resource: &ResourceState<S, T>| {
+                (resource.source, resource.skipped)
+            })
+        })
+        .expect(
+            "tried to call Resource::idle() in a runtime that has already \
+             been disposed.",
+        );
+
+        Signal::derive(move || source.with(skipped))
+    }
+
     /// Re-runs the async function with the current source data.
     #[cfg_attr(
         any(debug_assertions, feature = "ssr"),
@@ -1149,6 +1351,9 @@ where
     version: Rc<Cell<usize>>,
     suspense_contexts: Rc<RefCell<HashSet<SuspenseContext>>>,
     serializable: ResourceSerialization,
+    priority: ResourcePriority,
+    // whether the fetcher is not called for this value of the source
+    skipped: fn(&S) -> bool,
     #[cfg(feature = "experimental-islands")]
     should_send_to_client: Rc<Cell<Option<bool>>>,
 }
@@ -1189,6 +1394,8 @@ where
         location: &'static Location<'static>,
         id: ResourceId,
     ) -> Option<U> {
+        #[cfg(debug_assertions)]
+        crate::resource_graph::resource_read(id, location);
         let global_suspense_cx = use_context::<GlobalSuspenseContext>();
         let suspense_cx = use_context::<SuspenseContext>();
 
@@ -1215,6 +1422,8 @@ where
         location: &'static Location<'static>,
         id: ResourceId,
     ) -> Option<U> {
+        #[cfg(debug_assertions)]
+        crate::resource_graph::resource_read(id, location);
         let global_suspense_cx = use_context::<GlobalSuspenseContext>();
         let suspense_cx = use_context::<SuspenseContext>();
         let (was_loaded, v) =
@@ -1241,6 +1450,8 @@ where
     ) -> Option<U> {
         let suspense_contexts = self.suspense_contexts.clone();
         let has_value = v.is_some();
+        // an idle resource isn't loading, so it doesn't suspend
+        let idle = self.is_idle();
 
         let serializable = self.serializable;
         if let Some(suspense_cx) = &suspense_cx {
@@ -1306,7 +1517,7 @@ where
                         // on subsequent reads, increment will be triggered in load()
                         // because the context has been tracked here
                         // on the first read, resource is already loading without having incremented
-                        if !has_value || force_suspend {
+                        if (!has_value || force_suspend) && !idle {
                             s.increment_for_resource(
                                 serializable != ResourceSerialization::Local,
                                 id,
@@ -1351,6 +1562,13 @@ where
         self.load(true, id);
     }
 
+    /// Whether the current value of the source is one that isn't fetched.
+    fn is_idle(&self) -> bool {
+        self.source
+            .try_with_untracked(|source| (self.skipped)(source))
+            .unwrap_or(false)
+    }
+
     #[cfg_attr(
         any(debug_assertions, feature = "ssr"),
         instrument(level = "trace", skip_all,)
@@ -1375,6 +1593,18 @@ where
         self.scheduled.set(false);
 
         _ = self.source.try_with_untracked(|source| {
+            if (self.skipped)(source) {
+                // idle: the value of the previous source, and any load that
+                // is still running for it, are stale
+                if self.value.with_untracked(Option::is_some) {
+                    self.set_value.update(|n| *n = None);
+                }
+                if self.loading.get_untracked() {
+                    self.set_loading.update(|n| *n = false);
+                }
+                return;
+            }
+
             let fut = (self.fetcher)(source.clone());
 
             // `scheduled` is true for the rest of this code only
@@ -1404,14 +1634,30 @@ where
             let current_span = tracing::Span::current();
             // run the Future
             let serializable = self.serializable;
-            spawn_local({
+            let priority = self.priority;
+            queue_resource_load(priority, {
                 let resolved = self.resolved.clone();
                 let set_value = self.set_value;
                 let set_loading = self.set_loading;
                 let last_version = self.version.clone();
-                async move {
+                Box::pin(async move {
                     // continue trace context within resource fetcher
                     let _guard = current_span.enter();
+                    // waits for its turn within the budget of the suspense
+                    // it is read under, which is known once it's rendered
+                    let budget = suspense_contexts
+                        .borrow()
+                        .iter()
+                        .filter_map(|context| {
+                            context.max_concurrent().map(|max| (max, *context))
+                        })
+                        .min_by_key(|(max, _)| *max);
+                    let _permit = match budget {
+                        Some((_, context)) => {
+                            Some(context.acquire_load_permit().await)
+                        }
+                        None => None,
+                    };
                     let res = fut.await;
 
                     if version == last_version.get() {
@@ -1426,7 +1672,8 @@ where
                             id,
                         );
                     }
-                }
+                    finish_resource_load(priority);
+                })
             })
         });
     }
@@ -1515,6 +1762,10 @@ where
     )]
     #[inline(always)]
     fn should_send_to_client(&self) -> bool {
+        // an idle resource has no value to wait for
+        if self.is_idle() {
+            return false;
+        }
         #[cfg(feature = "experimental-islands")]
         {
             self.should_send_to_client.get() == Some(true)
@@ -1536,6 +1787,207 @@ impl<S, T> UnserializableResource for ResourceState<S, T> {
     }
 }
 
+/// Refetches the resources that are created while it is provided as context,
+/// when [`retry`](Self::retry) is called.
+///
+/// `<ErrorBoundary/>` provides one to its children, so that its fallback can
+/// load the resources whose errors it shows again, including resources whose
+/// errors were sent from the server and hydrated without being fetched.
+///
+/// ```
+/// # use leptos_reactive::*;
+/// # let runtime = create_runtime();
+/// let retry = ResourceRetry::new();
+/// provide_context(retry);
+/// # // we're not running in a browser or in Tokio, so this can't spawn
+/// # if false {
+/// let user = create_resource(
+///     || (),
+///     |_| async { Err::<String, _>("offline".to_string()) },
+/// );
+/// # _ = user;
+///
+/// // later, e.g., in an `<ErrorBoundary/>` fallback
+/// if let Some(retry) = use_context::<ResourceRetry>() {
+///     retry.retry();
+/// }
+/// # }
+/// # runtime.dispose();
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ResourceRetry(Trigger);
+
+impl ResourceRetry {
+    /// Creates a new handle, which refetches the resources created while it
+    /// is provided as context.
+    pub fn new() -> Self {
+        Self(Trigger::new())
+    }
+
+    /// Refetches all the resources that were created under this handle.
+    pub fn retry(&self) {
+        self.0.notify();
+    }
+}
+
+impl Default for ResourceRetry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn refetch_on_retry<S, T>(id: ResourceId, r: &Rc<ResourceState<S, T>>)
+where
+    S: PartialEq + Clone + 'static,
+    T: 'static,
+{
+    if let Some(retry) = use_context::<ResourceRetry>() {
+        let r = Rc::clone(r);
+        create_render_effect(move |prev: Option<()>| {
+            retry.0.track();
+            if prev.is_some() {
+                r.refetch(id);
+            }
+        });
+    }
+}
+
+/// How soon a resource created with [`create_resource_with_priority`] starts
+/// loading, relative to the other resources.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ResourcePriority {
+    /// Starts loading as soon as it is created or its source changes, like
+    /// every other resource. This is the default.
+    #[default]
+    High,
+    /// Waits until no high-priority resource is loading, including the ones
+    /// that are created later in the same render.
+    Low,
+    /// Waits until no high- or low-priority resource is loading, and then
+    /// loads together with at most a few other prefetches, as set by
+    /// [`set_resource_prefetch_limit`].
+    Prefetch,
+}
+
+/// Sets how many [`ResourcePriority::Prefetch`] resources can load at the
+/// same time, which is at least 1. Defaults to 2.
+///
+/// On the server, this applies to the current request.
+pub fn set_resource_prefetch_limit(limit: usize) {
+    _ = with_runtime(|runtime| {
+        runtime.resource_queue.borrow_mut().prefetch_limit = Some(limit.max(1))
+    });
+}
+
+type ResourceLoad = Pin<Box<dyn Future<Output = ()>>>;
+
+// the loads that are waiting for their priority to start
+#[derive(Default)]
+pub(crate) struct ResourceQueue {
+    loading: [usize; 3],
+    low: VecDeque<ResourceLoad>,
+    prefetch: VecDeque<ResourceLoad>,
+    prefetch_limit: Option<usize>,
+    scheduled: bool,
+}
+
+impl ResourceQueue {
+    const DEFAULT_PREFETCH_LIMIT: usize = 2;
+
+    fn can_start(&self, priority: ResourcePriority) -> bool {
+        let [high, low, prefetch] = self.loading;
+        match priority {
+            ResourcePriority::High => true,
+            ResourcePriority::Low => high == 0,
+            ResourcePriority::Prefetch => {
+                high == 0
+                    && low == 0
+                    && self.low.is_empty()
+                    && prefetch
+                        < self
+                            .prefetch_limit
+                            .unwrap_or(Self::DEFAULT_PREFETCH_LIMIT)
+            }
+        }
+    }
+
+    fn waiting(
+        &mut self,
+        priority: ResourcePriority,
+    ) -> &mut VecDeque<ResourceLoad> {
+        match priority {
+            ResourcePriority::Low => &mut self.low,
+            _ => &mut self.prefetch,
+        }
+    }
+
+    // the waiting loads that can start now, which are counted as loading
+    fn ready(&mut self) -> Vec<ResourceLoad> {
+        let mut ready = Vec::new();
+        for priority in [ResourcePriority::Low, ResourcePriority::Prefetch] {
+            while !self.waiting(priority).is_empty() && self.can_start(priority)
+            {
+                if let Some(load) = self.waiting(priority).pop_front() {
+                    self.loading[priority as usize] += 1;
+                    ready.push(load);
+                }
+            }
+        }
+        ready
+    }
+}
+
+fn queue_resource_load(priority: ResourcePriority, load: ResourceLoad) {
+    if priority == ResourcePriority::High {
+        _ = with_runtime(|runtime| {
+            runtime.resource_queue.borrow_mut().loading
+                [ResourcePriority::High as usize] += 1
+        });
+        spawn_local(load);
+        return;
+    }
+
+    let schedule = with_runtime(|runtime| {
+        let mut queue = runtime.resource_queue.borrow_mut();
+        queue.waiting(priority).push_back(load);
+        !std::mem::replace(&mut queue.scheduled, true)
+    })
+    .unwrap_or(false);
+    // starts the load once the resources that are created in the same render
+    // have been queued, so that they start in order of priority
+    if schedule {
+        spawn_local(async {
+            _ = with_runtime(|runtime| {
+                runtime.resource_queue.borrow_mut().scheduled = false
+            });
+            start_ready_resource_loads(None);
+        });
+    }
+}
+
+fn finish_resource_load(priority: ResourcePriority) {
+    start_ready_resource_loads(Some(priority));
+}
+
+fn start_ready_resource_loads(finished: Option<ResourcePriority>) {
+    let ready = with_runtime(|runtime| {
+        let mut queue = runtime.resource_queue.borrow_mut();
+        if let Some(priority) = finished {
+            let loading = &mut queue.loading[priority as usize];
+            *loading = loading.saturating_sub(1);
+        }
+        if queue.scheduled {
+            Vec::new()
+        } else {
+            queue.ready()
+        }
+    })
+    .unwrap_or_default();
+    for load in ready {
+        spawn_local(load);
+    }
+}
+
 thread_local! {
     static SUPPRESS_RESOURCE_LOAD: Cell<bool> = const { Cell::new(false) };
 }
@@ -0,0 +1,175 @@
+use crate::ResourceId;
+use std::panic::Location;
+#[cfg(debug_assertions)]
+use {
+    crate::{node::NodeId, runtime::with_runtime},
+    rustc_hash::{FxHashMap, FxHashSet},
+    slotmap::SparseSecondaryMap,
+};
+
+/// A resource that can only start loading once another resource has loaded,
+/// as returned by [`resource_dependency_graph`].
+///
+/// This happens when the resource's source reads the other resource, or when
+/// the resource is created inside a closure that has read the other one, like
+/// `move || user.get().map(|user| create_resource(...))`. Each dependency is a
+/// request waterfall: the two requests are made one after the other.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ResourceDependency {
+    /// The resource that waits.
+    pub resource: ResourceId,
+    /// Where the resource that waits was created.
+    pub defined_at: &'static Location<'static>,
+    /// The resource it waits for.
+    pub depends_on: ResourceId,
+    /// Where the resource it waits for was created.
+    pub depends_on_defined_at: &'static Location<'static>,
+    /// Where the resource it waits for was read.
+    pub read_at: &'static Location<'static>,
+}
+
+/// Returns every dependency between resources that has been found in the
+/// current runtime, in the order they were found.
+///
+/// When the same pair of resources is created again, for example each time
+/// a component is rendered, the dependency is only reported the first time.
+/// Dependencies are only tracked in debug builds; in release builds, this
+/// always returns an empty list.
+///
+/// ```
+/// # use leptos_reactive::*;
+/// # let runtime = create_runtime();
+/// for dependency in resource_dependency_graph() {
+///     println!(
+///         "{} waits for {}",
+///         dependency.defined_at, dependency.depends_on_defined_at
+///     );
+/// }
+/// # runtime.dispose();
+/// ```
+pub fn resource_dependency_graph() -> Vec<ResourceDependency> {
+    #[cfg(debug_assertions)]
+    {
+        with_runtime(|runtime| runtime.resource_graph.borrow().edges.clone())
+            .unwrap_or_default()
+    }
+    #[cfg(not(debug_assertions))]
+    {
+        Vec::new()
+    }
+}
+
+#[cfg(debug_assertions)]
+#[derive(Default)]
+pub(crate) struct ResourceGraph {
+    // where each resource was created, and the memo that runs its source
+    resources: FxHashMap<ResourceId, (&'static Location<'static>, NodeId)>,
+    // the resource whose source each of these memos runs
+    sources: FxHashMap<NodeId, ResourceId>,
+    // the resources each computation has read during its current run
+    reads: SparseSecondaryMap<
+        NodeId,
+        Vec<(ResourceId, &'static Location<'static>)>,
+    >,
+    edges: Vec<ResourceDependency>,
+    // the locations of the pairs of resources that have been reported
+    reported:
+        FxHashSet<(&'static Location<'static>, &'static Location<'static>)>,
+}
+
+#[cfg(debug_assertions)]
+impl ResourceGraph {
+    /// Forgets the resources a computation has read, when it runs again or
+    /// is disposed.
+    pub fn forget_reads(&mut self, node: NodeId) {
+        if !self.reads.is_empty() {
+            self.reads.remove(node);
+        }
+    }
+
+    pub fn resource_disposed(&mut self, id: ResourceId) {
+        if let Some((_, source)) = self.resources.remove(&id) {
+            self.sources.remove(&source);
+        }
+    }
+
+    fn add_edge(
+        &mut self,
+        resource: ResourceId,
+        depends_on: ResourceId,
+        read_at: &'static Location<'static>,
+    ) {
+        let (Some((defined_at, _)), Some((depends_on_defined_at, _))) = (
+            self.resources.get(&resource).copied(),
+            self.resources.get(&depends_on).copied(),
+        ) else {
+            return;
+        };
+        if !self.reported.insert((depends_on_defined_at, defined_at)) {
+            return;
+        }
+        crate::macros::debug_warn!(
+            "[LEPTOS WARNING] Request waterfall: the resource created at \
+             {defined_at} can only start loading once the resource created \
+             at {depends_on_defined_at} (read at {read_at}) has loaded. If it \
+             does not need that value, create both resources side by side so \
+             they load in parallel. If it does, consider loading both in a \
+             single server function."
+        );
+        self.edges.push(ResourceDependency {
+            resource,
+            defined_at,
+            depends_on,
+            depends_on_defined_at,
+            read_at,
+        });
+    }
+}
+
+/// Records a new resource, and any resource that the computation creating it
+/// has already read.
+#[cfg(debug_assertions)]
+pub(crate) fn resource_created(
+    id: ResourceId,
+    source: NodeId,
+    defined_at: &'static Location<'static>,
+) {
+    _ = with_runtime(|runtime| {
+        let mut graph = runtime.resource_graph.borrow_mut();
+        graph.resources.insert(id, (defined_at, source));
+        graph.sources.insert(source, id);
+
+        let read = runtime
+            .observer
+            .get()
+            .and_then(|observer| graph.reads.get(observer).cloned());
+        for (depends_on, read_at) in read.into_iter().flatten() {
+            graph.add_edge(id, depends_on, read_at);
+        }
+    });
+}
+
+/// Records that a resource has been read by the current computation.
+#[cfg(debug_assertions)]
+pub(crate) fn resource_read(
+    id: ResourceId,
+    location: &'static Location<'static>,
+) {
+    _ = with_runtime(|runtime| {
+        let Some(observer) = runtime.observer.get() else {
+            return;
+        };
+        let mut graph = runtime.resource_graph.borrow_mut();
+        if let Some(resource) = graph.sources.get(&observer).copied() {
+            // read by another resource's source
+            if resource != id {
+                graph.add_edge(resource, id, location);
+            }
+        } else if let Some(reads) = graph.reads.entry(observer) {
+            let reads = reads.or_default();
+            if !reads.iter().any(|(read, _)| *read == id) {
+                reads.push((id, location));
+            }
+        }
+    });
+}
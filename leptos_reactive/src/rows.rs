@@ -0,0 +1,337 @@
+use crate::{
+    create_trigger, runtime::with_runtime, store_value, with_owner, Owner,
+    RwSignal, SignalGet, SignalGetUntracked, SignalSet, SignalUpdate,
+    SignalWith, SignalWithUntracked, StoredValue, Trigger,
+};
+use rustc_hash::FxHashMap;
+use std::{
+    any::Any,
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+impl<T> RwSignal<Vec<T>> {
+    /// Creates a [`Rows`] adapter, which iterates over the rows of this
+    /// signal as per-row handles, without cloning the vector.
+    ///
+    /// The adapter keeps track of the row handles it has created, so create
+    /// it once (for example, when creating the component) and copy it where
+    /// it is needed: row handles created by two different adapters for the
+    /// same signal do not notify each other.
+    ///
+    /// See [`Rows::iter_keyed`] for an example.
+    #[track_caller]
+    pub fn rows(&self) -> Rows<T> {
+        Rows {
+            store: *self,
+            state: store_value(RowsState::default()),
+            owner: Owner::current(),
+        }
+    }
+}
+
+/// Iterates over the rows of an [`RwSignal<Vec<T>>`](RwSignal) as [`Row`]
+/// handles, which is useful for rendering the rows in a
+/// [`<For/>`](https://docs.rs/leptos/latest/leptos/fn.For.html).
+///
+/// Created with [`RwSignal::rows`].
+pub struct Rows<T: 'static> {
+    store: RwSignal<Vec<T>>,
+    state: StoredValue<RowsState>,
+    owner: Option<Owner>,
+}
+
+#[derive(Default)]
+struct RowsState {
+    // a `FxHashMap<K, Row<T>>` of the handles, by the key of their row
+    handles: Option<Box<dyn Any>>,
+    // whether a row is currently being updated through its handle
+    updating_row: bool,
+}
+
+impl<T> Clone for Rows<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Rows<T> {}
+
+impl<T> fmt::Debug for Rows<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Rows").field("store", &self.store).finish()
+    }
+}
+
+impl<T> Rows<T> {
+    /// Returns a handle for each row, identifying the rows by the given `key`.
+    ///
+    /// A row keeps the same handle as long as its key does not change, even
+    /// if it moves to another index, so the handles can be used as the keys
+    /// of a `<For/>`. Updating a row through its handle only notifies the
+    /// code that reads that row: when the `<For/>` re-runs, it finds the same
+    /// keys and leaves every row as it is. Updating the whole signal notifies
+    /// every row.
+    ///
+    /// This subscribes the running effect to the signal.
+    ///
+    /// ```
+    /// # use leptos_reactive::*;
+    /// # let runtime = create_runtime();
+    /// // `Todo` does not implement `Clone`
+    /// struct Todo {
+    ///     id: u32,
+    ///     title: String,
+    /// }
+    ///
+    /// let todos = create_rw_signal(vec![
+    ///     Todo { id: 1, title: "Write".into() },
+    ///     Todo { id: 2, title: "Test".into() },
+    /// ]);
+    /// let rows = todos.rows();
+    ///
+    /// // in a view, this would be
+    /// // <For each=move || rows.iter_keyed(|todo| todo.id) key=|row| *row ...>
+    /// let handles = rows.iter_keyed(|todo| todo.id);
+    /// let second = handles[1];
+    /// assert_eq!(second.with(|todo| todo.title.clone()), "Test");
+    ///
+    /// // edit a single row through its handle
+    /// second.update(|todo| todo.title = "Ship".into());
+    /// assert_eq!(todos.with(|todos| todos[1].title.clone()), "Ship");
+    ///
+    /// // the handle follows its row when the rows are reordered
+    /// todos.update(|todos| todos.reverse());
+    /// let handles = rows.iter_keyed(|todo| todo.id);
+    /// assert_eq!(handles[0], second);
+    /// assert_eq!(second.index(), Some(0));
+    /// # runtime.dispose();
+    /// ```
+    #[track_caller]
+    pub fn iter_keyed<K>(&self, key: impl Fn(&T) -> K) -> Vec<Row<T>>
+    where
+        K: Eq + Hash + 'static,
+    {
+        let keys = self
+            .store
+            .with(|rows| rows.iter().map(&key).collect::<Vec<_>>());
+
+        let updating_row = self.state.with_value(|state| state.updating_row);
+        let mut previous = self
+            .state
+            .try_update_value(|state| state.handles.take())
+            .flatten()
+            .and_then(|handles| handles.downcast::<FxHashMap<K, Row<T>>>().ok())
+            .map(|handles| *handles)
+            .unwrap_or_default();
+        let mut handles =
+            FxHashMap::with_capacity_and_hasher(keys.len(), Default::default());
+        let mut stale = Vec::new();
+        let rows = keys
+            .into_iter()
+            .enumerate()
+            .map(|(index, key)| {
+                let row = match previous.remove(&key) {
+                    Some(row) => {
+                        row.index.set_value(Some(index));
+                        // the row may have been changed by updating the whole
+                        // signal, which only notifies the signal itself
+                        if !updating_row {
+                            stale.push(row);
+                        }
+                        row
+                    }
+                    None => self.create_row(index),
+                };
+                handles.insert(key, row);
+                row
+            })
+            .collect();
+        for removed in previous.into_values() {
+            removed.dispose();
+        }
+        self.state.update_value(|state| {
+            state.handles = Some(Box::new(handles));
+        });
+
+        for row in stale {
+            row.trigger.notify();
+        }
+        rows
+    }
+
+    #[track_caller]
+    fn create_row(&self, index: usize) -> Row<T> {
+        let create = || Row {
+            store: self.store,
+            state: self.state,
+            index: store_value(Some(index)),
+            trigger: create_trigger(),
+        };
+        // the handles outlive the effect that iterates over the rows, so they
+        // belong to the owner of the adapter
+        match self.owner {
+            Some(owner) => with_owner(owner, create),
+            None => create(),
+        }
+    }
+}
+
+/// A handle for a single row of an [`RwSignal<Vec<T>>`](RwSignal), created
+/// with [`Rows::iter_keyed`].
+///
+/// Reading the row with [`.with()`](SignalWith::with) or
+/// [`.get()`](SignalGet::get) only subscribes to changes to this row, and
+/// updating it with [`.update()`](SignalUpdate::update) or
+/// [`.set()`](SignalSet::set) only notifies the code that reads this row, and
+/// the code that reads the whole signal.
+///
+/// Once the row has been removed from the signal, reading or updating the
+/// handle does nothing, and the `try_` methods return [`None`].
+pub struct Row<T: 'static> {
+    store: RwSignal<Vec<T>>,
+    state: StoredValue<RowsState>,
+    index: StoredValue<Option<usize>>,
+    trigger: Trigger,
+}
+
+impl<T> Clone for Row<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Row<T> {}
+
+impl<T> PartialEq for Row<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.trigger.id == other.trigger.id
+    }
+}
+
+impl<T> Eq for Row<T> {}
+
+impl<T> Hash for Row<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.trigger.id.hash(state);
+    }
+}
+
+impl<T> fmt::Debug for Row<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Row")
+            .field("index", &self.index())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> Row<T> {
+    /// The current index of the row in the signal, or [`None`] if it has been
+    /// removed.
+    ///
+    /// The index is updated each time the rows are iterated over with
+    /// [`Rows::iter_keyed`]. This does not subscribe to changes.
+    pub fn index(&self) -> Option<usize> {
+        self.index.try_get_value().flatten()
+    }
+
+    fn dispose(self) {
+        self.index.dispose();
+        _ = with_runtime(|runtime| runtime.dispose_node(self.trigger.id));
+    }
+}
+
+impl<T> SignalWith for Row<T> {
+    type Value = T;
+
+    #[track_caller]
+    fn with<O>(&self, f: impl FnOnce(&T) -> O) -> O {
+        self.try_with(f)
+            .expect("Row::with(): the row has been removed from the signal")
+    }
+
+    fn try_with<O>(&self, f: impl FnOnce(&T) -> O) -> Option<O> {
+        _ = self.trigger.try_track();
+        self.try_with_untracked(f)
+    }
+}
+
+impl<T> SignalWithUntracked for Row<T> {
+    type Value = T;
+
+    #[track_caller]
+    fn with_untracked<O>(&self, f: impl FnOnce(&T) -> O) -> O {
+        self.try_with_untracked(f).expect(
+            "Row::with_untracked(): the row has been removed from the signal",
+        )
+    }
+
+    fn try_with_untracked<O>(&self, f: impl FnOnce(&T) -> O) -> Option<O> {
+        let index = self.index()?;
+        self.store
+            .try_with_untracked(|rows| rows.get(index).map(f))
+            .flatten()
+    }
+}
+
+impl<T: Clone> SignalGet for Row<T> {
+    type Value = T;
+
+    #[track_caller]
+    fn get(&self) -> T {
+        self.with(T::clone)
+    }
+
+    fn try_get(&self) -> Option<T> {
+        self.try_with(T::clone)
+    }
+}
+
+impl<T: Clone> SignalGetUntracked for Row<T> {
+    type Value = T;
+
+    #[track_caller]
+    fn get_untracked(&self) -> T {
+        self.with_untracked(T::clone)
+    }
+
+    fn try_get_untracked(&self) -> Option<T> {
+        self.try_with_untracked(T::clone)
+    }
+}
+
+impl<T> SignalUpdate for Row<T> {
+    type Value = T;
+
+    fn update(&self, f: impl FnOnce(&mut T)) {
+        _ = self.try_update(f);
+    }
+
+    fn try_update<O>(&self, f: impl FnOnce(&mut T) -> O) -> Option<O> {
+        let index = self.index()?;
+        self.state.update_value(|state| state.updating_row = true);
+        let output = self
+            .store
+            .try_update(|rows| rows.get_mut(index).map(f))
+            .flatten();
+        self.state.update_value(|state| state.updating_row = false);
+        if output.is_some() {
+            self.trigger.notify();
+        }
+        output
+    }
+}
+
+impl<T> SignalSet for Row<T> {
+    type Value = T;
+
+    fn set(&self, new_value: T) {
+        self.update(|row| *row = new_value);
+    }
+
+    fn try_set(&self, new_value: T) -> Option<T> {
+        let mut new_value = Some(new_value);
+        _ = self.try_update(|row| *row = new_value.take().unwrap());
+        new_value
+    }
+}
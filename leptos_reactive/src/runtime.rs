@@ -1,14 +1,26 @@
 #[cfg(debug_assertions)]
-use crate::SpecialNonReactiveZone;
+use crate::explain::ExplainState;
+#[cfg(debug_assertions)]
+use crate::render_counts::RenderCounts;
+#[cfg(debug_assertions)]
+use crate::resource_graph::ResourceGraph;
 use crate::{
+    compare::AnyCompare,
     hydration::SharedContext,
     node::{
         Disposer, NodeId, ReactiveNode, ReactiveNodeState, ReactiveNodeType,
     },
+    resource::ResourceQueue,
+    scheduler::{Lanes, Priority},
     AnyComputation, AnyResource, EffectState, Memo, MemoState, ReadSignal,
     ResourceId, ResourceState, RwSignal, SerializableResource, StoredValueId,
     Trigger, UnserializableResource, WriteSignal,
 };
+#[cfg(debug_assertions)]
+use crate::{
+    diagnostics::{with_render_node, RenderNode},
+    SpecialNonReactiveZone,
+};
 use cfg_if::cfg_if;
 use core::hash::BuildHasherDefault;
 use futures::stream::FuturesUnordered;
@@ -62,6 +74,9 @@ pub(crate) struct Runtime {
     #[allow(clippy::type_complexity)]
     pub on_cleanups:
         RefCell<SparseSecondaryMap<NodeId, Vec<Box<dyn FnOnce()>>>>,
+    #[allow(clippy::type_complexity)]
+    pub on_cleanups_after_children:
+        RefCell<SparseSecondaryMap<NodeId, Vec<Box<dyn FnOnce()>>>>,
     pub stored_values: RefCell<SlotMap<StoredValueId, Rc<RefCell<dyn Any>>>>,
     pub nodes: RefCell<SlotMap<NodeId, ReactiveNode>>,
     pub node_subscribers:
@@ -69,6 +84,12 @@ pub(crate) struct Runtime {
     pub node_sources:
         RefCell<SecondaryMap<NodeId, RefCell<FxIndexSet<NodeId>>>>,
     pub node_owners: RefCell<SecondaryMap<NodeId, NodeId>>,
+    #[cfg(debug_assertions)]
+    pub explained_effects: RefCell<SparseSecondaryMap<NodeId, ExplainState>>,
+    #[cfg(debug_assertions)]
+    pub resource_graph: RefCell<ResourceGraph>,
+    #[cfg(debug_assertions)]
+    pub render_counts: RefCell<RenderCounts>,
     pub node_properties:
         RefCell<SparseSecondaryMap<NodeId, Vec<ScopeProperty>>>,
     #[allow(clippy::type_complexity)]
@@ -76,7 +97,10 @@ pub(crate) struct Runtime {
         RefCell<SparseSecondaryMap<NodeId, FxHashMap<TypeId, Box<dyn Any>>>>,
     pub pending_effects: RefCell<Vec<NodeId>>,
     pub resources: RefCell<SlotMap<ResourceId, AnyResource>>,
+    pub resource_queue: RefCell<ResourceQueue>,
     pub batching: Cell<bool>,
+    pub priority: Cell<Priority>,
+    pub lanes: RefCell<Lanes>,
 }
 
 /// The current reactive runtime.
@@ -209,6 +233,15 @@ impl Runtime {
                 self.cleanup_property(property);
             }
         }
+
+        // then, run the cleanups that wait for them
+        let c =
+            { self.on_cleanups_after_children.borrow_mut().remove(node_id) };
+        let prev_observer = self.observer.take();
+        for cleanup in c.into_iter().flatten() {
+            cleanup();
+        }
+        self.observer.set(prev_observer);
     }
 
     pub(crate) fn update(&self, node_id: NodeId) {
@@ -221,17 +254,62 @@ impl Runtime {
             // memos and effects rerun
             // signals simply have their value
             let changed = match node.node_type {
-                ReactiveNodeType::Signal | ReactiveNodeType::Trigger => true,
+                ReactiveNodeType::Signal { .. } | ReactiveNodeType::Trigger => {
+                    true
+                }
                 ReactiveNodeType::Memo { ref f }
                 | ReactiveNodeType::Effect { ref f } => {
                     let value = node.value();
+                    #[cfg(debug_assertions)]
+                    let explained = self
+                        .explained_effects
+                        .borrow_mut()
+                        .get_mut(node_id)
+                        .map(ExplainState::start_run)
+                        .is_some();
+                    #[cfg(debug_assertions)]
+                    self.resource_graph.borrow_mut().forget_reads(node_id);
+                    // writes are expected in effects, but not in memos
+                    #[cfg(debug_assertions)]
+                    let render_node = match node.node_type {
+                        ReactiveNodeType::Memo { .. } => {
+                            f.defined_at().map(RenderNode::Memo)
+                        }
+                        _ => None,
+                    };
+                    #[cfg(feature = "long-task-warnings")]
+                    let timer = crate::long_tasks::LongTaskTimer::start();
                     // set this node as the observer
-                    self.with_observer(node_id, move || {
+                    let changed = self.with_observer(node_id, move || {
                         // clean up sources of this memo/effect
                         self.cleanup_sources(node_id);
 
+                        #[cfg(debug_assertions)]
+                        {
+                            with_render_node(render_node, || f.run(value))
+                        }
+                        #[cfg(not(debug_assertions))]
                         f.run(value)
-                    })
+                    });
+                    #[cfg(feature = "long-task-warnings")]
+                    timer.finish(
+                        match node.node_type {
+                            ReactiveNodeType::Memo { .. } => {
+                                crate::ComputationKind::Memo
+                            }
+                            _ => crate::ComputationKind::Effect,
+                        },
+                        f.defined_at(),
+                    );
+                    #[cfg(debug_assertions)]
+                    if explained {
+                        if let Some(state) =
+                            self.explained_effects.borrow().get(node_id)
+                        {
+                            state.log();
+                        }
+                    }
+                    changed
                 }
             };
 
@@ -273,6 +351,14 @@ impl Runtime {
                     self.cleanup_property(property);
                 }
 
+                // run the cleanups that wait for the children
+                let cleanups = {
+                    self.on_cleanups_after_children.borrow_mut().remove(node)
+                };
+                for cleanup in cleanups.into_iter().flatten() {
+                    cleanup();
+                }
+
                 // each of the subs needs to remove the node from its dependencies
                 // so that it doesn't try to read the (now disposed) signal
                 let subs = self.node_subscribers.borrow_mut().remove(node);
@@ -288,12 +374,19 @@ impl Runtime {
 
                 // no longer needs to track its sources
                 self.node_sources.borrow_mut().remove(node);
+                #[cfg(debug_assertions)]
+                {
+                    self.explained_effects.borrow_mut().remove(node);
+                    self.resource_graph.borrow_mut().forget_reads(node);
+                }
 
                 // remove the node from the graph
                 let node = { self.nodes.borrow_mut().remove(node) };
                 drop(node);
             }
             ScopeProperty::Resource(id) => {
+                #[cfg(debug_assertions)]
+                self.resource_graph.borrow_mut().resource_disposed(id);
                 let value = self.resources.borrow_mut().remove(id);
                 drop(value);
             }
@@ -327,6 +420,14 @@ impl Runtime {
         }
     }
 
+    #[cfg(debug_assertions)]
+    pub(crate) fn has_subscribers(&self, node: NodeId) -> bool {
+        self.node_subscribers
+            .borrow()
+            .get(node)
+            .is_some_and(|subs| !subs.borrow().is_empty())
+    }
+
     fn current_state(&self, node: NodeId) -> ReactiveNodeState {
         match self.nodes.borrow().get(node) {
             None => ReactiveNodeState::Clean,
@@ -358,7 +459,13 @@ impl Runtime {
         let mut nodes = self.nodes.borrow_mut();
 
         if let Some(current_node) = nodes.get_mut(node) {
-            if current_node.state == ReactiveNodeState::DirtyMarked {
+            // effects that wait in a lower-priority lane have already been
+            // marked, but are queued again so that they keep up with this
+            // update's priority, and new subscribers are marked
+            let priority = self.priority.get();
+            let deferred = self.lanes.borrow().is_waiting_below(priority);
+            if current_node.state == ReactiveNodeState::DirtyMarked && !deferred
+            {
                 return;
             }
 
@@ -425,6 +532,19 @@ impl Runtime {
                         if node.state == ReactiveNodeState::Check
                             || node.state == ReactiveNodeState::DirtyMarked
                         {
+                            if deferred
+                                && matches!(
+                                    node.node_type,
+                                    ReactiveNodeType::Effect { .. }
+                                )
+                                && current_observer != Some(child)
+                                && self
+                                    .lanes
+                                    .borrow_mut()
+                                    .take_waiting_below(child, priority)
+                            {
+                                pending_effects.push(child);
+                            }
                             return IterResult::Continue;
                         }
 
@@ -496,8 +616,13 @@ impl Runtime {
     pub(crate) fn run_effects(&self) {
         if !self.batching.get() {
             let effects = self.pending_effects.take();
-            for effect_id in effects {
-                self.update_if_necessary(effect_id);
+            match self.priority.get() {
+                Priority::UserInput => {
+                    for effect_id in effects {
+                        self.update_if_necessary(effect_id);
+                    }
+                }
+                priority => self.lanes.borrow_mut().defer(priority, effects),
             }
         }
     }
@@ -505,7 +630,15 @@ impl Runtime {
     pub(crate) fn dispose_node(&self, node: NodeId) {
         self.node_sources.borrow_mut().remove(node);
         self.node_subscribers.borrow_mut().remove(node);
-        self.nodes.borrow_mut().remove(node);
+        #[cfg(debug_assertions)]
+        {
+            self.explained_effects.borrow_mut().remove(node);
+            self.resource_graph.borrow_mut().forget_reads(node);
+        }
+        // drop the value only after releasing the borrow, because dropping it
+        // can run an effect's cleanup
+        let node = { self.nodes.borrow_mut().remove(node) };
+        drop(node);
     }
 
     #[track_caller]
@@ -1015,7 +1148,7 @@ impl RuntimeId {
             let id = runtime.nodes.borrow_mut().insert(ReactiveNode {
                 value: Some(value),
                 state: ReactiveNodeState::Clean,
-                node_type: ReactiveNodeType::Signal,
+                node_type: ReactiveNodeType::Signal { compare: None },
             });
             runtime.push_scope_property(ScopeProperty::Signal(id));
             id
@@ -1123,6 +1256,8 @@ impl RuntimeId {
                 ty: PhantomData,
                 #[cfg(any(debug_assertions, feature = "ssr"))]
                 defined_at: std::panic::Location::caller(),
+                #[cfg(debug_assertions)]
+                component_stack: crate::ComponentStack::current(),
             }),
         )
     }
@@ -1187,6 +1322,8 @@ impl RuntimeId {
                 ty: PhantomData,
                 #[cfg(any(debug_assertions, feature = "ssr"))]
                 defined_at: std::panic::Location::caller(),
+                #[cfg(debug_assertions)]
+                component_stack: crate::ComponentStack::current(),
             }),
         );
 
@@ -1367,6 +1504,22 @@ impl Runtime {
         let signals = self.nodes.borrow();
         signals.get(node_id).map(|node| node.value())
     }
+
+    // the value of a signal, with its comparison if it was created with one
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn get_signal_value(
+        &self,
+        node_id: NodeId,
+    ) -> Option<(Rc<RefCell<dyn Any>>, Option<AnyCompare>)> {
+        let signals = self.nodes.borrow();
+        signals.get(node_id).map(|node| {
+            let compare = match &node.node_type {
+                ReactiveNodeType::Signal { compare } => compare.clone(),
+                _ => None,
+            };
+            (node.value(), compare)
+        })
+    }
 }
 
 impl PartialEq for Runtime {
@@ -1397,6 +1550,32 @@ impl Drop for SetObserverOnDrop {
 /// function has run. This allows you to prevent rerunning effects if multiple
 /// signal updates might cause the same effect to run.
 ///
+/// Signals still hold their new values inside the batch, and memos that are
+/// read inside it are recalculated, but effects only run once, when the
+/// outermost batch ends.
+///
+/// ```
+/// # use leptos_reactive::*;
+/// # let runtime = create_runtime();
+/// let (first, set_first) = create_signal("Ada".to_string());
+/// let (last, set_last) = create_signal("Lovelace".to_string());
+/// let renders = create_rw_signal(0);
+/// create_isomorphic_effect(move |_| {
+///     (first.get(), last.get());
+///     renders.update(|n| *n += 1);
+/// });
+///
+/// batch(|| {
+///     set_first.set("Grace".into());
+///     batch(|| set_last.set("Hopper".into()));
+///     // the effect has not rerun, even after the inner batch
+///     assert_eq!(renders.get_untracked(), 1);
+///     assert_eq!(last.get_untracked(), "Hopper");
+/// });
+/// assert_eq!(renders.get_untracked(), 2);
+/// # runtime.dispose();
+/// ```
+///
 /// # Panics
 /// Panics if the runtime has already been disposed.
 ///
@@ -1447,12 +1626,64 @@ impl Drop for SetBatchingOnDrop {
     }
 }
 
-/// Creates a cleanup function, which will be run when the current reactive owner is disposed.
+/// Creates a cleanup function, which will be run when the current reactive owner is disposed,
+/// or before it runs again if it is an effect or memo.
 ///
-/// It runs after child nodes have been disposed, but before signals, effects, and resources
-/// are invalidated.
+/// It runs before the signals, effects, memos, and resources created under the owner are
+/// disposed, in the order the cleanups were registered. This is the same as
+/// [`on_cleanup_before_children`].
 #[inline(always)]
 pub fn on_cleanup(cleanup_fn: impl FnOnce() + 'static) {
+    on_cleanup_before_children(cleanup_fn)
+}
+
+/// Creates a cleanup function, which will be run when the current reactive owner is disposed,
+/// before anything created under it is disposed.
+///
+/// Nested components, and the effects that update their part of the view, are still alive
+/// when it runs. This is where a widget that wraps a third-party JavaScript library should
+/// detach it, before the DOM it manages is taken down.
+#[inline(always)]
+pub fn on_cleanup_before_children(cleanup_fn: impl FnOnce() + 'static) {
+    push_cleanup(Box::new(non_reactive_cleanup(cleanup_fn)), false)
+}
+
+/// Creates a cleanup function, which will be run when the current reactive owner is disposed,
+/// after everything created under it has been disposed, including the cleanups of nested
+/// owners.
+///
+/// ```
+/// # use leptos_reactive::*;
+/// # use std::{cell::RefCell, rc::Rc};
+/// # let runtime = create_runtime();
+/// let order = Rc::new(RefCell::new(Vec::new()));
+/// let (_, disposer) = as_child_of_current_owner({
+///     let order = Rc::clone(&order);
+///     move |_| {
+///         let o = Rc::clone(&order);
+///         on_cleanup_after_children(move || o.borrow_mut().push("after"));
+///         let o = Rc::clone(&order);
+///         on_cleanup_before_children(move || o.borrow_mut().push("before"));
+///         let o = Rc::clone(&order);
+///         create_isomorphic_effect(move |_| {
+///             let o = Rc::clone(&o);
+///             on_cleanup(move || o.borrow_mut().push("child"));
+///         });
+///     }
+/// })(());
+/// drop(disposer);
+/// assert_eq!(*order.borrow(), ["before", "child", "after"]);
+/// # runtime.dispose();
+/// ```
+#[inline(always)]
+pub fn on_cleanup_after_children(cleanup_fn: impl FnOnce() + 'static) {
+    push_cleanup(Box::new(non_reactive_cleanup(cleanup_fn)), true)
+}
+
+#[inline(always)]
+fn non_reactive_cleanup(
+    cleanup_fn: impl FnOnce() + 'static,
+) -> impl FnOnce() + 'static {
     #[cfg(debug_assertions)]
     let cleanup_fn = move || {
         #[cfg(debug_assertions)]
@@ -1463,17 +1694,21 @@ pub fn on_cleanup(cleanup_fn: impl FnOnce() + 'static) {
             crate::SpecialNonReactiveZone::exit(prev);
         }
     };
-    push_cleanup(Box::new(cleanup_fn))
+    cleanup_fn
 }
 
 #[cfg_attr(
     any(debug_assertions, features = "ssr"),
     instrument(level = "trace", skip_all,)
 )]
-fn push_cleanup(cleanup_fn: Box<dyn FnOnce()>) {
+fn push_cleanup(cleanup_fn: Box<dyn FnOnce()>, after_children: bool) {
     _ = with_runtime(|runtime| {
         if let Some(owner) = runtime.owner.get() {
-            let mut cleanups = runtime.on_cleanups.borrow_mut();
+            let mut cleanups = if after_children {
+                runtime.on_cleanups_after_children.borrow_mut()
+            } else {
+                runtime.on_cleanups.borrow_mut()
+            };
             if let Some(entries) = cleanups.get_mut(owner) {
                 entries.push(cleanup_fn);
             } else {
@@ -0,0 +1,292 @@
+use crate::{
+    node::NodeId,
+    runtime::{with_runtime, Runtime},
+};
+use rustc_hash::FxHashMap;
+use std::collections::VecDeque;
+
+/// How urgently the effects that are notified by a signal update should run.
+///
+/// Updates run at [`Priority::UserInput`] unless they are made inside
+/// [`start_transition`] or [`with_priority`]. The effects notified by
+/// lower-priority updates are queued, and only run when there is no more
+/// urgent work, so that, e.g., typing into an input stays responsive while a
+/// large list is filtered.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Priority {
+    /// Effects run as soon as the update has been made. This is the default.
+    #[default]
+    UserInput,
+    /// Effects run after the current task, in chunks that yield to the
+    /// browser in between, so that input events can be handled.
+    Transition,
+    /// Effects run when the browser is idle, after every transition.
+    Idle,
+}
+
+// the effects that are waiting to run at a lower priority
+#[derive(Default)]
+pub(crate) struct Lanes {
+    transition: VecDeque<NodeId>,
+    idle: VecDeque<NodeId>,
+    // the lane each waiting effect will run in; entries in the queues that
+    // don't match it are skipped, because the effect has since been queued
+    // in a more urgent lane or run by an urgent update
+    waiting: FxHashMap<NodeId, Priority>,
+    waiting_idle: usize,
+    #[cfg(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    ))]
+    transition_scheduled: bool,
+    #[cfg(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    ))]
+    idle_scheduled: bool,
+}
+
+impl Lanes {
+    pub(crate) fn defer(
+        &mut self,
+        priority: Priority,
+        effects: impl IntoIterator<Item = NodeId>,
+    ) {
+        let lane = match priority {
+            Priority::UserInput => {
+                unreachable!("urgent effects are not queued")
+            }
+            Priority::Transition => &mut self.transition,
+            Priority::Idle => &mut self.idle,
+        };
+        for effect in effects {
+            match self.waiting.get(&effect) {
+                // already queued in this lane or a more urgent one
+                Some(waiting) if *waiting <= priority => continue,
+                Some(_) => self.waiting_idle -= 1,
+                None => {}
+            }
+            self.waiting.insert(effect, priority);
+            if priority == Priority::Idle {
+                self.waiting_idle += 1;
+            }
+            lane.push_back(effect);
+        }
+    }
+
+    /// Whether any effect waits in a lane that is less urgent than
+    /// `priority`.
+    pub(crate) fn is_waiting_below(&self, priority: Priority) -> bool {
+        match priority {
+            Priority::UserInput => !self.waiting.is_empty(),
+            Priority::Transition => self.waiting_idle > 0,
+            Priority::Idle => false,
+        }
+    }
+
+    /// Removes `effect` from its lane if that lane is less urgent than
+    /// `priority`, so that it can be queued again at `priority`.
+    pub(crate) fn take_waiting_below(
+        &mut self,
+        effect: NodeId,
+        priority: Priority,
+    ) -> bool {
+        match self.waiting.get(&effect) {
+            Some(waiting) if *waiting > priority => {
+                if *waiting == Priority::Idle {
+                    self.waiting_idle -= 1;
+                }
+                self.waiting.remove(&effect);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn pop(&mut self, priority: Priority) -> Option<NodeId> {
+        loop {
+            let effect = match priority {
+                Priority::UserInput => None,
+                Priority::Transition => self.transition.pop_front(),
+                // idle work waits for every transition
+                Priority::Idle if self.transition.is_empty() => {
+                    self.idle.pop_front()
+                }
+                Priority::Idle => None,
+            }?;
+            if self.waiting.get(&effect) == Some(&priority) {
+                self.waiting.remove(&effect);
+                if priority == Priority::Idle {
+                    self.waiting_idle -= 1;
+                }
+                return Some(effect);
+            }
+        }
+    }
+}
+
+/// Makes the updates in `f` at [`Priority::Transition`], so the effects they
+/// notify run once more urgent work is done, in chunks that yield to the
+/// browser between them.
+///
+/// Signals are updated immediately, and can be read inside and after `f`;
+/// only the effects that depend on them are delayed. Effects that are also
+/// notified by an urgent update run right away. This keeps the page
+/// responsive while an update rerenders a large part of it:
+///
+/// ```
+/// # use leptos_reactive::*;
+/// # let runtime = create_runtime();
+/// let query = create_rw_signal(String::new());
+/// let filter = create_rw_signal(String::new());
+/// let rendered = create_rw_signal(Vec::new());
+/// // imagine this rendered thousands of rows
+/// create_isomorphic_effect(move |_| {
+///     rendered.set(vec![filter.get()]);
+/// });
+///
+/// // in an `on:input` handler
+/// query.set("rust".into());
+/// start_transition(|| {
+///     filter.set(query.get_untracked());
+///     assert_eq!(filter.get_untracked(), "rust");
+///     // the list has not been rendered again yet
+///     assert_eq!(rendered.get_untracked(), [""]);
+/// });
+/// # // outside the browser, the queue is flushed when the transition ends
+/// # assert_eq!(rendered.get_untracked(), ["rust"]);
+/// # runtime.dispose();
+/// ```
+///
+/// In the browser, the effects run in a later task. On the server, they run
+/// when `f` returns.
+#[inline(always)]
+pub fn start_transition<T>(f: impl FnOnce() -> T) -> T {
+    with_priority(Priority::Transition, f)
+}
+
+/// Makes the updates in `f` at the given [`Priority`]. See
+/// [`start_transition`].
+pub fn with_priority<T>(priority: Priority, f: impl FnOnce() -> T) -> T {
+    let Ok(prev) = with_runtime(|runtime| runtime.priority.replace(priority))
+    else {
+        return f();
+    };
+    let guard = RestorePriorityOnDrop(prev);
+    let value = f();
+    drop(guard);
+    if prev == Priority::UserInput {
+        _ = with_runtime(schedule_lanes);
+    }
+    value
+}
+
+/// The [`Priority`] of the updates that are being made.
+pub fn current_priority() -> Priority {
+    with_runtime(|runtime| runtime.priority.get()).unwrap_or_default()
+}
+
+struct RestorePriorityOnDrop(Priority);
+
+impl Drop for RestorePriorityOnDrop {
+    fn drop(&mut self) {
+        _ = with_runtime(|runtime| runtime.priority.set(self.0));
+    }
+}
+
+// runs the queued effects on the server, and schedules them in the browser
+fn schedule_lanes(runtime: &Runtime) {
+    cfg_if::cfg_if! {
+        if #[cfg(all(
+            target_arch = "wasm32",
+            any(feature = "csr", feature = "hydrate")
+        ))] {
+            let mut lanes = runtime.lanes.borrow_mut();
+            if !lanes.transition.is_empty() {
+                if !lanes.transition_scheduled {
+                    lanes.transition_scheduled = true;
+                    browser::set_timeout(|| flush_lane(Priority::Transition));
+                }
+            } else if !lanes.idle.is_empty() && !lanes.idle_scheduled {
+                lanes.idle_scheduled = true;
+                browser::request_idle_callback(|| flush_lane(Priority::Idle));
+            }
+        } else {
+            run_lane(runtime, Priority::Transition, None);
+            run_lane(runtime, Priority::Idle, None);
+        }
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate")))]
+fn flush_lane(priority: Priority) {
+    // long enough to make progress, short enough for input to feel immediate
+    const BUDGET_MS: f64 = 5.0;
+
+    _ = with_runtime(|runtime| {
+        {
+            let mut lanes = runtime.lanes.borrow_mut();
+            match priority {
+                Priority::Transition => lanes.transition_scheduled = false,
+                _ => lanes.idle_scheduled = false,
+            }
+        }
+        run_lane(runtime, priority, Some(js_sys::Date::now() + BUDGET_MS));
+        schedule_lanes(runtime);
+    });
+}
+
+// runs the effects in the lane until it is empty or the deadline has passed;
+// effects that they notify are added to the same lane
+fn run_lane(runtime: &Runtime, priority: Priority, deadline: Option<f64>) {
+    let prev = runtime.priority.replace(priority);
+    let _guard = RestorePriorityOnDrop(prev);
+    loop {
+        let next = runtime.lanes.borrow_mut().pop(priority);
+        let Some(effect) = next else {
+            break;
+        };
+        runtime.update_if_necessary(effect);
+
+        #[cfg(all(
+            target_arch = "wasm32",
+            any(feature = "csr", feature = "hydrate")
+        ))]
+        if deadline.is_some_and(|deadline| js_sys::Date::now() >= deadline) {
+            break;
+        }
+    }
+    #[cfg(not(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    )))]
+    let _ = deadline;
+}
+
+#[cfg(all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate")))]
+mod browser {
+    use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+
+    pub(super) fn set_timeout(f: impl FnOnce() + 'static) {
+        let f = Closure::once_into_js(f);
+        _ = web_sys::window()
+            .expect("window not available")
+            .set_timeout_with_callback(f.unchecked_ref());
+    }
+
+    // falls back to a timeout in browsers without `requestIdleCallback`
+    pub(super) fn request_idle_callback(f: impl FnOnce() + 'static) {
+        let window = web_sys::window().expect("window not available");
+        let supported = js_sys::Reflect::has(
+            &window,
+            &JsValue::from_str("requestIdleCallback"),
+        )
+        .unwrap_or(false);
+        if supported {
+            let f = Closure::once_into_js(f);
+            _ = window.request_idle_callback(f.unchecked_ref());
+        } else {
+            set_timeout(f);
+        }
+    }
+}
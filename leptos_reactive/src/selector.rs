@@ -1,6 +1,7 @@
 use crate::{
-    create_isomorphic_effect, create_rw_signal, runtime::with_owner, Owner,
-    RwSignal, SignalUpdate, SignalWith,
+    create_isomorphic_effect, create_rw_signal, create_trigger,
+    runtime::{with_owner, with_runtime},
+    Compare, Owner, RwSignal, SignalUpdate, SignalWith, Trigger,
 };
 use std::{cell::RefCell, collections::HashMap, hash::Hash, rc::Rc};
 
@@ -187,3 +188,177 @@ where
         subs.remove(key);
     }
 }
+
+/// Creates a keyed selector, which projects the value of the source signal
+/// separately for each key that is read, and only notifies the readers of a
+/// key when its projected value changes, as determined by `compare`.
+///
+/// This gives cheap derived slices of a collection: when one item of a large
+/// list changes, only the views that read that item's projection rerun, and
+/// neither the source value nor the projections need to implement
+/// [`PartialEq`], because `compare` can be any [`Compare`] strategy.
+///
+/// ```
+/// # use leptos_reactive::*;
+/// # let runtime = create_runtime();
+/// struct Row {
+///     id: usize,
+///     score: f64,
+/// }
+///
+/// let rows = create_rw_signal(vec![
+///     Row { id: 1, score: 0.5 },
+///     Row { id: 2, score: 0.9 },
+/// ]);
+/// let scores = create_keyed_selector(
+///     move || rows.with(|rows| rows.iter().map(|row| (row.id, row.score)).collect::<Vec<_>>()),
+///     |rows, id: &usize| {
+///         rows.iter().find(|(row, _)| row == id).map(|(_, score)| *score)
+///     },
+///     // scores within 0.01 of each other are treated as equal
+///     |a: &Option<f64>, b: &Option<f64>| match (a, b) {
+///         (Some(a), Some(b)) => (a - b).abs() < 0.01,
+///         _ => a.is_none() && b.is_none(),
+///     },
+/// );
+///
+/// let runs = create_rw_signal(0);
+/// create_isomorphic_effect({
+///     let scores = scores.clone();
+///     move |_| {
+///         scores.get(1);
+///         runs.update(|n| *n += 1);
+///     }
+/// });
+/// assert_eq!(runs.get_untracked(), 1);
+///
+/// // changing another row does not notify the readers of row 1
+/// rows.update(|rows| rows[1].score = 0.2);
+/// assert_eq!(runs.get_untracked(), 1);
+///
+/// // neither does a change that `compare` treats as equal
+/// rows.update(|rows| rows[0].score = 0.505);
+/// assert_eq!(runs.get_untracked(), 1);
+///
+/// rows.update(|rows| rows[0].score = 0.75);
+/// assert_eq!(runs.get_untracked(), 2);
+/// assert_eq!(scores.get(1), Some(0.75));
+/// # runtime.dispose();
+/// ```
+#[track_caller]
+pub fn create_keyed_selector<S, K, O>(
+    source: impl Fn() -> S + 'static,
+    project: impl Fn(&S, &K) -> O + 'static,
+    compare: impl Compare<O>,
+) -> KeyedSelector<K, O>
+where
+    S: 'static,
+    K: Eq + Hash + Clone + 'static,
+    O: 'static,
+{
+    let entries: Rc<RefCell<HashMap<K, (Trigger, Rc<O>)>>> = Default::default();
+    let latest = Rc::new(RefCell::new(None::<S>));
+    let owner = Owner::current()
+        .expect("create_keyed_selector called outside the reactive system");
+    let project = Rc::new(project);
+
+    create_isomorphic_effect({
+        let entries = Rc::clone(&entries);
+        let latest = Rc::clone(&latest);
+        let project = Rc::clone(&project);
+        move |_| {
+            let value = source();
+            // project every key before notifying anyone, so that the readers
+            // which rerun see the new projections
+            let changed = {
+                let mut entries = entries.borrow_mut();
+                entries
+                    .iter_mut()
+                    .filter_map(|(key, (trigger, current))| {
+                        let new = project(&value, key);
+                        let is_equal = compare.is_equal(current, &new);
+                        *current = Rc::new(new);
+                        (!is_equal).then_some(*trigger)
+                    })
+                    .collect::<Vec<_>>()
+            };
+            *latest.borrow_mut() = Some(value);
+            for trigger in changed {
+                trigger.try_notify();
+            }
+        }
+    });
+
+    KeyedSelector {
+        entries,
+        project: Rc::new(move |key: &K| {
+            project(latest.borrow().as_ref().expect("source has run"), key)
+        }),
+        owner,
+    }
+}
+
+/// A keyed selector, created with [`create_keyed_selector`], which only
+/// notifies the readers of a key when its projected value changes.
+#[derive(Clone)]
+pub struct KeyedSelector<K, O>
+where
+    K: Eq + Hash + Clone + 'static,
+    O: 'static,
+{
+    entries: Rc<RefCell<HashMap<K, (Trigger, Rc<O>)>>>,
+    #[allow(clippy::type_complexity)]
+    project: Rc<dyn Fn(&K) -> O>,
+    owner: Owner,
+}
+
+impl<K, O> core::fmt::Debug for KeyedSelector<K, O>
+where
+    K: Eq + Hash + Clone + 'static,
+    O: 'static,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("KeyedSelector").finish()
+    }
+}
+
+impl<K, O> KeyedSelector<K, O>
+where
+    K: Eq + Hash + Clone + 'static,
+    O: 'static,
+{
+    /// Reactively reads the projected value for the given key.
+    pub fn with<U>(&self, key: K, f: impl FnOnce(&O) -> U) -> U {
+        let entry = self.entries.borrow().get(&key).cloned();
+        let (trigger, value) = entry.unwrap_or_else(|| {
+            // the projection is user code, so it runs without a borrow
+            let value = Rc::new((self.project)(&key));
+            let trigger = with_owner(self.owner, create_trigger);
+            self.entries
+                .borrow_mut()
+                .entry(key)
+                .or_insert((trigger, value))
+                .clone()
+        });
+        trigger.try_track();
+        // `f` may read other keys or rerun the source, so the entries are
+        // not borrowed while it runs
+        f(&value)
+    }
+
+    /// Reactively reads and clones the projected value for the given key.
+    pub fn get(&self, key: K) -> O
+    where
+        O: Clone,
+    {
+        self.with(key, O::clone)
+    }
+
+    /// Removes the listener for the given key, disposing of its trigger.
+    pub fn remove(&self, key: &K) {
+        let entry = self.entries.borrow_mut().remove(key);
+        if let Some((trigger, _)) = entry {
+            _ = with_runtime(|runtime| runtime.dispose_node(trigger.id));
+        }
+    }
+}
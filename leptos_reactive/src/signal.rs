@@ -1,7 +1,12 @@
 use crate::{
-    console_warn, create_isomorphic_effect, diagnostics, diagnostics::*,
-    macros::debug_warn, node::NodeId, on_cleanup, runtime::with_runtime,
-    Runtime,
+    compare::{erase_compare, AnyCompare},
+    console_warn, create_isomorphic_effect, diagnostics,
+    diagnostics::*,
+    macros::debug_warn,
+    node::{NodeId, ReactiveNodeType},
+    on_cleanup,
+    runtime::with_runtime,
+    Compare, Runtime,
 };
 use futures::Stream;
 use std::{
@@ -279,6 +284,112 @@ pub trait SignalStream<T> {
     // changes are non-breaking
     #[track_caller]
     fn to_stream(&self) -> Pin<Box<dyn Stream<Item = T>>>;
+
+    /// Like [`to_stream`](SignalStream::to_stream), but lets you skip the
+    /// value the signal has when the stream is created, and choose what
+    /// happens to values that change faster than the stream is read.
+    ///
+    /// ```
+    /// # use leptos_reactive::*;
+    /// # use futures::{executor::block_on, StreamExt};
+    /// # let runtime = create_runtime();
+    /// let (count, set_count) = create_signal(0);
+    /// let mut changes = count.to_stream_with_options(StreamOptions {
+    ///     initial: false,
+    ///     backpressure: Backpressure::LatestOnly,
+    /// });
+    /// set_count.set(1);
+    /// set_count.set(2);
+    /// // only the latest change is read
+    /// assert_eq!(block_on(changes.next()), Some(2));
+    /// # runtime.dispose();
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if you try to access a signal that is owned by a reactive node that has been disposed.
+    #[track_caller]
+    fn to_stream_with_options(
+        &self,
+        options: StreamOptions,
+    ) -> Pin<Box<dyn Stream<Item = T>>>
+    where
+        T: 'static,
+    {
+        use futures::StreamExt;
+
+        let stream = self.to_stream();
+        let stream = if options.initial {
+            stream
+        } else {
+            Box::pin(stream.skip(1))
+        };
+        match options.backpressure {
+            Backpressure::Buffered => stream,
+            Backpressure::LatestOnly => Box::pin(Latest(stream.fuse())),
+        }
+    }
+}
+
+/// What happens to the values of a stream that is bridged to or from a
+/// signal when they arrive faster than they are read.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Backpressure {
+    /// Every value is kept until it is read. This is the default.
+    #[default]
+    Buffered,
+    /// Only the most recent value is read; the values that it replaced
+    /// before they were read are dropped.
+    LatestOnly,
+}
+
+/// How [`SignalStream::to_stream_with_options`] creates a stream.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct StreamOptions {
+    /// Whether the stream begins with the value the signal has when the
+    /// stream is created, or only emits its changes. Defaults to `true`.
+    pub initial: bool,
+    /// What happens to the values that change faster than the stream is
+    /// read. Defaults to [`Backpressure::Buffered`].
+    pub backpressure: Backpressure,
+}
+
+impl Default for StreamOptions {
+    fn default() -> Self {
+        Self {
+            initial: true,
+            backpressure: Backpressure::default(),
+        }
+    }
+}
+
+// yields only the last of the values that are ready when it is polled
+struct Latest<S>(futures::stream::Fuse<S>);
+
+impl<S: Stream + Unpin> Stream for Latest<S> {
+    type Item = S::Item;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        use futures::StreamExt;
+        use std::task::Poll;
+
+        let mut latest = None;
+        loop {
+            match self.0.poll_next_unpin(cx) {
+                Poll::Ready(Some(value)) => latest = Some(value),
+                // the last value is returned before the end of the stream
+                Poll::Ready(None) => return Poll::Ready(latest),
+                Poll::Pending => {
+                    return match latest {
+                        Some(value) => Poll::Ready(Some(value)),
+                        None => Poll::Pending,
+                    }
+                }
+            }
+        }
+    }
 }
 
 /// This trait allows disposing a signal before its owner has been disposed.
@@ -381,6 +492,53 @@ pub fn create_signal_from_stream<T>(
     }
 }
 
+/// Creates a signal that starts with `initial`, and is set to each value that
+/// a [`Stream`](futures::stream::Stream) emits, e.g., the messages of a
+/// WebSocket. With [`Backpressure::LatestOnly`], values that arrive together
+/// set the signal once, to the last of them.
+///
+/// The stream is dropped once the signal has been disposed.
+///
+/// **Note**: If used on the server side during server rendering, the signal keeps its initial
+/// value and the stream is not driven.
+#[cfg_attr(
+    any(debug_assertions, features = "ssr"),
+    instrument(level = "trace", skip_all,)
+)]
+pub fn create_signal_from_stream_with_options<T>(
+    stream: impl Stream<Item = T> + Unpin + 'static,
+    initial: T,
+    backpressure: Backpressure,
+) -> ReadSignal<T>
+where
+    T: 'static,
+{
+    let (read, write) = create_signal(initial);
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "ssr")] {
+            _ = (stream, write, backpressure);
+        } else {
+            use crate::spawn_local;
+            use futures::StreamExt;
+
+            let mut stream = match backpressure {
+                Backpressure::Buffered => stream.boxed_local(),
+                Backpressure::LatestOnly => {
+                    Latest(stream.fuse()).boxed_local()
+                }
+            };
+            spawn_local(async move {
+                while let Some(value) = stream.next().await {
+                    if write.try_set(value).is_some() {
+                        break;
+                    }
+                }
+            });
+        }
+    }
+    read
+}
+
 /// The getter for a reactive signal.
 ///
 /// A signal is a piece of data that may change over time,
@@ -736,10 +894,11 @@ where
                 {
                     panic!(
                         "at {}, tried to access ReadSignal<{}> defined at {}, \
-                         but it has already been disposed",
+                         but it has already been disposed{}",
                         caller,
                         std::any::type_name::<T>(),
-                        self.defined_at
+                        self.defined_at,
+                        crate::ComponentStack::current()
                     )
                 }
             })
@@ -1048,8 +1207,8 @@ impl<T> SignalSet for WriteSignal<T> {
         )
     )]
     fn set(&self, new_value: T) {
-        self.id.update(
-            |n| *n = new_value,
+        self.id.set(
+            new_value,
             #[cfg(debug_assertions)]
             Some(self.defined_at),
         );
@@ -1069,15 +1228,11 @@ impl<T> SignalSet for WriteSignal<T> {
         )
     )]
     fn try_set(&self, new_value: T) -> Option<T> {
-        let mut new_value = Some(new_value);
-
-        self.id.update(
-            |t| *t = new_value.take().unwrap(),
+        self.id.set(
+            new_value,
             #[cfg(debug_assertions)]
             None,
-        );
-
-        new_value
+        )
     }
 }
 
@@ -1157,6 +1312,55 @@ pub fn create_rw_signal<T>(value: T) -> RwSignal<T> {
     Runtime::current().create_rw_signal(value)
 }
 
+/// Creates a [`RwSignal`] that only notifies its subscribers when it is set
+/// to a value that `compare` finds different from its current value.
+///
+/// `compare` can be a closure that takes the current and the new value and
+/// returns whether they are equal, or a [`Changed`](crate::Changed) strategy.
+/// Setting the signal to an equal value still stores the new value, but
+/// doesn't notify its subscribers. This only applies to
+/// [`set`](SignalSet::set) and [`try_set`](SignalSet::try_set) on this signal
+/// or its read and write halves; [`update`](SignalUpdate::update) always
+/// notifies.
+///
+/// ```
+/// # use leptos_reactive::*;
+/// # let runtime = create_runtime();
+/// let temperature = create_rw_signal_with_compare(20.0_f64, |a: &f64, b: &f64| {
+///     (a - b).abs() < 0.1
+/// });
+/// let readings = create_rw_signal(0);
+/// create_isomorphic_effect(move |_| {
+///     temperature.track();
+///     readings.update(|n| *n += 1);
+/// });
+/// assert_eq!(readings.get_untracked(), 1);
+///
+/// // within the tolerance: ignored
+/// temperature.set(20.05);
+/// assert_eq!(readings.get_untracked(), 1);
+/// assert_eq!(temperature.get_untracked(), 20.05);
+///
+/// temperature.set(21.0);
+/// assert_eq!(readings.get_untracked(), 2);
+/// # runtime.dispose();
+/// ```
+#[track_caller]
+pub fn create_rw_signal_with_compare<T: 'static>(
+    value: T,
+    compare: impl Compare<T>,
+) -> RwSignal<T> {
+    let signal = Runtime::current().create_rw_signal(value);
+    _ = with_runtime(|runtime| {
+        if let Some(node) = runtime.nodes.borrow_mut().get_mut(signal.id) {
+            node.node_type = ReactiveNodeType::Signal {
+                compare: Some(erase_compare(compare)),
+            };
+        }
+    });
+    signal
+}
+
 /// A signal that combines the getter and setter into one value, rather than
 /// separating them into a [`ReadSignal`] and a [`WriteSignal`]. You may prefer this
 /// its style, or it may be easier to pass around in a context or as a function argument.
@@ -1288,10 +1492,11 @@ impl<T: Clone> SignalGetUntracked for RwSignal<T> {
                 {
                     panic!(
                         "at {}, tried to access RwSignal<{}> defined at {}, \
-                         but it has already been disposed",
+                         but it has already been disposed{}",
                         caller,
                         std::any::type_name::<T>(),
-                        self.defined_at
+                        self.defined_at,
+                        crate::ComponentStack::current()
                     )
                 }
             })
@@ -1349,9 +1554,10 @@ impl<T> SignalWithUntracked for RwSignal<T> {
                 {
                     panic!(
                         "tried to access RwSignal<{}> defined at {}, but it \
-                         has already been disposed",
+                         has already been disposed{}",
                         std::any::type_name::<T>(),
-                        self.defined_at
+                        self.defined_at,
+                        crate::ComponentStack::current()
                     )
                 }
             })
@@ -1736,8 +1942,8 @@ impl<T> SignalSet for RwSignal<T> {
         )
     )]
     fn set(&self, value: T) {
-        self.id.update(
-            |n| *n = value,
+        self.id.set(
+            value,
             #[cfg(debug_assertions)]
             Some(self.defined_at),
         );
@@ -1757,15 +1963,11 @@ impl<T> SignalSet for RwSignal<T> {
         )
     )]
     fn try_set(&self, new_value: T) -> Option<T> {
-        let mut new_value = Some(new_value);
-
-        self.id.update(
-            |t| *t = new_value.take().unwrap(),
+        self.id.set(
+            new_value,
             #[cfg(debug_assertions)]
             None,
-        );
-
-        new_value
+        )
     }
 }
 
@@ -1823,6 +2025,16 @@ impl<T> RwSignal<T> {
         create_rw_signal(value)
     }
 
+    /// Creates a reactive signal that only notifies its subscribers when
+    /// `compare` finds its new value different from the current one.
+    ///
+    /// This is identical to [`create_rw_signal_with_compare`].
+    #[inline(always)]
+    #[track_caller]
+    pub fn new_with_compare(value: T, compare: impl Compare<T>) -> Self {
+        create_rw_signal_with_compare(value, compare)
+    }
+
     /// Returns a read-only handle to the signal.
     ///
     /// Useful if you're trying to give read access to another component but ensure that it can't write
@@ -1975,6 +2187,13 @@ impl NodeId {
                 let sources = sources.or_default();
                 sources.borrow_mut().insert(*self);
             }
+
+            #[cfg(debug_assertions)]
+            if let Some(explained) =
+                runtime.explained_effects.borrow_mut().get_mut(observer)
+            {
+                explained.record_read(*self, diagnostics.defined_at);
+            }
         } else {
             #[cfg(all(debug_assertions, not(feature = "ssr")))]
             {
@@ -2113,6 +2332,38 @@ impl NodeId {
         .unwrap_or_default()
     }
 
+    /// Sets the signal to `new_value`, but only notifies its subscribers if
+    /// it wasn't created with a comparison that finds `new_value` equal to
+    /// its current value. Returns `new_value` if the signal has been disposed
+    /// of.
+    #[inline(always)]
+    #[track_caller]
+    pub(crate) fn set<T: 'static>(
+        &self,
+        new_value: T,
+        #[cfg(debug_assertions)] defined_at: Option<
+            &'static std::panic::Location<'static>,
+        >,
+    ) -> Option<T> {
+        let new_value = RefCell::new(Some(new_value));
+        self.update_unless_equal(
+            |value: &T, compare| {
+                new_value
+                    .borrow()
+                    .as_ref()
+                    .is_some_and(|new| compare(value, new))
+            },
+            |value: &mut T| {
+                if let Some(new) = new_value.borrow_mut().take() {
+                    *value = new;
+                }
+            },
+            #[cfg(debug_assertions)]
+            defined_at,
+        );
+        new_value.into_inner()
+    }
+
     #[inline(always)]
     #[track_caller]
     pub(crate) fn update<T, U>(
@@ -2122,6 +2373,30 @@ impl NodeId {
             &'static std::panic::Location<'static>,
         >,
     ) -> Option<U>
+    where
+        T: 'static,
+    {
+        self.update_unless_equal(
+            |_, _| false,
+            f,
+            #[cfg(debug_assertions)]
+            defined_at,
+        )
+    }
+
+    // if the signal was created with a comparison, `is_equal` is called with
+    // it and the current value before `f` updates the value, and the
+    // subscribers aren't notified if it returns `true`
+    #[inline(always)]
+    #[track_caller]
+    fn update_unless_equal<T, U>(
+        &self,
+        is_equal: impl FnOnce(&T, &AnyCompare) -> bool,
+        f: impl FnOnce(&mut T) -> U,
+        #[cfg(debug_assertions)] defined_at: Option<
+            &'static std::panic::Location<'static>,
+        >,
+    ) -> Option<U>
     where
         T: 'static,
     {
@@ -2129,10 +2404,20 @@ impl NodeId {
         let location = std::panic::Location::caller();
 
         with_runtime(|runtime| {
-            let updated = if let Some(value) = runtime.get_value(*self) {
+            let updated = if let Some((value, compare)) =
+                runtime.get_signal_value(*self)
+            {
+                // compared before the value is borrowed mutably, so that the
+                // comparison can read the signal
+                let unchanged = compare.is_some_and(|compare| {
+                    value
+                        .borrow()
+                        .downcast_ref::<T>()
+                        .is_some_and(|value| is_equal(value, &compare))
+                });
                 let mut value = value.borrow_mut();
                 if let Some(value) = value.downcast_mut::<T>() {
-                    Some(f(value))
+                    Some((f(value), !unchanged))
                 } else {
                     debug_warn!(
                         "[Signal::update] failed when downcasting to \
@@ -2162,14 +2447,24 @@ impl NodeId {
             };
 
             // notify subscribers
-            if updated.is_some() {
+            if let Some((_, true)) = updated {
+                #[cfg(debug_assertions)]
+                if runtime.has_subscribers(*self) {
+                    if let Some(warning) =
+                        RenderWriteWarning::check(location, defined_at)
+                    {
+                        debug_warn!("{warning}");
+                    }
+                    crate::render_counts::signal_written(defined_at);
+                }
+
                 // mark descendants dirty
                 runtime.mark_dirty(*self);
 
                 runtime.run_effects();
             }
 
-            updated
+            updated.map(|(updated, _)| updated)
         })
         .unwrap_or_default()
     }
@@ -2217,7 +2512,10 @@ pub(crate) fn format_signal_warning(
         }
     };
 
-    format!("{msg}\n{defined_at_msg}warning happened here: {location}",)
+    format!(
+        "{msg}\n{defined_at_msg}warning happened here: {location}{}",
+        crate::ComponentStack::current()
+    )
 }
 
 #[cold]
@@ -850,7 +850,7 @@ impl From<&str> for MaybeSignal<String> {
 
 #[cfg(feature = "nightly")]
 mod from_fn_for_signals {
-    use super::{MaybeSignal, Memo, ReadSignal, RwSignal, Signal};
+    use super::{MaybeSignal, Memo, ReadSignal, RwSignal, Signal, SignalRef};
     auto trait NotSignalMarker {}
 
     impl<T> !NotSignalMarker for Signal<T> {}
@@ -858,6 +858,7 @@ mod from_fn_for_signals {
     impl<T> !NotSignalMarker for Memo<T> {}
     impl<T> !NotSignalMarker for RwSignal<T> {}
     impl<T> !NotSignalMarker for MaybeSignal<T> {}
+    impl<T> !NotSignalMarker for SignalRef<T> {}
 
     impl<F, T> From<F> for Signal<T>
     where
@@ -878,6 +879,290 @@ where
     }
 }
 
+/// A wrapping type for a read-only component prop, which can either be a
+/// reactive signal or a non-reactive value.
+///
+/// Unlike [`MaybeSignal`], a `SignalRef` is always [`Copy`], and does not
+/// require the value to be [`Clone`]. Use [`.with()`](SignalWith::with) to
+/// read the value by reference, which avoids cloning large values, like long
+/// lists, every time they are read.
+///
+/// A `#[prop(into)]` prop of type `SignalRef<T>` accepts a plain `T`, a
+/// [`ReadSignal`], [`RwSignal`], [`Memo`], [`Signal`], or [`MaybeSignal`].
+/// A derived closure can be passed as a [`Signal`], using
+/// [`Signal::derive`] or [`.into_signal()`](IntoSignal::into_signal).
+///
+/// ## Core Trait Implementations
+/// - [`.get()`](#impl-SignalGet-for-SignalRef<T>) clones the current value of
+///   the signal, if `T` is [`Clone`].
+/// - [`.with()`](#impl-SignalWith-for-SignalRef<T>) allows you to reactively
+///   access the value without cloning it.
+/// - The untracked variants [`.get_untracked()`](SignalGetUntracked) and
+///   [`.with_untracked()`](SignalWithUntracked) access the value without
+///   subscribing to it.
+///
+/// ## Examples
+/// ```rust
+/// # use leptos_reactive::*;
+/// # let runtime = create_runtime();
+/// // `Item` does not implement `Clone`
+/// struct Item(u32);
+///
+/// let (items, set_items) = create_signal(vec![Item(1), Item(2)]);
+/// let static_items = SignalRef::from(vec![Item(3)]);
+///
+/// // this function takes any kind of wrapped signal or value
+/// fn total(items: SignalRef<Vec<Item>>) -> u32 {
+///     items.with(|items| items.iter().map(|item| item.0).sum())
+/// }
+///
+/// assert_eq!(total(items.into()), 3);
+/// assert_eq!(total(static_items), 3);
+/// assert_eq!(total(Signal::derive(move || vec![Item(4)]).into()), 4);
+///
+/// set_items.update(|items| items.push(Item(3)));
+/// assert_eq!(total(items.into()), 6);
+/// # runtime.dispose();
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub enum SignalRef<T>
+where
+    T: 'static,
+{
+    /// An unchanging value of type `T`.
+    Static(StoredValue<T>),
+    /// A reactive signal that contains a value of type `T`.
+    Dynamic(Signal<T>),
+}
+
+impl<T> Clone for SignalRef<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for SignalRef<T> {}
+
+impl<T: Default> Default for SignalRef<T> {
+    fn default() -> Self {
+        Self::Static(store_value(Default::default()))
+    }
+}
+
+impl<T> SignalWith for SignalRef<T> {
+    type Value = T;
+
+    #[cfg_attr(
+        any(debug_assertions, feature = "ssr"),
+        instrument(
+            level = "trace",
+            name = "SignalRef::with()",
+            skip_all,
+            fields(ty = %std::any::type_name::<T>())
+        )
+    )]
+    fn with<O>(&self, f: impl FnOnce(&T) -> O) -> O {
+        match self {
+            Self::Static(t) => t.with_value(f),
+            Self::Dynamic(s) => s.with(f),
+        }
+    }
+
+    #[cfg_attr(
+        any(debug_assertions, feature = "ssr"),
+        instrument(
+            level = "trace",
+            name = "SignalRef::try_with()",
+            skip_all,
+            fields(ty = %std::any::type_name::<T>())
+        )
+    )]
+    fn try_with<O>(&self, f: impl FnOnce(&T) -> O) -> Option<O> {
+        match self {
+            Self::Static(t) => t.try_with_value(f),
+            Self::Dynamic(s) => s.try_with(f),
+        }
+    }
+}
+
+impl<T> SignalWithUntracked for SignalRef<T> {
+    type Value = T;
+
+    #[cfg_attr(
+        any(debug_assertions, feature = "ssr"),
+        instrument(
+            level = "trace",
+            name = "SignalRef::with_untracked()",
+            skip_all,
+            fields(ty = %std::any::type_name::<T>())
+        )
+    )]
+    fn with_untracked<O>(&self, f: impl FnOnce(&T) -> O) -> O {
+        match self {
+            Self::Static(t) => t.with_value(f),
+            Self::Dynamic(s) => s.with_untracked(f),
+        }
+    }
+
+    #[cfg_attr(
+        any(debug_assertions, feature = "ssr"),
+        instrument(
+            level = "trace",
+            name = "SignalRef::try_with_untracked()",
+            skip_all,
+            fields(ty = %std::any::type_name::<T>())
+        )
+    )]
+    fn try_with_untracked<O>(&self, f: impl FnOnce(&T) -> O) -> Option<O> {
+        match self {
+            Self::Static(t) => t.try_with_value(f),
+            Self::Dynamic(s) => s.try_with_untracked(f),
+        }
+    }
+}
+
+impl<T: Clone> SignalGet for SignalRef<T> {
+    type Value = T;
+
+    #[cfg_attr(
+        any(debug_assertions, feature = "ssr"),
+        instrument(
+            level = "trace",
+            name = "SignalRef::get()",
+            skip_all,
+            fields(ty = %std::any::type_name::<T>())
+        )
+    )]
+    fn get(&self) -> T {
+        self.with(T::clone)
+    }
+
+    #[cfg_attr(
+        any(debug_assertions, feature = "ssr"),
+        instrument(
+            level = "trace",
+            name = "SignalRef::try_get()",
+            skip_all,
+            fields(ty = %std::any::type_name::<T>())
+        )
+    )]
+    fn try_get(&self) -> Option<T> {
+        self.try_with(T::clone)
+    }
+}
+
+impl<T: Clone> SignalGetUntracked for SignalRef<T> {
+    type Value = T;
+
+    #[cfg_attr(
+        any(debug_assertions, feature = "ssr"),
+        instrument(
+            level = "trace",
+            name = "SignalRef::get_untracked()",
+            skip_all,
+            fields(ty = %std::any::type_name::<T>())
+        )
+    )]
+    fn get_untracked(&self) -> T {
+        self.with_untracked(T::clone)
+    }
+
+    #[cfg_attr(
+        any(debug_assertions, feature = "ssr"),
+        instrument(
+            level = "trace",
+            name = "SignalRef::try_get_untracked()",
+            skip_all,
+            fields(ty = %std::any::type_name::<T>())
+        )
+    )]
+    fn try_get_untracked(&self) -> Option<T> {
+        self.try_with_untracked(T::clone)
+    }
+}
+
+impl<T> SignalRef<T>
+where
+    T: 'static,
+{
+    /// Wraps a derived signal, i.e., any computation that accesses one or more
+    /// reactive signals.
+    /// ```rust
+    /// # use leptos_reactive::*;
+    /// # let runtime = create_runtime();
+    /// let (names, set_names) = create_signal(vec!["Alice".to_string()]);
+    /// let upper = SignalRef::derive(move || {
+    ///     names.with(|names| {
+    ///         names.iter().map(|n| n.to_uppercase()).collect::<Vec<_>>()
+    ///     })
+    /// });
+    ///
+    /// assert_eq!(upper.with(|names| names.join(", ")), "ALICE");
+    /// set_names.update(|names| names.push("Bob".to_string()));
+    /// assert_eq!(upper.with(|names| names.join(", ")), "ALICE, BOB");
+    /// # runtime.dispose();
+    /// ```
+    #[cfg_attr(
+        any(debug_assertions, feature = "ssr"),
+        instrument(
+            level = "trace",
+            name = "SignalRef::derive()",
+            skip_all,
+            fields(
+                ty = %std::any::type_name::<T>()
+            )
+        )
+    )]
+    pub fn derive(derived_signal: impl Fn() -> T + 'static) -> Self {
+        Self::Dynamic(Signal::derive(derived_signal))
+    }
+}
+
+impl<T> From<T> for SignalRef<T> {
+    fn from(value: T) -> Self {
+        Self::Static(store_value(value))
+    }
+}
+
+impl<T> From<ReadSignal<T>> for SignalRef<T> {
+    fn from(value: ReadSignal<T>) -> Self {
+        Self::Dynamic(value.into())
+    }
+}
+
+impl<T> From<RwSignal<T>> for SignalRef<T> {
+    fn from(value: RwSignal<T>) -> Self {
+        Self::Dynamic(value.into())
+    }
+}
+
+impl<T> From<Memo<T>> for SignalRef<T> {
+    fn from(value: Memo<T>) -> Self {
+        Self::Dynamic(value.into())
+    }
+}
+
+impl<T> From<Signal<T>> for SignalRef<T> {
+    fn from(value: Signal<T>) -> Self {
+        Self::Dynamic(value)
+    }
+}
+
+impl<T> From<MaybeSignal<T>> for SignalRef<T> {
+    fn from(value: MaybeSignal<T>) -> Self {
+        match value {
+            MaybeSignal::Static(value) => Self::Static(store_value(value)),
+            MaybeSignal::Dynamic(signal) => Self::Dynamic(signal),
+        }
+    }
+}
+
+impl From<&str> for SignalRef<String> {
+    fn from(value: &str) -> Self {
+        Self::Static(store_value(value.to_string()))
+    }
+}
+
 /// A wrapping type for an optional component prop, which can either be a signal or a
 /// non-reactive value, and which may or may not have a value. In other words, this is
 /// an `Option<MaybeSignal<Option<T>>>` that automatically flattens its getters.
@@ -1271,7 +1556,7 @@ impl From<&str> for MaybeProp<String> {
     }
 }
 
-impl_get_fn_traits![Signal, MaybeSignal];
+impl_get_fn_traits![Signal, MaybeSignal, SignalRef];
 
 #[cfg(feature = "nightly")]
 impl<T: Clone> FnOnce<()> for MaybeProp<T> {
@@ -1,6 +1,6 @@
 use crate::{
-    create_memo, IntoSignalSetter, RwSignal, Signal, SignalSetter,
-    SignalUpdate, SignalWith,
+    create_memo, create_memo_with_compare, Compare, IntoSignalSetter, RwSignal,
+    Signal, SignalSetter, SignalUpdate, SignalWith,
 };
 
 /// Derives a reactive slice of an [`RwSignal`](crate::RwSignal).
@@ -96,6 +96,58 @@ where
     create_memo(move |_| signal.with(getter)).into()
 }
 
+/// Like [`create_read_slice`], but decides whether the slice has changed
+/// with `compare` instead of [`PartialEq`], so that slices of types which
+/// don't implement it, or which should be compared in another way, still
+/// only notify their subscribers when they change.
+///
+/// ```
+/// # use leptos_reactive::*;
+/// # let runtime = create_runtime();
+/// struct Document {
+///     title: String,
+///     revision: u64,
+///     body: Vec<u8>,
+/// }
+///
+/// let doc = create_rw_signal(Document {
+///     title: "Draft".into(),
+///     revision: 1,
+///     body: Vec::new(),
+/// });
+/// // `Document` is not `PartialEq`; compare the revisions instead
+/// let latest = create_read_slice_with_compare(
+///     doc,
+///     |doc| (doc.revision, doc.title.clone()),
+///     |a: &(u64, String), b: &(u64, String)| a.0 == b.0,
+/// );
+///
+/// let runs = create_rw_signal(0);
+/// create_isomorphic_effect(move |_| {
+///     latest.track();
+///     runs.update(|n| *n += 1);
+/// });
+///
+/// doc.update(|doc| doc.body.push(b'!'));
+/// assert_eq!(runs.get_untracked(), 1);
+///
+/// doc.update(|doc| {
+///     doc.title = "Final".into();
+///     doc.revision += 1;
+/// });
+/// assert_eq!(runs.get_untracked(), 2);
+/// assert_eq!(latest.get().1, "Final");
+/// # runtime.dispose();
+/// ```
+#[track_caller]
+pub fn create_read_slice_with_compare<T, O>(
+    signal: RwSignal<T>,
+    getter: impl Fn(&T) -> O + Copy + 'static,
+    compare: impl Compare<O>,
+) -> Signal<O> {
+    create_memo_with_compare(move |_| signal.with(getter), compare).into()
+}
+
 /// Creates a setter to access one slice of a signal. This is equivalent to the
 /// write-only half of [`create_slice`].
 #[track_caller]
@@ -0,0 +1,423 @@
+use crate::{
+    batch, create_trigger, store_value, with_owner, Owner, SignalGet,
+    SignalGetUntracked, SignalSet, SignalUpdate, SignalWith,
+    SignalWithUntracked, StoredValue, Trigger,
+};
+use rustc_hash::FxHashMap;
+use std::fmt;
+
+/// Creates a [`Store`], a signal whose fields can be read and updated on
+/// their own.
+///
+/// The fields of a struct that derives
+/// [`Store`](https://docs.rs/leptos/latest/leptos/derive.Store.html) are
+/// accessed with methods of the same names, which return a [`Subfield`]. Code
+/// that reads a field is only notified when that field, one of its own
+/// fields, or the struct that contains it as a whole is updated, not when
+/// one of its siblings is.
+///
+/// ```
+/// # use leptos_reactive::*;
+/// # let runtime = create_runtime();
+/// struct User {
+///     name: String,
+///     visits: u32,
+/// }
+///
+/// let store = create_store(User {
+///     name: "Ada".into(),
+///     visits: 0,
+/// });
+/// // `#[derive(Store)]` generates these as `store.name()` and
+/// // `store.visits()`
+/// let name = Subfield::new(store, 0, |user: &User| &user.name, |user| {
+///     &mut user.name
+/// });
+/// let visits = Subfield::new(store, 1, |user: &User| &user.visits, |user| {
+///     &mut user.visits
+/// });
+///
+/// let name_changes = create_rw_signal(0);
+/// create_isomorphic_effect(move |_| {
+///     name.track();
+///     name_changes.update(|n| *n += 1);
+/// });
+/// assert_eq!(name_changes.get_untracked(), 1);
+///
+/// // updating a sibling does not notify readers of the name
+/// visits.update(|visits| *visits += 1);
+/// assert_eq!(name_changes.get_untracked(), 1);
+///
+/// name.set("Grace".into());
+/// assert_eq!(name_changes.get_untracked(), 2);
+/// assert_eq!(store.with(|user| user.name.clone()), "Grace");
+///
+/// // updating the whole struct notifies every field
+/// store.update(|user| user.visits = 0);
+/// assert_eq!(name_changes.get_untracked(), 3);
+/// # runtime.dispose();
+/// ```
+#[track_caller]
+pub fn create_store<T>(value: T) -> Store<T> {
+    Store::new(value)
+}
+
+/// A signal whose fields can be read and updated on their own, created with
+/// [`create_store`].
+///
+/// See [`create_store`] for an example.
+pub struct Store<T: 'static> {
+    value: StoredValue<T>,
+    // a trigger for each path that has been read, created when it is first
+    // read
+    triggers: StoredValue<FxHashMap<Vec<usize>, Trigger>>,
+    owner: Option<Owner>,
+}
+
+impl<T> Clone for Store<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Store<T> {}
+
+impl<T> fmt::Debug for Store<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Store").field("value", &self.value).finish()
+    }
+}
+
+impl<T> Store<T> {
+    /// Creates a store with the given value.
+    ///
+    /// This is identical to [`create_store`].
+    #[track_caller]
+    pub fn new(value: T) -> Self {
+        Self {
+            value: store_value(value),
+            triggers: store_value(FxHashMap::default()),
+            owner: Owner::current(),
+        }
+    }
+
+    /// Disposes of the store, so that its value can no longer be read or
+    /// updated.
+    pub fn dispose(self) {
+        self.triggers.dispose();
+        self.value.dispose();
+    }
+
+    fn trigger(&self, path: &[usize]) -> Option<Trigger> {
+        if let Some(trigger) = self
+            .triggers
+            .try_with_value(|triggers| triggers.get(path).copied())?
+        {
+            return Some(trigger);
+        }
+        // the triggers live as long as the store, even if a field is first
+        // read inside an effect that runs again
+        let trigger = match self.owner {
+            Some(owner) => with_owner(owner, create_trigger),
+            None => create_trigger(),
+        };
+        self.triggers.try_update_value(|triggers| {
+            triggers.insert(path.to_vec(), trigger);
+        })?;
+        Some(trigger)
+    }
+}
+
+/// A [`Store`] or one of its fields, whose own fields can be accessed with
+/// the methods that `#[derive(Store)]` generates.
+pub trait StoreField: Copy + 'static {
+    /// The type of the value of the store or field.
+    type Value: 'static;
+
+    /// The indices of the fields that lead from the store to this field.
+    #[doc(hidden)]
+    fn store_path(&self) -> Vec<usize>;
+
+    /// Subscribes the running effect to the field at the given path.
+    #[doc(hidden)]
+    fn store_track(&self, path: &[usize]) -> bool;
+
+    /// Notifies the code that reads the field at the given path, the fields
+    /// that contain it, or the fields that it contains.
+    #[doc(hidden)]
+    fn store_notify(&self, path: &[usize]);
+
+    /// Reads the value without subscribing to it.
+    #[doc(hidden)]
+    fn try_with_value<O>(&self, f: impl FnOnce(&Self::Value) -> O)
+        -> Option<O>;
+
+    /// Updates the value without notifying anyone.
+    #[doc(hidden)]
+    fn try_update_value<O>(
+        &self,
+        f: impl FnOnce(&mut Self::Value) -> O,
+    ) -> Option<O>;
+}
+
+impl<T: 'static> StoreField for Store<T> {
+    type Value = T;
+
+    fn store_path(&self) -> Vec<usize> {
+        Vec::new()
+    }
+
+    fn store_track(&self, path: &[usize]) -> bool {
+        self.trigger(path)
+            .map(|trigger| trigger.try_track())
+            .unwrap_or(false)
+    }
+
+    fn store_notify(&self, path: &[usize]) {
+        let affected = self
+            .triggers
+            .try_with_value(|triggers| {
+                triggers
+                    .iter()
+                    .filter(|(other, _)| {
+                        other.starts_with(path) || path.starts_with(other)
+                    })
+                    .map(|(_, trigger)| *trigger)
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        batch(|| {
+            for trigger in affected {
+                trigger.try_notify();
+            }
+        });
+    }
+
+    fn try_with_value<O>(&self, f: impl FnOnce(&T) -> O) -> Option<O> {
+        self.value.try_with_value(f)
+    }
+
+    fn try_update_value<O>(&self, f: impl FnOnce(&mut T) -> O) -> Option<O> {
+        self.value.try_update_value(f)
+    }
+}
+
+/// A field of a [`Store`], or of one of its fields, which can be read and
+/// updated like a signal.
+///
+/// Reading it only subscribes to changes to this field, and updating it only
+/// notifies the code that reads this field, the fields that contain it, or
+/// its own fields.
+///
+/// The methods that `#[derive(Store)]` generates return a `Subfield`.
+pub struct Subfield<Inner, Prev, T> {
+    inner: Inner,
+    index: usize,
+    read: fn(&Prev) -> &T,
+    write: fn(&mut Prev) -> &mut T,
+}
+
+impl<Inner: Copy, Prev, T> Clone for Subfield<Inner, Prev, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Inner: Copy, Prev, T> Copy for Subfield<Inner, Prev, T> {}
+
+impl<Inner, Prev, T> fmt::Debug for Subfield<Inner, Prev, T>
+where
+    Inner: StoreField<Value = Prev>,
+    Prev: 'static,
+    T: 'static,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Subfield")
+            .field("path", &self.store_path())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Inner, Prev, T> Subfield<Inner, Prev, T> {
+    /// Creates the field with the given index, which is read and updated
+    /// through the given functions.
+    ///
+    /// This is usually generated by `#[derive(Store)]`.
+    pub fn new(
+        inner: Inner,
+        index: usize,
+        read: fn(&Prev) -> &T,
+        write: fn(&mut Prev) -> &mut T,
+    ) -> Self {
+        Self {
+            inner,
+            index,
+            read,
+            write,
+        }
+    }
+}
+
+impl<Inner, Prev, T> StoreField for Subfield<Inner, Prev, T>
+where
+    Inner: StoreField<Value = Prev>,
+    Prev: 'static,
+    T: 'static,
+{
+    type Value = T;
+
+    fn store_path(&self) -> Vec<usize> {
+        let mut path = self.inner.store_path();
+        path.push(self.index);
+        path
+    }
+
+    fn store_track(&self, path: &[usize]) -> bool {
+        self.inner.store_track(path)
+    }
+
+    fn store_notify(&self, path: &[usize]) {
+        self.inner.store_notify(path)
+    }
+
+    fn try_with_value<O>(&self, f: impl FnOnce(&T) -> O) -> Option<O> {
+        let read = self.read;
+        self.inner.try_with_value(|prev| f(read(prev)))
+    }
+
+    fn try_update_value<O>(&self, f: impl FnOnce(&mut T) -> O) -> Option<O> {
+        let write = self.write;
+        self.inner.try_update_value(|prev| f(write(prev)))
+    }
+}
+
+macro_rules! impl_signal_traits {
+    ($ty:ty, $name:literal, [$($params:tt)*], [$($bounds:tt)*]) => {
+        impl<$($params)*> SignalWith for $ty
+        where
+            $($bounds)*
+        {
+            type Value = <Self as StoreField>::Value;
+
+            #[track_caller]
+            fn with<O>(&self, f: impl FnOnce(&Self::Value) -> O) -> O {
+                self.try_with(f).expect(concat!(
+                    $name,
+                    "::with(): the store has been disposed"
+                ))
+            }
+
+            fn try_with<O>(
+                &self,
+                f: impl FnOnce(&Self::Value) -> O,
+            ) -> Option<O> {
+                self.store_track(&self.store_path());
+                self.try_with_value(f)
+            }
+        }
+
+        impl<$($params)*> SignalWithUntracked for $ty
+        where
+            $($bounds)*
+        {
+            type Value = <Self as StoreField>::Value;
+
+            #[track_caller]
+            fn with_untracked<O>(
+                &self,
+                f: impl FnOnce(&Self::Value) -> O,
+            ) -> O {
+                self.try_with_untracked(f).expect(concat!(
+                    $name,
+                    "::with_untracked(): the store has been disposed"
+                ))
+            }
+
+            fn try_with_untracked<O>(
+                &self,
+                f: impl FnOnce(&Self::Value) -> O,
+            ) -> Option<O> {
+                self.try_with_value(f)
+            }
+        }
+
+        impl<$($params)*> SignalGet for $ty
+        where
+            $($bounds)*
+            <Self as StoreField>::Value: Clone,
+        {
+            type Value = <Self as StoreField>::Value;
+
+            #[track_caller]
+            fn get(&self) -> Self::Value {
+                SignalWith::with(self, Clone::clone)
+            }
+
+            fn try_get(&self) -> Option<Self::Value> {
+                SignalWith::try_with(self, Clone::clone)
+            }
+        }
+
+        impl<$($params)*> SignalGetUntracked for $ty
+        where
+            $($bounds)*
+            <Self as StoreField>::Value: Clone,
+        {
+            type Value = <Self as StoreField>::Value;
+
+            #[track_caller]
+            fn get_untracked(&self) -> Self::Value {
+                SignalWithUntracked::with_untracked(self, Clone::clone)
+            }
+
+            fn try_get_untracked(&self) -> Option<Self::Value> {
+                SignalWithUntracked::try_with_untracked(self, Clone::clone)
+            }
+        }
+
+        impl<$($params)*> SignalUpdate for $ty
+        where
+            $($bounds)*
+        {
+            type Value = <Self as StoreField>::Value;
+
+            fn update(&self, f: impl FnOnce(&mut Self::Value)) {
+                _ = self.try_update(f);
+            }
+
+            fn try_update<O>(
+                &self,
+                f: impl FnOnce(&mut Self::Value) -> O,
+            ) -> Option<O> {
+                let output = self.try_update_value(f)?;
+                self.store_notify(&self.store_path());
+                Some(output)
+            }
+        }
+
+        impl<$($params)*> SignalSet for $ty
+        where
+            $($bounds)*
+        {
+            type Value = <Self as StoreField>::Value;
+
+            fn set(&self, new_value: Self::Value) {
+                self.update(|value| *value = new_value);
+            }
+
+            fn try_set(&self, new_value: Self::Value) -> Option<Self::Value> {
+                let mut new_value = Some(new_value);
+                _ = self.try_update(|value| *value = new_value.take().unwrap());
+                new_value
+            }
+        }
+    };
+}
+
+impl_signal_traits!(Store<T>, "Store", [T], [T: 'static,]);
+impl_signal_traits!(
+    Subfield<Inner, Prev, T>,
+    "Subfield",
+    [Inner, Prev, T],
+    [Inner: StoreField<Value = Prev>, Prev: 'static, T: 'static,]
+);
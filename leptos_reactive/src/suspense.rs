@@ -2,13 +2,15 @@
 
 use crate::{
     batch, create_isomorphic_effect, create_memo, create_rw_signal,
-    create_signal, oco::Oco, queue_microtask, store_value, Memo, ReadSignal,
-    ResourceId, RwSignal, SignalSet, SignalUpdate, SignalWith, StoredValue,
-    WriteSignal,
+    create_signal, create_trigger, oco::Oco, queue_microtask, store_value,
+    use_context, Memo, ReadSignal, ResourceId, RwSignal, SignalSet,
+    SignalUpdate, SignalWith, StoredValue, Trigger, WriteSignal,
 };
-use futures::Future;
+use futures::{channel::oneshot, Future};
 use rustc_hash::FxHashSet;
-use std::{cell::RefCell, collections::VecDeque, pin::Pin, rc::Rc};
+use std::{
+    cell::RefCell, collections::VecDeque, pin::Pin, rc::Rc, time::Duration,
+};
 
 /// Tracks [`Resource`](crate::Resource)s that are read under a suspense context,
 /// i.e., within a [`Suspense`](https://docs.rs/leptos_core/latest/leptos_core/fn.Suspense.html) component.
@@ -24,6 +26,105 @@ pub struct SuspenseContext {
     pub(crate) pending_serializable_resources_count: RwSignal<usize>,
     pub(crate) local_status: StoredValue<Option<LocalStatus>>,
     pub(crate) should_block: StoredValue<bool>,
+    streaming: StoredValue<SuspenseStreaming>,
+    budget: StoredValue<SuspenseBudget>,
+    load_permits: StoredValue<LoadPermits>,
+    timed_out: StoredValue<bool>,
+}
+
+/// How the HTML under a `<Suspense/>` is streamed during server rendering,
+/// when its resources are still loading once the rest of the page has been
+/// rendered.
+///
+/// By default, this depends on the resources read under it. Setting it lets
+/// a latency-critical shell flush immediately, or keeps a fragment that
+/// matters for SEO in the first chunk of the response.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SuspenseStreaming {
+    /// Blocks the response if a blocking resource (see
+    /// [`create_blocking_resource`](crate::create_blocking_resource)) is read
+    /// under the suspense, and streams it otherwise.
+    #[default]
+    Auto,
+    /// Sends the fallback with the rest of the page, and streams the HTML
+    /// once it is ready, even if blocking resources are read under the
+    /// suspense.
+    Stream,
+    /// Waits for the HTML before sending the first chunk of the response, as
+    /// if a blocking resource was read under the suspense. With
+    /// [`SsrMode::PartiallyBlocked`](https://docs.rs/leptos_router/latest/leptos_router/enum.SsrMode.html),
+    /// the HTML replaces the fallback in the page itself.
+    Block {
+        /// How long to wait, after which the fallback is sent and the HTML
+        /// is streamed once it is ready instead. Waits as long as it takes
+        /// if `None`.
+        ///
+        /// This only applies to out-of-order streaming: streaming in order
+        /// sends the page in document order, so it always waits.
+        max_wait: Option<Duration>,
+    },
+}
+
+/// Limits on how the resources under a `<Suspense/>` load, so that a page
+/// with many suspended widgets can't overwhelm the services they load from.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SuspenseBudget {
+    /// How many of the resources that are read under the suspense can load
+    /// at the same time, which is at least 1. The others wait until one of
+    /// them has loaded. A resource that is read under several suspenses
+    /// waits for the one with the lowest limit. Unlimited if `None`.
+    pub max_concurrent: Option<usize>,
+    /// How long server rendering waits for the resources read under the
+    /// suspense. After that, the fallback is rendered in place of the
+    /// children, and a [`SuspenseTimeout`] is provided to it as context.
+    /// Waits as long as it takes if `None`.
+    pub deadline: Option<Duration>,
+}
+
+/// The error of a `<Suspense/>` whose resources had not loaded by the
+/// deadline of its [`SuspenseBudget`], which its fallback can show with
+/// [`use_suspense_timeout`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, thiserror::Error)]
+#[error("resources did not load within {deadline:?}")]
+pub struct SuspenseTimeout {
+    /// The deadline that was missed.
+    pub deadline: Duration,
+}
+
+/// Returns the [`SuspenseTimeout`] if the fallback of a `<Suspense/>` is
+/// being rendered because its resources missed their deadline during server
+/// rendering.
+///
+/// Returning it as an `Err` from a view inside an `<ErrorBoundary/>` shows it
+/// like any other error. The client renders the fallback without it, so
+/// anything shown only for a timeout is replaced once the page hydrates.
+pub fn use_suspense_timeout() -> Option<SuspenseTimeout> {
+    use_context::<SuspenseTimeout>()
+}
+
+// the loads of resources that count toward `max_concurrent`
+#[derive(Default)]
+pub(crate) struct LoadPermits {
+    loading: usize,
+    waiting: VecDeque<oneshot::Sender<()>>,
+}
+
+/// Lets a resource load under the `max_concurrent` of a [`SuspenseBudget`],
+/// and lets the next one load once it is dropped.
+pub(crate) struct LoadPermit(StoredValue<LoadPermits>);
+
+impl Drop for LoadPermit {
+    fn drop(&mut self) {
+        self.0.try_update_value(|permits| {
+            // hands the permit over to the next load that is still waiting
+            while let Some(waiting) = permits.waiting.pop_front() {
+                if waiting.send(()).is_ok() {
+                    return;
+                }
+            }
+            permits.loading = permits.loading.saturating_sub(1);
+        });
+    }
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -37,12 +138,26 @@ pub(crate) enum LocalStatus {
 /// are read. This won’t be “blocked” by lower suspense components. This is
 /// useful for e.g., holding route transitions.
 #[derive(Clone, Debug)]
-pub struct GlobalSuspenseContext(Rc<RefCell<SuspenseContext>>);
+pub struct GlobalSuspenseContext(Rc<RefCell<SuspenseContext>>, Trigger);
 
 impl GlobalSuspenseContext {
     /// Creates an empty global suspense context.
     pub fn new() -> Self {
-        Self(Rc::new(RefCell::new(SuspenseContext::new())))
+        Self(
+            Rc::new(RefCell::new(SuspenseContext::new())),
+            create_trigger(),
+        )
+    }
+
+    /// Whether any resource that has been read since the context was last
+    /// [`reset`](Self::reset) is still loading.
+    ///
+    /// This is reactive, and also notifies its subscribers when the context
+    /// is reset (as the router does when a navigation starts), so an effect
+    /// that calls it keeps tracking the resources of the current route.
+    pub fn is_pending(&self) -> bool {
+        self.1.track();
+        self.with_inner(|s| !s.none_pending())
     }
 
     /// Runs a function with a reference to the underlying suspense context.
@@ -52,8 +167,11 @@ impl GlobalSuspenseContext {
 
     /// Runs a function with a reference to the underlying suspense context.
     pub fn reset(&self) {
-        let mut inner = self.0.borrow_mut();
-        _ = std::mem::replace(&mut *inner, SuspenseContext::new());
+        {
+            let mut inner = self.0.borrow_mut();
+            _ = std::mem::replace(&mut *inner, SuspenseContext::new());
+        }
+        self.1.notify();
     }
 }
 
@@ -79,9 +197,82 @@ impl SuspenseContext {
     }
 
     /// Whether any blocking resources are read under this suspense context,
-    /// meaning the HTML stream should not begin until it has resolved.
+    /// or its [`SuspenseStreaming`] says it should block, meaning the HTML
+    /// stream should not begin until it has resolved.
     pub fn should_block(&self) -> bool {
-        self.should_block.get_value()
+        match self.streaming.get_value() {
+            SuspenseStreaming::Auto => self.should_block.get_value(),
+            SuspenseStreaming::Stream => false,
+            SuspenseStreaming::Block { .. } => true,
+        }
+    }
+
+    /// How the HTML under this suspense context is streamed during server
+    /// rendering.
+    pub fn streaming(&self) -> SuspenseStreaming {
+        self.streaming.get_value()
+    }
+
+    /// Sets how the HTML under this suspense context is streamed during
+    /// server rendering.
+    pub fn set_streaming(&self, streaming: SuspenseStreaming) {
+        self.streaming.set_value(streaming);
+    }
+
+    /// The limits on how the resources under this suspense context load.
+    pub fn budget(&self) -> SuspenseBudget {
+        self.budget.get_value()
+    }
+
+    /// Sets the limits on how the resources under this suspense context
+    /// load.
+    pub fn set_budget(&self, budget: SuspenseBudget) {
+        self.budget.set_value(budget);
+    }
+
+    pub(crate) fn max_concurrent(&self) -> Option<usize> {
+        self.budget
+            .try_get_value()
+            .and_then(|budget| budget.max_concurrent)
+    }
+
+    /// Whether server rendering stopped waiting for the resources under this
+    /// suspense context, because they missed the deadline of its budget.
+    pub fn timed_out(&self) -> bool {
+        self.timed_out.try_get_value().unwrap_or(false)
+    }
+
+    pub(crate) fn set_timed_out(&self) {
+        _ = self.timed_out.try_set_value(true);
+    }
+
+    /// Waits until a resource read under this suspense context can load
+    /// within the `max_concurrent` of its budget.
+    pub(crate) fn acquire_load_permit(
+        &self,
+    ) -> impl Future<Output = LoadPermit> {
+        let max_concurrent = self.max_concurrent();
+        let permits = self.load_permits;
+        let waiting = permits
+            .try_update_value(|permits| {
+                if max_concurrent
+                    .map_or(true, |max| permits.loading < max.max(1))
+                {
+                    permits.loading += 1;
+                    None
+                } else {
+                    let (tx, rx) = oneshot::channel();
+                    permits.waiting.push_back(tx);
+                    Some(rx)
+                }
+            })
+            .flatten();
+        async move {
+            if let Some(waiting) = waiting {
+                _ = waiting.await;
+            }
+            LoadPermit(permits)
+        }
     }
 
     /// Returns a `Future` that resolves when this suspense is resolved.
@@ -132,6 +323,10 @@ impl SuspenseContext {
         let pending_serializable_resources_count = create_rw_signal(0); // can be removed when possible
         let local_status = store_value(None);
         let should_block = store_value(false);
+        let streaming = store_value(SuspenseStreaming::default());
+        let budget = store_value(SuspenseBudget::default());
+        let load_permits = store_value(LoadPermits::default());
+        let timed_out = store_value(false);
         let pending = create_rw_signal(Default::default());
         Self {
             pending,
@@ -141,6 +336,10 @@ impl SuspenseContext {
             pending_serializable_resources_count,
             local_status,
             should_block,
+            streaming,
+            budget,
+            load_permits,
+            timed_out,
         }
     }
 
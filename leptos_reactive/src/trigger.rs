@@ -73,6 +73,8 @@ impl Trigger {
     /// Returns `false` if there is no current reactive runtime.
     pub fn try_notify(&self) -> bool {
         with_runtime(|runtime| {
+            #[cfg(debug_assertions)]
+            crate::render_counts::signal_written(Some(self.defined_at));
             runtime.mark_dirty(self.id);
             runtime.run_effects();
         })
@@ -223,6 +225,8 @@ impl SignalUpdate for Trigger {
         with_runtime(|runtime| {
             let res = f(&mut ());
 
+            #[cfg(debug_assertions)]
+            crate::render_counts::signal_written(Some(self.defined_at));
             runtime.mark_dirty(self.id);
             runtime.run_effects();
 
@@ -43,3 +43,45 @@ fn cleanup() {
 
     runtime.dispose();
 }
+
+#[test]
+fn cleanup_order_relative_to_children() {
+    use leptos_reactive::{
+        create_isomorphic_effect, create_runtime, create_signal, on_cleanup,
+        on_cleanup_after_children, on_cleanup_before_children, SignalSet,
+        SignalWith,
+    };
+    use std::{cell::RefCell, rc::Rc};
+
+    let runtime = create_runtime();
+
+    type Order = Rc<RefCell<Vec<&'static str>>>;
+    fn push(order: &Order, label: &'static str) -> impl FnOnce() {
+        let order = Rc::clone(order);
+        move || order.borrow_mut().push(label)
+    }
+
+    let order = Order::default();
+    let (a, set_a) = create_signal(0);
+
+    create_isomorphic_effect({
+        let order = Rc::clone(&order);
+        move |_| {
+            a.track();
+            // registered first, but still waits for the child effect
+            on_cleanup_after_children(push(&order, "after"));
+            on_cleanup_before_children(push(&order, "before"));
+            let order = Rc::clone(&order);
+            create_isomorphic_effect(move |_| {
+                on_cleanup(push(&order, "child"))
+            });
+        }
+    });
+
+    assert!(order.borrow().is_empty());
+
+    set_a.set(1);
+    assert_eq!(*order.borrow(), ["before", "child", "after"]);
+
+    runtime.dispose();
+}
@@ -41,3 +41,42 @@ fn context() {
 
     runtime.dispose();
 }
+
+#[cfg(debug_assertions)]
+#[test]
+fn expect_context_panic_includes_component_stack() {
+    use leptos_reactive::{
+        create_isomorphic_effect, create_runtime, create_rw_signal,
+        expect_context, ComponentStack, SignalGet, SignalSet,
+    };
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let runtime = create_runtime();
+    let trigger = create_rw_signal(0);
+
+    ComponentStack::with_component("App", || {
+        ComponentStack::with_component("Counter", || {
+            create_isomorphic_effect(move |_| {
+                trigger.get();
+                let stack = ComponentStack::current();
+                assert_eq!(
+                    stack.names().collect::<Vec<_>>(),
+                    ["Counter", "App"]
+                );
+            });
+        })
+    });
+    assert!(ComponentStack::current().is_empty());
+    // the stack is restored when the effect reruns
+    trigger.set(1);
+
+    let panic = catch_unwind(AssertUnwindSafe(|| {
+        ComponentStack::with_component("Counter", || expect_context::<u8>())
+    }))
+    .unwrap_err();
+    let message = panic.downcast_ref::<String>().unwrap();
+    assert!(message.ends_with("\ncomponent stack:\n    <Counter/>"));
+    assert!(ComponentStack::current().is_empty());
+
+    runtime.dispose();
+}
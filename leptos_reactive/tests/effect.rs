@@ -1,6 +1,6 @@
 use leptos_reactive::{
     batch, create_isomorphic_effect, create_memo, create_runtime,
-    create_rw_signal, create_signal, untrack, SignalGet, SignalSet,
+    create_rw_signal, create_signal, untrack, SignalGet, SignalSet, SignalWith,
 };
 
 #[test]
@@ -131,3 +131,255 @@ fn batching_actually_batches() {
 
     runtime.dispose();
 }
+
+#[test]
+fn batching_hides_intermediate_memo_values() {
+    use leptos_reactive::{SignalGetUntracked, SignalUpdate};
+
+    let runtime = create_runtime();
+
+    // like the index and length of an item in a keyed list
+    let index = create_rw_signal(1);
+    let len = create_rw_signal(2);
+    let is_last = create_memo(move |_| index.get() + 1 == len.get());
+    let seen = create_rw_signal(Vec::new());
+    create_isomorphic_effect(move |_| {
+        let is_last = is_last.get();
+        seen.update(|seen| seen.push(is_last));
+    });
+    assert_eq!(seen.get_untracked(), [true]);
+
+    // without a batch, the effect sees the new length with the old index
+    len.set(3);
+    index.set(2);
+    assert_eq!(seen.get_untracked(), [true, false, true]);
+
+    // with one, it only sees that the item is still the last one
+    batch(|| {
+        len.set(4);
+        index.set(3);
+    });
+    assert_eq!(seen.get_untracked(), [true, false, true]);
+
+    runtime.dispose();
+}
+
+#[cfg(debug_assertions)]
+#[test]
+fn effect_explain_diffs_dependencies() {
+    let runtime = create_runtime();
+
+    let use_b = create_rw_signal(false);
+    let a = create_rw_signal(0);
+    let b = create_rw_signal(0);
+    let effect = create_isomorphic_effect(move |_| {
+        if use_b.get() {
+            b.track();
+        } else {
+            a.track();
+        }
+    });
+    assert_eq!(effect.explanation(), None);
+
+    effect.explain();
+    a.set(1);
+    let first = effect.explanation().unwrap();
+    assert_eq!(first.runs, 1);
+    assert_eq!(first.read.len(), 2);
+    // nothing to compare the first run to
+    assert!(first.added.is_empty() && first.removed.is_empty());
+
+    use_b.set(true);
+    let second = effect.explanation().unwrap();
+    assert_eq!(second.runs, 2);
+    assert_eq!(second.added.len(), 1);
+    assert_eq!(second.removed.len(), 1);
+    assert_ne!(second.added, second.removed);
+
+    runtime.dispose();
+}
+
+#[test]
+fn effect_cleanup_runs_before_next_run_and_on_dispose() {
+    use leptos_reactive::{EffectCleanup, SignalDispose};
+    use std::{cell::RefCell, rc::Rc};
+
+    let runtime = create_runtime();
+    let a = create_rw_signal(0);
+    let log = Rc::new(RefCell::new(Vec::new()));
+
+    let effect = create_isomorphic_effect({
+        let log = Rc::clone(&log);
+        move |prev: Option<EffectCleanup>| {
+            assert!(prev.is_none());
+            let value = a.get();
+            log.borrow_mut().push(format!("run {value}"));
+            let log = Rc::clone(&log);
+            EffectCleanup::new(move || {
+                log.borrow_mut().push(format!("cleanup {value}"));
+            })
+        }
+    });
+    a.set(1);
+    effect.dispose();
+
+    assert_eq!(*log.borrow(), ["run 0", "cleanup 0", "run 1", "cleanup 1"]);
+
+    runtime.dispose();
+}
+
+#[cfg(debug_assertions)]
+#[test]
+fn strict_mode_runs_effects_twice() {
+    use leptos_reactive::{on_cleanup, set_strict_mode};
+    use std::{cell::RefCell, rc::Rc};
+
+    let runtime = create_runtime();
+    let a = create_rw_signal(0);
+    let log = Rc::new(RefCell::new(Vec::new()));
+
+    set_strict_mode(true);
+    create_isomorphic_effect({
+        let log = Rc::clone(&log);
+        move |prev: Option<i32>| {
+            let value = a.get();
+            log.borrow_mut().push(format!("run {value} after {prev:?}"));
+            let log = Rc::clone(&log);
+            on_cleanup(move || {
+                log.borrow_mut().push(format!("cleanup {value}"))
+            });
+            value
+        }
+    });
+    set_strict_mode(false);
+    a.set(1);
+
+    assert_eq!(
+        *log.borrow(),
+        [
+            "run 0 after None",
+            "cleanup 0",
+            "run 0 after None",
+            "cleanup 0",
+            "run 1 after Some(0)"
+        ]
+    );
+
+    runtime.dispose();
+}
+
+#[test]
+fn transitions_run_after_urgent_effects() {
+    use leptos_reactive::{
+        current_priority, start_transition, with_priority, Priority,
+    };
+    use std::{cell::RefCell, rc::Rc};
+
+    let runtime = create_runtime();
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let urgent = create_rw_signal(0);
+    let transition = create_rw_signal(0);
+    let idle = create_rw_signal(0);
+    for (name, signal) in [
+        ("urgent", urgent),
+        ("transition", transition),
+        ("idle", idle),
+    ] {
+        let log = Rc::clone(&log);
+        create_isomorphic_effect(move |prev: Option<()>| {
+            let value = signal.get();
+            if prev.is_some() {
+                log.borrow_mut().push((name, value, current_priority()));
+            }
+        });
+    }
+
+    start_transition(|| {
+        with_priority(Priority::Idle, || idle.set(1));
+        transition.set(1);
+        urgent.set(1);
+        with_priority(Priority::UserInput, || urgent.set(2));
+        // the deferred effect has already run, so it isn't run again
+        assert_eq!(*log.borrow(), [("urgent", 2, Priority::UserInput)]);
+    });
+    assert_eq!(
+        *log.borrow(),
+        [
+            ("urgent", 2, Priority::UserInput),
+            ("transition", 1, Priority::Transition),
+            ("idle", 1, Priority::Idle),
+        ]
+    );
+    assert_eq!(current_priority(), Priority::UserInput);
+
+    runtime.dispose();
+}
+
+#[test]
+fn urgent_updates_requeue_deferred_effects_once() {
+    use leptos_reactive::{start_transition, with_priority, Priority};
+    use std::{cell::RefCell, rc::Rc};
+
+    let runtime = create_runtime();
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let filter = create_rw_signal(0);
+    let other = create_rw_signal(0);
+    create_isomorphic_effect({
+        let log = Rc::clone(&log);
+        move |prev: Option<()>| {
+            let value = filter.get();
+            if prev.is_some() {
+                log.borrow_mut().push(value);
+            }
+        }
+    });
+    create_isomorphic_effect(move |_| other.get());
+
+    start_transition(|| {
+        filter.set(1);
+        filter.set(2);
+        // an unrelated urgent update doesn't run the deferred effect
+        with_priority(Priority::UserInput, || other.set(1));
+        assert!(log.borrow().is_empty());
+        // updating its source urgently runs it right away
+        with_priority(Priority::UserInput, || filter.set(3));
+        assert_eq!(*log.borrow(), [3]);
+    });
+    // and it doesn't run again when the transition lane is flushed
+    assert_eq!(*log.borrow(), [3]);
+
+    runtime.dispose();
+}
+
+#[test]
+fn component_render_counts_record_rebuilds() {
+    #[cfg(debug_assertions)]
+    {
+        use leptos_reactive::{component_render_counts, ComponentStack};
+
+        let runtime = create_runtime();
+
+        let (count, set_count) = create_signal(0);
+        let (unrelated, set_unrelated) = create_signal(0);
+        ComponentStack::with_component("Counter", || {
+            create_isomorphic_effect(move |_| count.get());
+        });
+        ComponentStack::with_component("Counter", || {});
+
+        set_count.set(1);
+        set_count.set(2);
+        set_unrelated.set(1);
+
+        let counts = component_render_counts();
+        assert_eq!(counts.len(), 1);
+        assert_eq!(counts[0].name, "Counter");
+        assert_eq!(counts[0].builds, 2);
+        assert_eq!(counts[0].rebuilds, 2);
+        let cause = counts[0].last_rebuild_cause.unwrap();
+        assert!(cause.file().ends_with("effect.rs"));
+        assert!(cause.line() < line!());
+        _ = unrelated;
+
+        runtime.dispose();
+    }
+}
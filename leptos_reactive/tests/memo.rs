@@ -293,3 +293,32 @@ fn owning_memo_slice() {
 
     runtime.dispose();
 }
+
+#[test]
+fn memo_with_compare_strategies() {
+    let runtime = create_runtime();
+
+    let (count, set_count) = create_signal(0);
+    let always =
+        create_memo_with_compare(move |_| count.get() / 10, Changed::Always);
+    let never = create_memo_with_compare(move |_| count.get(), Changed::Never);
+
+    let always_runs = create_rw_signal(0);
+    let never_runs = create_rw_signal(0);
+    create_isomorphic_effect(move |_| {
+        always.track();
+        always_runs.update(|n| *n += 1);
+    });
+    create_isomorphic_effect(move |_| {
+        never.track();
+        never_runs.update(|n| *n += 1);
+    });
+
+    // the value of `always` stays 0, but it notifies anyway
+    set_count.set(1);
+    assert_eq!(always_runs.get_untracked(), 2);
+    assert_eq!(never_runs.get_untracked(), 1);
+    assert_eq!(never.get_untracked(), 1);
+
+    runtime.dispose();
+}
@@ -64,3 +64,219 @@ fn resource_returns_last_future() {
         runtime.dispose();
     }
 }
+
+#[test]
+fn resource_dependency_graph_finds_waterfalls() {
+    #[cfg(all(feature = "ssr", debug_assertions))]
+    {
+        use leptos_reactive::{
+            create_resource, create_runtime, resource_dependency_graph,
+            SignalGet,
+        };
+        use tokio::task;
+        use tokio_test::block_on;
+
+        let runtime = create_runtime();
+
+        block_on(task::LocalSet::new().run_until(async move {
+            let user = create_resource(|| (), |_| async { 1 });
+            // loads in parallel with `user`
+            let settings = create_resource(|| (), |_| async { 2 });
+            // can only start loading once `user` has loaded
+            let posts = create_resource(move || user.get(), |_| async { 3 });
+
+            let graph = resource_dependency_graph();
+            assert_eq!(graph.len(), 1);
+            let dependency = graph[0];
+            assert!(dependency.defined_at.file().ends_with("resource.rs"));
+            assert_eq!(dependency.read_at.line(), dependency.defined_at.line());
+            assert!(
+                dependency.depends_on_defined_at.line()
+                    < dependency.defined_at.line()
+            );
+            _ = (settings, posts);
+        }));
+
+        runtime.dispose();
+    }
+}
+
+#[test]
+fn resources_start_in_priority_order() {
+    #[cfg(feature = "ssr")]
+    {
+        use futures::{channel::oneshot::channel, FutureExt};
+        use leptos_reactive::{
+            create_resource, create_resource_with_priority, create_runtime,
+            set_resource_prefetch_limit, ResourcePriority, SignalGet,
+        };
+        use std::{cell::RefCell, rc::Rc};
+        use tokio::task;
+        use tokio_test::block_on;
+
+        let runtime = create_runtime();
+
+        block_on(task::LocalSet::new().run_until(async move {
+            task::spawn_local(async move {
+                let started = Rc::new(RefCell::new(Vec::new()));
+                let (tx_high, rx_high) = channel::<()>();
+                let (tx_low, rx_low) = channel::<()>();
+                let (tx_prefetch, rx_prefetch) = channel::<()>();
+                let rx_high = rx_high.shared();
+                let rx_low = rx_low.shared();
+                let rx_prefetch = rx_prefetch.shared();
+                let fetcher =
+                    |name: &'static str, rx: futures::future::Shared<_>| {
+                        let started = Rc::clone(&started);
+                        move |_| {
+                            let started = Rc::clone(&started);
+                            let rx = rx.clone();
+                            async move {
+                                started.borrow_mut().push(name);
+                                _ = rx.await;
+                                name.to_string()
+                            }
+                        }
+                    };
+
+                set_resource_prefetch_limit(2);
+                let prefetches = ["a", "b", "c"].map(|name| {
+                    create_resource_with_priority(
+                        || (),
+                        fetcher(name, rx_prefetch.clone()),
+                        ResourcePriority::Prefetch,
+                    )
+                });
+                let low = create_resource_with_priority(
+                    || (),
+                    fetcher("low", rx_low),
+                    ResourcePriority::Low,
+                );
+                let high = create_resource(|| (), fetcher("high", rx_high));
+                task::yield_now().await;
+                assert_eq!(*started.borrow(), ["high"]);
+
+                tx_high.send(()).unwrap();
+                task::yield_now().await;
+                assert_eq!(high.get().as_deref(), Some("high"));
+                assert_eq!(*started.borrow(), ["high", "low"]);
+
+                tx_low.send(()).unwrap();
+                task::yield_now().await;
+                assert_eq!(low.get().as_deref(), Some("low"));
+                // only two prefetches load at a time
+                assert_eq!(*started.borrow(), ["high", "low", "a", "b"]);
+
+                tx_prefetch.send(()).unwrap();
+                for _ in 0..3 {
+                    task::yield_now().await;
+                }
+                assert_eq!(*started.borrow(), ["high", "low", "a", "b", "c"]);
+                assert!(prefetches
+                    .iter()
+                    .all(|prefetch| prefetch.get().is_some()));
+            })
+            .await
+            .unwrap();
+        }));
+
+        runtime.dispose();
+    }
+}
+
+#[test]
+fn skipped_resource_is_idle() {
+    #[cfg(feature = "ssr")]
+    {
+        use leptos_reactive::{
+            create_runtime, create_signal, create_skippable_resource,
+            SignalGet, SignalSet,
+        };
+        use std::{cell::Cell, rc::Rc};
+        use tokio::task;
+        use tokio_test::block_on;
+
+        let runtime = create_runtime();
+
+        block_on(task::LocalSet::new().run_until(async move {
+            task::spawn_local(async move {
+                let fetches = Rc::new(Cell::new(0));
+                let (user, set_user) = create_signal(None::<u32>);
+                let (page, set_page) = create_signal(1);
+
+                let posts = create_skippable_resource(
+                    move || user.get().map(|user| (user, page.get())).into(),
+                    {
+                        let fetches = Rc::clone(&fetches);
+                        move |(user, page)| {
+                            fetches.set(fetches.get() + 1);
+                            async move { user * 10 + page }
+                        }
+                    },
+                );
+                task::yield_now().await;
+
+                // the fetcher isn't called while the source is skipped
+                assert_eq!(fetches.get(), 0);
+                assert!(posts.idle().get());
+                assert!(!posts.loading().get());
+                assert_eq!(posts.get(), None);
+
+                set_page.set(2);
+                task::yield_now().await;
+                assert_eq!(fetches.get(), 0);
+
+                set_user.set(Some(1));
+                assert!(!posts.idle().get());
+                task::yield_now().await;
+                assert_eq!(fetches.get(), 1);
+                assert_eq!(posts.get(), Some(12));
+
+                // going back to skipped drops the stale value
+                set_user.set(None);
+                task::yield_now().await;
+                assert_eq!(fetches.get(), 1);
+                assert!(posts.idle().get());
+                assert_eq!(posts.get(), None);
+            })
+            .await
+            .unwrap();
+        }));
+
+        runtime.dispose();
+    }
+}
+
+#[test]
+fn resources_refetch_on_retry() {
+    #[cfg(not(feature = "ssr"))]
+    {
+        use leptos_reactive::{
+            create_resource, create_runtime, provide_context, ResourceRetry,
+        };
+        use std::{cell::Cell, rc::Rc};
+
+        let runtime = create_runtime();
+
+        let fetches = Rc::new(Cell::new(0));
+        let fetcher = {
+            let fetches = Rc::clone(&fetches);
+            move |_| {
+                fetches.set(fetches.get() + 1);
+                async { Err::<u32, _>("offline".to_string()) }
+            }
+        };
+
+        // not refetched without a retry handle
+        _ = create_resource(|| (), fetcher.clone());
+        let retry = ResourceRetry::new();
+        provide_context(retry);
+        _ = create_resource(|| (), fetcher);
+        assert_eq!(fetches.get(), 2);
+
+        retry.retry();
+        assert_eq!(fetches.get(), 3);
+
+        runtime.dispose();
+    }
+}
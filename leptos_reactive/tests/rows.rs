@@ -0,0 +1,78 @@
+use std::{cell::Cell, rc::Rc};
+
+#[test]
+fn rows() {
+    use leptos_reactive::*;
+    let runtime = create_runtime();
+
+    struct Todo {
+        id: u32,
+        done: bool,
+    }
+
+    let todos = create_rw_signal(vec![
+        Todo { id: 1, done: false },
+        Todo { id: 2, done: false },
+        Todo { id: 3, done: false },
+    ]);
+    let rows = todos.rows();
+
+    // stands in for the `each` of a `<For/>`
+    let list_runs = Rc::new(Cell::new(0));
+    let handles = create_memo({
+        let list_runs = Rc::clone(&list_runs);
+        move |_| {
+            list_runs.set(list_runs.get() + 1);
+            rows.iter_keyed(|todo| todo.id)
+        }
+    });
+
+    // stands in for the children of a `<For/>`
+    let row_runs = handles
+        .get_untracked()
+        .into_iter()
+        .map(|row| {
+            let runs = Rc::new(Cell::new(0));
+            create_isomorphic_effect({
+                let runs = Rc::clone(&runs);
+                move |_| {
+                    row.with(|todo| todo.done);
+                    runs.set(runs.get() + 1);
+                }
+            });
+            runs
+        })
+        .collect::<Vec<_>>();
+    create_isomorphic_effect(move |_| handles.track());
+
+    let runs = || row_runs.iter().map(|runs| runs.get()).collect::<Vec<_>>();
+    assert_eq!(list_runs.get(), 1);
+    assert_eq!(runs(), [1, 1, 1]);
+
+    // updating a row only re-runs the code that reads that row
+    let second = handles.get_untracked()[1];
+    second.update(|todo| todo.done = true);
+    assert!(todos.with(|todos| todos[1].done));
+    assert_eq!(list_runs.get(), 2);
+    assert_eq!(runs(), [1, 2, 1]);
+    assert_eq!(handles.get_untracked()[1], second);
+
+    // updating the whole signal notifies every row
+    todos.update(|todos| todos.swap(0, 1));
+    assert_eq!(list_runs.get(), 3);
+    assert_eq!(runs(), [2, 3, 2]);
+    assert_eq!(handles.get_untracked()[0], second);
+    assert_eq!(second.index(), Some(0));
+
+    // removed rows can no longer be read or updated
+    let third = handles.get_untracked()[2];
+    todos.update(|todos| {
+        todos.pop();
+    });
+    assert_eq!(handles.get_untracked().len(), 2);
+    assert_eq!(third.index(), None);
+    assert_eq!(third.try_with(|todo| todo.id), None);
+    assert_eq!(third.try_update(|todo| todo.done = true), None);
+
+    runtime.dispose();
+}
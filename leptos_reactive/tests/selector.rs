@@ -0,0 +1,76 @@
+use leptos_reactive::*;
+
+#[test]
+fn keyed_selector_notifies_only_changed_keys() {
+    let runtime = create_runtime();
+
+    let items = create_rw_signal(vec!["a".to_string(), "b".into(), "c".into()]);
+    let lengths = create_keyed_selector(
+        move || items.get(),
+        |items, index: &usize| items.get(*index).map(String::len),
+        |a: &Option<usize>, b: &Option<usize>| a == b,
+    );
+
+    let runs = [0, 1, 2].map(|index| {
+        let runs = create_rw_signal(0);
+        create_isomorphic_effect({
+            let lengths = lengths.clone();
+            move |_| {
+                lengths.get(index);
+                runs.update(|n| *n += 1);
+            }
+        });
+        runs
+    });
+    let counts = move || runs.map(|runs| runs.get_untracked());
+    assert_eq!(counts(), [1, 1, 1]);
+
+    items.update(|items| items[1] = "bb".into());
+    assert_eq!(counts(), [1, 2, 1]);
+    assert_eq!(lengths.get(1), Some(2));
+
+    // same length, so nothing changes under the comparator
+    items.update(|items| items[0] = "z".into());
+    assert_eq!(counts(), [1, 2, 1]);
+
+    items.update(|items| {
+        items.pop();
+    });
+    assert_eq!(counts(), [1, 2, 2]);
+    assert_eq!(lengths.get(2), None);
+
+    // a removed key is projected again when it is next read
+    lengths.remove(&0);
+    items.update(|items| items[0] = "zzz".into());
+    assert_eq!(counts(), [1, 2, 2]);
+    assert_eq!(lengths.with(0, |len| *len), Some(3));
+
+    runtime.dispose();
+}
+
+#[test]
+fn keyed_selector_reads_and_writes_inside_with() {
+    let runtime = create_runtime();
+
+    let items = create_rw_signal(vec![1, 2, 3]);
+    let doubled = create_keyed_selector(
+        move || items.get(),
+        |items, index: &usize| items.get(*index).map(|n| n * 2),
+        |a: &Option<i32>, b: &Option<i32>| a == b,
+    );
+
+    // reading a key that hasn't been read yet
+    let sum = doubled.with(0, |first| {
+        first.unwrap() + doubled.with(1, |second| second.unwrap())
+    });
+    assert_eq!(sum, 6);
+
+    // writing the source, which reruns the selector
+    doubled.with(2, |third| {
+        assert_eq!(*third, Some(6));
+        items.update(|items| items[2] = 10);
+    });
+    assert_eq!(doubled.get(2), Some(20));
+
+    runtime.dispose();
+}
@@ -27,3 +27,105 @@ fn derived_signals() {
 
     runtime.dispose();
 }
+
+#[test]
+fn rw_signal_with_compare() {
+    let runtime = create_runtime();
+
+    let name = RwSignal::new_with_compare(
+        String::from("Alice"),
+        |a: &String, b: &String| a.eq_ignore_ascii_case(b),
+    );
+    let (read_name, write_name) = name.split();
+    let runs = create_rw_signal(0);
+    create_isomorphic_effect(move |_| {
+        read_name.track();
+        runs.update(|n| *n += 1);
+    });
+
+    // the comparison applies to the write half as well, and an equal value
+    // is still stored
+    write_name.set("ALICE".into());
+    assert_eq!(runs.get_untracked(), 1);
+    assert_eq!(name.get_untracked(), "ALICE");
+
+    name.set("Bob".into());
+    assert_eq!(runs.get_untracked(), 2);
+
+    // `update` always notifies
+    name.update(|name| name.make_ascii_lowercase());
+    assert_eq!(runs.get_untracked(), 3);
+
+    runtime.dispose();
+}
+
+#[test]
+fn rw_signal_with_compare_stores_every_value() {
+    let runtime = create_runtime();
+
+    let frozen = create_rw_signal_with_compare(0, Changed::Never);
+    let runs = create_rw_signal(0);
+    create_isomorphic_effect(move |_| {
+        frozen.track();
+        runs.update(|n| *n += 1);
+    });
+    frozen.set(1);
+    frozen.set(2);
+    assert_eq!(frozen.get_untracked(), 2);
+    assert_eq!(runs.get_untracked(), 1);
+
+    // the comparison can read the signal it compares
+    let signal = store_value(None::<RwSignal<i32>>);
+    let even = create_rw_signal_with_compare(0, move |_: &i32, new: &i32| {
+        let current = signal.get_value().map(|signal| signal.get_untracked());
+        current.is_some_and(|current| current % 2 == new % 2)
+    });
+    signal.set_value(Some(even));
+    even.set(2);
+    assert_eq!(even.get_untracked(), 2);
+
+    runtime.dispose();
+}
+
+#[test]
+fn signal_to_stream_with_options() {
+    use futures::{executor::block_on, StreamExt};
+
+    let runtime = create_runtime();
+
+    let (a, set_a) = create_signal(0);
+    let mut buffered = a.to_stream_with_options(StreamOptions {
+        initial: false,
+        ..Default::default()
+    });
+    let mut latest = a.to_stream_with_options(StreamOptions {
+        backpressure: Backpressure::LatestOnly,
+        ..Default::default()
+    });
+    set_a.set(1);
+    set_a.set(2);
+    assert_eq!(block_on(buffered.next()), Some(1));
+    assert_eq!(block_on(buffered.next()), Some(2));
+    assert_eq!(block_on(latest.next()), Some(2));
+
+    runtime.dispose();
+}
+
+#[test]
+fn signal_from_stream_with_options() {
+    let runtime = create_runtime();
+
+    for backpressure in [Backpressure::Buffered, Backpressure::LatestOnly] {
+        let values = create_signal_from_stream_with_options(
+            futures::stream::iter([1, 2, 3]),
+            0,
+            backpressure,
+        );
+        // outside the browser, the stream is driven to its end right away,
+        // while server rendering doesn't drive it at all
+        let expected = if cfg!(feature = "ssr") { 0 } else { 3 };
+        assert_eq!(values.get_untracked(), expected);
+    }
+
+    runtime.dispose();
+}
@@ -0,0 +1,251 @@
+#[test]
+fn blocking_suspense_is_streamed_after_max_wait() {
+    #[cfg(feature = "ssr")]
+    {
+        use futures::{channel::oneshot::channel, FutureExt, StreamExt};
+        use leptos::*;
+        use std::time::Duration;
+        use tokio::task;
+        use tokio_test::block_on;
+
+        block_on(task::LocalSet::new().run_until(async move {
+            let (tx, rx) = channel::<()>();
+            let rx = rx.shared();
+            let (stream, runtime) =
+                leptos::ssr::render_to_stream_with_prefix_undisposed(
+                    move || {
+                        let data = create_resource(
+                            || (),
+                            move |_| {
+                                let rx = rx.clone();
+                                async move {
+                                    _ = rx.await;
+                                    "loaded".to_string()
+                                }
+                            },
+                        );
+                        view! {
+                            <Suspense
+                                fallback=|| "loading"
+                                streaming=SuspenseStreaming::Block {
+                                    max_wait: Some(Duration::from_millis(10)),
+                                }
+                            >
+                                {move || data.get()}
+                            </Suspense>
+                        }
+                        .into_view()
+                    },
+                    || "".into(),
+                );
+            let mut stream = Box::pin(stream);
+
+            // the shell doesn't wait any longer than `max_wait`
+            let shell = stream.next().await.unwrap();
+            assert!(shell.contains("loading"));
+            assert!(!shell.contains("loaded"));
+
+            tx.send(()).unwrap();
+            let rest = stream.collect::<String>().await;
+            assert!(rest.contains("loaded"));
+
+            runtime.dispose();
+        }));
+    }
+}
+
+#[test]
+fn suspense_budget_limits_loads_and_times_out() {
+    #[cfg(feature = "ssr")]
+    {
+        use futures::StreamExt;
+        use leptos::*;
+        use std::{cell::Cell, rc::Rc, time::Duration};
+        use tokio::task;
+        use tokio_test::block_on;
+
+        block_on(task::LocalSet::new().run_until(async move {
+            let loading = Rc::new(Cell::new(0));
+            let most_loading = Rc::new(Cell::new(0));
+            let (stream, runtime) =
+                leptos::ssr::render_to_stream_with_prefix_undisposed(
+                    {
+                        let loading = Rc::clone(&loading);
+                        let most_loading = Rc::clone(&most_loading);
+                        move || {
+                            let widgets = ["a", "b", "c"].map(|name| {
+                                let loading = Rc::clone(&loading);
+                                let most_loading = Rc::clone(&most_loading);
+                                create_resource(
+                                    || (),
+                                    move |_| {
+                                        let loading = Rc::clone(&loading);
+                                        let most_loading =
+                                            Rc::clone(&most_loading);
+                                        async move {
+                                            loading.set(loading.get() + 1);
+                                            most_loading.set(
+                                                most_loading
+                                                    .get()
+                                                    .max(loading.get()),
+                                            );
+                                            task::yield_now().await;
+                                            loading.set(loading.get() - 1);
+                                            format!("widget {name}")
+                                        }
+                                    },
+                                )
+                            });
+                            let slow = create_resource(
+                                || (),
+                                |_| async {
+                                    tokio::time::sleep(Duration::from_millis(
+                                        50,
+                                    ))
+                                    .await;
+                                    "slow widget".to_string()
+                                },
+                            );
+                            view! {
+                                <Suspense fallback=|| "loading" max_concurrent=1>
+                                    {move || widgets.map(|widget| widget.get()).to_vec()}
+                                </Suspense>
+                                <Suspense
+                                    fallback=|| {
+                                        use_suspense_timeout()
+                                            .map(|timeout| timeout.to_string())
+                                            .unwrap_or_else(|| "loading".to_string())
+                                    }
+                                    deadline=Duration::from_millis(10)
+                                >
+                                    {move || slow.get()}
+                                </Suspense>
+                            }
+                            .into_view()
+                        }
+                    },
+                    || "".into(),
+                );
+            let html = Box::pin(stream).collect::<String>().await;
+
+            assert!(html.contains("widget a"));
+            assert!(html.contains("widget c"));
+            assert_eq!(most_loading.get(), 1);
+            // the slow widget's fragment is its fallback, with the timeout
+            assert!(html.contains("resources did not load within 10ms"));
+            assert!(!html.contains(">slow widget<"));
+
+            runtime.dispose();
+        }));
+    }
+}
+
+#[test]
+fn global_suspense_is_pending_for_each_navigation() {
+    #[cfg(feature = "ssr")]
+    {
+        use futures::{channel::oneshot::channel, FutureExt};
+        use leptos::*;
+        use std::{cell::RefCell, rc::Rc};
+        use tokio::task;
+        use tokio_test::block_on;
+
+        block_on(task::LocalSet::new().run_until(async move {
+            let runtime = create_runtime();
+            let global = GlobalSuspenseContext::new();
+            provide_context(global.clone());
+            let log = Rc::new(RefCell::new(Vec::new()));
+            create_isomorphic_effect({
+                let global = global.clone();
+                let log = Rc::clone(&log);
+                move |_| log.borrow_mut().push(global.is_pending())
+            });
+
+            // navigates to a route that reads a resource, as the router does
+            let navigate = |global: &GlobalSuspenseContext| {
+                global.reset();
+                let (tx, rx) = channel::<()>();
+                let rx = rx.shared();
+                let data = create_resource(
+                    || (),
+                    move |_| {
+                        let rx = rx.clone();
+                        async move {
+                            _ = rx.await;
+                        }
+                    },
+                );
+                create_isomorphic_effect(move |_| data.get());
+                tx
+            };
+
+            for _ in 0..2 {
+                let tx = navigate(&global);
+                assert_eq!(log.borrow().last(), Some(&true));
+                _ = tx.send(());
+                while global.is_pending() {
+                    task::yield_now().await;
+                }
+                assert_eq!(log.borrow().last(), Some(&false));
+            }
+
+            runtime.dispose();
+        }));
+    }
+}
+
+#[test]
+fn streamed_pages_reuse_chunk_buffers() {
+    #[cfg(feature = "ssr")]
+    {
+        use futures::StreamExt;
+        use leptos::{ssr::*, *};
+        use tokio::task;
+        use tokio_test::block_on;
+
+        fn page() -> View {
+            let data = create_resource(
+                || (),
+                |_| async {
+                    task::yield_now().await;
+                    "loaded <b>".repeat(100)
+                },
+            );
+            view! {
+                <p>"before"</p>
+                <Suspense fallback=|| "loading">{move || data.get()}</Suspense>
+                <p>"after"</p>
+            }
+            .into_view()
+        }
+
+        block_on(task::LocalSet::new().run_until(async move {
+            for in_order in [false, true] {
+                reset_buffer_pool_stats();
+                for _ in 0..2 {
+                    let (stream, runtime) = if in_order {
+                        let (stream, runtime) =
+                            render_to_stream_in_order_with_prefix_undisposed_with_context(
+                                page,
+                                || "".into(),
+                                || {},
+                            );
+                        (stream.boxed_local(), runtime)
+                    } else {
+                        let (stream, runtime) =
+                            render_to_stream_with_prefix_undisposed(
+                                page,
+                                || "".into(),
+                            );
+                        (stream.boxed_local(), runtime)
+                    };
+                    let html = stream.collect::<String>().await;
+                    assert!(html.contains("loaded"));
+                    runtime.dispose();
+                }
+                let stats = buffer_pool_stats();
+                assert!(stats.reused > 0, "{in_order}: {stats:?}");
+            }
+        }));
+    }
+}
@@ -117,6 +117,8 @@ pub use server_fn::{error::ServerFnErrorErr, ServerFnError};
 
 mod action;
 mod multi_action;
+mod request_id;
 pub use action::*;
 pub use multi_action::*;
+pub use request_id::*;
 extern crate tracing;
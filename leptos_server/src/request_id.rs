@@ -0,0 +1,111 @@
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+use leptos_reactive::{provide_context, use_context};
+use std::fmt;
+
+/// Identifies a server-rendered page request or a client-side navigation, so
+/// that traces of the page render can be connected to the server functions it
+/// called.
+///
+/// The server integrations give each request a `RequestId`, which is included
+/// in the page so the client can keep using it until the first navigation.
+/// The router gives each client-side navigation a new `RequestId`. The current
+/// ID is sent with every server function request in the
+/// [`REQUEST_ID_HEADER`](server_fn::client::REQUEST_ID_HEADER) header, and
+/// recorded as the `request_id` field of a tracing span on both sides.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RequestId(String);
+
+impl RequestId {
+    /// The longest ID that is accepted from a request header.
+    const MAX_LEN: usize = 128;
+
+    /// Creates a new, random ID.
+    pub fn new() -> Self {
+        Self(uuid::Uuid::new_v4().simple().to_string())
+    }
+
+    /// Uses the ID sent in the
+    /// [`REQUEST_ID_HEADER`](server_fn::client::REQUEST_ID_HEADER) header,
+    /// either by the client or by a proxy in front of the server, or creates a
+    /// new ID if there is none.
+    ///
+    /// IDs that are longer than 128 characters, or contain characters other
+    /// than ASCII letters, digits, `-`, `_`, and `.`, are replaced with a new
+    /// ID.
+    ///
+    /// ```
+    /// # use leptos_server::RequestId;
+    /// let id = RequestId::from_header(Some("3f2a-b1c9"));
+    /// assert_eq!(id.as_str(), "3f2a-b1c9");
+    ///
+    /// let id = RequestId::from_header(Some("<script>"));
+    /// assert_ne!(id.as_str(), "<script>");
+    /// ```
+    pub fn from_header(value: Option<&str>) -> Self {
+        match value {
+            Some(id)
+                if !id.is_empty()
+                    && id.len() <= Self::MAX_LEN
+                    && id.chars().all(|c| {
+                        c.is_ascii_alphanumeric()
+                            || matches!(c, '-' | '_' | '.')
+                    }) =>
+            {
+                Self(id.to_string())
+            }
+            _ => Self::new(),
+        }
+    }
+
+    /// Returns the ID as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Creates a tracing span for the request, with the ID as its
+    /// `request_id` field.
+    pub fn span(&self) -> tracing::Span {
+        tracing::info_span!("leptos_request", request_id = %self.0)
+    }
+}
+
+impl Default for RequestId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for RequestId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Sets the [`RequestId`] of the current request or navigation.
+///
+/// On the server, this provides the ID via context; the server integrations
+/// call it for each request. In the browser, this sets the ID that is sent
+/// with every server function request; the router calls it on each
+/// navigation.
+pub fn provide_request_id(id: RequestId) {
+    #[cfg(any(feature = "csr", feature = "hydrate"))]
+    server_fn::client::set_request_id(Some(id.0));
+    #[cfg(not(any(feature = "csr", feature = "hydrate")))]
+    provide_context(id);
+}
+
+/// Returns the [`RequestId`] of the current request on the server, or of the
+/// current navigation in the browser.
+///
+/// This is not reactive: in the browser, it returns the ID of the navigation
+/// that is current when it is called.
+pub fn use_request_id() -> Option<RequestId> {
+    #[cfg(any(feature = "csr", feature = "hydrate"))]
+    {
+        server_fn::client::get_request_id().map(RequestId)
+    }
+    #[cfg(not(any(feature = "csr", feature = "hydrate")))]
+    {
+        use_context::<RequestId>()
+    }
+}
@@ -0,0 +1,147 @@
+use crate::{use_head, MetaContext};
+use leptos::{
+    component_view, create_runtime, leptos_dom::HydrationCtx, provide_context,
+    ssr::render_to_static_string_undisposed_with_context, ComponentConstructor,
+};
+
+/// The result of rendering a single component with
+/// [`render_component_to_string`] or [`render_component_to_string_async`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RenderedComponent {
+    /// The HTML of the component.
+    pub html: String,
+    /// The `<head>` metadata set by the component, like its
+    /// [`Title`](crate::Title), [`Meta`](crate::Meta) tags, or
+    /// [`Stylesheet`](crate::Stylesheet)s, as HTML to include in the page’s
+    /// `<head>`.
+    pub head: String,
+}
+
+/// Renders a single component with the given props to HTML, for embedding it
+/// in a page generated by something else, like a template engine.
+///
+/// The component is rendered in its own reactive runtime, which is disposed
+/// afterwards. The HTML contains no hydration keys, so it is not meant to be
+/// hydrated. `<Suspense/>` renders its fallback; use
+/// [`render_component_to_string_async`] to wait for resources to load.
+///
+/// ```
+/// # #[cfg(feature = "ssr")] {
+/// use leptos::*;
+/// use leptos_meta::*;
+///
+/// #[component]
+/// fn Greeting(name: String) -> impl IntoView {
+///     view! {
+///         <Title text="Greetings"/>
+///         <p>"Hello, " {name} "!"</p>
+///     }
+/// }
+///
+/// let rendered = render_component_to_string(
+///     Greeting,
+///     GreetingProps::builder().name("World".to_string()).build(),
+/// );
+/// assert_eq!(rendered.html, "<p>Hello, World!</p>");
+/// assert_eq!(rendered.head, "<title>Greetings</title>");
+/// # }
+/// ```
+pub fn render_component_to_string<P>(
+    component: impl ComponentConstructor<P>,
+    props: P,
+) -> RenderedComponent {
+    render_component_to_string_with_context(component, props, || {})
+}
+
+/// Renders a single component with the given props to HTML, like
+/// [`render_component_to_string`], providing context with
+/// `additional_context` before the component is created.
+pub fn render_component_to_string_with_context<P>(
+    component: impl ComponentConstructor<P>,
+    props: P,
+    additional_context: impl FnOnce(),
+) -> RenderedComponent {
+    HydrationCtx::reset_id();
+    let runtime = create_runtime();
+    provide_context(MetaContext::new());
+    additional_context();
+
+    let html = HydrationCtx::without_hydration_keys(|| {
+        component_view(component, props)
+            .render_to_string()
+            .into_owned()
+    });
+    let head = HydrationCtx::without_hydration_keys(|| use_head().dehydrate());
+    runtime.dispose();
+
+    RenderedComponent { html, head }
+}
+
+/// Renders a single component with the given props to HTML, like
+/// [`render_component_to_string`], but waits for all `async`
+/// [`Resource`](leptos::Resource)s read under `<Suspense/>` to load first.
+pub async fn render_component_to_string_async<P>(
+    component: impl ComponentConstructor<P> + 'static,
+    props: P,
+) -> RenderedComponent
+where
+    P: 'static,
+{
+    render_component_to_string_async_with_context(component, props, || {}).await
+}
+
+/// Renders a single component with the given props to HTML, like
+/// [`render_component_to_string_async`], providing context with
+/// `additional_context` before the component is created.
+///
+/// ```
+/// # #[cfg(feature = "ssr")] {
+/// use leptos::*;
+/// use leptos_meta::*;
+///
+/// #[derive(Clone)]
+/// struct User(String);
+///
+/// #[component]
+/// fn Profile() -> impl IntoView {
+///     let user = expect_context::<User>();
+///     let posts = create_resource(|| (), |_| async { 3 });
+///     view! {
+///         <Suspense fallback=|| "Loading...">
+///             <p>{user.0.clone()} " has " {move || posts.get()} " posts"</p>
+///         </Suspense>
+///     }
+/// }
+///
+/// # tokio_test::block_on(tokio::task::LocalSet::new().run_until(async {
+/// let rendered = render_component_to_string_async_with_context(
+///     Profile,
+///     (),
+///     || provide_context(User("Ada".to_string())),
+/// )
+/// .await;
+/// assert_eq!(rendered.html, "<p>Ada has 3 posts</p>");
+/// # }));
+/// # }
+/// ```
+pub async fn render_component_to_string_async_with_context<P>(
+    component: impl ComponentConstructor<P> + 'static,
+    props: P,
+    additional_context: impl FnOnce() + 'static,
+) -> RenderedComponent
+where
+    P: 'static,
+{
+    let (html, runtime) = render_to_static_string_undisposed_with_context(
+        move || component_view(component, props),
+        move || {
+            provide_context(MetaContext::new());
+            additional_context();
+        },
+    )
+    .await;
+    let head = HydrationCtx::without_hydration_keys(|| use_head().dehydrate());
+    runtime.dispose();
+
+    RenderedComponent { html, head }
+}
@@ -0,0 +1,415 @@
+use crate::{use_head, MetaContext};
+use leptos::{
+    provide_context, ssr::render_to_static_string_undisposed_with_context, View,
+};
+use std::rc::Rc;
+
+/// Options for [`render_to_document_string_with_options`].
+#[derive(Clone, Default)]
+pub struct DocumentOptions {
+    /// The URL against which relative `href` and `src` attributes are resolved,
+    /// e.g., `https://example.com/invoices/`. If `None`, URLs are left as they are.
+    pub base_url: Option<String>,
+    /// Loads the contents of a stylesheet, given the `href` of a
+    /// `<link rel="stylesheet">` (such as one created by [`Stylesheet`](crate::Stylesheet)).
+    /// If it returns `Some(_)`, the link is replaced by an inline `<style>`.
+    #[allow(clippy::type_complexity)]
+    pub load_stylesheet: Option<Rc<dyn Fn(&str) -> Option<String>>>,
+}
+
+impl core::fmt::Debug for DocumentOptions {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DocumentOptions")
+            .field("base_url", &self.base_url)
+            .finish_non_exhaustive()
+    }
+}
+
+impl DocumentOptions {
+    /// Sets the URL against which relative URLs are resolved.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Sets the function used to load stylesheets so they can be inlined.
+    pub fn load_stylesheet(
+        mut self,
+        load: impl Fn(&str) -> Option<String> + 'static,
+    ) -> Self {
+        self.load_stylesheet = Some(Rc::new(load));
+        self
+    }
+}
+
+/// Renders a view to a complete, self-contained HTML document that is not meant
+/// to be hydrated.
+///
+/// See [`render_to_document_string_with_options`].
+pub async fn render_to_document_string(
+    view: impl FnOnce() -> View + 'static,
+) -> String {
+    render_to_document_string_with_options(view, DocumentOptions::default())
+        .await
+}
+
+/// Renders a view to a complete, self-contained HTML document that is not meant
+/// to be hydrated, for use as the input to an HTML-to-PDF tool or similar.
+///
+/// The document:
+/// 1. waits for all resources read under `<Suspense/>` to resolve
+/// 2. includes the `<head>` metadata collected from [`Title`](crate::Title),
+///    [`Meta`](crate::Meta), [`Stylesheet`](crate::Stylesheet), etc.
+/// 3. inlines stylesheets, if [`DocumentOptions::load_stylesheet`] is set
+/// 4. resolves relative URLs, if [`DocumentOptions::base_url`] is set
+/// 5. contains no hydration keys, markers, or `<script>`s
+///
+/// ```
+/// # #[cfg(feature = "ssr")] {
+/// use leptos::*;
+/// use leptos_meta::*;
+///
+/// # tokio_test::block_on(tokio::task::LocalSet::new().run_until(async {
+/// let html = render_to_document_string_with_options(
+///     || {
+///         view! {
+///             <Title text="Invoice #1"/>
+///             <Stylesheet href="/invoice.css"/>
+///             <img src="/logo.png"/>
+///         }
+///         .into_view()
+///     },
+///     DocumentOptions::default()
+///         .base_url("https://example.com/")
+///         .load_stylesheet(|_| Some("body { color: black; }".into())),
+/// )
+/// .await;
+///
+/// assert!(html.contains("<title>Invoice #1</title>"));
+/// assert!(html.contains("<style>body { color: black; }</style>"));
+/// assert!(html.contains(r#"<img src="https://example.com/logo.png"/>"#));
+/// # }));
+/// # }
+/// ```
+pub async fn render_to_document_string_with_options(
+    view: impl FnOnce() -> View + 'static,
+    options: DocumentOptions,
+) -> String {
+    let (body, runtime) =
+        render_to_static_string_undisposed_with_context(view, || {
+            provide_context(MetaContext::new())
+        })
+        .await;
+
+    let meta = use_head();
+    let html_attrs = meta.html.as_string().unwrap_or_default();
+    let body_attrs = meta.body.as_string().unwrap_or_default();
+    let head = meta.dehydrate();
+    runtime.dispose();
+
+    let document = format!(
+        "<!DOCTYPE html><html{html_attrs}><head><meta \
+         charset=\"utf-8\"/>{head}</head><body{body_attrs}>{body}</body></html>"
+    );
+    let document = strip_scripts(&document);
+    let document = match &options.load_stylesheet {
+        Some(load) => inline_stylesheets(&document, load.as_ref()),
+        None => document,
+    };
+    match &options.base_url {
+        Some(base_url) => absolutize_urls(&document, base_url),
+        None => document,
+    }
+}
+
+// A piece of an HTML document. Only tags are rewritten, so that text which
+// looks like markup, such as `href="/x"` in a `<code>`, is left alone.
+#[derive(Debug, PartialEq)]
+enum Part<'a> {
+    /// Text, a comment, or the contents of a `<script>` or `<style>`.
+    Text(&'a str),
+    /// A start or end tag, from its `<` to its `>`.
+    Tag(&'a str),
+}
+
+fn parts(html: &str) -> Vec<Part<'_>> {
+    let mut parts = Vec::new();
+    let mut rest = html;
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            parts.push(Part::Text(rest));
+            break;
+        };
+        if start > 0 {
+            parts.push(Part::Text(&rest[..start]));
+            rest = &rest[start..];
+        }
+
+        let len = if rest.starts_with("<!--") {
+            rest.find("-->").map(|end| end + "-->".len())
+        } else if rest[1..].starts_with(|c: char| {
+            c.is_ascii_alphabetic() || c == '/' || c == '!'
+        }) {
+            None
+        } else {
+            Some(1)
+        };
+        if let Some(len) = len {
+            let len = len.min(rest.len());
+            parts.push(Part::Text(&rest[..len]));
+            rest = &rest[len..];
+            continue;
+        }
+
+        let tag = &rest[..tag_len(rest)];
+        parts.push(Part::Tag(tag));
+        rest = &rest[tag.len()..];
+
+        // the contents of these are not parsed as HTML
+        if !tag.starts_with("</") {
+            let close = match tag_name(tag) {
+                "script" => Some("</script"),
+                "style" => Some("</style"),
+                _ => None,
+            };
+            if let Some(close) = close {
+                let len = rest.find(close).unwrap_or(rest.len());
+                if len > 0 {
+                    parts.push(Part::Text(&rest[..len]));
+                }
+                rest = &rest[len..];
+            }
+        }
+    }
+    parts
+}
+
+// the length of the tag at the start of `html`, up to the first `>` that is
+// not in a quoted attribute value
+fn tag_len(html: &str) -> usize {
+    let mut quote = None;
+    for (idx, c) in html.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), c) if q == c => quote = None,
+            (None, '>') => return idx + 1,
+            _ => {}
+        }
+    }
+    html.len()
+}
+
+fn tag_name(tag: &str) -> &str {
+    let name = tag.trim_start_matches('<').trim_start_matches('/');
+    let len = name
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+        .unwrap_or(name.len());
+    &name[..len]
+}
+
+// the attributes of a start tag, with the range of each value within the tag
+fn attributes(tag: &str) -> Vec<(&str, Option<std::ops::Range<usize>>)> {
+    let mut attrs = Vec::new();
+    if tag.starts_with("</") || tag.starts_with("<!") {
+        return attrs;
+    }
+    let bytes = tag.as_bytes();
+    let is_space = |idx: usize| bytes[idx].is_ascii_whitespace();
+    let mut idx = 1 + tag_name(tag).len();
+    loop {
+        while idx < bytes.len() && (is_space(idx) || bytes[idx] == b'/') {
+            idx += 1;
+        }
+        if idx >= bytes.len() || bytes[idx] == b'>' {
+            break;
+        }
+        let name_start = idx;
+        while idx < bytes.len()
+            && !is_space(idx)
+            && !matches!(bytes[idx], b'=' | b'>' | b'/')
+        {
+            idx += 1;
+        }
+        let name = &tag[name_start..idx];
+        while idx < bytes.len() && is_space(idx) {
+            idx += 1;
+        }
+        if idx >= bytes.len() || bytes[idx] != b'=' {
+            attrs.push((name, None));
+            continue;
+        }
+        idx += 1;
+        while idx < bytes.len() && is_space(idx) {
+            idx += 1;
+        }
+        let value = match bytes.get(idx) {
+            Some(&quote @ (b'"' | b'\'')) => {
+                let start = idx + 1;
+                let end = tag[start..]
+                    .find(quote as char)
+                    .map(|len| start + len)
+                    .unwrap_or(tag.len());
+                idx = (end + 1).min(tag.len());
+                start..end
+            }
+            _ => {
+                let start = idx;
+                while idx < bytes.len() && !is_space(idx) && bytes[idx] != b'>'
+                {
+                    idx += 1;
+                }
+                start..idx
+            }
+        };
+        attrs.push((name, Some(value)));
+    }
+    attrs
+}
+
+fn attr_value<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    attributes(tag)
+        .into_iter()
+        .find(|(attr, _)| attr.eq_ignore_ascii_case(name))
+        .and_then(|(_, value)| value)
+        .map(|value| &tag[value])
+}
+
+fn strip_scripts(html: &str) -> String {
+    let mut out = String::with_capacity(html.len());
+    let mut in_script = false;
+    for part in parts(html) {
+        match part {
+            Part::Tag(tag) if tag_name(tag) == "script" => {
+                in_script = !tag.starts_with("</");
+            }
+            Part::Text(_) if in_script => {}
+            Part::Text(part) | Part::Tag(part) => out.push_str(part),
+        }
+    }
+    out
+}
+
+fn inline_stylesheets(
+    html: &str,
+    load: &dyn Fn(&str) -> Option<String>,
+) -> String {
+    let mut out = String::with_capacity(html.len());
+    for part in parts(html) {
+        let css = match part {
+            Part::Tag(tag) if tag_name(tag) == "link" => attr_value(tag, "rel")
+                .filter(|rel| *rel == "stylesheet")
+                .and_then(|_| attr_value(tag, "href"))
+                .and_then(|href| load(&href.replace("&amp;", "&"))),
+            _ => None,
+        };
+        match (css, part) {
+            (Some(css), _) => {
+                out.push_str("<style>");
+                out.push_str(&css);
+                out.push_str("</style>");
+            }
+            (None, Part::Text(part) | Part::Tag(part)) => out.push_str(part),
+        }
+    }
+    out
+}
+
+fn absolutize_urls(html: &str, base_url: &str) -> String {
+    let mut out = String::with_capacity(html.len());
+    for part in parts(html) {
+        let tag = match part {
+            Part::Tag(tag) => tag,
+            Part::Text(text) => {
+                out.push_str(text);
+                continue;
+            }
+        };
+        let mut written = 0;
+        for (name, value) in attributes(tag) {
+            let Some(value) = value else { continue };
+            if name.eq_ignore_ascii_case("href")
+                || name.eq_ignore_ascii_case("src")
+            {
+                out.push_str(&tag[written..value.start]);
+                out.push_str(&resolve_url(base_url, &tag[value.clone()]));
+                written = value.end;
+            }
+        }
+        out.push_str(&tag[written..]);
+    }
+    out
+}
+
+fn resolve_url(base_url: &str, url: &str) -> String {
+    let is_absolute = url.is_empty()
+        || url.starts_with('#')
+        || url.contains("://")
+        || ["data:", "mailto:", "tel:", "javascript:"]
+            .iter()
+            .any(|scheme| url.starts_with(scheme));
+    if is_absolute {
+        return url.to_string();
+    }
+
+    let (scheme, after_scheme) =
+        base_url.split_once("://").unwrap_or(("https", base_url));
+    if let Some(url) = url.strip_prefix("//") {
+        return format!("{scheme}://{url}");
+    }
+    let origin_len = after_scheme.find('/').unwrap_or(after_scheme.len());
+    let origin = &after_scheme[..origin_len];
+    if url.starts_with('/') {
+        format!("{scheme}://{origin}{url}")
+    } else {
+        let path = &after_scheme[origin_len..];
+        let dir = &path[..path.rfind('/').map(|idx| idx + 1).unwrap_or(0)];
+        let dir = if dir.is_empty() { "/" } else { dir };
+        format!("{scheme}://{origin}{dir}{url}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_only_script_elements() {
+        let html = "<p>a<script>let x = \"</p>\";</script>b</p>\
+                    <pre>&lt;script&gt; and <!-- <script> --></pre>";
+        assert_eq!(
+            strip_scripts(html),
+            "<p>ab</p><pre>&lt;script&gt; and <!-- <script> --></pre>"
+        );
+    }
+
+    #[test]
+    fn inlines_only_link_tags() {
+        let html = "<link href=\"/a.css\" rel=\"stylesheet\"/>\
+                    <code>&lt;link rel=\"stylesheet\" href=\"/b.css\"&gt;</code>\
+                    <!-- <link rel=\"stylesheet\" href=\"/c.css\"> -->\
+                    <link title=' rel=\"stylesheet\"' href=\"/d.css\"/>";
+        let html =
+            inline_stylesheets(html, &|href| Some(format!("/*{href}*/")));
+        assert_eq!(
+            html,
+            "<style>/*/a.css*/</style>\
+             <code>&lt;link rel=\"stylesheet\" href=\"/b.css\"&gt;</code>\
+             <!-- <link rel=\"stylesheet\" href=\"/c.css\"> -->\
+             <link title=' rel=\"stylesheet\"' href=\"/d.css\"/>"
+        );
+    }
+
+    #[test]
+    fn absolutizes_only_attributes() {
+        let html = "<a href=\"/x\" title=' src=\"y\"'>link</a>\
+                    <code> href=\"/x\"</code><!-- <img src=\"/z\"> -->\
+                    <style>a[href=\"/x\"] {}</style><img src='logo.png'>";
+        assert_eq!(
+            absolutize_urls(html, "https://example.com/docs/"),
+            "<a href=\"https://example.com/x\" title=' src=\"y\"'>link</a>\
+             <code> href=\"/x\"</code><!-- <img src=\"/z\"> -->\
+             <style>a[href=\"/x\"] {}</style>\
+             <img src='https://example.com/docs/logo.png'>"
+        );
+    }
+}
@@ -0,0 +1,586 @@
+use leptos::{component, Children, IntoView};
+
+/// A layout table, as used for structuring HTML email.
+///
+/// Renders `<table role="presentation">` with borders, padding, and spacing
+/// reset, which is the most reliable way to lay out email across clients.
+/// Use it with [`EmailRow`] and [`EmailColumn`].
+#[component]
+pub fn EmailTable(
+    /// The `width` attribute, e.g. `"600"` or `"100%"`.
+    #[prop(optional, into)]
+    width: Option<String>,
+    /// The `align` attribute, e.g. `"center"`.
+    #[prop(optional, into)]
+    align: Option<String>,
+    /// A CSS class, which will be inlined by [`render_to_email_string`].
+    #[prop(optional, into)]
+    class: Option<String>,
+    /// The rows of the table.
+    children: Children,
+) -> impl IntoView {
+    leptos::view! {
+        <table
+            role="presentation"
+            width=width
+            align=align
+            class=class
+            border="0"
+            cellpadding="0"
+            cellspacing="0"
+        >
+            <tbody>{children()}</tbody>
+        </table>
+    }
+}
+
+/// A row in an [`EmailTable`].
+#[component]
+pub fn EmailRow(
+    /// A CSS class, which will be inlined by [`render_to_email_string`].
+    #[prop(optional, into)]
+    class: Option<String>,
+    /// The columns in this row.
+    children: Children,
+) -> impl IntoView {
+    leptos::view! { <tr class=class>{children()}</tr> }
+}
+
+/// A column (cell) in an [`EmailRow`].
+#[component]
+pub fn EmailColumn(
+    /// The `width` attribute, e.g. `"50%"`.
+    #[prop(optional, into)]
+    width: Option<String>,
+    /// The `align` attribute, e.g. `"left"`.
+    #[prop(optional, into)]
+    align: Option<String>,
+    /// The `valign` attribute, e.g. `"top"`.
+    #[prop(optional, into)]
+    valign: Option<String>,
+    /// A CSS class, which will be inlined by [`render_to_email_string`].
+    #[prop(optional, into)]
+    class: Option<String>,
+    /// The content of the cell.
+    children: Children,
+) -> impl IntoView {
+    leptos::view! {
+        <td width=width align=align valign=valign class=class>
+            {children()}
+        </td>
+    }
+}
+
+#[cfg(feature = "ssr")]
+pub use render::*;
+
+#[cfg(feature = "ssr")]
+mod render {
+    use crate::{render_to_document_string_with_options, DocumentOptions};
+    use leptos::View;
+    use std::{collections::HashSet, fmt};
+
+    /// Elements that are allowed in email by default.
+    pub const DEFAULT_EMAIL_ELEMENTS: &[&str] = &[
+        "html",
+        "head",
+        "body",
+        "title",
+        "meta",
+        "style",
+        "table",
+        "thead",
+        "tbody",
+        "tfoot",
+        "tr",
+        "td",
+        "th",
+        "caption",
+        "colgroup",
+        "col",
+        "div",
+        "span",
+        "p",
+        "a",
+        "img",
+        "br",
+        "hr",
+        "h1",
+        "h2",
+        "h3",
+        "h4",
+        "h5",
+        "h6",
+        "strong",
+        "b",
+        "em",
+        "i",
+        "u",
+        "s",
+        "small",
+        "sub",
+        "sup",
+        "ul",
+        "ol",
+        "li",
+        "blockquote",
+        "pre",
+        "code",
+        "center",
+        "font",
+    ];
+
+    /// Attributes that are allowed in email by default.
+    pub const DEFAULT_EMAIL_ATTRIBUTES: &[&str] = &[
+        "style",
+        "class",
+        "id",
+        "href",
+        "target",
+        "src",
+        "alt",
+        "title",
+        "width",
+        "height",
+        "align",
+        "valign",
+        "bgcolor",
+        "color",
+        "border",
+        "cellpadding",
+        "cellspacing",
+        "colspan",
+        "rowspan",
+        "role",
+        "dir",
+        "lang",
+        "name",
+        "content",
+        "charset",
+        "http-equiv",
+        "face",
+        "size",
+    ];
+
+    /// Options for [`render_to_email_string`].
+    #[derive(Clone, Debug)]
+    pub struct EmailOptions {
+        /// CSS whose rules are inlined into the `style` attribute of each
+        /// matching element. Only type (`td`), class (`.button`), and
+        /// compound (`a.button`) selectors are supported; `@` rules such as
+        /// `@media` are kept in a `<style>` in the `<head>`.
+        pub stylesheet: Option<String>,
+        /// Elements that may appear in the email.
+        pub allowed_elements: HashSet<String>,
+        /// Attributes that may appear in the email.
+        pub allowed_attributes: HashSet<String>,
+        /// The URL against which relative `href` and `src` attributes are resolved.
+        pub base_url: Option<String>,
+    }
+
+    impl Default for EmailOptions {
+        fn default() -> Self {
+            Self {
+                stylesheet: None,
+                allowed_elements: DEFAULT_EMAIL_ELEMENTS
+                    .iter()
+                    .map(|el| el.to_string())
+                    .collect(),
+                allowed_attributes: DEFAULT_EMAIL_ATTRIBUTES
+                    .iter()
+                    .map(|attr| attr.to_string())
+                    .collect(),
+                base_url: None,
+            }
+        }
+    }
+
+    impl EmailOptions {
+        /// Sets the stylesheet that will be inlined.
+        pub fn stylesheet(mut self, css: impl Into<String>) -> Self {
+            self.stylesheet = Some(css.into());
+            self
+        }
+
+        /// Sets the URL against which relative URLs are resolved.
+        pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+            self.base_url = Some(base_url.into());
+            self
+        }
+
+        /// Allows an additional element.
+        pub fn allow_element(mut self, element: impl Into<String>) -> Self {
+            self.allowed_elements.insert(element.into());
+            self
+        }
+
+        /// Allows an additional attribute.
+        pub fn allow_attribute(mut self, attribute: impl Into<String>) -> Self {
+            self.allowed_attributes.insert(attribute.into());
+            self
+        }
+    }
+
+    /// Something in the rendered email that is not allowed by [`EmailOptions`].
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum EmailViolation {
+        /// An element that is not in [`EmailOptions::allowed_elements`].
+        Element(String),
+        /// An attribute that is not in [`EmailOptions::allowed_attributes`].
+        Attribute {
+            /// The element on which the attribute was found.
+            element: String,
+            /// The name of the attribute.
+            attribute: String,
+        },
+    }
+
+    impl fmt::Display for EmailViolation {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Self::Element(el) => {
+                    write!(f, "<{el}> is not allowed in email")
+                }
+                Self::Attribute { element, attribute } => write!(
+                    f,
+                    "the `{attribute}` attribute on <{element}> is not \
+                     allowed in email"
+                ),
+            }
+        }
+    }
+
+    /// The error returned when a rendered email contains elements or
+    /// attributes that are not allowed.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct EmailValidationError(pub Vec<EmailViolation>);
+
+    impl fmt::Display for EmailValidationError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "invalid email HTML: ")?;
+            for (idx, violation) in self.0.iter().enumerate() {
+                if idx > 0 {
+                    write!(f, "; ")?;
+                }
+                write!(f, "{violation}")?;
+            }
+            Ok(())
+        }
+    }
+
+    impl std::error::Error for EmailValidationError {}
+
+    /// Renders a view to an HTML email.
+    ///
+    /// This works like [`render_to_document_string`](crate::render_to_document_string),
+    /// so it includes no hydration keys or scripts, and it additionally
+    /// 1. inlines the rules from [`EmailOptions::stylesheet`] into the `style`
+    ///    attribute of each matching element, and
+    /// 2. checks every element and attribute against the allowed lists,
+    ///    returning an error listing any that are not allowed.
+    ///
+    /// ```
+    /// # #[cfg(feature = "ssr")] {
+    /// use leptos::*;
+    /// use leptos_meta::*;
+    ///
+    /// # tokio_test::block_on(tokio::task::LocalSet::new().run_until(async {
+    /// let html = render_to_email_string(
+    ///     || {
+    ///         view! {
+    ///             <EmailTable width="600">
+    ///                 <EmailRow>
+    ///                     <EmailColumn class="greeting">"Welcome!"</EmailColumn>
+    ///                 </EmailRow>
+    ///             </EmailTable>
+    ///         }
+    ///         .into_view()
+    ///     },
+    ///     EmailOptions::default().stylesheet(".greeting { color: #333; }"),
+    /// )
+    /// .await
+    /// .unwrap();
+    /// assert!(html.contains(r#"style="color: #333""#));
+    ///
+    /// let err = render_to_email_string(
+    ///     || view! { <button on:click=|_| ()>"Click"</button> }.into_view(),
+    ///     EmailOptions::default(),
+    /// )
+    /// .await
+    /// .unwrap_err();
+    /// assert_eq!(err.0, vec![EmailViolation::Element("button".into())]);
+    /// # }));
+    /// # }
+    /// ```
+    pub async fn render_to_email_string(
+        view: impl FnOnce() -> View + 'static,
+        options: EmailOptions,
+    ) -> Result<String, EmailValidationError> {
+        let document_options = DocumentOptions {
+            base_url: options.base_url.clone(),
+            ..Default::default()
+        };
+        let html =
+            render_to_document_string_with_options(view, document_options)
+                .await;
+
+        let rules = options
+            .stylesheet
+            .as_deref()
+            .map(parse_stylesheet)
+            .unwrap_or_default();
+        let mut violations = Vec::new();
+        let mut out = String::with_capacity(html.len());
+        let mut rest = html.as_str();
+        while let Some(start) = rest.find('<') {
+            out.push_str(&rest[..start]);
+            rest = &rest[start..];
+            let len = tag_len(rest);
+            let tag = &rest[..len];
+            rest = &rest[len..];
+
+            let Some(mut el) = StartTag::parse(tag) else {
+                out.push_str(tag);
+                continue;
+            };
+            if !options.allowed_elements.contains(&el.name) {
+                violations.push(EmailViolation::Element(el.name.clone()));
+            }
+            for (attribute, _) in &el.attrs {
+                if !options.allowed_attributes.contains(attribute) {
+                    violations.push(EmailViolation::Attribute {
+                        element: el.name.clone(),
+                        attribute: attribute.clone(),
+                    });
+                }
+            }
+            el.inline_styles(&rules.rules);
+            out.push_str(&el.to_html());
+            if el.name == "head" && !rules.at_rules.is_empty() {
+                out.push_str("<style>");
+                out.push_str(&rules.at_rules);
+                out.push_str("</style>");
+            }
+        }
+        out.push_str(rest);
+
+        if violations.is_empty() {
+            Ok(out)
+        } else {
+            Err(EmailValidationError(violations))
+        }
+    }
+
+    /// Returns the length of the tag at the start of `html`, ignoring any `>`
+    /// inside quoted attribute values.
+    fn tag_len(html: &str) -> usize {
+        let mut in_quotes = false;
+        for (idx, c) in html.char_indices() {
+            match c {
+                '"' => in_quotes = !in_quotes,
+                '>' if !in_quotes => return idx + 1,
+                _ => {}
+            }
+        }
+        html.len()
+    }
+
+    struct StartTag {
+        name: String,
+        attrs: Vec<(String, Option<String>)>,
+        self_closing: bool,
+    }
+
+    impl StartTag {
+        fn parse(tag: &str) -> Option<Self> {
+            let inner = tag.strip_prefix('<')?.strip_suffix('>')?;
+            if inner.starts_with(['/', '!', '?']) {
+                return None;
+            }
+            let (inner, self_closing) = match inner.strip_suffix('/') {
+                Some(inner) => (inner, true),
+                None => (inner, false),
+            };
+            let name_len = inner
+                .find(|c: char| c.is_ascii_whitespace())
+                .unwrap_or(inner.len());
+            let name = inner[..name_len].to_ascii_lowercase();
+            let mut attrs = Vec::new();
+            let mut rest = inner[name_len..].trim_start();
+            while !rest.is_empty() {
+                let key_len = rest
+                    .find(|c: char| c == '=' || c.is_ascii_whitespace())
+                    .unwrap_or(rest.len());
+                let key = rest[..key_len].to_ascii_lowercase();
+                rest = &rest[key_len..];
+                let value = if let Some(after_eq) = rest.strip_prefix("=\"") {
+                    let value_len =
+                        after_eq.find('"').unwrap_or(after_eq.len());
+                    rest = after_eq.get(value_len + 1..).unwrap_or_default();
+                    Some(after_eq[..value_len].to_string())
+                } else {
+                    None
+                };
+                attrs.push((key, value));
+                rest = rest.trim_start();
+            }
+            Some(Self {
+                name,
+                attrs,
+                self_closing,
+            })
+        }
+
+        fn inline_styles(&mut self, rules: &[StyleRule]) {
+            let classes = self
+                .attrs
+                .iter()
+                .find(|(key, _)| key == "class")
+                .and_then(|(_, value)| value.clone())
+                .unwrap_or_default();
+            let classes = classes.split_ascii_whitespace().collect::<Vec<_>>();
+            let mut style = rules
+                .iter()
+                .filter(|rule| {
+                    rule.selectors.iter().any(|(tag, class)| {
+                        tag.as_ref()
+                            .map(|tag| *tag == self.name)
+                            .unwrap_or(true)
+                            && class
+                                .as_ref()
+                                .map(|class| classes.contains(&class.as_str()))
+                                .unwrap_or(true)
+                    })
+                })
+                .map(|rule| rule.declarations.as_str())
+                .collect::<Vec<_>>()
+                .join("; ");
+            if style.is_empty() {
+                return;
+            }
+            // existing inline styles take precedence over the stylesheet
+            match self.attrs.iter_mut().find(|(key, _)| key == "style") {
+                Some((_, Some(existing))) => {
+                    style.push_str("; ");
+                    style.push_str(existing);
+                    *existing = style;
+                }
+                _ => {
+                    self.attrs.retain(|(key, _)| key != "style");
+                    self.attrs.push(("style".into(), Some(style)));
+                }
+            }
+        }
+
+        fn to_html(&self) -> String {
+            let mut html = format!("<{}", self.name);
+            for (key, value) in &self.attrs {
+                html.push(' ');
+                html.push_str(key);
+                if let Some(value) = value {
+                    html.push_str("=\"");
+                    html.push_str(value);
+                    html.push('"');
+                }
+            }
+            html.push_str(if self.self_closing { "/>" } else { ">" });
+            html
+        }
+    }
+
+    struct StyleRule {
+        /// `(tag, class)` pairs, either of which may be omitted.
+        selectors: Vec<(Option<String>, Option<String>)>,
+        declarations: String,
+    }
+
+    #[derive(Default)]
+    struct Stylesheet {
+        rules: Vec<StyleRule>,
+        at_rules: String,
+    }
+
+    fn parse_stylesheet(css: &str) -> Stylesheet {
+        let mut stylesheet = Stylesheet::default();
+        let css = strip_comments(css);
+        let mut rest = css.trim();
+        while let Some(open) = rest.find('{') {
+            let prelude = rest[..open].trim();
+            // find the matching closing brace, accounting for nested blocks
+            let mut depth = 0;
+            let mut close = rest.len();
+            for (idx, c) in rest[open..].char_indices() {
+                match c {
+                    '{' => depth += 1,
+                    '}' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            close = open + idx;
+                            break;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            let body = &rest[open + 1..close.min(rest.len())];
+            if prelude.starts_with('@') {
+                stylesheet.at_rules.push_str(prelude);
+                stylesheet.at_rules.push('{');
+                stylesheet.at_rules.push_str(body);
+                stylesheet.at_rules.push('}');
+            } else {
+                let selectors = prelude
+                    .split(',')
+                    .filter_map(|selector| parse_selector(selector.trim()))
+                    .collect::<Vec<_>>();
+                let declarations = body
+                    .split(';')
+                    .map(str::trim)
+                    .filter(|decl| !decl.is_empty())
+                    .collect::<Vec<_>>()
+                    .join("; ");
+                if !selectors.is_empty() && !declarations.is_empty() {
+                    stylesheet.rules.push(StyleRule {
+                        selectors,
+                        declarations,
+                    });
+                }
+            }
+            rest = rest.get(close + 1..).unwrap_or_default().trim_start();
+        }
+        stylesheet
+    }
+
+    fn parse_selector(
+        selector: &str,
+    ) -> Option<(Option<String>, Option<String>)> {
+        let is_simple = !selector.is_empty()
+            && selector.chars().all(|c| {
+                c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.'
+            })
+            && selector.matches('.').count() <= 1;
+        if !is_simple {
+            return None;
+        }
+        let (tag, class) = match selector.split_once('.') {
+            Some((tag, class)) => (tag, Some(class.to_string())),
+            None => (selector, None),
+        };
+        let tag = (!tag.is_empty()).then(|| tag.to_ascii_lowercase());
+        Some((tag, class))
+    }
+
+    fn strip_comments(css: &str) -> String {
+        let mut out = String::with_capacity(css.len());
+        let mut rest = css;
+        while let Some(start) = rest.find("/*") {
+            out.push_str(&rest[..start]);
+            rest = match rest[start + 2..].find("*/") {
+                Some(end) => &rest[start + 2 + end + 2..],
+                None => "",
+            };
+        }
+        out.push_str(rest);
+        out
+    }
+}
@@ -19,6 +19,11 @@ pub struct HtmlContext {
 }
 
 impl HtmlContext {
+    #[cfg(feature = "ssr")]
+    pub(crate) fn set_attribute(&self, name: &'static str, value: Attribute) {
+        self.attributes.borrow_mut().insert(name, value);
+    }
+
     /// Converts the `<html>` metadata into an HTML string.
     #[cfg(any(feature = "ssr", doc))]
     pub fn as_string(&self) -> Option<String> {
@@ -55,6 +55,7 @@ use leptos::{
 };
 use std::{
     cell::{Cell, RefCell},
+    collections::HashMap,
     fmt::Debug,
     rc::Rc,
 };
@@ -62,20 +63,32 @@ use std::{
 use wasm_bindgen::{JsCast, UnwrapThrowExt};
 
 mod body;
+#[cfg(feature = "ssr")]
+mod component;
+#[cfg(feature = "ssr")]
+mod document;
+mod email;
 mod html;
 mod link;
 mod meta_tags;
 mod script;
 mod style;
 mod stylesheet;
+mod theme;
 mod title;
 pub use body::*;
+#[cfg(feature = "ssr")]
+pub use component::*;
+#[cfg(feature = "ssr")]
+pub use document::*;
+pub use email::*;
 pub use html::*;
 pub use link::*;
 pub use meta_tags::*;
 pub use script::*;
 pub use style::*;
 pub use stylesheet::*;
+pub use theme::*;
 pub use title::*;
 
 /// Contains the current state of meta tags. To access it, you can use [`use_head`].
@@ -95,6 +108,11 @@ pub struct MetaContext {
 }
 
 /// Manages all of the element created by components.
+///
+/// Elements can be registered with a deduplication key (see
+/// [`MetaTagsContext::register_with_key`]). Only the most recently registered
+/// element for each key is rendered, so the deepest component in the tree wins.
+/// When that component is unmounted, the element it replaced is restored.
 #[derive(Clone, Default)]
 pub struct MetaTagsContext {
     next_id: Rc<Cell<MetaTagId>>,
@@ -107,6 +125,9 @@ pub struct MetaTagsContext {
             >,
         >,
     >,
+    /// For each deduplication key, the IDs of the elements registered with
+    /// that key, in registration order. The last one is the active element.
+    keyed: Rc<RefCell<HashMap<String, Vec<Oco<'static, str>>>>>,
 }
 
 impl core::fmt::Debug for MetaTagsContext {
@@ -117,11 +138,16 @@ impl core::fmt::Debug for MetaTagsContext {
 
 impl MetaTagsContext {
     /// Converts metadata tags into an HTML string.
+    ///
+    /// Elements that have been overridden by another element with the same
+    /// deduplication key are skipped.
     #[cfg(any(feature = "ssr", docs))]
     pub fn as_string(&self) -> String {
+        let keyed = self.keyed.borrow();
         self.els
             .borrow()
             .iter()
+            .filter(|(id, _)| !is_shadowed(&keyed, id))
             .map(|(_, (builder_el, _))| {
                 builder_el.clone().into_view().render_to_string()
             })
@@ -131,24 +157,76 @@ impl MetaTagsContext {
     #[doc(hidden)]
     pub fn register(
         &self,
+        id: Oco<'static, str>,
+        builder_el: HtmlElement<AnyElement>,
+    ) {
+        self.register_with_key(id, None, builder_el);
+    }
 
+    /// Registers an element to be rendered in the `<head>`.
+    ///
+    /// If `key` is `Some(_)`, this element replaces any element previously
+    /// registered with the same key until it is unmounted, at which point the
+    /// replaced element is restored.
+    #[doc(hidden)]
+    pub fn register_with_key(
+        &self,
         id: Oco<'static, str>,
+        key: Option<String>,
         builder_el: HtmlElement<AnyElement>,
     ) {
+        let shadowed = key.as_ref().and_then(|key| {
+            let mut keyed = self.keyed.borrow_mut();
+            let stack = keyed.entry(key.clone()).or_default();
+            let prev = stack.last().cloned();
+            stack.push(id.clone());
+            prev
+        });
+
         cfg_if! {
             if #[cfg(any(feature = "csr", feature = "hydrate"))] {
                 use leptos::document;
 
+                let head = document().head().unwrap_throw();
+
+                // the element this one replaces should no longer be in the DOM
+                if let Some(shadowed) = shadowed {
+                    if let Some((_, Some(el))) = self.els.borrow().get(&shadowed) {
+                        _ = head.remove_child(el);
+                    }
+                }
+
                 let element_to_hydrate = document()
                     .get_element_by_id(&id);
 
                 let el = element_to_hydrate.unwrap_or_else({
                     let builder_el = builder_el.clone();
+                    let head = head.clone();
+                    let keyed = self.keyed.clone();
+                    let id = id.clone();
+                    let key = key.clone();
                     move || {
-                        let head = document().head().unwrap_throw();
-                        head
-                            .append_child(&builder_el)
-                            .unwrap_throw();
+                        if let Some(key) = key {
+                            // a component rendered below this one may register
+                            // an element with the same key in the same pass,
+                            // so only add this one if it is still active then;
+                            // while hydrating, that is why the server didn't
+                            // render it
+                            let builder_el = builder_el.clone();
+                            queue_microtask(move || {
+                                let active = keyed
+                                    .borrow()
+                                    .get(&key)
+                                    .is_some_and(|stack| stack.last() == Some(&id));
+                                if active {
+                                    _ = head.append_child(&builder_el);
+                                }
+                            });
+                        } else {
+                            head
+                                .append_child(&builder_el)
+                                .unwrap_throw();
+                        }
 
                         (*builder_el).clone().unchecked_into()
                     }
@@ -157,11 +235,30 @@ impl MetaTagsContext {
                 on_cleanup({
                     let el = el.clone();
                     let els = self.els.clone();
+                    let keyed = self.keyed.clone();
                     let id = id.clone();
                     move || {
-                        let head = document().head().unwrap_throw();
                         _ = head.remove_child(&el);
                         els.borrow_mut().swap_remove(&id);
+
+                        // if this was the active element for its key, restore
+                        // the one it replaced
+                        let Some(key) = key else { return };
+                        let mut keyed = keyed.borrow_mut();
+                        let Some(stack) = keyed.get_mut(&key) else { return };
+                        let was_active = stack.last() == Some(&id);
+                        stack.retain(|other| other != &id);
+                        if was_active {
+                            if let Some((_, Some(restored))) = stack
+                                .last()
+                                .and_then(|restored| els.borrow().get(restored).cloned())
+                            {
+                                _ = head.append_child(&restored);
+                            }
+                        }
+                        if stack.is_empty() {
+                            keyed.remove(&key);
+                        }
                     }
                 });
 
@@ -171,12 +268,23 @@ impl MetaTagsContext {
                     .insert(id, (builder_el.into_any(), Some(el)));
 
             } else {
+                _ = shadowed;
                 self.els.borrow_mut().insert(id, (builder_el, None));
             }
         }
     }
 }
 
+#[cfg(any(feature = "ssr", docs))]
+fn is_shadowed(
+    keyed: &HashMap<String, Vec<Oco<'static, str>>>,
+    id: &Oco<'static, str>,
+) -> bool {
+    keyed
+        .values()
+        .any(|stack| stack.contains(id) && stack.last() != Some(id))
+}
+
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
 struct MetaTagId(usize);
 
@@ -230,7 +338,11 @@ pub fn use_head() -> MetaContext {
 impl MetaContext {
     /// Creates an empty [`MetaContext`].
     pub fn new() -> Self {
-        Default::default()
+        let meta = Self::default();
+        // create the title trigger here, usually at the root of the app, so
+        // that it lives as long as the context
+        meta.title.trigger();
+        meta
     }
 
     #[cfg(feature = "ssr")]
@@ -4,6 +4,9 @@ use leptos::{nonce::use_nonce, *};
 /// Injects an [`HTMLLinkElement`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLLinkElement) into the document
 /// head, accepting any of the valid attributes for that tag.
 ///
+/// Favicons (`rel="icon"`) with the same `sizes` replace one another: only the
+/// most recently rendered one is included, as with [`use_favicon`].
+///
 /// ```
 /// use leptos::*;
 /// use leptos_meta::*;
@@ -89,6 +92,15 @@ pub fn Link(
     let mut id: Oco<'static, str> =
         id.unwrap_or_else(|| format!("leptos-link-{}", next_id.0).into());
 
+    // favicons replace each other, like `use_favicon`
+    let key = rel
+        .as_ref()
+        .filter(|rel| {
+            rel.split_ascii_whitespace()
+                .any(|rel| rel.eq_ignore_ascii_case("icon"))
+        })
+        .map(|_| favicon_key(sizes.as_deref()));
+
     let builder_el = leptos::leptos_dom::html::as_meta_tag({
         let id = id.clone_inplace();
         move || {
@@ -119,5 +131,60 @@ pub fn Link(
         }
     });
 
-    meta.tags.register(id, builder_el.into_any());
+    meta.tags.register_with_key(id, key, builder_el.into_any());
+}
+
+fn favicon_key(sizes: Option<&str>) -> String {
+    format!("link:icon:{}", sizes.unwrap_or_default())
+}
+
+/// Sets the document’s favicon, updating it whenever `href` changes (for
+/// example, to show a badge for unread messages).
+///
+/// This renders a `<link rel="icon">` into the document head. It replaces any
+/// favicon set by a `<Link rel="icon"/>` (without `sizes`) or a
+/// `use_favicon` higher in the tree, and restores the previous favicon when
+/// the current component is unmounted.
+///
+/// ```
+/// use leptos::*;
+/// use leptos_meta::*;
+///
+/// #[component]
+/// fn Inbox() -> impl IntoView {
+///     let (unread, set_unread) = create_signal(0);
+///     use_favicon(Signal::derive(move || {
+///         if unread.get() > 0 {
+///             "/favicon-badge.ico".to_string()
+///         } else {
+///             "/favicon.ico".to_string()
+///         }
+///     }));
+///
+///     view! {
+///       <button on:click=move |_| set_unread.update(|n| *n += 1)>"New mail"</button>
+///     }
+/// }
+/// ```
+pub fn use_favicon(href: impl Into<MaybeSignal<String>>) {
+    let href = href.into();
+    let meta = use_head();
+    let next_id = meta.tags.get_next_id();
+    let mut id: Oco<'static, str> = format!("leptos-link-{}", next_id.0).into();
+
+    let builder_el = leptos::leptos_dom::html::as_meta_tag({
+        let id = id.clone_inplace();
+        move || {
+            leptos::leptos_dom::html::link()
+                .attr("id", id)
+                .attr("rel", "icon")
+                .attr("href", move || href.get())
+        }
+    });
+
+    meta.tags.register_with_key(
+        id,
+        Some(favicon_key(None)),
+        builder_el.into_any(),
+    );
 }
@@ -4,6 +4,12 @@ use leptos::{component, Attribute, IntoView};
 /// Injects an [`HTMLMetaElement`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMetaElement) into the document
 /// head to set metadata
 ///
+/// `<Meta/>` tags are deduplicated by their `charset`, `name`, `property`,
+/// `http-equiv`, or `id`. If several components render a tag with the same key
+/// (for example, a `<Meta name="description"/>` in both a parent and a nested
+/// route), only the deepest one is rendered. When it is unmounted, the tag it
+/// overrode is restored.
+///
 /// ```
 /// use leptos::*;
 /// use leptos_meta::*;
@@ -20,6 +26,25 @@ use leptos::{component, Attribute, IntoView};
 ///     </main>
 ///   }
 /// }
+///
+/// # #[cfg(not(any(feature = "csr", feature = "hydrate")))] {
+/// # let runtime = create_runtime();
+/// provide_meta_context();
+///
+/// let app = view! {
+///   <Meta name="description" content="A Leptos fan site."/>
+///   <main>
+///     <Meta name="description" content="The about page."/>
+///   </main>
+/// };
+/// # _ = app.into_view().render_to_string();
+/// # #[cfg(feature = "ssr")] {
+/// let head = use_head().dehydrate();
+/// assert!(head.contains("The about page."));
+/// assert!(!head.contains("A Leptos fan site."));
+/// # }
+/// # runtime.dispose();
+/// # }
 /// ```
 #[component(transparent)]
 pub fn Meta(
@@ -46,6 +71,27 @@ pub fn Meta(
     let next_id = meta.tags.get_next_id();
     let id = format!("leptos-link-{}", next_id.0);
 
+    let attr_id = attrs.iter().find_map(|(name, value)| match value {
+        Attribute::String(value) if *name == "id" => Some(value.to_string()),
+        _ => None,
+    });
+    let key = if charset.is_some() {
+        Some("meta:charset".to_string())
+    } else {
+        [
+            ("name", &name),
+            ("property", &property),
+            ("http-equiv", &http_equiv),
+        ]
+        .into_iter()
+        .find_map(|(attr, value)| {
+            value
+                .as_ref()
+                .map(|value| format!("meta:{attr}:{}", value.get()))
+        })
+        .or_else(|| attr_id.map(|id| format!("meta:id:{id}")))
+    };
+
     let builder_el = leptos::leptos_dom::html::as_meta_tag(move || {
         attrs
             .into_iter()
@@ -59,5 +105,6 @@ pub fn Meta(
             .attr("content", move || content.as_ref().map(|v| v.get()))
     });
 
-    meta.tags.register(id.into(), builder_el.into_any());
+    meta.tags
+        .register_with_key(id.into(), key, builder_el.into_any());
 }
@@ -0,0 +1,159 @@
+use leptos::*;
+
+/// A set of values that are made available to the whole document as
+/// [CSS custom properties](https://developer.mozilla.org/en-US/docs/Web/CSS/Using_CSS_custom_properties),
+/// with [`provide_theme`].
+///
+/// This is usually derived with `#[derive(Theme)]`, which maps each field to
+/// a property named after it: `primary_color` becomes `--primary-color`.
+pub trait Theme {
+    /// The names of the custom properties, like `--primary-color`, and their
+    /// values.
+    fn css_variables(&self) -> Vec<(&'static str, String)>;
+
+    /// The custom properties as the value of a `style` attribute, like
+    /// `--primary-color: #3366ff; --radius: 4px`.
+    ///
+    /// This can be used to apply a different theme to part of the page.
+    fn to_style(&self) -> String {
+        self.css_variables()
+            .into_iter()
+            .map(|(name, value)| format!("{name}: {value}"))
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+}
+
+struct ThemeContext<T: 'static>(Signal<T>);
+
+impl<T> Clone for ThemeContext<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ThemeContext<T> {}
+
+/// Provides the theme of the app as context, so that it can be read with
+/// [`use_theme`], and sets its CSS custom properties on the `<html>` element.
+///
+/// The properties are updated whenever the signal changes. When rendering
+/// on the server, they are added to the `style` attribute of the `<html>`,
+/// so the page is styled correctly before it is hydrated.
+///
+/// ```
+/// use leptos::*;
+/// use leptos_meta::*;
+///
+/// #[derive(Clone, Theme)]
+/// struct AppTheme {
+///     primary_color: Color,
+///     #[theme(rename = "--radius")]
+///     border_radius: Length,
+/// }
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     provide_meta_context();
+///     let theme = create_rw_signal(AppTheme {
+///         primary_color: Color::rgb(0x33, 0x66, 0xff),
+///         border_radius: Length::px(4.0),
+///     });
+///     provide_theme(theme);
+///
+///     view! {
+///         // uses `var(--primary-color)` and `var(--radius)` in its styles
+///         <button class="primary">"Save"</button>
+///     }
+/// }
+///
+/// # let runtime = create_runtime();
+/// let theme = AppTheme {
+///     primary_color: Color::rgb(0x33, 0x66, 0xff),
+///     border_radius: Length::px(4.0),
+/// };
+/// assert_eq!(
+///     theme.css_variables(),
+///     [
+///         ("--primary-color", "#3366ff".to_string()),
+///         ("--radius", "4px".to_string())
+///     ]
+/// );
+/// assert_eq!(theme.to_style(), "--primary-color: #3366ff; --radius: 4px");
+/// # runtime.dispose();
+/// ```
+pub fn provide_theme<T>(theme: impl Into<Signal<T>>)
+where
+    T: Theme + Clone + 'static,
+{
+    let theme = theme.into();
+    provide_context(ThemeContext(theme));
+
+    cfg_if::cfg_if! {
+        if #[cfg(all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate")))] {
+            let el = document()
+                .document_element()
+                .expect("there to be a <html> element");
+            // the properties that are set can't change, so each one gets its
+            // own effect
+            let names = theme.with_untracked(|theme| {
+                theme
+                    .css_variables()
+                    .into_iter()
+                    .map(|(name, _)| name)
+                    .collect::<Vec<_>>()
+            });
+            for name in names {
+                let value = move || {
+                    let value = theme.with(|theme| {
+                        theme
+                            .css_variables()
+                            .into_iter()
+                            .find_map(|(n, value)| (n == name).then_some(value))
+                    });
+                    Style::Option(value.map(Oco::from))
+                };
+                leptos::leptos_dom::style_helper(
+                    &el,
+                    name.into(),
+                    Style::Fn(std::rc::Rc::new(value)),
+                );
+            }
+        } else if #[cfg(feature = "ssr")] {
+            let meta = crate::use_head();
+            meta.html.set_attribute(
+                "style",
+                Attribute::Fn(std::rc::Rc::new(move || {
+                    Attribute::String(theme.with(Theme::to_style).into())
+                })),
+            );
+        } else {
+            #[cfg(debug_assertions)]
+            crate::feature_warning();
+        }
+    }
+}
+
+/// Returns the theme provided with [`provide_theme`].
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_meta::*;
+/// # #[derive(Clone, Theme)]
+/// # struct AppTheme {
+/// #     primary_color: Color,
+/// # }
+/// #[component]
+/// fn Swatch() -> impl IntoView {
+///     let theme = use_theme::<AppTheme>();
+///     let color = move || theme.with(|theme| theme.primary_color.to_string());
+///     view! { <span>{color}</span> }
+/// }
+/// ```
+///
+/// # Panics
+/// Panics if no theme of type `T` has been provided.
+#[track_caller]
+pub fn use_theme<T: 'static>() -> Signal<T> {
+    expect_context::<ThemeContext<T>>().0
+}
@@ -12,19 +12,34 @@ pub struct TitleContext {
     el: Rc<RefCell<Option<web_sys::HtmlTitleElement>>>,
     formatter: Rc<RefCell<Option<Formatter>>>,
     text: Rc<RefCell<Option<TextProp>>>,
+    pending: Rc<RefCell<PendingTitle>>,
+    trigger: Rc<RefCell<Option<Trigger>>>,
 }
 
 impl TitleContext {
     /// Converts the title into a string that can be used as the text content of a `<title>` tag.
     pub fn as_string(&self) -> Option<Oco<'static, str>> {
         let title = self.text.borrow().as_ref().map(TextProp::get);
-        title.map(|title| {
-            if let Some(formatter) = &*self.formatter.borrow() {
-                (formatter.0)(title.into_owned()).into()
-            } else {
-                title
-            }
-        })
+        title.map(|title| self.format(title))
+    }
+
+    /// Notified whenever the title’s text is replaced, either by a `<Title/>`
+    /// or by [`use_document_title`].
+    pub(crate) fn trigger(&self) -> Trigger {
+        *self.trigger.borrow_mut().get_or_insert_with(create_trigger)
+    }
+
+    fn set_text(&self, text: TextProp) {
+        *self.text.borrow_mut() = Some(text);
+        self.trigger().notify();
+    }
+
+    fn format(&self, title: Oco<'static, str>) -> Oco<'static, str> {
+        if let Some(formatter) = &*self.formatter.borrow() {
+            (formatter.0)(title.into_owned()).into()
+        } else {
+            title
+        }
     }
 }
 
@@ -34,6 +49,31 @@ impl core::fmt::Debug for TitleContext {
     }
 }
 
+/// Determines what `document.title` shows while a route transition is pending,
+/// i.e., while resources read during a client-side navigation are still loading.
+#[derive(Clone, Default)]
+pub enum PendingTitle {
+    /// Keeps showing the previous page’s title until the navigation commits.
+    /// This is the default.
+    #[default]
+    KeepPrevious,
+    /// Updates the title immediately, even while the navigation is pending.
+    Immediate,
+    /// Shows the given text (passed through the formatter, if any) while the
+    /// navigation is pending.
+    Loading(TextProp),
+}
+
+impl core::fmt::Debug for PendingTitle {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::KeepPrevious => f.write_str("KeepPrevious"),
+            Self::Immediate => f.write_str("Immediate"),
+            Self::Loading(_) => f.debug_tuple("Loading").finish(),
+        }
+    }
+}
+
 /// A function that is applied to the text value before setting `document.title`.
 #[repr(transparent)]
 pub struct Formatter(Box<dyn Fn(String) -> String>);
@@ -53,6 +93,10 @@ where
 /// The `title` and `formatter` can be set independently of one another. For example, you can create a root-level
 /// `<Title formatter=.../>` that will wrap each of the text values of `<Title/>` components created lower in the tree.
 ///
+/// During client-side navigation, the title is not updated until the resources loaded by the new
+/// route have resolved, so a title derived from async data does not flash the previous page’s value.
+/// This behavior can be customized with the `pending` prop; see [`PendingTitle`].
+///
 /// ```
 /// use leptos::*;
 /// use leptos_meta::*;
@@ -64,7 +108,7 @@ where
 ///
 ///     view! {
 ///       <main>
-///         <Title formatter/>
+///         <Title formatter pending=PendingTitle::Loading("Loading…".into())/>
 ///         // ... routing logic here
 ///       </main>
 ///     }
@@ -96,8 +140,15 @@ pub fn Title(
     /// Sets the current `document.title`.
     #[prop(optional, into)]
     text: Option<TextProp>,
+    /// What to show while a route transition is pending. Like the `formatter`,
+    /// this applies to all `<Title/>` components once it has been set.
+    #[prop(optional, into)]
+    pending: Option<PendingTitle>,
 ) -> impl IntoView {
     let meta = use_head();
+    if let Some(pending) = pending {
+        *meta.title.pending.borrow_mut() = pending;
+    }
 
     cfg_if! {
         if #[cfg(any(feature = "csr", feature = "hydrate"))] {
@@ -105,7 +156,7 @@ pub fn Title(
                 *meta.title.formatter.borrow_mut() = Some(formatter);
             }
             if let Some(text) = text {
-                *meta.title.text.borrow_mut() = Some(text);
+                meta.title.set_text(text);
             }
 
             let el = {
@@ -140,8 +191,24 @@ pub fn Title(
                 el
             };
 
-            create_render_effect(move |_| {
-                let text = meta.title.as_string().unwrap_or_default();
+            // this runs as a (deferred) effect rather than a render effect, so that any
+            // resources read while rendering the rest of the route have been registered
+            let global_suspense = use_context::<GlobalSuspenseContext>();
+            let trigger = meta.title.trigger();
+            create_effect(move |_| {
+                trigger.track();
+                let is_pending = global_suspense
+                    .as_ref()
+                    .map(GlobalSuspenseContext::is_pending)
+                    .unwrap_or(false);
+                let pending = meta.title.pending.borrow().clone();
+                let text = match (is_pending, pending) {
+                    (true, PendingTitle::KeepPrevious) => return,
+                    (true, PendingTitle::Loading(text)) => {
+                        meta.title.format(text.get())
+                    }
+                    _ => meta.title.as_string().unwrap_or_default(),
+                };
 
                 el.set_text_content(Some(&text));
             });
@@ -150,8 +217,69 @@ pub fn Title(
                 *meta.title.formatter.borrow_mut() = Some(formatter);
             }
             if let Some(text) = text {
-                *meta.title.text.borrow_mut() = Some(text);
+                meta.title.set_text(text);
             }
         }
     }
 }
+
+/// Returns the document’s title as a signal, along with a setter that
+/// changes it.
+///
+/// This works together with [`Title`]: setting the title replaces the text of
+/// the most recently rendered `<Title/>` (and is passed through its
+/// `formatter`, if any), and a `<Title/>` that is rendered later replaces the
+/// value that was set. The signal always holds the formatted title managed by
+/// `leptos_meta`, or an empty string if no title has been set.
+///
+/// ```
+/// use leptos::*;
+/// use leptos_meta::*;
+///
+/// #[component]
+/// fn Inbox() -> impl IntoView {
+///     let (title, set_title) = use_document_title();
+///     let (unread, set_unread) = create_signal(0);
+///     create_effect(move |_| set_title.set(format!("Inbox ({})", unread.get())));
+///
+///     view! {
+///       <h1>{title}</h1>
+///       <button on:click=move |_| set_unread.update(|n| *n += 1)>"New mail"</button>
+///     }
+/// }
+/// ```
+pub fn use_document_title() -> (Signal<String>, SignalSetter<String>) {
+    let meta = use_head();
+    let trigger = meta.title.trigger();
+
+    let title = Signal::derive({
+        let meta = meta.clone();
+        move || {
+            trigger.track();
+            meta.title
+                .as_string()
+                .map(Oco::into_owned)
+                .unwrap_or_default()
+        }
+    });
+
+    // a mounted <Title/> updates the document itself, respecting its
+    // `pending` setting; otherwise, the title is set here
+    #[cfg(any(feature = "csr", feature = "hydrate"))]
+    create_effect({
+        let meta = meta.clone();
+        move |_| {
+            trigger.track();
+            if meta.title.el.borrow().is_none() {
+                if let Some(text) = meta.title.as_string() {
+                    document().set_title(&text);
+                }
+            }
+        }
+    });
+
+    let set_title =
+        SignalSetter::map(move |text: String| meta.title.set_text(text.into()));
+
+    (title, set_title)
+}
@@ -0,0 +1,172 @@
+#[cfg(not(feature = "ssr"))]
+use wasm_bindgen::JsCast;
+
+/// The `id` of the live region used by [`announce`].
+#[cfg(not(feature = "ssr"))]
+const ANNOUNCER_ID: &str = "leptos-route-announcer";
+
+/// Hides the live region visually, while keeping it readable by screen
+/// readers.
+#[cfg(not(feature = "ssr"))]
+const VISUALLY_HIDDEN: &str = "position:absolute;width:1px;height:1px;\
+                               padding:0;margin:-1px;overflow:hidden;\
+                               clip:rect(0,0,0,0);white-space:nowrap;border:0";
+
+/// Accessibility options for client-side navigations, set with the `a11y`
+/// prop of the [`Router`](crate::Router).
+///
+/// When a page is loaded from the server, screen readers announce its title
+/// and focus starts at the top of the new page. A client-side navigation does
+/// neither, so by default, once a navigation has finished (including any
+/// `<Suspense/>` it is waiting for), the router moves focus to the page's main
+/// heading and announces the new `document.title`. Navigations that replace
+/// the current history entry, like redirects or updates to the query string,
+/// are skipped, and so is focus for URLs with a `#hash`, which scroll to their
+/// target instead.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_router::*;
+/// # if false {
+/// view! {
+///   <Router a11y=RouterA11y {
+///     focus_target: Some("#content h1".into()),
+///     ..Default::default()
+///   }>
+///     <main id="content">
+///       <Routes>
+///         <Route path="/" view=|| view! { <h1>"Home"</h1> }/>
+///       </Routes>
+///     </main>
+///   </Router>
+/// }
+/// # ;
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RouterA11y {
+    /// A CSS selector for the element that receives focus after a
+    /// navigation, or `None` to leave focus where it is. Defaults to `"h1"`.
+    pub focus_target: Option<String>,
+    /// Whether to announce the new `document.title` to screen readers after
+    /// a navigation. Defaults to `true`.
+    pub announce_title: bool,
+}
+
+impl Default for RouterA11y {
+    fn default() -> Self {
+        Self {
+            focus_target: Some("h1".to_string()),
+            announce_title: true,
+        }
+    }
+}
+
+impl RouterA11y {
+    /// Neither moves focus nor announces anything after a navigation.
+    pub fn disabled() -> Self {
+        Self {
+            focus_target: None,
+            announce_title: false,
+        }
+    }
+
+    /// Moves focus and announces the new title once the current navigation
+    /// has finished rendering.
+    #[cfg(not(feature = "ssr"))]
+    pub(crate) fn navigated(&self) {
+        use leptos::{
+            request_animation_frame, spawn_local, use_context,
+            GlobalSuspenseContext,
+        };
+
+        if self.focus_target.is_none() && !self.announce_title {
+            return;
+        }
+        let options = self.clone();
+        let suspense = use_context::<GlobalSuspenseContext>();
+        spawn_local(async move {
+            if let Some(suspense) = suspense {
+                suspense.with_inner(|s| s.to_future()).await;
+            }
+            // the title is usually updated by an effect of the new route, so
+            // give it a frame to do so
+            request_animation_frame(move || {
+                let has_hash = leptos::window()
+                    .location()
+                    .hash()
+                    .is_ok_and(|hash| hash.len() > 1);
+                if let (Some(target), false) = (&options.focus_target, has_hash)
+                {
+                    focus_first(target);
+                }
+                if options.announce_title {
+                    let title = leptos::document().title();
+                    if !title.is_empty() {
+                        announce(&title);
+                    }
+                }
+            });
+        });
+    }
+}
+
+/// Announces `message` to screen readers, without moving focus, through a
+/// visually hidden [live region](https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/ARIA_Live_Regions)
+/// that is added to the `<body>` the first time it is needed.
+///
+/// The router uses this to announce the title of the new page after each
+/// navigation (see [`RouterA11y`]). This does nothing on the server.
+pub fn announce(message: &str) {
+    #[cfg(not(feature = "ssr"))]
+    {
+        let document = leptos::document();
+        let region = document.get_element_by_id(ANNOUNCER_ID).or_else(|| {
+            let region = document.create_element("div").ok()?;
+            region.set_id(ANNOUNCER_ID);
+            _ = region.set_attribute("aria-live", "assertive");
+            _ = region.set_attribute("aria-atomic", "true");
+            _ = region.set_attribute("style", VISUALLY_HIDDEN);
+            document.body()?.append_child(&region).ok()?;
+            Some(region)
+        });
+        if let Some(region) = region {
+            // clear it first, so the same message is announced again
+            region.set_text_content(None);
+            region.set_text_content(Some(message));
+        }
+    }
+    #[cfg(feature = "ssr")]
+    {
+        _ = message;
+    }
+}
+
+/// Moves focus to the first element that matches the CSS `selector`, making
+/// it focusable first if needed, and returns whether it was found.
+///
+/// Elements that are not focusable by default, like headings, are given
+/// `tabindex="-1"`, so they can be focused from a script without being added
+/// to the tab order. This does nothing on the server.
+pub fn focus_first(selector: &str) -> bool {
+    #[cfg(not(feature = "ssr"))]
+    {
+        let Some(el) = leptos::document()
+            .query_selector(selector)
+            .ok()
+            .flatten()
+            .and_then(|el| el.dyn_into::<web_sys::HtmlElement>().ok())
+        else {
+            return false;
+        };
+        if el.tab_index() < 0 && !el.has_attribute("tabindex") {
+            _ = el.set_attribute("tabindex", "-1");
+        }
+        el.focus().is_ok()
+    }
+    #[cfg(feature = "ssr")]
+    {
+        _ = selector;
+        false
+    }
+}
@@ -1,5 +1,7 @@
 use crate::{
-    matching::{resolve_path, PathMatch, RouteDefinition, RouteMatch},
+    matching::{
+        resolve_path, PathMatch, RouteDefinition, RouteMatch, RouteMeta,
+    },
     ParamsMap, RouterContext, SsrMode, StaticData, StaticMode, StaticParamsMap,
     TrailingSlash,
 };
@@ -81,6 +83,14 @@ pub fn Route<E, F, P>(
     /// Serves as a default for any inner Routes.
     #[prop(optional)]
     trailing_slash: Option<TrailingSlash>,
+    /// Arbitrary typed metadata for this route, which can be read from
+    /// [`use_matched_routes`](crate::use_matched_routes).
+    #[prop(optional)]
+    meta: RouteMeta,
+    /// Runs on the server when this route is matched, before its view is
+    /// rendered, and can redirect instead. See [`ServerMiddleware`].
+    #[prop(optional, into)]
+    server_middleware: Option<ServerMiddleware>,
     /// `children` may be empty or include nested routes.
     #[prop(optional)]
     children: Option<Children>,
@@ -90,16 +100,18 @@ where
     F: Fn() -> E + 'static,
     P: core::fmt::Display,
 {
+    let view = Rc::new(move || view().into_view());
     define_route(
         children,
         path.to_string(),
-        Rc::new(move || view().into_view()),
+        ServerMiddleware::wrap(server_middleware, view),
         ssr,
         methods,
         data,
         None,
         None,
         trailing_slash,
+        meta,
     )
 }
 
@@ -169,6 +181,14 @@ pub fn ProtectedRoute<P, E, F, C>(
     /// Serves as a default for any inner Routes.
     #[prop(optional)]
     trailing_slash: Option<TrailingSlash>,
+    /// Arbitrary typed metadata for this route, which can be read from
+    /// [`use_matched_routes`](crate::use_matched_routes).
+    #[prop(optional)]
+    meta: RouteMeta,
+    /// Runs on the server when this route is matched, before its view is
+    /// rendered, and can redirect instead. See [`ServerMiddleware`].
+    #[prop(optional, into)]
+    server_middleware: Option<ServerMiddleware>,
     /// `children` may be empty or include nested routes.
     #[prop(optional)]
     children: Option<Children>,
@@ -182,22 +202,24 @@ where
     use crate::Redirect;
     let redirect_path = redirect_path.to_string();
 
+    let view = Rc::new(move || {
+        if condition() {
+            view().into_view()
+        } else {
+            view! { <Redirect path=redirect_path.clone()/> }.into_view()
+        }
+    });
     define_route(
         children,
         path.to_string(),
-        Rc::new(move || {
-            if condition() {
-                view().into_view()
-            } else {
-                view! { <Redirect path=redirect_path.clone()/> }.into_view()
-            }
-        }),
+        ServerMiddleware::wrap(server_middleware, view),
         ssr,
         methods,
         data,
         None,
         None,
         trailing_slash,
+        meta,
     )
 }
 
@@ -231,6 +253,10 @@ pub fn StaticRoute<E, F, P, S>(
     /// Serves as a default for any inner Routes.
     #[prop(optional)]
     trailing_slash: Option<TrailingSlash>,
+    /// Arbitrary typed metadata for this route, which can be read from
+    /// [`use_matched_routes`](crate::use_matched_routes).
+    #[prop(optional)]
+    meta: RouteMeta,
     /// `children` may be empty or include nested routes.
     #[prop(optional)]
     children: Option<Children>,
@@ -254,6 +280,7 @@ where
         Some(mode),
         Some(Arc::new(static_params)),
         trailing_slash,
+        meta,
     )
 }
 
@@ -272,6 +299,7 @@ pub(crate) fn define_route(
     static_mode: Option<StaticMode>,
     static_params: Option<StaticData>,
     trailing_slash: Option<TrailingSlash>,
+    meta: RouteMeta,
 ) -> RouteDefinition {
     let children = children
         .map(|children| {
@@ -299,6 +327,7 @@ pub(crate) fn define_route(
         static_mode,
         static_params,
         trailing_slash,
+        meta,
     }
 }
 
@@ -478,6 +507,82 @@ impl core::fmt::Debug for RouteContextInner {
     }
 }
 
+/// What a route's [`ServerMiddleware`] decides about a request.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MiddlewareOutcome {
+    /// Render the route.
+    Continue,
+    /// Redirect to the given path instead of rendering the route.
+    Redirect(String),
+}
+
+/// A function that runs on the server when a [`<Route/>`](Route) is matched
+/// while rendering a request, before the route's view is rendered. A parent
+/// route's middleware runs before that of its children.
+///
+/// This keeps checks like authorization next to the route they protect. The
+/// middleware can read the request from context, and set the status or
+/// headers of the response with the server integration's `ResponseOptions`.
+/// It is not run by the client-side router, so routes should still be guarded
+/// on the client, for example with [`<ProtectedRoute/>`](ProtectedRoute).
+///
+/// ```rust
+/// # use leptos::*; use leptos_router::*;
+/// fn auth_required() -> MiddlewareOutcome {
+///     let logged_in = false; // TODO: check the session of the request
+///     if logged_in {
+///         MiddlewareOutcome::Continue
+///     } else {
+///         MiddlewareOutcome::Redirect("/login".to_string())
+///     }
+/// }
+///
+/// # if false {
+/// view! {
+///   <Routes>
+///     <Route path="/admin" view=|| "Admin" server_middleware=auth_required/>
+///   </Routes>
+/// }
+/// # ;}
+/// ```
+#[derive(Clone)]
+pub struct ServerMiddleware(Rc<dyn Fn() -> MiddlewareOutcome>);
+
+impl<F> From<F> for ServerMiddleware
+where
+    F: Fn() -> MiddlewareOutcome + 'static,
+{
+    fn from(f: F) -> Self {
+        Self(Rc::new(f))
+    }
+}
+
+impl core::fmt::Debug for ServerMiddleware {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("ServerMiddleware").finish()
+    }
+}
+
+impl ServerMiddleware {
+    // runs the middleware before the view on the server
+    fn wrap(
+        middleware: Option<Self>,
+        view: Rc<dyn Fn() -> View>,
+    ) -> Rc<dyn Fn() -> View> {
+        match middleware {
+            Some(middleware) if cfg!(feature = "ssr") => {
+                Rc::new(move || match (middleware.0)() {
+                    MiddlewareOutcome::Continue => view(),
+                    MiddlewareOutcome::Redirect(path) => {
+                        view! { <crate::Redirect path/> }.into_view()
+                    }
+                })
+            }
+            _ => view,
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct Loader {
     pub(crate) data: Rc<dyn Fn() -> Rc<dyn Any>>,
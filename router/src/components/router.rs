@@ -1,7 +1,9 @@
 use crate::{
-    create_location, matching::resolve_path, resolve_redirect_url,
-    scroll_to_el, use_location, use_navigate, Branch, History, Location,
-    LocationChange, RouteContext, RouterIntegrationContext, State,
+    create_location,
+    matching::{join_paths, resolve_path},
+    resolve_redirect_url, scroll_to_el, use_location, use_navigate, Branch,
+    History, Location, LocationChange, RouteContext, RouterA11y,
+    RouterIntegrationContext, State,
 };
 #[cfg(not(feature = "ssr"))]
 use crate::{unescape, Url};
@@ -24,6 +26,13 @@ use wasm_bindgen::UnwrapThrowExt;
 
 /// Provides for client-side and server-side routing. This should usually be somewhere near
 /// the root of the application.
+///
+/// Clicks on any `<a href>` within the app's origin and base path, including
+/// plain links in HTML the app didn't render itself, like rendered Markdown,
+/// are turned into client-side navigations. The browser handles the click
+/// instead if the link has a `target` other than `_self`, a `download`
+/// attribute, `rel="external"` or a `data-external` attribute, or if a
+/// modifier key is held.
 #[component]
 pub fn Router(
     /// The base URL for the router. Defaults to `""`.
@@ -46,15 +55,32 @@ pub fn Router(
     /// different routes from the same server.
     #[prop(optional)]
     id: usize,
+    /// How focus and screen reader announcements are handled after each
+    /// client-side navigation. By default, focus moves to the first `<h1>` and
+    /// the new page title is announced; see [`RouterA11y`].
+    #[prop(optional)]
+    a11y: RouterA11y,
 ) -> impl IntoView {
     // create a new RouterContext and provide it to every component beneath the router
-    let router = RouterContext::new(id, base, fallback, trailing_slash);
+    let router = RouterContext::new(id, base, fallback, trailing_slash, a11y);
     provide_context(router);
     provide_context(GlobalSuspenseContext::new());
     if let Some(set_is_routing) = set_is_routing {
         provide_context(SetIsRouting(set_is_routing));
     }
 
+    // keep using the ID of the server-rendered page until the first navigation
+    #[cfg(not(feature = "ssr"))]
+    provide_request_id(
+        document()
+            .query_selector("meta[name=leptos-request-id]")
+            .ok()
+            .flatten()
+            .and_then(|meta| meta.get_attribute("content"))
+            .map(|id| RequestId::from_header(Some(&id)))
+            .unwrap_or_default(),
+    );
+
     // set server function redirect hook
     let navigate = use_navigate();
     let navigate = SendWrapper::new(navigate);
@@ -92,6 +118,92 @@ pub fn Router(
     children()
 }
 
+/// Runs `navigate`, which updates the current location, as a new navigation
+/// with its own [`RequestId`], so that the server functions called while
+/// rendering the new route can be correlated with it.
+fn start_navigation(navigate: impl FnOnce()) {
+    cfg_if! {
+        if #[cfg(feature = "ssr")] {
+            navigate();
+        } else {
+            let request_id = RequestId::new();
+            let span = request_id.span();
+            provide_request_id(request_id);
+            span.in_scope(navigate);
+        }
+    }
+}
+
+/// Mounts a routed sub-application at `base` within the surrounding
+/// [`Router`], for example one whose routes are defined in another crate.
+///
+/// The parent delegates every path under `base` to it with a wildcard route,
+/// like `<Route path="/admin/*any" view=admin::App/>`. The sub-application
+/// defines its [`Routes`](crate::Routes) relative to `base`, and its
+/// [`A`](crate::A) links, [`Form`](crate::Form)s and
+/// [`use_navigate`](crate::use_navigate) resolve paths within `base`, so
+/// `/users` in it means `/admin/users`. It shares the location and history
+/// of the surrounding router, so both always agree on the current URL.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_router::*;
+/// mod admin {
+///     use leptos::*;
+///     use leptos_router::*;
+///
+///     #[component]
+///     pub fn App() -> impl IntoView {
+///         view! {
+///             <SubRouter base="/admin">
+///                 <nav><A href="/users">"Users"</A></nav>
+///                 <Routes>
+///                     <Route path="/" view=|| "Dashboard"/>
+///                     <Route path="/users" view=|| "Users"/>
+///                 </Routes>
+///             </SubRouter>
+///         }
+///     }
+/// }
+///
+/// # if false {
+/// view! {
+///     <Router>
+///         <Routes>
+///             <Route path="/" view=|| "Home"/>
+///             <Route path="/admin/*any" view=admin::App/>
+///         </Routes>
+///     </Router>
+/// }
+/// # ;}
+/// ```
+#[component]
+pub fn SubRouter(
+    /// The path at which the sub-application is mounted, relative to the
+    /// base of the surrounding router.
+    base: &'static str,
+    /// A fallback that should be shown if no route is matched.
+    #[prop(optional)]
+    fallback: Option<fn() -> View>,
+    /// How trailing slashes should be handled in [`Route`] paths.
+    #[prop(optional)]
+    trailing_slash: TrailingSlash,
+    /// The sub-application, which should include a
+    /// [`Routes`](crate::Routes) component somewhere.
+    children: Children,
+) -> impl IntoView {
+    let parent = use_context::<RouterContext>()
+        .expect("<SubRouter/> should be nested within a <Router/>.");
+    let router =
+        RouterContext::mounted(&parent, base, fallback, trailing_slash);
+    // paths are resolved within the base until a route of the sub-application
+    // has been matched, rather than relative to the route it is mounted in
+    provide_context(router.base());
+    provide_context(router);
+
+    children()
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub(crate) struct SetIsRouting(pub SignalSetter<bool>);
 
@@ -105,10 +217,14 @@ pub(crate) struct RouterContextInner {
     pub location: Location,
     pub base: RouteContext,
     trailing_slash: TrailingSlash,
+    #[allow(unused)] // used in CSR/hydrate
+    a11y: RouterA11y,
     pub possible_routes: RefCell<Option<Vec<Branch>>>,
     #[allow(unused)] // used in CSR/hydrate
     base_path: String,
-    history: Box<dyn History>,
+    // the base path of a router mounted with <SubRouter/>
+    mounted_at: Option<String>,
+    history: RouterIntegrationContext,
     reference: ReadSignal<String>,
     set_reference: WriteSignal<String>,
     referrers: Rc<RefCell<Vec<LocationChange>>>,
@@ -143,11 +259,12 @@ impl RouterContext {
         base: Option<&'static str>,
         fallback: Option<fn() -> View>,
         trailing_slash: TrailingSlash,
+        a11y: RouterA11y,
     ) -> Self {
         cfg_if! {
             if #[cfg(any(feature = "csr", feature = "hydrate"))] {
                 let history = use_context::<RouterIntegrationContext>()
-                    .unwrap_or_else(|| RouterIntegrationContext(Rc::new(crate::BrowserIntegration {})));
+                    .unwrap_or_else(crate::history::default_integration);
             } else {
                 let history = use_context::<RouterIntegrationContext>().unwrap_or_else(|| {
                     let msg = "No router integration found.\n\nIf you are using this in the browser, \
@@ -208,24 +325,45 @@ impl RouterContext {
         // 3) update the state
         // this will trigger the new route match below
 
-        create_render_effect(move |_| {
-            let LocationChange { value, state, .. } = source.get();
-            untrack(move || {
-                if value != reference.get() {
-                    set_reference.update(move |r| *r = value);
-                    set_state.update(move |s| *s = state);
-                }
-            });
+        create_render_effect({
+            #[cfg(not(feature = "ssr"))]
+            let a11y = a11y.clone();
+            move |_| {
+                let LocationChange {
+                    value,
+                    #[cfg(not(feature = "ssr"))]
+                    replace,
+                    state,
+                    ..
+                } = source.get();
+                #[cfg(not(feature = "ssr"))]
+                let a11y = &a11y;
+                untrack(move || {
+                    if value != reference.get() {
+                        start_navigation(move || {
+                            set_reference.update(move |r| *r = value);
+                            set_state.update(move |s| *s = state);
+                        });
+                        // e.g., going back or forward in the history
+                        #[cfg(not(feature = "ssr"))]
+                        if !replace {
+                            a11y.navigated();
+                        }
+                    }
+                });
+            }
         });
 
         let inner = Rc::new(RouterContextInner {
             id,
             base_path: base_path.into_owned(),
+            mounted_at: None,
             path_stack: store_value(vec![location.pathname.get_untracked()]),
             location,
             base,
             trailing_slash,
-            history: Box::new(history),
+            a11y,
+            history,
 
             reference,
             set_reference,
@@ -249,6 +387,39 @@ impl RouterContext {
         Self { inner }
     }
 
+    // shares everything but the base and the routes with the parent router,
+    // whose click handler already turns clicks on links into navigations
+    fn mounted(
+        parent: &RouterContext,
+        base: &str,
+        fallback: Option<fn() -> View>,
+        trailing_slash: TrailingSlash,
+    ) -> Self {
+        let parent = &parent.inner;
+        let base_path = join_paths(&parent.base_path, base);
+
+        Self {
+            inner: Rc::new(RouterContextInner {
+                id: parent.id,
+                location: parent.location.clone(),
+                base: RouteContext::base(&base_path, fallback),
+                trailing_slash,
+                a11y: parent.a11y.clone(),
+                possible_routes: Default::default(),
+                mounted_at: Some(base_path.clone()),
+                base_path,
+                history: parent.history.clone(),
+                reference: parent.reference,
+                set_reference: parent.set_reference,
+                referrers: Rc::clone(&parent.referrers),
+                state: parent.state,
+                set_state: parent.set_state,
+                is_back: parent.is_back,
+                path_stack: parent.path_stack,
+            }),
+        }
+    }
+
     /// The current [`pathname`](https://developer.mozilla.org/en-US/docs/Web/API/Location/pathname).
     pub fn pathname(&self) -> Memo<String> {
         self.inner.location.pathname
@@ -267,6 +438,24 @@ impl RouterContext {
         self.inner.trailing_slash.clone()
     }
 
+    pub(crate) fn is_mounted(&self) -> bool {
+        self.inner.mounted_at.is_some()
+    }
+
+    pub(crate) fn mounted_at(&self) -> Option<&str> {
+        self.inner.mounted_at.as_deref()
+    }
+
+    /// The path at which the given `base` of a `<Routes/>` mounts its routes,
+    /// which is within the base of a router mounted with [`SubRouter`].
+    pub(crate) fn routes_base(&self, base: Option<String>) -> String {
+        match (&self.inner.mounted_at, base) {
+            (Some(mounted_at), Some(base)) => join_paths(mounted_at, &base),
+            (Some(mounted_at), None) => mounted_at.clone(),
+            (None, base) => base.unwrap_or_default(),
+        }
+    }
+
     /// A list of all possible routes this router can match.
     pub fn possible_branches(&self) -> Vec<Branch> {
         self.inner
@@ -328,11 +517,13 @@ impl RouterContextInner {
 
                         let resolved = resolved_to.to_string();
                         let state = options.state.clone();
-                        set_reference.update(move |r| *r = resolved);
+                        start_navigation(|| {
+                            set_reference.update(move |r| *r = resolved);
 
-                        set_state.update({
-                            let next_state = state.clone();
-                            move |state| *state = next_state
+                            set_state.update({
+                                let next_state = state.clone();
+                                move |state| *state = next_state
+                            });
                         });
 
                         let global_suspense =
@@ -345,6 +536,11 @@ impl RouterContextInner {
                             });
                         }
 
+                        #[cfg(not(feature = "ssr"))]
+                        if !options.replace {
+                            self.a11y.navigated();
+                        }
+
                         let set_is_routing = use_context::<SetIsRouting>();
                         if let Some(set_is_routing) = set_is_routing {
                             set_is_routing.0.set(true);
@@ -420,7 +616,7 @@ impl RouterContextInner {
             // let browser handle this event if link has target,
             // or if it doesn't have href or state
             // TODO "state" is set as a prop, not an attribute
-            if !target.is_empty()
+            if !(target.is_empty() || target == "_self")
                 || (href.is_empty() && !a.has_attribute("state"))
             {
                 return;
@@ -429,8 +625,12 @@ impl RouterContextInner {
             let rel = a.get_attribute("rel").unwrap_or_default();
             let mut rel = rel.split([' ', '\t']);
 
-            // let browser handle event if it has rel=external or download
-            if a.has_attribute("download") || rel.any(|p| p == "external") {
+            // let browser handle event if it has rel=external, data-external
+            // or download
+            if a.has_attribute("download")
+                || a.has_attribute("data-external")
+                || rel.any(|p| p == "external")
+            {
                 return;
             }
 
@@ -82,7 +82,7 @@ pub fn Routes(
     let router_id = router.id();
 
     let base_route = router.base();
-    let base = base.unwrap_or_default();
+    let base = router.routes_base(base);
 
     Branches::initialize(&router, &base, children());
 
@@ -164,7 +164,7 @@ pub fn AnimatedRoutes(
     let router_id = router.id();
 
     let base_route = router.base();
-    let base = base.unwrap_or_default();
+    let base = router.routes_base(base);
 
     Branches::initialize(&router, &base, children());
 
@@ -285,6 +285,7 @@ impl Branches {
             #[cfg(debug_assertions)]
             {
                 if cfg!(any(feature = "csr", feature = "hydrate"))
+                    && !router.is_mounted()
                     && !branches.borrow().is_empty()
                 {
                     leptos::logging::warn!(
@@ -584,6 +585,12 @@ pub(crate) struct RouterState {
     root: Option<RouteContext>,
 }
 
+impl RouterState {
+    pub(crate) fn matches(&self) -> &[RouteMatch] {
+        &self.matches
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct RouteData {
     // This ID is always the same as key.id.  Deprecate?
@@ -766,6 +773,7 @@ fn redirect_route_for(route: &RouteDefinition) -> Option<RouteDefinition> {
         static_mode: route.static_mode,
         static_params: None,
         trailing_slash: None, // Shouldn't be needed/used from here on out
+        meta: route.meta.clone(),
     };
 
     Some(new_route)
@@ -280,6 +280,44 @@ where
     Ok(())
 }
 
+/// Renders a single path again and replaces its file, so that a statically
+/// rendered route serves fresh HTML without restarting the server, e.g., after
+/// the data it shows has changed. The path must have its params filled in,
+/// like `/posts/1`, and is rendered the same way as [`build_static_routes`]
+/// renders it. Returns the new HTML.
+#[cfg(feature = "ssr")]
+pub async fn regenerate_static_path<IV>(
+    options: &LeptosOptions,
+    app_fn: impl Fn() -> IV + 'static + Clone,
+    additional_context: impl Fn() + 'static + Clone,
+    path: &str,
+) -> Result<String, std::io::Error>
+where
+    IV: IntoView + 'static,
+{
+    let path = format!("/{}", path.trim_start_matches('/'));
+    ResolvedStaticPath(path)
+        .write(options, app_fn, additional_context)
+        .await
+}
+
+/// Removes the file that was rendered for a single path, like `/posts/1`.
+///
+/// A route with [`StaticMode::Incremental`] is rendered again on its next
+/// request, while a route with [`StaticMode::Upfront`] responds with the
+/// not-found page until it is rebuilt with [`regenerate_static_path`]. Does
+/// nothing if the path has not been rendered.
+#[cfg(feature = "ssr")]
+pub fn invalidate_static_path(
+    options: &LeptosOptions,
+    path: &str,
+) -> Result<(), std::io::Error> {
+    match std::fs::remove_file(static_file_path(options, path)) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e),
+        _ => Ok(()),
+    }
+}
+
 pub type StaticData = Arc<StaticDataFn>;
 
 pub type StaticDataFn = dyn Fn() -> Pin<Box<dyn Future<Output = StaticParamsMap> + Send + Sync>>
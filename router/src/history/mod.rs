@@ -56,75 +56,139 @@ impl BrowserIntegration {
 
 impl History for BrowserIntegration {
     fn location(&self) -> ReadSignal<LocationChange> {
-        use crate::{NavigateOptions, RouterContext};
+        listen_to_history(Self::current)
+    }
 
-        let (location, set_location) = create_signal(Self::current());
+    fn navigate(&self, loc: &LocationChange) {
+        push_history(loc, &loc.value);
+        // scroll to el
+        scroll_to_el(loc.scroll);
+    }
+}
 
-        leptos::window_event_listener_untyped("popstate", move |_| {
-            let router = use_context::<RouterContext>();
-            if let Some(router) = router {
-                let path_stack = router.inner.path_stack;
+/// An integration that keeps the current route in the hash of the URL, like
+/// `index.html#/about`, so the page itself never changes.
+///
+/// This is useful when the app is not served by a web server that can respond
+/// to every route, like in an embedded webview that loads it from a `file://`
+/// URL or a custom URI scheme. The [`Router`](crate::Router) uses it by default
+/// when the page was not loaded over `http:` or `https:`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct HashIntegration {}
+
+impl HashIntegration {
+    fn current() -> LocationChange {
+        let hash = leptos_dom::helpers::location().hash().unwrap_or_default();
+        let value = match hash.strip_prefix('#') {
+            Some(route) if route.starts_with('/') => route.to_string(),
+            _ => "/".to_string(),
+        };
+        let state = window()
+            .history()
+            .and_then(|h| h.state())
+            .ok()
+            .and_then(|s| (!s.is_null()).then_some(s));
 
-                let is_back = router.inner.is_back;
-                let change = Self::current();
+        LocationChange {
+            value,
+            replace: true,
+            scroll: true,
+            state: State(state),
+        }
+    }
+}
 
-                let is_navigating_back = path_stack.with_value(|stack| {
-                    stack.len() == 1
-                        || (stack.len() >= 2
-                            && stack.get(stack.len() - 2)
-                                == Some(&change.value))
-                });
-                if is_navigating_back {
-                    path_stack.update_value(|stack| {
-                        stack.pop();
-                    });
-                }
+impl History for HashIntegration {
+    fn location(&self) -> ReadSignal<LocationChange> {
+        listen_to_history(Self::current)
+    }
+
+    fn navigate(&self, loc: &LocationChange) {
+        push_history(loc, &format!("#{}", loc.value));
+        if loc.scroll {
+            leptos_dom::window().scroll_to_with_x_and_y(0.0, 0.0);
+        }
+    }
+}
+
+/// Returns the integration the [`Router`](crate::Router) uses in the browser
+/// when none has been provided: a [`HashIntegration`] if the page was not
+/// loaded over `http:` or `https:`, and a [`BrowserIntegration`] otherwise.
+#[cfg(any(feature = "csr", feature = "hydrate"))]
+pub(crate) fn default_integration() -> RouterIntegrationContext {
+    let protocol = leptos_dom::helpers::location().protocol();
+    if matches!(protocol.as_deref(), Ok("http:" | "https:")) {
+        RouterIntegrationContext::new(BrowserIntegration {})
+    } else {
+        RouterIntegrationContext::new(HashIntegration {})
+    }
+}
+
+// navigates the router whenever the user goes back or forward in the history
+fn listen_to_history(
+    current: fn() -> LocationChange,
+) -> ReadSignal<LocationChange> {
+    use crate::{NavigateOptions, RouterContext};
 
-                is_back.set(is_navigating_back);
+    let (location, set_location) = create_signal(current());
 
-                request_animation_frame(move || {
-                    is_back.set(false);
+    leptos::window_event_listener_untyped("popstate", move |_| {
+        let router = use_context::<RouterContext>();
+        if let Some(router) = router {
+            let path_stack = router.inner.path_stack;
+
+            let is_back = router.inner.is_back;
+            let change = current();
+
+            let is_navigating_back = path_stack.with_value(|stack| {
+                stack.len() == 1
+                    || (stack.len() >= 2
+                        && stack.get(stack.len() - 2) == Some(&change.value))
+            });
+            if is_navigating_back {
+                path_stack.update_value(|stack| {
+                    stack.pop();
                 });
-                if let Err(e) = router.inner.navigate_from_route(
-                    &change.value,
-                    &NavigateOptions {
-                        resolve: false,
-                        replace: change.replace,
-                        scroll: change.scroll,
-                        state: change.state,
-                    },
-                ) {
-                    leptos::logging::error!("{e:#?}");
-                }
-                set_location.set(Self::current());
-            } else {
-                leptos::logging::warn!("RouterContext not found");
             }
-        });
 
-        location
-    }
+            is_back.set(is_navigating_back);
 
-    fn navigate(&self, loc: &LocationChange) {
-        let history = leptos_dom::window().history().unwrap_throw();
-
-        if loc.replace {
-            history
-                .replace_state_with_url(
-                    &loc.state.to_js_value(),
-                    "",
-                    Some(&loc.value),
-                )
-                .unwrap_throw();
+            request_animation_frame(move || {
+                is_back.set(false);
+            });
+            if let Err(e) = router.inner.navigate_from_route(
+                &change.value,
+                &NavigateOptions {
+                    resolve: false,
+                    replace: change.replace,
+                    scroll: change.scroll,
+                    state: change.state,
+                },
+            ) {
+                leptos::logging::error!("{e:#?}");
+            }
+            set_location.set(current());
         } else {
-            // push the "forward direction" marker
-            let state = &loc.state.to_js_value();
-            history
-                .push_state_with_url(state, "", Some(&loc.value))
-                .unwrap_throw();
+            leptos::logging::warn!("RouterContext not found");
         }
-        // scroll to el
-        scroll_to_el(loc.scroll);
+    });
+
+    location
+}
+
+fn push_history(loc: &LocationChange, url: &str) {
+    let history = leptos_dom::window().history().unwrap_throw();
+
+    if loc.replace {
+        history
+            .replace_state_with_url(&loc.state.to_js_value(), "", Some(url))
+            .unwrap_throw();
+    } else {
+        // push the "forward direction" marker
+        let state = &loc.state.to_js_value();
+        history
+            .push_state_with_url(state, "", Some(url))
+            .unwrap_throw();
     }
 }
 
@@ -1,6 +1,6 @@
 use crate::{
-    Location, NavigateOptions, Params, ParamsError, ParamsMap, RouteContext,
-    RouterContext,
+    join_paths, Location, NavigateOptions, Params, ParamsError, ParamsMap,
+    RouteContext, RouteMeta, RouterContext, RouterState,
 };
 use leptos::{
     request_animation_frame, signal_prelude::*, use_context, window, Oco,
@@ -129,6 +129,68 @@ pub fn use_route_data<T: Clone + 'static>() -> Option<T> {
     downcast
 }
 
+/// A route that matches the current URL, as returned by [`use_matched_routes`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchedRoute {
+    /// The part of the URL path matched by this route, with params filled in,
+    /// e.g., `/users/42`.
+    pub path: String,
+    /// The full path pattern of this route, e.g., `/users/:id`.
+    pub pattern: String,
+    /// The params matched by this route and its parents.
+    pub params: ParamsMap,
+    /// The metadata attached to this route.
+    pub meta: RouteMeta,
+}
+
+/// Returns the chain of routes that match the current URL, from the outermost
+/// to the innermost, along with their metadata and params.
+///
+/// This must be called inside [`<Routes/>`](crate::Routes), e.g., in the view
+/// of a parent route. Otherwise, it returns an empty list.
+///
+/// ```rust
+/// # use leptos::*; use leptos_router::*;
+/// struct BreadcrumbLabel(&'static str);
+///
+/// #[component]
+/// fn Breadcrumbs() -> impl IntoView {
+///     let routes = use_matched_routes();
+///     move || {
+///         routes
+///             .get()
+///             .into_iter()
+///             .filter_map(|route| {
+///                 let label = route.meta.get::<BreadcrumbLabel>()?.0;
+///                 Some(view! { <A href=route.path>{label}</A> })
+///             })
+///             .collect_view()
+///     }
+/// }
+/// ```
+#[track_caller]
+pub fn use_matched_routes() -> Memo<Vec<MatchedRoute>> {
+    let state = use_context::<Memo<RouterState>>();
+    create_memo(move |_| {
+        state
+            .map(|state| {
+                state.with(|state| {
+                    state
+                        .matches()
+                        .iter()
+                        .map(|matched| MatchedRoute {
+                            path: matched.path_match.path.clone(),
+                            pattern: matched.route.pattern.clone(),
+                            params: matched.path_match.params.clone(),
+                            meta: matched.route.key.meta.clone(),
+                        })
+                        .collect()
+                })
+            })
+            .unwrap_or_default()
+    })
+}
+
 /// Returns the current [`Location`], which contains reactive variables
 #[track_caller]
 pub fn use_location() -> Location {
@@ -174,11 +236,17 @@ pub fn use_resolved_path(
     path: impl Fn() -> String + 'static,
 ) -> Memo<Option<String>> {
     let route = use_route();
+    // absolute paths stay within the base of a <SubRouter/>
+    let mounted_at = use_context::<RouterContext>()
+        .and_then(|router| router.mounted_at().map(String::from));
 
     create_memo(move |_| {
         let path = path();
         if path.starts_with('/') {
-            Some(path)
+            Some(match &mounted_at {
+                Some(mounted_at) => join_paths(mounted_at, &path),
+                None => path,
+            })
         } else {
             route.resolve_path_tracked(&path)
         }
@@ -189,6 +189,7 @@
 // to prevent warnings from popping up when a nightly feature is stabilized
 #![allow(stable_features)]
 
+mod a11y;
 mod animation;
 mod components;
 #[cfg(any(feature = "ssr", doc))]
@@ -198,6 +199,9 @@ mod hooks;
 #[doc(hidden)]
 pub mod matching;
 mod render_mode;
+mod service_worker;
+mod web_push;
+pub use a11y::*;
 pub use components::*;
 #[cfg(any(feature = "ssr", doc))]
 pub use extract_routes::*;
@@ -205,4 +209,6 @@ pub use history::*;
 pub use hooks::*;
 pub use matching::{RouteDefinition, *};
 pub use render_mode::*;
+pub use service_worker::*;
+pub use web_push::*;
 extern crate tracing;
@@ -1,6 +1,10 @@
 use crate::{Loader, Method, SsrMode, StaticData, StaticMode, TrailingSlash};
 use leptos::leptos_dom::View;
-use std::rc::Rc;
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    rc::Rc,
+};
 
 /// Defines a single route in a nested route tree. This is the return
 /// type of the [`<Route/>`](crate::Route) component, but can also be
@@ -27,6 +31,18 @@ pub struct RouteDefinition {
     pub static_params: Option<StaticData>,
     /// How a trailng slash in `path` should be handled.
     pub trailing_slash: Option<TrailingSlash>,
+    /// Arbitrary typed metadata attached to this route.
+    pub meta: RouteMeta,
+}
+
+impl RouteDefinition {
+    /// Attaches a value to this route’s [`RouteMeta`], replacing any value of
+    /// the same type. It can be read with
+    /// [`use_matched_routes`](crate::use_matched_routes).
+    pub fn meta<T: 'static>(mut self, value: T) -> Self {
+        self.meta = self.meta.with(value);
+        self
+    }
 }
 
 impl core::fmt::Debug for RouteDefinition {
@@ -37,6 +53,7 @@ impl core::fmt::Debug for RouteDefinition {
             .field("ssr_mode", &self.ssr_mode)
             .field("static_render", &self.static_mode)
             .field("trailing_slash", &self.trailing_slash)
+            .field("meta", &self.meta)
             .finish()
     }
 }
@@ -46,3 +63,73 @@ impl PartialEq for RouteDefinition {
         self.path == other.path && self.children == other.children
     }
 }
+
+/// Arbitrary typed metadata attached to a route, holding at most one value of
+/// each type.
+///
+/// This can be used to describe a route for generic components like
+/// breadcrumbs, sidebars, or permission banners, which read the metadata of
+/// all matched routes with [`use_matched_routes`](crate::use_matched_routes).
+///
+/// ```rust
+/// # use leptos::*; use leptos_router::*;
+/// # if false {
+/// struct BreadcrumbLabel(&'static str);
+///
+/// view! {
+///   <Routes>
+///     <Route
+///       path="/settings"
+///       view=|| view! { <p>"Settings"</p> }
+///       meta=RouteMeta::new().with(BreadcrumbLabel("Settings"))
+///     />
+///   </Routes>
+/// }
+/// # ;}
+/// ```
+#[derive(Clone, Default)]
+pub struct RouteMeta(Rc<HashMap<TypeId, Rc<dyn Any>>>);
+
+impl RouteMeta {
+    /// Creates empty metadata.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a value, replacing any value of the same type.
+    pub fn with<T: 'static>(mut self, value: T) -> Self {
+        Rc::make_mut(&mut self.0).insert(TypeId::of::<T>(), Rc::new(value));
+        self
+    }
+
+    /// Returns the value of type `T`, if any.
+    pub fn get<T: 'static>(&self) -> Option<&T> {
+        self.0
+            .get(&TypeId::of::<T>())
+            .and_then(|value| value.downcast_ref())
+    }
+
+    /// Whether there is a value of type `T`.
+    pub fn contains<T: 'static>(&self) -> bool {
+        self.0.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Whether there is no metadata.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl PartialEq for RouteMeta {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl core::fmt::Debug for RouteMeta {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RouteMeta")
+            .field("len", &self.0.len())
+            .finish()
+    }
+}
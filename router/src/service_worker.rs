@@ -0,0 +1,466 @@
+use leptos::*;
+#[cfg(feature = "ssr")]
+use {
+    crate::RouteListing,
+    std::{
+        hash::{Hash, Hasher},
+        io,
+        path::{Path, PathBuf},
+    },
+};
+
+/// Whether the service worker passed to [`use_service_worker`] has been
+/// registered.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ServiceWorkerStatus {
+    /// The service worker is being registered. This is also the status while
+    /// rendering on the server.
+    Registering,
+    /// The service worker has been registered.
+    Registered,
+    /// The browser does not support service workers, or the page was not
+    /// loaded in a secure context.
+    Unsupported,
+    /// Registering the service worker failed. The error is logged.
+    Failed,
+}
+
+/// The state of the service worker registered by [`use_service_worker`].
+#[derive(Copy, Clone, Debug)]
+pub struct ServiceWorker {
+    /// Whether the service worker has been registered.
+    pub status: Signal<ServiceWorkerStatus>,
+    /// `true` once a new version of the service worker has been installed,
+    /// which will be used after [`ServiceWorker::reload`] is called.
+    pub update_available: Signal<bool>,
+    #[cfg(not(feature = "ssr"))]
+    waiting: StoredValue<Option<web_sys::ServiceWorker>>,
+}
+
+impl ServiceWorker {
+    /// Reloads the page. If a new version of the service worker is waiting,
+    /// it is activated first, so that the page is loaded with the new version
+    /// of the app.
+    pub fn reload(&self) {
+        #[cfg(not(feature = "ssr"))]
+        {
+            use wasm_bindgen::{closure::Closure, JsCast};
+
+            let Some(waiting) = self.waiting.get_value() else {
+                _ = window().location().reload();
+                return;
+            };
+            let reload = Closure::once_into_js(|| {
+                _ = window().location().reload();
+            });
+            window()
+                .navigator()
+                .service_worker()
+                .set_oncontrollerchange(Some(reload.unchecked_ref()));
+            _ = waiting.post_message(&"skip-waiting".into());
+        }
+    }
+}
+
+/// Registers the service worker at `script_url`, like the one written by
+/// [`generate_service_worker`], and returns its state.
+///
+/// When a new version of the app has been deployed, the browser installs the
+/// new service worker in the background, and [`ServiceWorker::update_available`]
+/// becomes `true`. The new version is used once the user reloads the page
+/// with [`ServiceWorker::reload`].
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_router::*;
+/// #[component]
+/// fn UpdateBanner() -> impl IntoView {
+///     let service_worker = use_service_worker("/sw.js");
+///     view! {
+///         <Show when=move || service_worker.update_available.get()>
+///             <p>
+///                 "A new version is available. "
+///                 <button on:click=move |_| service_worker.reload()>
+///                     "Reload"
+///                 </button>
+///             </p>
+///         </Show>
+///     }
+/// }
+/// ```
+pub fn use_service_worker(script_url: &str) -> ServiceWorker {
+    let (status, set_status) = create_signal(ServiceWorkerStatus::Registering);
+    let (update_available, set_update_available) = create_signal(false);
+
+    #[cfg(feature = "ssr")]
+    {
+        _ = (script_url, set_status, set_update_available);
+        ServiceWorker {
+            status: status.into(),
+            update_available: update_available.into(),
+        }
+    }
+    #[cfg(not(feature = "ssr"))]
+    {
+        let waiting = store_value(None);
+        register(script_url.to_string(), set_status, move |worker| {
+            waiting.set_value(Some(worker));
+            set_update_available.set(true);
+        });
+        ServiceWorker {
+            status: status.into(),
+            update_available: update_available.into(),
+            waiting,
+        }
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+fn register(
+    script_url: String,
+    set_status: WriteSignal<ServiceWorkerStatus>,
+    on_update: impl Fn(web_sys::ServiceWorker) + Clone + 'static,
+) {
+    use wasm_bindgen::{closure::Closure, JsCast};
+    use wasm_bindgen_futures::JsFuture;
+
+    let navigator = window().navigator();
+    if !js_sys::Reflect::has(&navigator, &"serviceWorker".into())
+        .unwrap_or(false)
+    {
+        set_status.set(ServiceWorkerStatus::Unsupported);
+        return;
+    }
+    let container = navigator.service_worker();
+
+    spawn_local(async move {
+        let registration = match JsFuture::from(container.register(&script_url))
+            .await
+        {
+            Ok(registration) => registration
+                .unchecked_into::<web_sys::ServiceWorkerRegistration>(),
+            Err(e) => {
+                leptos::logging::error!(
+                    "failed to register the service worker at {script_url}: \
+                     {e:?}"
+                );
+                set_status.set(ServiceWorkerStatus::Failed);
+                return;
+            }
+        };
+        set_status.set(ServiceWorkerStatus::Registered);
+
+        // without a controller, this is the first install, not an update
+        let is_update = move || {
+            window().navigator().service_worker().controller().is_some()
+        };
+        // a new version that was installed before this page was loaded
+        if let Some(waiting) = registration.waiting() {
+            if is_update() {
+                on_update(waiting);
+            }
+        }
+
+        let on_update_found = Closure::<dyn Fn()>::new({
+            let registration = registration.clone();
+            move || {
+                let Some(installing) = registration.installing() else {
+                    return;
+                };
+                let on_state_change = Closure::<dyn Fn()>::new({
+                    let installing = installing.clone();
+                    let on_update = on_update.clone();
+                    move || {
+                        if installing.state()
+                            == web_sys::ServiceWorkerState::Installed
+                            && is_update()
+                        {
+                            on_update(installing.clone());
+                        }
+                    }
+                });
+                installing.set_onstatechange(Some(
+                    on_state_change.as_ref().unchecked_ref(),
+                ));
+                on_state_change.forget();
+            }
+        });
+        registration
+            .set_onupdatefound(Some(on_update_found.as_ref().unchecked_ref()));
+        on_update_found.forget();
+    });
+}
+
+/// Configures the service worker written by [`generate_service_worker`].
+#[cfg(feature = "ssr")]
+#[derive(Clone, Debug)]
+pub struct ServiceWorkerOptions {
+    /// The name of the service worker script, written to the site root.
+    /// Defaults to `sw.js`.
+    pub file_name: String,
+    /// The routes that make up the app shell, which are cached when the
+    /// service worker is installed. Defaults to `/`.
+    pub shell_routes: Vec<String>,
+    /// A route of the app that is shown when the user navigates to a page
+    /// while offline. It is cached along with the app shell, and must be one
+    /// of the routes passed to [`generate_service_worker`].
+    pub offline_route: Option<String>,
+}
+
+#[cfg(feature = "ssr")]
+impl Default for ServiceWorkerOptions {
+    fn default() -> Self {
+        Self {
+            file_name: "sw.js".to_string(),
+            shell_routes: vec!["/".to_string()],
+            offline_route: None,
+        }
+    }
+}
+
+/// A file or page that a service worker caches when it is installed, as
+/// listed in a [`PrecacheManifest`].
+#[cfg(feature = "ssr")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrecacheEntry {
+    /// The URL that is cached.
+    pub url: String,
+    /// A hash of the contents of a static file, or `None` for a page that is
+    /// rendered by the server.
+    pub revision: Option<String>,
+}
+
+/// The files and pages cached by the service worker written by
+/// [`generate_service_worker`].
+#[cfg(feature = "ssr")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrecacheManifest {
+    /// A hash of every entry. It changes whenever a static file changes, so
+    /// browsers install the new service worker.
+    pub version: String,
+    /// The cached files and pages.
+    pub entries: Vec<PrecacheEntry>,
+}
+
+/// Writes a service worker script and a `precache-manifest.json` to the site
+/// root. Like [`build_static_routes`](crate::build_static_routes), this is
+/// meant to be called when the server starts, or in a build step, after the
+/// site has been built.
+///
+/// The service worker caches the app shell routes, the offline route, and
+/// every file in the site's package directory, like the WASM and JS bundles
+/// and the stylesheet. It then serves those files from its cache, loads pages
+/// from the network, and falls back to the cached page, or the offline route,
+/// when the network is unavailable. It also shows the push notifications
+/// that the app subscribes to with [`use_web_push`](crate::use_web_push).
+/// Register it in the app with [`use_service_worker`].
+///
+/// ```no_run
+/// # use leptos::*;
+/// # use leptos_router::*;
+/// # fn app() -> impl IntoView {}
+/// # let leptos_options = LeptosOptions::builder().output_name("app").build();
+/// let (routes, _static_data) = generate_route_list_inner(app);
+/// generate_service_worker(
+///     &leptos_options,
+///     &routes,
+///     &ServiceWorkerOptions {
+///         offline_route: Some("/offline".to_string()),
+///         ..Default::default()
+///     },
+/// )
+/// .expect("could not write the service worker");
+/// ```
+#[cfg(feature = "ssr")]
+pub fn generate_service_worker(
+    options: &LeptosOptions,
+    routes: &[RouteListing],
+    sw_options: &ServiceWorkerOptions,
+) -> io::Result<PrecacheManifest> {
+    if let Some(offline_route) = &sw_options.offline_route {
+        if !routes.iter().any(|route| route.path() == offline_route) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "the offline route {offline_route} is not one of the \
+                     app's routes"
+                ),
+            ));
+        }
+    }
+
+    let mut entries = sw_options
+        .shell_routes
+        .iter()
+        .chain(&sw_options.offline_route)
+        .map(|route| PrecacheEntry {
+            url: route.clone(),
+            revision: None,
+        })
+        .collect::<Vec<_>>();
+    let site_root = Path::new(&options.site_root);
+    let mut files = Vec::new();
+    collect_files(&site_root.join(&options.site_pkg_dir), &mut files)?;
+    files.sort();
+    for file in files {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        std::fs::read(&file)?.hash(&mut hasher);
+        let path = file.strip_prefix(site_root).unwrap_or(&file);
+        let url = path
+            .components()
+            .map(|part| part.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        entries.push(PrecacheEntry {
+            url: format!("/{url}"),
+            revision: Some(format!("{:016x}", hasher.finish())),
+        });
+    }
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    for entry in &entries {
+        entry.url.hash(&mut hasher);
+        entry.revision.hash(&mut hasher);
+    }
+    let manifest = PrecacheManifest {
+        version: format!("{:016x}", hasher.finish()),
+        entries,
+    };
+
+    let entries = serde_json::Value::Array(
+        manifest
+            .entries
+            .iter()
+            .map(|entry| {
+                serde_json::json!({
+                    "url": entry.url,
+                    "revision": entry.revision,
+                })
+            })
+            .collect(),
+    );
+    std::fs::write(
+        site_root.join("precache-manifest.json"),
+        serde_json::to_string_pretty(&serde_json::json!({
+            "version": manifest.version,
+            "entries": entries,
+        }))?,
+    )?;
+    std::fs::write(
+        site_root.join(&sw_options.file_name),
+        SERVICE_WORKER
+            .replace("__VERSION__", &manifest.version)
+            .replace(
+                "__URLS__",
+                &serde_json::to_string(
+                    &manifest
+                        .entries
+                        .iter()
+                        .map(|entry| &entry.url)
+                        .collect::<Vec<_>>(),
+                )?,
+            )
+            .replace(
+                "__OFFLINE__",
+                &serde_json::to_string(&sw_options.offline_route)?,
+            ),
+    )?;
+
+    Ok(manifest)
+}
+
+#[cfg(feature = "ssr")]
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_files(&path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(feature = "ssr")]
+const SERVICE_WORKER: &str = r#"const CACHE = "leptos-precache-__VERSION__";
+const URLS = __URLS__;
+const OFFLINE = __OFFLINE__;
+
+self.addEventListener("install", (event) => {
+  event.waitUntil(caches.open(CACHE).then((cache) => cache.addAll(URLS)));
+});
+
+self.addEventListener("activate", (event) => {
+  event.waitUntil(
+    caches
+      .keys()
+      .then((keys) =>
+        Promise.all(
+          keys
+            .filter((key) => key.startsWith("leptos-precache-") && key !== CACHE)
+            .map((key) => caches.delete(key))
+        )
+      )
+      .then(() => self.clients.claim())
+  );
+});
+
+self.addEventListener("message", (event) => {
+  if (event.data === "skip-waiting") {
+    self.skipWaiting();
+  }
+});
+
+self.addEventListener("push", (event) => {
+  let data = {};
+  try {
+    data = event.data ? event.data.json() : {};
+  } catch (e) {
+    data = { title: event.data.text() };
+  }
+  event.waitUntil(self.registration.showNotification(data.title || "", data));
+});
+
+self.addEventListener("notificationclick", (event) => {
+  event.notification.close();
+  const data = event.notification.data;
+  event.waitUntil(self.clients.openWindow((data && data.url) || "/"));
+});
+
+self.addEventListener("pushsubscriptionchange", (event) => {
+  event.waitUntil(
+    self.registration.pushManager
+      .subscribe(event.oldSubscription.options)
+      .then((subscription) => self.clients.matchAll().then((clients) => {
+        for (const client of clients) {
+          client.postMessage({
+            type: "leptos-push-subscription-change",
+            subscription: subscription.toJSON(),
+          });
+        }
+      }))
+  );
+});
+
+self.addEventListener("fetch", (event) => {
+  const request = event.request;
+  if (request.method !== "GET") {
+    return;
+  }
+  if (request.mode === "navigate") {
+    event.respondWith(
+      fetch(request).catch(() =>
+        caches
+          .match(request)
+          .then((cached) => cached || (OFFLINE && caches.match(OFFLINE)))
+          .then((cached) => cached || Response.error())
+      )
+    );
+  } else {
+    event.respondWith(
+      caches.match(request).then((cached) => cached || fetch(request))
+    );
+  }
+});
+"#;
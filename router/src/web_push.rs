@@ -0,0 +1,334 @@
+use leptos::*;
+use serde::{Deserialize, Serialize};
+
+/// Whether the user allows the app to show notifications, as tracked by
+/// [`use_web_push`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PushPermission {
+    /// The user has not been asked yet. This is also the permission while
+    /// rendering on the server.
+    Default,
+    /// The user allows notifications.
+    Granted,
+    /// The user has blocked notifications.
+    Denied,
+    /// The browser does not support push notifications.
+    Unsupported,
+}
+
+/// A push subscription in the format of the browser's
+/// [`PushSubscription.toJSON()`](https://developer.mozilla.org/en-US/docs/Web/API/PushSubscription/toJSON),
+/// which can be sent to a server function and used there to send
+/// notifications, for example with the `web-push` crate.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PushSubscription {
+    /// The URL of the push service that notifications are sent to.
+    pub endpoint: String,
+    /// When the subscription expires, in milliseconds since the Unix epoch.
+    pub expiration_time: Option<f64>,
+    /// The keys that notifications are encrypted with.
+    pub keys: PushSubscriptionKeys,
+}
+
+/// The keys of a [`PushSubscription`], encoded as base64url.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PushSubscriptionKeys {
+    /// The public key of the subscription.
+    pub p256dh: String,
+    /// The authentication secret of the subscription.
+    pub auth: String,
+}
+
+/// The push notification state returned by [`use_web_push`].
+#[derive(Copy, Clone, Debug)]
+pub struct WebPush {
+    /// Whether the user allows notifications.
+    pub permission: Signal<PushPermission>,
+    /// The current subscription of this browser, if any. This also changes
+    /// when the push service replaces the subscription.
+    pub subscription: Signal<Option<PushSubscription>>,
+    /// The last error that happened while subscribing or unsubscribing.
+    pub error: Signal<Option<String>>,
+    #[cfg(not(feature = "ssr"))]
+    set_permission: WriteSignal<PushPermission>,
+    #[cfg(not(feature = "ssr"))]
+    set_subscription: WriteSignal<Option<PushSubscription>>,
+    #[cfg(not(feature = "ssr"))]
+    set_error: WriteSignal<Option<String>>,
+    #[cfg(not(feature = "ssr"))]
+    vapid_public_key: StoredValue<String>,
+}
+
+impl WebPush {
+    /// Asks the user for permission to show notifications if needed, and
+    /// subscribes this browser to push messages.
+    ///
+    /// Browsers only allow asking for permission in response to a user
+    /// action, like a click.
+    pub fn subscribe(&self) {
+        #[cfg(not(feature = "ssr"))]
+        {
+            let this = *self;
+            spawn_local(async move {
+                match client::subscribe(this.vapid_public_key.get_value()).await
+                {
+                    Ok((permission, subscription)) => {
+                        this.set_permission.set(permission);
+                        if subscription.is_some() {
+                            this.set_subscription.set(subscription);
+                        }
+                        this.set_error.set(None);
+                    }
+                    Err(e) => this.set_error.set(Some(e)),
+                }
+            });
+        }
+    }
+
+    /// Unsubscribes this browser from push messages.
+    pub fn unsubscribe(&self) {
+        #[cfg(not(feature = "ssr"))]
+        {
+            let this = *self;
+            spawn_local(async move {
+                match client::unsubscribe().await {
+                    Ok(()) => {
+                        this.set_subscription.set(None);
+                        this.set_error.set(None);
+                    }
+                    Err(e) => this.set_error.set(Some(e)),
+                }
+            });
+        }
+    }
+}
+
+/// Subscribes the browser to push notifications through the service worker
+/// registered with [`use_service_worker`](crate::use_service_worker), whose
+/// generated script shows the notifications it receives.
+///
+/// `vapid_public_key` is the base64url-encoded public key of the server that
+/// sends the notifications. On the server, this does nothing, and the
+/// permission stays [`PushPermission::Default`].
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_router::*;
+/// # async fn save_subscription(_: PushSubscription) {}
+/// #[component]
+/// fn Notifications() -> impl IntoView {
+///     let push = use_web_push("BEl62iUYgUivxIkv69yViEuiBIa...");
+///     // send new subscriptions to the server, which sends the notifications
+///     create_effect(move |_| {
+///         if let Some(subscription) = push.subscription.get() {
+///             spawn_local(async move {
+///                 save_subscription(subscription).await;
+///             });
+///         }
+///     });
+///     view! {
+///         <button on:click=move |_| push.subscribe()>
+///             "Turn on notifications"
+///         </button>
+///     }
+/// }
+/// ```
+pub fn use_web_push(vapid_public_key: &str) -> WebPush {
+    let (permission, set_permission) = create_signal(PushPermission::Default);
+    let (subscription, set_subscription) = create_signal(None);
+    let (error, set_error) = create_signal(None);
+
+    #[cfg(feature = "ssr")]
+    let _ = (
+        vapid_public_key,
+        set_permission,
+        set_subscription,
+        set_error,
+    );
+    #[cfg(not(feature = "ssr"))]
+    {
+        if client::is_supported() {
+            set_permission.set(client::permission());
+            spawn_local(async move {
+                match client::current_subscription().await {
+                    Ok(current) => set_subscription.set(current),
+                    Err(e) => set_error.set(Some(e)),
+                }
+            });
+            client::on_subscription_change(move |new| {
+                set_subscription.set(Some(new))
+            });
+        } else {
+            set_permission.set(PushPermission::Unsupported);
+        }
+    }
+
+    WebPush {
+        permission: permission.into(),
+        subscription: subscription.into(),
+        error: error.into(),
+        #[cfg(not(feature = "ssr"))]
+        set_permission,
+        #[cfg(not(feature = "ssr"))]
+        set_subscription,
+        #[cfg(not(feature = "ssr"))]
+        set_error,
+        #[cfg(not(feature = "ssr"))]
+        vapid_public_key: store_value(vapid_public_key.to_string()),
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+mod client {
+    use super::{PushPermission, PushSubscription};
+    use leptos::{on_cleanup, window};
+    use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+    use wasm_bindgen_futures::JsFuture;
+    use web_sys::{NotificationPermission, ServiceWorkerRegistration};
+
+    pub fn is_supported() -> bool {
+        let window = window();
+        ["Notification", "PushManager"]
+            .into_iter()
+            .all(|name| js_sys::Reflect::has(&window, &name.into()) == Ok(true))
+            && js_sys::Reflect::has(
+                &window.navigator(),
+                &"serviceWorker".into(),
+            ) == Ok(true)
+    }
+
+    pub fn permission() -> PushPermission {
+        match web_sys::Notification::permission() {
+            NotificationPermission::Granted => PushPermission::Granted,
+            NotificationPermission::Denied => PushPermission::Denied,
+            _ => PushPermission::Default,
+        }
+    }
+
+    pub async fn current_subscription(
+    ) -> Result<Option<PushSubscription>, String> {
+        let registration = ready().await?;
+        let subscription = JsFuture::from(
+            registration
+                .push_manager()
+                .and_then(|push| push.get_subscription())
+                .map_err(js_error)?,
+        )
+        .await
+        .map_err(js_error)?;
+        if subscription.is_null() || subscription.is_undefined() {
+            Ok(None)
+        } else {
+            deserialize(&subscription).map(Some)
+        }
+    }
+
+    pub async fn subscribe(
+        vapid_public_key: String,
+    ) -> Result<(PushPermission, Option<PushSubscription>), String> {
+        if !is_supported() {
+            return Ok((PushPermission::Unsupported, None));
+        }
+        JsFuture::from(
+            web_sys::Notification::request_permission().map_err(js_error)?,
+        )
+        .await
+        .map_err(js_error)?;
+        let permission = permission();
+        if permission != PushPermission::Granted {
+            return Ok((permission, None));
+        }
+
+        let registration = ready().await?;
+        let options = web_sys::PushSubscriptionOptionsInit::new();
+        options.set_user_visible_only(true);
+        options.set_application_server_key(&vapid_public_key.into());
+        let subscription = JsFuture::from(
+            registration
+                .push_manager()
+                .and_then(|push| push.subscribe_with_options(&options))
+                .map_err(js_error)?,
+        )
+        .await
+        .map_err(js_error)?;
+        Ok((permission, Some(deserialize(&subscription)?)))
+    }
+
+    pub async fn unsubscribe() -> Result<(), String> {
+        let registration = ready().await?;
+        let subscription = JsFuture::from(
+            registration
+                .push_manager()
+                .and_then(|push| push.get_subscription())
+                .map_err(js_error)?,
+        )
+        .await
+        .map_err(js_error)?;
+        if let Some(subscription) =
+            subscription.dyn_ref::<web_sys::PushSubscription>()
+        {
+            JsFuture::from(subscription.unsubscribe().map_err(js_error)?)
+                .await
+                .map_err(js_error)?;
+        }
+        Ok(())
+    }
+
+    /// Calls `f` when the service worker reports that the push service has
+    /// replaced the subscription.
+    pub fn on_subscription_change(f: impl Fn(PushSubscription) + 'static) {
+        let container = window().navigator().service_worker();
+        let listener = Closure::<dyn Fn(web_sys::MessageEvent)>::new(
+            move |ev: web_sys::MessageEvent| {
+                let data = ev.data();
+                let is_change = js_sys::Reflect::get(&data, &"type".into())
+                    .ok()
+                    .and_then(|ty| ty.as_string())
+                    .is_some_and(|ty| ty == "leptos-push-subscription-change");
+                if !is_change {
+                    return;
+                }
+                if let Ok(subscription) =
+                    js_sys::Reflect::get(&data, &"subscription".into())
+                        .map_err(js_error)
+                        .and_then(|subscription| deserialize(&subscription))
+                {
+                    f(subscription);
+                }
+            },
+        )
+        .into_js_value();
+        _ = container.add_event_listener_with_callback(
+            "message",
+            listener.unchecked_ref(),
+        );
+        on_cleanup(move || {
+            _ = container.remove_event_listener_with_callback(
+                "message",
+                listener.unchecked_ref(),
+            );
+        });
+    }
+
+    async fn ready() -> Result<ServiceWorkerRegistration, String> {
+        let container = window().navigator().service_worker();
+        JsFuture::from(container.ready().map_err(js_error)?)
+            .await
+            .map(JsCast::unchecked_into)
+            .map_err(js_error)
+    }
+
+    // reads a `PushSubscription`, or the object returned by its `toJSON()`
+    fn deserialize(subscription: &JsValue) -> Result<PushSubscription, String> {
+        let json = js_sys::JSON::stringify(subscription)
+            .map_err(js_error)?
+            .as_string()
+            .unwrap_or_default();
+        serde_json::from_str(&json).map_err(|e| e.to_string())
+    }
+
+    fn js_error(e: JsValue) -> String {
+        e.as_string().unwrap_or_else(|| format!("{e:?}"))
+    }
+}
@@ -0,0 +1,55 @@
+#[cfg(feature = "ssr")]
+#[test]
+fn matched_routes_expose_meta_and_params() {
+    use leptos::*;
+    use leptos_router::*;
+
+    struct BreadcrumbLabel(&'static str);
+
+    #[component]
+    fn Breadcrumbs() -> impl IntoView {
+        let routes = use_matched_routes();
+        move || {
+            routes
+                .get()
+                .into_iter()
+                .filter_map(|route| {
+                    let label = route.meta.get::<BreadcrumbLabel>()?.0;
+                    let id =
+                        route.params.get("id").cloned().unwrap_or_default();
+                    Some(format!("[{label}{id} {}]", route.path))
+                })
+                .collect::<String>()
+        }
+    }
+
+    let runtime = create_runtime();
+    provide_context(RouterIntegrationContext::new(ServerIntegration {
+        path: "http://leptos.rs/users/42".to_string(),
+    }));
+    let html = view! {
+        <Router>
+            <Routes>
+                <Route
+                    path="/users"
+                    view=|| view! { <Breadcrumbs/> <Outlet/> }
+                    meta=RouteMeta::new().with(BreadcrumbLabel("Users"))
+                >
+                    <Route
+                        path=":id"
+                        view=|| ()
+                        meta=RouteMeta::new().with(BreadcrumbLabel("User "))
+                    />
+                </Route>
+            </Routes>
+        </Router>
+    }
+    .into_view()
+    .render_to_string();
+
+    assert!(
+        html.contains("[Users &#x2F;users][User 42 &#x2F;users&#x2F;42]"),
+        "{html}"
+    );
+    runtime.dispose();
+}
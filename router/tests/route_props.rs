@@ -0,0 +1,43 @@
+#[cfg(feature = "ssr")]
+#[test]
+fn route_props_are_parsed_from_the_url() {
+    use leptos::*;
+    use leptos_router::*;
+
+    #[component]
+    fn Post(
+        #[prop(param)] id: u64,
+        #[prop(query)] page: Option<u32>,
+    ) -> impl IntoView {
+        view! { <p>"Post " {id} ", page " {page.unwrap_or(1)}</p> }
+    }
+
+    let render = |path: &'static str| {
+        leptos::ssr::render_to_string(move || {
+            provide_context(RouterIntegrationContext::new(ServerIntegration {
+                path: format!("http://leptos.dev{path}"),
+            }));
+            view! {
+                <Router>
+                    <ErrorBoundary fallback=|_| "Not found">
+                        <Routes>
+                            <Route path="/posts/:id" view=Post/>
+                        </Routes>
+                    </ErrorBoundary>
+                </Router>
+            }
+        })
+        .to_string()
+    };
+
+    let html = render("/posts/42");
+    assert!(html.contains("Post 42, page 1"));
+    assert!(!html.contains("Not found"));
+
+    let html = render("/posts/42?page=3");
+    assert!(html.contains("Post 42, page 3"));
+
+    let html = render("/posts/latest");
+    assert!(html.contains("Not found"));
+    assert!(!html.contains("<p"));
+}
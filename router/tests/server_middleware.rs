@@ -0,0 +1,51 @@
+#[cfg(feature = "ssr")]
+#[test]
+fn server_middleware_runs_before_rendering_the_route() {
+    use leptos::*;
+    use leptos_router::*;
+    use std::{cell::RefCell, rc::Rc};
+
+    fn auth_required() -> MiddlewareOutcome {
+        MiddlewareOutcome::Redirect("/login".to_string())
+    }
+
+    let render = |path: &'static str| {
+        let redirected_to = Rc::new(RefCell::new(None));
+        let html = leptos::ssr::render_to_string({
+            let redirected_to = Rc::clone(&redirected_to);
+            move || {
+                provide_context(RouterIntegrationContext::new(
+                    ServerIntegration {
+                        path: format!("http://leptos.dev{path}"),
+                    },
+                ));
+                provide_server_redirect(move |path| {
+                    *redirected_to.borrow_mut() = Some(path.to_string());
+                });
+                view! {
+                    <Router>
+                        <Routes>
+                            <Route path="/" view=|| "Home"/>
+                            <Route
+                                path="/admin"
+                                view=|| "Admin"
+                                server_middleware=auth_required
+                            />
+                        </Routes>
+                    </Router>
+                }
+            }
+        })
+        .to_string();
+        let redirected_to = redirected_to.borrow().clone();
+        (html, redirected_to)
+    };
+
+    let (html, redirected_to) = render("/");
+    assert!(html.contains("Home"));
+    assert_eq!(redirected_to, None);
+
+    let (html, redirected_to) = render("/admin");
+    assert!(!html.contains("Admin"));
+    assert_eq!(redirected_to.as_deref(), Some("/login"));
+}
@@ -0,0 +1,58 @@
+#[cfg(feature = "ssr")]
+#[test]
+fn generates_service_worker_and_precache_manifest() {
+    use leptos::LeptosOptions;
+    use leptos_router::*;
+
+    let site_root = std::env::temp_dir()
+        .join(format!("leptos_router_sw_{}", std::process::id()));
+    let pkg = site_root.join("pkg");
+    std::fs::create_dir_all(&pkg).unwrap();
+    std::fs::write(pkg.join("app.wasm"), b"wasm").unwrap();
+    std::fs::write(pkg.join("app.js"), b"js").unwrap();
+
+    let options = LeptosOptions::builder()
+        .output_name("app")
+        .site_root(site_root.to_string_lossy().to_string())
+        .site_pkg_dir("pkg")
+        .build();
+    let routes = ["/", "/offline"]
+        .into_iter()
+        .map(|path| {
+            RouteListing::new(path, path, SsrMode::OutOfOrder, [], None)
+        })
+        .collect::<Vec<_>>();
+    let sw_options = ServiceWorkerOptions {
+        offline_route: Some("/offline".to_string()),
+        ..Default::default()
+    };
+
+    let manifest =
+        generate_service_worker(&options, &routes, &sw_options).unwrap();
+    let urls = manifest
+        .entries
+        .iter()
+        .map(|entry| entry.url.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(urls, ["/", "/offline", "/pkg/app.js", "/pkg/app.wasm"]);
+    assert!(manifest.entries[0].revision.is_none());
+    assert!(manifest.entries[2].revision.is_some());
+
+    let sw = std::fs::read_to_string(site_root.join("sw.js")).unwrap();
+    assert!(sw.contains(&format!("leptos-precache-{}", manifest.version)));
+    assert!(sw.contains(r#"const OFFLINE = "/offline";"#));
+    assert!(site_root.join("precache-manifest.json").exists());
+
+    // changing a file changes the version, so browsers update
+    std::fs::write(pkg.join("app.js"), b"js 2").unwrap();
+    let updated =
+        generate_service_worker(&options, &routes, &sw_options).unwrap();
+    assert_ne!(updated.version, manifest.version);
+
+    // the offline route has to be one of the app's routes
+    assert!(
+        generate_service_worker(&options, &routes[..1], &sw_options).is_err()
+    );
+
+    std::fs::remove_dir_all(site_root).unwrap();
+}
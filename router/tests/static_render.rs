@@ -0,0 +1,25 @@
+#[cfg(feature = "ssr")]
+#[test]
+fn invalidating_a_static_path_removes_its_file() {
+    use leptos::LeptosOptions;
+    use leptos_router::*;
+
+    let site_root = std::env::temp_dir()
+        .join(format!("leptos-static-render-{}", std::process::id()));
+    let options = LeptosOptions::builder()
+        .output_name("app")
+        .site_root(site_root.to_string_lossy().to_string())
+        .build();
+
+    let file = static_file_path(&options, "/posts/1");
+    std::fs::create_dir_all(site_root.join("posts")).unwrap();
+    std::fs::write(&file, "<p>stale</p>").unwrap();
+    assert_eq!(file, format!("{}/posts/1.html", site_root.display()));
+
+    invalidate_static_path(&options, "/posts/1").unwrap();
+    assert!(!std::path::Path::new(&file).exists());
+    // paths that have not been rendered are ignored
+    invalidate_static_path(&options, "/posts/2").unwrap();
+
+    std::fs::remove_dir_all(site_root).unwrap();
+}
@@ -0,0 +1,43 @@
+#[cfg(feature = "ssr")]
+#[test]
+fn sub_router_matches_and_links_within_its_base() {
+    use leptos::*;
+    use leptos_router::*;
+
+    #[component]
+    fn AdminApp() -> impl IntoView {
+        view! {
+            <SubRouter base="/admin">
+                <A href="/settings">"Settings"</A>
+                <Routes>
+                    <Route path="/" view=|| "Dashboard"/>
+                    <Route path="/users" view=|| view! {
+                        "Users"
+                        <A href="42">"User"</A>
+                    }/>
+                </Routes>
+            </SubRouter>
+        }
+    }
+
+    let runtime = create_runtime();
+    provide_context(RouterIntegrationContext::new(ServerIntegration {
+        path: "http://leptos.rs/admin/users".to_string(),
+    }));
+    let html = view! {
+        <Router>
+            <Routes>
+                <Route path="/" view=|| "Home"/>
+                <Route path="/admin/*any" view=AdminApp/>
+            </Routes>
+        </Router>
+    }
+    .into_view()
+    .render_to_string();
+
+    assert!(html.contains("Users"), "{html}");
+    assert!(!html.contains("Dashboard"), "{html}");
+    assert!(html.contains("href=\"/admin/settings\""), "{html}");
+    assert!(html.contains("href=\"/admin/users/42\""), "{html}");
+    runtime.dispose();
+}
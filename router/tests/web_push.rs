@@ -0,0 +1,28 @@
+use leptos_router::{PushSubscription, PushSubscriptionKeys};
+
+#[test]
+fn push_subscription_matches_browser_json() {
+    // the shape of `PushSubscription.toJSON()` in the browser
+    let json = r#"{
+        "endpoint": "https://push.example.com/send/abc",
+        "expirationTime": null,
+        "keys": { "p256dh": "BNcRdreALRFX", "auth": "tBHItJI5svbp" }
+    }"#;
+    let subscription: PushSubscription = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        subscription,
+        PushSubscription {
+            endpoint: "https://push.example.com/send/abc".to_string(),
+            expiration_time: None,
+            keys: PushSubscriptionKeys {
+                p256dh: "BNcRdreALRFX".to_string(),
+                auth: "tBHItJI5svbp".to_string(),
+            },
+        }
+    );
+
+    let round_trip: PushSubscription =
+        serde_json::from_str(&serde_json::to_string(&subscription).unwrap())
+            .unwrap();
+    assert_eq!(round_trip, subscription);
+}
@@ -1,7 +1,11 @@
 use crate::{error::ServerFnError, request::ClientReq, response::ClientRes};
-use std::{future::Future, sync::OnceLock};
+use std::{
+    future::Future,
+    sync::{Mutex, OnceLock},
+};
 
 static ROOT_URL: OnceLock<&'static str> = OnceLock::new();
+static REQUEST_ID: Mutex<Option<String>> = Mutex::new(None);
 
 /// Set the root server URL that all server function paths are relative to for the client.
 ///
@@ -15,6 +19,58 @@ pub fn get_server_url() -> &'static str {
     ROOT_URL.get().copied().unwrap_or("")
 }
 
+/// Sets the root server URL and the [`reqwest`](::reqwest) client that a
+/// native binary, like a desktop app, a CLI tool or an integration test, uses
+/// to call server functions.
+///
+/// Outside the browser there is no origin to resolve server function paths
+/// against, so the URL of the server must be set before any server function
+/// is called. The client can be used to configure things like timeouts, a
+/// cookie store or default headers; if this is not called, a default client
+/// is used.
+///
+/// ```rust,no_run
+/// # use server_fn::client::set_server_fn_base_url_and_client;
+/// set_server_fn_base_url_and_client(
+///     "http://localhost:3000",
+///     reqwest::Client::builder()
+///         .timeout(std::time::Duration::from_secs(10))
+///         .build()
+///         .unwrap(),
+/// );
+/// ```
+///
+/// # Panics
+/// Panics if the server URL has already been set, or if a server function has
+/// already been called with the default client.
+#[cfg(feature = "reqwest")]
+pub fn set_server_fn_base_url_and_client(
+    url: &'static str,
+    client: ::reqwest::Client,
+) {
+    set_server_url(url);
+    crate::request::reqwest::CLIENT
+        .set(client)
+        .expect("the server function client has already been set");
+}
+
+/// The header in which a server function request carries the ID set with
+/// [`set_request_id`], so the server can correlate the request with the page
+/// request or navigation that made it.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Sets the ID that is sent with every server function request made by the
+/// client, in the [`REQUEST_ID_HEADER`] header. Pass `None` to stop sending it.
+pub fn set_request_id(id: Option<String>) {
+    *REQUEST_ID.lock().unwrap_or_else(|e| e.into_inner()) = id;
+}
+
+/// Returns the ID that is sent with every server function request made by the
+/// client, if one has been set with [`set_request_id`].
+pub fn get_request_id() -> Option<String> {
+    REQUEST_ID.lock().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
 /// A client defines a pair of request/response types and the logic to send
 /// and receive them.
 ///
@@ -33,10 +89,32 @@ pub trait Client<CustErr> {
     ) -> impl Future<Output = Result<Self::Response, ServerFnError<CustErr>>> + Send;
 }
 
+/// The client that server functions use when no `client` is given to the
+/// `#[server]` macro.
+///
+/// This is [`reqwest::ReqwestClient`] when the `reqwest` feature is enabled,
+/// except in the browser, where it is [`browser::BrowserClient`].
+#[cfg(all(
+    feature = "browser",
+    any(not(feature = "reqwest"), target_arch = "wasm32")
+))]
+pub type DefaultClient = browser::BrowserClient;
+
+/// The client that server functions use when no `client` is given to the
+/// `#[server]` macro.
+///
+/// This is [`reqwest::ReqwestClient`] when the `reqwest` feature is enabled,
+/// except in the browser, where it is `browser::BrowserClient`.
+#[cfg(all(
+    feature = "reqwest",
+    not(all(feature = "browser", target_arch = "wasm32"))
+))]
+pub type DefaultClient = reqwest::ReqwestClient;
+
 #[cfg(feature = "browser")]
 /// Implements [`Client`] for a `fetch` request in the browser.
 pub mod browser {
-    use super::Client;
+    use super::{get_request_id, Client, REQUEST_ID_HEADER};
     use crate::{
         error::ServerFnError, request::browser::BrowserRequest,
         response::browser::BrowserResponse,
@@ -47,6 +125,37 @@ pub mod browser {
     /// Implements [`Client`] for a `fetch` request in the browser.    
     pub struct BrowserClient;
 
+    /// Sends server function requests to a custom URI scheme instead of the
+    /// origin of the page, so that an app in an embedded webview, like a Tauri
+    /// desktop app, can answer them itself without running an HTTP server.
+    ///
+    /// Webviews on Windows and Android reach a custom scheme at
+    /// `http://<scheme>.localhost`, and those on other platforms at
+    /// `<scheme>://localhost`; this picks the right one. On the native side,
+    /// requests to the scheme can be answered with
+    /// `server_fn::axum::handle_server_fn_bytes`.
+    ///
+    /// # Panics
+    /// Panics if the server URL has already been set.
+    pub fn set_custom_protocol(scheme: &str) {
+        let user_agent =
+            js_sys::Reflect::get(&js_sys::global(), &"navigator".into())
+                .and_then(|navigator| {
+                    js_sys::Reflect::get(&navigator, &"userAgent".into())
+                })
+                .ok()
+                .and_then(|user_agent| user_agent.as_string())
+                .unwrap_or_default();
+        let url = if user_agent.contains("Windows")
+            || user_agent.contains("Android")
+        {
+            format!("http://{scheme}.localhost")
+        } else {
+            format!("{scheme}://localhost")
+        };
+        super::set_server_url(Box::leak(url.into_boxed_str()));
+    }
+
     impl<CustErr> Client<CustErr> for BrowserClient {
         type Request = BrowserRequest;
         type Response = BrowserResponse;
@@ -56,9 +165,11 @@ pub mod browser {
         ) -> impl Future<Output = Result<Self::Response, ServerFnError<CustErr>>>
                + Send {
             SendWrapper::new(async move {
-                req.0
-                    .take()
-                    .send()
+                let req = req.0.take();
+                if let Some(id) = get_request_id() {
+                    req.headers().set(REQUEST_ID_HEADER, &id);
+                }
+                req.send()
                     .await
                     .map(|res| BrowserResponse(SendWrapper::new(res)))
                     .map_err(|e| ServerFnError::Request(e.to_string()))
@@ -70,10 +181,10 @@ pub mod browser {
 #[cfg(feature = "reqwest")]
 /// Implements [`Client`] for a request made by [`reqwest`].
 pub mod reqwest {
-    use super::Client;
-    use crate::{error::ServerFnError, request::reqwest::CLIENT};
+    use super::{get_request_id, Client, REQUEST_ID_HEADER};
+    use crate::{error::ServerFnError, request::reqwest::client};
     use futures::TryFutureExt;
-    use reqwest::{Request, Response};
+    use reqwest::{header::HeaderValue, Request, Response};
     use std::future::Future;
 
     /// Implements [`Client`] for a request made by [`reqwest`].
@@ -84,10 +195,15 @@ pub mod reqwest {
         type Response = Response;
 
         fn send(
-            req: Self::Request,
+            mut req: Self::Request,
         ) -> impl Future<Output = Result<Self::Response, ServerFnError<CustErr>>>
                + Send {
-            CLIENT
+            if let Some(id) =
+                get_request_id().and_then(|id| HeaderValue::from_str(&id).ok())
+            {
+                req.headers_mut().insert(REQUEST_ID_HEADER, id);
+            }
+            client()
                 .execute(req)
                 .map_err(|e| ServerFnError::Request(e.to_string()))
         }
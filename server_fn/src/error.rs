@@ -413,6 +413,90 @@ impl<CustErr> From<ServerFnError<CustErr>> for ServerFnErrorErr<CustErr> {
     }
 }
 
+/// An error that can be sent from the server to the client, to be shown by
+/// the client in the same way as on the server.
+///
+/// Errors in general cannot be serialized, so a resource that returns
+/// `Result<T, E>` can only send its error to the client if `E` can be. A
+/// resource can return a `SerializedError` instead, which keeps a URL that
+/// identifies the kind of the original error, its message, and optionally a
+/// typed payload with more details.
+///
+/// The type URL is chosen by the caller, so that it stays the same across
+/// compiler versions and refactors, and the client can compare it to tell
+/// errors apart.
+///
+/// ```
+/// # use server_fn::error::SerializedError;
+/// const INVALID_ID: &str = "https://example.com/errors/invalid-id";
+///
+/// let error = "abc".parse::<u32>().unwrap_err();
+/// let error = SerializedError::new(INVALID_ID, &error).with_payload(&404);
+///
+/// assert_eq!(error.type_url(), INVALID_ID);
+/// assert_eq!(error.to_string(), "invalid digit found in string");
+/// assert_eq!(error.payload::<u16>(), Some(404));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SerializedError {
+    type_url: String,
+    message: String,
+    payload: Option<String>,
+}
+
+impl SerializedError {
+    /// Creates a serialized copy of the error, with its message and the given
+    /// type URL, e.g., a stable URL that documents the error.
+    pub fn new<E>(type_url: impl Into<String>, error: &E) -> Self
+    where
+        E: error::Error + ?Sized,
+    {
+        Self {
+            type_url: type_url.into(),
+            message: error.to_string(),
+            payload: None,
+        }
+    }
+
+    /// Adds a payload, which is serialized as JSON. If it cannot be
+    /// serialized, the error has no payload.
+    pub fn with_payload<P>(mut self, payload: &P) -> Self
+    where
+        P: Serialize + ?Sized,
+    {
+        self.payload = serde_json::to_string(payload).ok();
+        self
+    }
+
+    /// The URL that identifies the type of the original error.
+    pub fn type_url(&self) -> &str {
+        &self.type_url
+    }
+
+    /// The message of the original error.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Deserializes the payload, if there is one of type `P`.
+    pub fn payload<P>(&self) -> Option<P>
+    where
+        P: serde::de::DeserializeOwned,
+    {
+        self.payload
+            .as_deref()
+            .and_then(|payload| serde_json::from_str(payload).ok())
+    }
+}
+
+impl Display for SerializedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl error::Error for SerializedError {}
+
 /// Associates a particular server function error with the server function
 /// found at a particular path.
 ///
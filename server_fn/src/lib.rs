@@ -521,6 +521,41 @@ pub mod axum {
         }
     }
 
+    /// Responds to a server function request whose body has already been
+    /// read, and reads the whole body of the response.
+    ///
+    /// This lets an app in an embedded webview, like a Tauri desktop app,
+    /// answer server function requests made to a custom URI scheme without
+    /// running an HTTP server. The webview should send them to that scheme
+    /// with [`set_custom_protocol`](crate::client::browser::set_custom_protocol).
+    ///
+    /// ```rust,ignore
+    /// tauri::Builder::default()
+    ///     .register_asynchronous_uri_scheme_protocol(
+    ///         "leptos",
+    ///         |_ctx, req, responder| {
+    ///             tauri::async_runtime::spawn(async move {
+    ///                 responder.respond(handle_server_fn_bytes(req).await);
+    ///             });
+    ///         },
+    ///     )
+    /// ```
+    pub async fn handle_server_fn_bytes(
+        req: Request<Vec<u8>>,
+    ) -> Response<Vec<u8>> {
+        use http_body_util::BodyExt;
+
+        let (parts, body) =
+            handle_server_fn(req.map(Body::from)).await.into_parts();
+        match body.collect().await {
+            Ok(body) => Response::from_parts(parts, body.to_bytes().to_vec()),
+            Err(e) => Response::builder()
+                .status(StatusCode::INTERNAL_SERVER_ERROR)
+                .body(e.to_string().into_bytes())
+                .unwrap(),
+        }
+    }
+
     /// Returns the server function at the given path as a service that can be modified.
     pub fn get_server_fn_service(
         path: &str,
@@ -2,11 +2,18 @@ use super::ClientReq;
 use crate::{client::get_server_url, error::ServerFnError};
 use bytes::Bytes;
 use futures::Stream;
-use once_cell::sync::Lazy;
 use reqwest::header::{ACCEPT, CONTENT_TYPE};
 pub use reqwest::{multipart::Form, Client, Method, Request, Url};
+use std::sync::OnceLock;
 
-pub(crate) static CLIENT: Lazy<Client> = Lazy::new(Client::new);
+pub(crate) static CLIENT: OnceLock<Client> = OnceLock::new();
+
+/// Returns the client set with
+/// [`set_server_fn_base_url_and_client`](crate::client::set_server_fn_base_url_and_client),
+/// or a default client.
+pub(crate) fn client() -> &'static Client {
+    CLIENT.get_or_init(Client::new)
+}
 
 impl<CustErr> ClientReq<CustErr> for Request {
     type FormData = Form;
@@ -21,7 +28,7 @@ impl<CustErr> ClientReq<CustErr> for Request {
         let mut url = Url::try_from(url.as_str())
             .map_err(|e| ServerFnError::Request(e.to_string()))?;
         url.set_query(Some(query));
-        let req = CLIENT
+        let req = client()
             .get(url)
             .header(CONTENT_TYPE, content_type)
             .header(ACCEPT, accepts)
@@ -37,7 +44,7 @@ impl<CustErr> ClientReq<CustErr> for Request {
         body: String,
     ) -> Result<Self, ServerFnError<CustErr>> {
         let url = format!("{}{}", get_server_url(), path);
-        CLIENT
+        client()
             .post(url)
             .header(CONTENT_TYPE, content_type)
             .header(ACCEPT, accepts)
@@ -53,7 +60,7 @@ impl<CustErr> ClientReq<CustErr> for Request {
         body: Bytes,
     ) -> Result<Self, ServerFnError<CustErr>> {
         let url = format!("{}{}", get_server_url(), path);
-        CLIENT
+        client()
             .post(url)
             .header(CONTENT_TYPE, content_type)
             .header(ACCEPT, accepts)
@@ -67,8 +74,9 @@ impl<CustErr> ClientReq<CustErr> for Request {
         accepts: &str,
         body: Self::FormData,
     ) -> Result<Self, ServerFnError<CustErr>> {
-        CLIENT
-            .post(path)
+        let url = format!("{}{}", get_server_url(), path);
+        client()
+            .post(url)
             .header(ACCEPT, accepts)
             .multipart(body)
             .build()
@@ -81,8 +89,9 @@ impl<CustErr> ClientReq<CustErr> for Request {
         content_type: &str,
         body: Self::FormData,
     ) -> Result<Self, ServerFnError<CustErr>> {
-        CLIENT
-            .post(path)
+        let url = format!("{}{}", get_server_url(), path);
+        client()
+            .post(url)
             .header(CONTENT_TYPE, content_type)
             .header(ACCEPT, accepts)
             .multipart(body)
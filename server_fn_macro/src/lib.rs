@@ -431,13 +431,9 @@ pub fn server_macro_impl(
 
     let client = if let Some(client) = client {
         client.to_token_stream()
-    } else if cfg!(feature = "reqwest") {
-        quote! {
-            #server_fn_path::client::reqwest::ReqwestClient
-        }
     } else {
         quote! {
-            #server_fn_path::client::browser::BrowserClient
+            #server_fn_path::client::DefaultClient
         }
     };
 